@@ -29,6 +29,10 @@ func _enter_tree() -> void:
 	# Initialize project settings
 	ProjectSetting.binary_path()
 	ProjectSetting.output_directory()
+	ProjectSetting.typed_dictionaries()
+	ProjectSetting.tagged()
+	ProjectSetting.class_names()
+	ProjectSetting.instantiate_messages()
 
 	# Clear platform cache
 	Platform.clear_cache()
@@ -97,6 +97,18 @@ func _import(
 		["generate", "-o", output_dir_fs, "-I", import_root_fs, source_file_fs]
 	)
 
+	if not ProjectSetting.typed_dictionaries().get_value():
+		args.append("--untyped-dictionaries")
+
+	if ProjectSetting.tagged().get_value():
+		args.append("--tagged")
+
+	if ProjectSetting.class_names().get_value():
+		args.append("--class-names")
+
+	if ProjectSetting.instantiate_messages().get_value():
+		args.append("--instantiate-messages")
+
 	# Step 5: Execute binary.
 	var output: Array = []
 	var exit_code := OS.execute(binary_path, args, output, true)
@@ -44,6 +44,39 @@ static func output_directory() -> ProjectSetting:
 	)
 
 
+## `typed_dictionaries` returns a new `ProjectSetting` instance for the setting which
+## controls whether map fields are generated as typed dictionaries. Typed dictionaries
+## require Godot 4.4 or later, so this defaults to whether the engine supports them.
+static func typed_dictionaries() -> ProjectSetting:
+	return ProjectSetting.new(
+		"baproto/generate/typed_dictionaries",
+		Engine.get_version_info().hex >= 0x040400,
+		false,
+		TYPE_BOOL
+	)
+
+
+## `tagged` returns a new `ProjectSetting` instance for the setting which controls
+## whether messages are generated using the tagged wire format. Tagged messages can be
+## decoded by older and newer versions of a schema, at the cost of a larger encoding.
+static func tagged() -> ProjectSetting:
+	return ProjectSetting.new("baproto/generate/tagged", false, false, TYPE_BOOL)
+
+
+## `class_names` returns a new `ProjectSetting` instance for the setting which controls
+## whether each generated type registers a global, package-prefixed `class_name`.
+static func class_names() -> ProjectSetting:
+	return ProjectSetting.new("baproto/generate/class_names", false, false, TYPE_BOOL)
+
+
+## `instantiate_messages` returns a new `ProjectSetting` instance for the setting which
+## controls whether required message and enum fields are constructed with their message.
+static func instantiate_messages() -> ProjectSetting:
+	return ProjectSetting.new(
+		"baproto/generate/instantiate_messages", false, false, TYPE_BOOL
+	)
+
+
 ## `clear` completely removes the setting from `ProjectSettings`.
 ##
 ## NOTE: This is the same thing as calling `set_value` with `null`.
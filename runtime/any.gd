@@ -0,0 +1,92 @@
+##
+## any.gd
+##
+## A shared library providing an envelope for messages whose type is only known at
+## runtime. Each envelope consists of the message's stable type id (as an unsigned
+## 32-bit integer), the size of its serialized data (as an unsigned LEB128 varint), and
+## the serialized data itself. Types are looked up in a registry, which maps each type
+## id to a generated message script (see `TYPES` in the generated root `mod.gd`).
+##
+
+extends RefCounted
+
+# -- DEPENDENCIES -------------------------------------------------------------------- #
+
+const Reader := preload("./reader.gd")
+const Writer := preload("./writer.gd")
+
+# -- PUBLIC METHODS ------------------------------------------------------------------ #
+
+
+## `get_type_id` returns the type id of `message` within `registry`, or `-1` if its
+## script isn't registered.
+static func get_type_id(registry: Dictionary, message: Object) -> int:
+	if message == null:
+		return -1
+
+	var type_id: Variant = registry.find_key(message.get_script())
+	if type_id == null:
+		return -1
+
+	return type_id
+
+
+## `write` writes `message` to `writer` as an envelope. If the message's type isn't in
+## `registry`, `ERR_INVALID_PARAMETER` is set and nothing is written.
+static func write(writer: Writer, registry: Dictionary, message: Object) -> Error:
+	var type_id := get_type_id(registry, message)
+	if type_id < 0:
+		writer.set_error(ERR_INVALID_PARAMETER)
+		return writer.get_error()
+
+	var payload := PackedByteArray()
+	var err: Error = message.serialize(payload)
+	if err != OK:
+		writer.set_error(err)
+		return writer.get_error()
+
+	writer.write_u32(type_id)
+	writer.write_varint_unsigned(payload.size())
+	writer.write_bytes(payload)
+
+	return writer.get_error()
+
+
+## `read` reads an envelope from `reader` and stores the decoded message in `out[0]`.
+## If the message's type isn't in `registry`, its data is skipped (leaving `reader`
+## positioned after the envelope) and `ERR_UNAVAILABLE` is returned. If the message's
+## size exceeds the reader's `max_message_size`, `Reader.ERR_LIMIT_EXCEEDED` is
+## returned.
+static func read(reader: Reader, registry: Dictionary, out: Array) -> Error:
+	out.resize(1)
+	out[0] = null
+
+	var type_id := reader.read_u32()
+	var size := reader.read_varint_unsigned()
+	if reader.get_error() != OK:
+		return reader.get_error()
+
+	# NOTE: The payload is a whole message, so it's limited by the maximum message size
+	# rather than the (smaller) maximum size of a bytes field.
+	var payload := reader.read_bytes(size, reader.max_message_size)
+	if reader.get_error() != OK:
+		return reader.get_error()
+
+	var script: Script = registry.get(type_id)
+	if script == null:
+		return ERR_UNAVAILABLE
+
+	var message: Object = script.new()
+	var err: Error = message.deserialize(payload)
+	if err != OK:
+		return err
+
+	out[0] = message
+	return OK
+
+
+# -- ENGINE METHODS (OVERRIDES) ------------------------------------------------------ #
+
+
+func _init() -> void:
+	assert(false, "Invalid config; this 'Object' should not be instantiated!")
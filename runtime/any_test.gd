@@ -0,0 +1,189 @@
+##
+## any_test.gd
+##
+## Test suite for the type-erased message envelope.
+##
+
+extends GutTest
+
+# -- DEPENDENCIES -------------------------------------------------------------------- #
+
+const Any := preload("res://runtime/any.gd")
+const Reader := preload("res://runtime/reader.gd")
+const Writer := preload("res://runtime/writer.gd")
+
+# -- DEFINITIONS --------------------------------------------------------------------- #
+
+
+class Counter:
+	extends RefCounted
+
+	var value: int = 0
+
+	func serialize(out: PackedByteArray) -> Error:
+		var writer := Writer.new()
+		writer.write_varint_unsigned(value)
+		out.append_array(writer.to_bytes())
+		return writer.get_error()
+
+	func deserialize(data: PackedByteArray) -> Error:
+		var reader := Reader.new(data)
+		value = reader.read_varint_unsigned()
+		return reader.get_error()
+
+
+class Label:
+	extends RefCounted
+
+	var text: String = ""
+
+	func serialize(out: PackedByteArray) -> Error:
+		var writer := Writer.new()
+		writer.write_string(text)
+		out.append_array(writer.to_bytes())
+		return writer.get_error()
+
+	func deserialize(data: PackedByteArray) -> Error:
+		var reader := Reader.new(data)
+		text = reader.read_string()
+		return reader.get_error()
+
+
+# -- TEST METHODS -------------------------------------------------------------------- #
+
+
+func test_write_read_roundtrip_decodes_registered_types() -> void:
+	# Given: A registry of message types.
+	var registry := {1: Counter, 2: Label}
+
+	# Given: A stream containing one message of each type.
+	var counter := Counter.new()
+	counter.value = 300
+	var label := Label.new()
+	label.text = "abc"
+
+	var writer := Writer.new()
+	assert_eq(Any.write(writer, registry, counter), OK)
+	assert_eq(Any.write(writer, registry, label), OK)
+
+	# When: The messages are read back.
+	var reader := Reader.new(writer.to_bytes())
+	var first := []
+	var first_err := Any.read(reader, registry, first)
+	var second := []
+	var second_err := Any.read(reader, registry, second)
+
+	# Then: Each message is decoded into its original type.
+	assert_eq(first_err, OK)
+	assert_true(first[0] is Counter)
+	assert_eq(first[0].value, 300)
+	assert_eq(second_err, OK)
+	assert_true(second[0] is Label)
+	assert_eq(second[0].text, "abc")
+	assert_true(reader.is_at_end())
+
+
+func test_write_unregistered_type_sets_error() -> void:
+	# Given: A registry which doesn't contain the message's type.
+	var registry := {1: Counter}
+
+	# When: The message is written.
+	var writer := Writer.new()
+	var err := Any.write(writer, registry, Label.new())
+
+	# Then: An error is returned and nothing is written.
+	assert_eq(err, ERR_INVALID_PARAMETER)
+	assert_eq(writer.get_error(), ERR_INVALID_PARAMETER)
+	assert_eq(writer.to_bytes(), PackedByteArray())
+
+
+func test_read_unregistered_type_skips_message() -> void:
+	# Given: A stream containing an unknown message followed by a known one.
+	var counter := Counter.new()
+	counter.value = 7
+
+	var writer := Writer.new()
+	Any.write(writer, {2: Label}, Label.new())
+	Any.write(writer, {1: Counter}, counter)
+
+	# When: The messages are read with a registry missing the first type.
+	var reader := Reader.new(writer.to_bytes())
+	var first := []
+	var first_err := Any.read(reader, {1: Counter}, first)
+	var second := []
+	var second_err := Any.read(reader, {1: Counter}, second)
+
+	# Then: The unknown message is skipped.
+	assert_eq(first_err, ERR_UNAVAILABLE)
+	assert_null(first[0])
+
+	# Then: The following message is decoded.
+	assert_eq(second_err, OK)
+	assert_eq(second[0].value, 7)
+
+
+func test_read_payload_larger_than_bytes_limit() -> void:
+	# Given: A message whose 6-byte payload exceeds the limit on bytes fields.
+	var counter := Counter.new()
+	counter.value = 1 << 40
+
+	var writer := Writer.new()
+	assert_eq(Any.write(writer, {1: Counter}, counter), OK)
+
+	# When: The message is read by a reader with a lowered bytes limit.
+	var reader := Reader.new(writer.to_bytes())
+	reader.max_bytes_length = 2
+	var out := []
+	var err := Any.read(reader, {1: Counter}, out)
+
+	# Then: The message is decoded.
+	assert_eq(err, OK)
+	assert_eq(out[0].value, 1 << 40)
+	assert_true(reader.is_at_end())
+
+
+func test_read_payload_at_message_size_limit(
+	params = use_parameters(
+		[
+			[6, OK],
+			[5, Reader.ERR_LIMIT_EXCEEDED],
+		]
+	)
+) -> void:
+	# Given: A stream containing a message with a 6-byte payload.
+	var counter := Counter.new()
+	counter.value = 1 << 40
+
+	var writer := Writer.new()
+	assert_eq(Any.write(writer, {1: Counter}, counter), OK)
+	var reader := Reader.new(writer.to_bytes())
+
+	# Given: A maximum message size.
+	reader.max_message_size = params[0]
+
+	# When: The message is read.
+	var out := []
+	var err := Any.read(reader, {1: Counter}, out)
+
+	# Then: Only a payload within the limit is decoded.
+	assert_eq(err, params[1])
+	assert_eq(out[0] != null, params[1] == OK)
+
+
+func test_get_type_id(
+	params = use_parameters(
+		[
+			[Counter.new(), 1],
+			[Label.new(), -1],
+			[null, -1],
+		]
+	)
+) -> void:
+	# Given: A registry of message types.
+	var registry := {1: Counter}
+
+	# When: The message's type id is looked up.
+	var type_id := Any.get_type_id(registry, params[0])
+
+	# Then: The type id matches expectations.
+	assert_eq(type_id, params[1])
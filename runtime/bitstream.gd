@@ -64,6 +64,12 @@ func seek(position: int) -> void:
 	_position = position
 
 
+## `set_error` records an error encountered while encoding or decoding data. Only the
+## first error is retained; subsequent calls have no effect until `clear` is called.
+func set_error(err: Error) -> void:
+	_set_error(err)
+
+
 ## `set_data` resets the `BitStream` to the provided `data` array. Any errors or cursor
 ## positioning will be reset.
 func set_data(data: PackedByteArray) -> void:
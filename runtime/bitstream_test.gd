@@ -74,6 +74,19 @@ func test_bitstream_error_persistence() -> void:
 	assert_eq(bs.get_error(), ERR_INVALID_PARAMETER)
 
 
+func test_bitstream_set_error_keeps_first_error() -> void:
+	# Given: A bitstream.
+	var bs := BitStream.new()
+
+	# When: Setting multiple errors.
+	bs.set_error(ERR_INVALID_DATA)
+	bs.set_error(ERR_FILE_EOF)
+
+	# Then: Only the first error is retained.
+	assert_false(bs.is_valid())
+	assert_eq(bs.get_error(), ERR_INVALID_DATA)
+
+
 func test_bitstream_seek_invalid() -> void:
 	# Given: A bitstream with some data.
 	var bs := BitStream.new(PackedByteArray([0xFF]))
@@ -1,8 +1,9 @@
 ##
 ## encoding.gd
 ##
-## A shared library providing bit-level binary serialization utilities. Supports ZigZag
-## encoding and common constants for bit stream operations.
+## A shared library providing bit-level binary serialization utilities. Supports the
+## value transforms applied before writing (ZigZag and float quantization) and common
+## constants for bit stream operations.
 ##
 
 extends RefCounted
@@ -26,6 +27,33 @@ static func zigzag_decode(value: int) -> int:
 	return (value >> 1) ^ (-(value & 1))
 
 
+## `quantize` maps a float within `[minimum, maximum]` onto an unsigned integer using
+## the specified number of bits (1-63). Values outside the range are clamped.
+static func quantize(
+	value: float, minimum: float, maximum: float, bit_count: int
+) -> int:
+	assert(bit_count > 0 and bit_count < 64, "Invalid argument; bit count out of range")
+	assert(maximum > minimum, "Invalid argument; range is empty")
+
+	var steps := (1 << bit_count) - 1
+	var normalized := (clampf(value, minimum, maximum) - minimum) / (maximum - minimum)
+
+	return clampi(roundi(normalized * steps), 0, steps)
+
+
+## `dequantize` maps an unsigned integer produced by `quantize` back onto a float
+## within `[minimum, maximum]`.
+static func dequantize(
+	value: int, minimum: float, maximum: float, bit_count: int
+) -> float:
+	assert(bit_count > 0 and bit_count < 64, "Invalid argument; bit count out of range")
+	assert(maximum > minimum, "Invalid argument; range is empty")
+
+	var steps := (1 << bit_count) - 1
+
+	return minimum + (float(clampi(value, 0, steps)) / steps) * (maximum - minimum)
+
+
 # -- ENGINE METHODS (OVERRIDES) ------------------------------------------------------ #
 
 
@@ -63,6 +63,41 @@ func test_zigzag_roundtrip() -> void:
 		assert_eq(decoded, val, "Roundtrip failed for %d" % val)
 
 
+func test_quantize_range_endpoints() -> void:
+	# Given: A range of [-1, 1] quantized to 8 bits.
+	# When: Quantizing the range endpoints.
+	var low := Encoding.quantize(-1.0, -1.0, 1.0, 8)
+	var high := Encoding.quantize(1.0, -1.0, 1.0, 8)
+
+	# Then: The endpoints map to the extremes of the integer range.
+	assert_eq(low, 0)
+	assert_eq(high, 255)
+
+
+func test_quantize_clamps_out_of_range() -> void:
+	# Given: Values outside of a range of [0, 10].
+	# When: Quantizing the values.
+	var low := Encoding.quantize(-5.0, 0.0, 10.0, 4)
+	var high := Encoding.quantize(50.0, 0.0, 10.0, 4)
+
+	# Then: The values are clamped to the range.
+	assert_eq(low, 0)
+	assert_eq(high, 15)
+
+
+func test_quantize_roundtrip_within_precision() -> void:
+	# Given: Various values within a range of [0, 100].
+	var values := [0.0, 12.5, 33.3, 50.0, 99.9, 100.0]
+
+	for val in values:
+		# When: Quantizing to 16 bits then dequantizing.
+		var encoded := Encoding.quantize(val, 0.0, 100.0, 16)
+		var decoded := Encoding.dequantize(encoded, 0.0, 100.0, 16)
+
+		# Then: The value is recovered within the quantization step.
+		assert_almost_eq(decoded, val, 100.0 / 65535.0, "Roundtrip failed for %f" % val)
+
+
 # -- TEST HOOKS ---------------------------------------------------------------------- #
 
 
@@ -0,0 +1,129 @@
+##
+## framing.gd
+##
+## A shared library providing length-delimited framing of serialized messages, which
+## allows multiple messages to be concatenated in a single buffer or stream. Each frame
+## consists of the payload's size (as an unsigned LEB128 varint) followed by the
+## payload.
+##
+
+extends RefCounted
+
+# -- DEPENDENCIES -------------------------------------------------------------------- #
+
+const Encoding := preload("./encoding.gd")
+const Reader := preload("./reader.gd")
+const Writer := preload("./writer.gd")
+
+# -- DEFINITIONS --------------------------------------------------------------------- #
+
+## `INCOMPLETE` is returned in place of a byte count when the data ends before a
+## complete frame; reading can be retried once more data is available.
+const INCOMPLETE := 0
+
+## `INVALID` is returned in place of a byte count when the data contains a malformed
+## frame or a message which can't be decoded.
+const INVALID := -1
+
+# -- PUBLIC METHODS ------------------------------------------------------------------ #
+
+
+## `write_frame` appends `payload` to `out` as a length-delimited frame.
+static func write_frame(payload: PackedByteArray, out: PackedByteArray) -> void:
+	var writer := Writer.new()
+	writer.write_varint_unsigned(payload.size())
+
+	out.append_array(writer.to_bytes())
+	out.append_array(payload)
+
+
+## `read_frame` reads the length-delimited frame in `data` starting at `offset` and
+## stores its payload in `out[0]`. Returns the number of bytes consumed (including the
+## length prefix), `INCOMPLETE` if `data` ends before the frame does, or `INVALID` if
+## the length prefix is malformed or exceeds `max_size`.
+static func read_frame(
+	data: PackedByteArray,
+	offset: int,
+	out: Array,
+	max_size: int = Reader.DEFAULT_MAX_MESSAGE_SIZE,
+) -> int:
+	out.resize(1)
+	out[0] = null
+
+	if offset < 0 or offset > data.size():
+		return INVALID
+
+	var size := 0
+	var position := offset
+
+	while true:
+		if position - offset >= Encoding.VARINT_BYTES_MAX:
+			return INVALID
+		if position >= data.size():
+			return INCOMPLETE
+
+		var byte := data[position]
+		size |= (byte & 0x7F) << (7 * (position - offset))
+		position += 1
+
+		if byte & 0x80 == 0:
+			break
+
+	if size < 0 or size > max_size:
+		return INVALID
+	if position + size > data.size():
+		return INCOMPLETE
+
+	out[0] = data.slice(position, position + size)
+	return position + size - offset
+
+
+## `read_messages` decodes consecutive length-delimited messages of type `script` (a
+## generated message or enum) from `data`, starting at `offset`, and appends them to
+## `out`. Reading stops at the end of `data` or at an incomplete frame, whose bytes are
+## left unconsumed. Returns the number of bytes consumed or `INVALID` if a frame or
+## message is invalid.
+static func read_messages(
+	data: PackedByteArray, script: Script, out: Array, offset: int = 0
+) -> int:
+	var frame := [null]
+	var position := offset
+
+	while true:
+		var size := read_frame(data, position, frame)
+		if size == INVALID:
+			return INVALID
+		if size == INCOMPLETE:
+			break
+
+		var message: Object = script.new()
+		if message.deserialize(frame[0]) != OK:
+			return INVALID
+
+		out.append(message)
+		position += size
+
+	return position - offset
+
+
+## `read_messages_from_stream` decodes consecutive length-delimited messages of type
+## `script` from `stream`, starting at its current position, and appends them to
+## `out`. The stream's position is advanced past all complete frames. Returns the number
+## of bytes consumed or `INVALID` if a frame or message is invalid.
+static func read_messages_from_stream(
+	stream: StreamPeerBuffer, script: Script, out: Array
+) -> int:
+	var position := stream.get_position()
+
+	var consumed := read_messages(stream.data_array, script, out, position)
+	if consumed > 0:
+		stream.seek(position + consumed)
+
+	return consumed
+
+
+# -- ENGINE METHODS (OVERRIDES) ------------------------------------------------------ #
+
+
+func _init() -> void:
+	assert(false, "Invalid config; this 'Object' should not be instantiated!")
@@ -0,0 +1,165 @@
+##
+## framing_test.gd
+##
+## Test suite for length-delimited framing utilities.
+##
+
+extends GutTest
+
+# -- DEPENDENCIES -------------------------------------------------------------------- #
+
+const Framing := preload("res://runtime/framing.gd")
+const Reader := preload("res://runtime/reader.gd")
+const Writer := preload("res://runtime/writer.gd")
+
+# -- DEFINITIONS --------------------------------------------------------------------- #
+
+
+class Counter:
+	extends RefCounted
+
+	var value: int = 0
+
+	func serialize(out: PackedByteArray) -> Error:
+		var writer := Writer.new()
+		writer.write_varint_unsigned(value)
+		out.append_array(writer.to_bytes())
+		return writer.get_error()
+
+	func deserialize(data: PackedByteArray) -> Error:
+		var reader := Reader.new(data)
+		value = reader.read_varint_unsigned()
+		if reader.get_error() == OK and not reader.is_at_end():
+			return ERR_INVALID_DATA
+		return reader.get_error()
+
+
+# -- TEST METHODS -------------------------------------------------------------------- #
+
+
+func test_read_frame_roundtrip() -> void:
+	# Given: A buffer containing two frames.
+	var data := PackedByteArray()
+	Framing.write_frame(PackedByteArray([1, 2, 3]), data)
+	Framing.write_frame(PackedByteArray(), data)
+
+	# When: The frames are read.
+	var out := []
+	var first := Framing.read_frame(data, 0, out)
+	var first_payload: PackedByteArray = out[0]
+	var second := Framing.read_frame(data, first, out)
+
+	# Then: Each frame's payload and size are returned.
+	assert_eq(first, 4)
+	assert_eq(first_payload, PackedByteArray([1, 2, 3]))
+	assert_eq(second, 1)
+	assert_eq(out[0], PackedByteArray())
+
+
+func test_read_frame_incomplete(
+	params = use_parameters(
+		[
+			[PackedByteArray()],
+			[PackedByteArray([0x80])],
+			[PackedByteArray([3, 1])],
+		]
+	)
+) -> void:
+	# Given: A buffer ending before the frame does.
+	var data: PackedByteArray = params[0]
+
+	# When: The frame is read.
+	var out := []
+	var size := Framing.read_frame(data, 0, out)
+
+	# Then: The frame is reported as incomplete.
+	assert_eq(size, Framing.INCOMPLETE)
+	assert_null(out[0])
+
+
+func test_read_frame_invalid_length() -> void:
+	# Given: A length prefix exceeding the maximum message size.
+	var writer := Writer.new()
+	writer.write_varint_unsigned(Reader.DEFAULT_MAX_MESSAGE_SIZE + 1)
+	var data := writer.to_bytes()
+
+	# When: The frame is read.
+	var size := Framing.read_frame(data, 0, [])
+
+	# Then: The frame is reported as invalid.
+	assert_eq(size, Framing.INVALID)
+
+
+func test_read_frame_exceeds_custom_max_size() -> void:
+	# Given: A complete frame with a 3-byte payload.
+	var data := PackedByteArray()
+	Framing.write_frame(PackedByteArray([0x01, 0x02, 0x03]), data)
+
+	# When: The frame is read with a 2-byte maximum size.
+	var size := Framing.read_frame(data, 0, [], 2)
+
+	# Then: The frame is reported as invalid.
+	assert_eq(size, Framing.INVALID)
+
+
+func test_read_messages_leaves_partial_frame() -> void:
+	# Given: A buffer containing two messages and the start of a third.
+	var data := PackedByteArray()
+	for value in [1, 300]:
+		var message := Counter.new()
+		message.value = value
+
+		var payload := PackedByteArray()
+		message.serialize(payload)
+		Framing.write_frame(payload, data)
+
+	var complete := data.size()
+	data.append_array(PackedByteArray([2, 0]))
+
+	# When: The messages are read.
+	var out := []
+	var consumed := Framing.read_messages(data, Counter, out)
+
+	# Then: Only the complete messages are consumed.
+	assert_eq(consumed, complete)
+	assert_eq(out.size(), 2)
+	assert_eq(out[0].value, 1)
+	assert_eq(out[1].value, 300)
+
+
+func test_read_messages_invalid_message() -> void:
+	# Given: A frame whose payload isn't a valid message.
+	var data := PackedByteArray()
+	Framing.write_frame(PackedByteArray([0x80]), data)
+
+	# When: The messages are read.
+	var consumed := Framing.read_messages(data, Counter, [])
+
+	# Then: The data is reported as invalid.
+	assert_eq(consumed, Framing.INVALID)
+
+
+func test_read_messages_from_stream_advances_position() -> void:
+	# Given: A stream containing a framed message followed by a partial frame.
+	var message := Counter.new()
+	message.value = 7
+
+	var payload := PackedByteArray()
+	message.serialize(payload)
+
+	var data := PackedByteArray()
+	Framing.write_frame(payload, data)
+	data.append(5)
+
+	var stream := StreamPeerBuffer.new()
+	stream.data_array = data
+
+	# When: The messages are read from the stream.
+	var out := []
+	var consumed := Framing.read_messages_from_stream(stream, Counter, out)
+
+	# Then: The stream is positioned at the start of the partial frame.
+	assert_eq(consumed, 2)
+	assert_eq(stream.get_position(), 2)
+	assert_eq(out.size(), 1)
+	assert_eq(out[0].value, 7)
@@ -11,14 +11,72 @@ extends "./bitstream.gd"
 
 const Encoding := preload("./encoding.gd")
 
+# -- DEFINITIONS --------------------------------------------------------------------- #
+
+## `ERR_LIMIT_EXCEEDED` is the error set when data exceeds one of the reader's decoding
+## limits (see `max_collection_length`, `max_bytes_length`, `max_depth`, and
+## `max_message_size`). Its value lies outside the engine's `Error` values, so it can't
+## be confused with errors caused by truncated or invalid data.
+@warning_ignore("int_as_enum_without_match")
+const ERR_LIMIT_EXCEEDED := 256 as Error
+
+## `DEFAULT_MAX_COLLECTION_LENGTH` is the default value of `max_collection_length`.
+const DEFAULT_MAX_COLLECTION_LENGTH := 1 << 16
+
+## `DEFAULT_MAX_BYTES_LENGTH` is the default value of `max_bytes_length`.
+const DEFAULT_MAX_BYTES_LENGTH := 1 << 20
+
+## `DEFAULT_MAX_DEPTH` is the default value of `max_depth`.
+const DEFAULT_MAX_DEPTH := 64
+
+## `DEFAULT_MAX_MESSAGE_SIZE` is the default value of `max_message_size`.
+const DEFAULT_MAX_MESSAGE_SIZE := 1 << 24
+
 # -- INITIALIZATION ------------------------------------------------------------------ #
 
+## `max_collection_length` is the maximum number of elements in a decoded array or map.
+var max_collection_length: int = DEFAULT_MAX_COLLECTION_LENGTH
+
+## `max_bytes_length` is the maximum size, in bytes, of a decoded string or byte array.
+var max_bytes_length: int = DEFAULT_MAX_BYTES_LENGTH
+
+## `max_depth` is the maximum nesting depth of decoded messages.
+var max_depth: int = DEFAULT_MAX_DEPTH
+
+## `max_message_size` is the maximum size, in bytes, of the data passed to this reader.
+## It's checked whenever data is loaded, so a custom limit applies to data passed to
+## `set_data` after the limit is changed.
+var max_message_size: int = DEFAULT_MAX_MESSAGE_SIZE
+
+## `validate_padding` controls whether `skip_padding` verifies that skipped padding bits
+## are zero. When enabled, non-zero padding sets `ERR_INVALID_DATA`.
+var validate_padding: bool = false
+
 static var _f32_bytes := PackedByteArray([0, 0, 0, 0])
 static var _f64_bytes := PackedByteArray([0, 0, 0, 0, 0, 0, 0, 0])
 
+var _depth: int = 0
+
 # -- PUBLIC METHODS ------------------------------------------------------------------ #
 
 
+## `clear` resets the reader to its initial state.
+func clear() -> void:
+	super()
+	_depth = 0
+
+
+## `set_data` resets the reader to the provided `data` array, along with any errors,
+## cursor positioning, and message nesting depth. If the data exceeds
+## `max_message_size`, `ERR_LIMIT_EXCEEDED` is set.
+func set_data(data: PackedByteArray) -> void:
+	super(data)
+	_depth = 0
+
+	if data.size() > max_message_size:
+		_set_error(ERR_LIMIT_EXCEEDED)
+
+
 ## `can_read_bits` returns true if the specified number of bits is available.
 func can_read_bits(count: int) -> bool:
 	return _position + count <= get_capacity()
@@ -82,6 +140,48 @@ func read_bits(count: int) -> int:
 	return result
 
 
+## `read_bits_signed` reads a signed value (two's complement) from the specified number
+## of bits (1-64), sign-extending the result.
+func read_bits_signed(count: int) -> int:
+	var value := read_bits(count)
+	if _error != OK or count >= 64:
+		return value
+
+	var sign_bit := 1 << (count - 1)
+	return (value ^ sign_bit) - sign_bit
+
+
+## `skip_padding` advances past the specified number of padding bits. See
+## `validate_padding` for optionally verifying that the skipped bits are zero.
+func skip_padding(count: int) -> void:
+	if _error != OK:
+		return
+
+	if count < 0:
+		_set_error(ERR_INVALID_PARAMETER)
+		return
+
+	if not can_read_bits(count):
+		_set_error(ERR_FILE_EOF)
+		return
+
+	if not validate_padding:
+		_position += count
+		return
+
+	var start := _position
+
+	var remaining := count
+	while remaining > 0:
+		var bits := mini(remaining, 64)
+		if read_bits(bits) != 0:
+			_position = start
+			_set_error(ERR_INVALID_DATA)
+			return
+
+		remaining -= bits
+
+
 ## `read_zigzag` reads a ZigZag-encoded signed value from fixed bits.
 func read_zigzag(bit_count: int) -> int:
 	return Encoding.zigzag_decode(read_bits(bit_count))
@@ -102,6 +202,11 @@ func read_u32() -> int:
 	return read_bits(32)
 
 
+## `read_u64` reads an unsigned 64-bit integer.
+func read_u64() -> int:
+	return read_bits(64)
+
+
 ## `read_i8` reads a signed 8-bit integer (two's complement).
 func read_i8() -> int:
 	var value := read_bits(8)
@@ -152,6 +257,176 @@ func read_f64() -> float:
 	return _f64_bytes.decode_double(0)
 
 
+## `read_quantized` reads a float within `[minimum, maximum]` which was encoded as an
+## unsigned integer using the specified number of bits (1-63).
+func read_quantized(minimum: float, maximum: float, bit_count: int) -> float:
+	if bit_count < 1 or bit_count > 63 or not maximum > minimum:
+		_set_error(ERR_INVALID_PARAMETER)
+		return 0.0
+
+	var value := read_bits(bit_count)
+	if _error != OK:
+		return 0.0
+
+	return Encoding.dequantize(value, minimum, maximum, bit_count)
+
+
+## `read_vector2` reads a `Vector2` as two single-precision floats.
+func read_vector2() -> Vector2:
+	var start := _position
+
+	var x := read_f32()
+	var y := read_f32()
+
+	if _error != OK:
+		_position = start
+		return Vector2()
+
+	return Vector2(x, y)
+
+
+## `read_vector2i` reads a `Vector2i` as two signed 32-bit integers.
+func read_vector2i() -> Vector2i:
+	var start := _position
+
+	var x := read_i32()
+	var y := read_i32()
+
+	if _error != OK:
+		_position = start
+		return Vector2i()
+
+	return Vector2i(x, y)
+
+
+## `read_vector3` reads a `Vector3` as three single-precision floats.
+func read_vector3() -> Vector3:
+	var start := _position
+
+	var x := read_f32()
+	var y := read_f32()
+	var z := read_f32()
+
+	if _error != OK:
+		_position = start
+		return Vector3()
+
+	return Vector3(x, y, z)
+
+
+## `read_vector3i` reads a `Vector3i` as three signed 32-bit integers.
+func read_vector3i() -> Vector3i:
+	var start := _position
+
+	var x := read_i32()
+	var y := read_i32()
+	var z := read_i32()
+
+	if _error != OK:
+		_position = start
+		return Vector3i()
+
+	return Vector3i(x, y, z)
+
+
+## `read_vector4` reads a `Vector4` as four single-precision floats.
+func read_vector4() -> Vector4:
+	var start := _position
+
+	var x := read_f32()
+	var y := read_f32()
+	var z := read_f32()
+	var w := read_f32()
+
+	if _error != OK:
+		_position = start
+		return Vector4()
+
+	return Vector4(x, y, z, w)
+
+
+## `read_vector4i` reads a `Vector4i` as four signed 32-bit integers.
+func read_vector4i() -> Vector4i:
+	var start := _position
+
+	var x := read_i32()
+	var y := read_i32()
+	var z := read_i32()
+	var w := read_i32()
+
+	if _error != OK:
+		_position = start
+		return Vector4i()
+
+	return Vector4i(x, y, z, w)
+
+
+## `read_quaternion` reads a `Quaternion` as four single-precision floats (x, y, z,
+## w).
+func read_quaternion() -> Quaternion:
+	var start := _position
+
+	var x := read_f32()
+	var y := read_f32()
+	var z := read_f32()
+	var w := read_f32()
+
+	if _error != OK:
+		_position = start
+		return Quaternion()
+
+	return Quaternion(x, y, z, w)
+
+
+## `read_color` reads a `Color` as four single-precision floats (r, g, b, a).
+func read_color() -> Color:
+	var start := _position
+
+	var r := read_f32()
+	var g := read_f32()
+	var b := read_f32()
+	var a := read_f32()
+
+	if _error != OK:
+		_position = start
+		return Color()
+
+	return Color(r, g, b, a)
+
+
+## `read_transform2d` reads a `Transform2D` as its basis columns followed by its
+## origin.
+func read_transform2d() -> Transform2D:
+	var start := _position
+
+	var x := read_vector2()
+	var y := read_vector2()
+	var origin := read_vector2()
+
+	if _error != OK:
+		_position = start
+		return Transform2D()
+
+	return Transform2D(x, y, origin)
+
+
+## `read_transform3d` reads a `Transform3D` as its basis columns followed by its
+## origin.
+func read_transform3d() -> Transform3D:
+	var start := _position
+
+	var x := read_vector3()
+	var y := read_vector3()
+	var z := read_vector3()
+	var origin := read_vector3()
+
+	if _error != OK:
+		_position = start
+		return Transform3D()
+
+	return Transform3D(x, y, z, origin)
+
+
 ## `read_varint_unsigned` reads an unsigned LEB128 varint.
 func read_varint_unsigned() -> int:
 	var start := _position
@@ -182,8 +457,72 @@ func read_varint_signed() -> int:
 	return Encoding.zigzag_decode(read_varint_unsigned())
 
 
-## `read_bytes` reads the specified number of raw bytes.
-func read_bytes(count: int) -> PackedByteArray:
+## `read_size` reads the size of a string, byte array, or collection as an unsigned
+## integer of `prefix_bits` bits (1-64) or, if `prefix_bits` is `0`, as an unsigned
+## LEB128 varint. If `max_size` is non-negative and the size exceeds it,
+## `ERR_INVALID_DATA` is set and `0` is returned. The size isn't otherwise validated
+## (see `read_length`).
+func read_size(prefix_bits: int = 0, max_size: int = -1) -> int:
+	var size := read_varint_unsigned() if prefix_bits == 0 else read_bits(prefix_bits)
+	if _error != OK:
+		return 0
+
+	# NOTE: Sizes above the maximum signed integer wrap around to negative values.
+	if max_size >= 0 and (size < 0 or size > max_size):
+		_set_error(ERR_INVALID_DATA)
+		return 0
+
+	return size
+
+
+## `read_length` reads the number of elements in an array or map (see `read_size`). If
+## the length exceeds `max_collection_length`, `ERR_LIMIT_EXCEEDED` is set and `0` is
+## returned.
+func read_length(prefix_bits: int = 0, max_size: int = -1) -> int:
+	var length := read_size(prefix_bits, max_size)
+	if _error != OK:
+		return 0
+
+	# NOTE: Lengths above the maximum signed integer wrap around to negative values.
+	if length < 0 or length > max_collection_length:
+		_set_error(ERR_LIMIT_EXCEEDED)
+		return 0
+
+	return length
+
+
+## `enter_message` records the start of decoding a (possibly nested) message. If doing
+## so exceeds `max_depth`, `ERR_LIMIT_EXCEEDED` is set. Must be paired with a call to
+## `exit_message` once the message has been decoded.
+func enter_message() -> Error:
+	if _error != OK:
+		return _error
+
+	if _depth >= max_depth:
+		_set_error(ERR_LIMIT_EXCEEDED)
+		return _error
+
+	_depth += 1
+
+	return OK
+
+
+## `exit_message` records the end of decoding a message (see `enter_message`).
+func exit_message() -> void:
+	_depth = maxi(_depth - 1, 0)
+
+
+## `read_bytes` reads the specified number of raw bytes. If the count exceeds
+## `max_count` (or `max_bytes_length`, if `max_count` is negative),
+## `ERR_LIMIT_EXCEEDED` is set.
+func read_bytes(count: int, max_count: int = -1) -> PackedByteArray:
+	if max_count < 0:
+		max_count = max_bytes_length
+
+	if count < 0 or count > max_count:
+		_set_error(ERR_LIMIT_EXCEEDED)
+		return PackedByteArray()
+
 	if not can_read_bytes(count):
 		_set_error(ERR_FILE_EOF)
 		return PackedByteArray()
@@ -204,10 +543,12 @@ func read_bytes(count: int) -> PackedByteArray:
 	return result
 
 
-## `read_string` reads a varint-prefixed UTF-8 string (byte-aligned).
-func read_string() -> String:
+## `read_string` reads a UTF-8 string prefixed with its size in bytes (see
+## `read_size`). If the string's size exceeds `max_bytes_length`, `ERR_LIMIT_EXCEEDED`
+## is set.
+func read_string(prefix_bits: int = 0, max_size: int = -1) -> String:
 	var start := _position
-	var size := read_varint_unsigned()
+	var size := read_size(prefix_bits, max_size)
 	if _error != OK:
 		return ""
 
@@ -219,8 +560,159 @@ func read_string() -> String:
 	return utf8.get_string_from_utf8()
 
 
+## `read_int32_array` reads `count` integers of `byte_count` bytes (1, 2, 4, or 8) into
+## a `PackedInt32Array`. Byte-aligned, signed 4-byte elements are read in bulk.
+func read_int32_array(count: int, byte_count: int, signed: bool) -> PackedInt32Array:
+	if not _can_read_array(count, byte_count):
+		return PackedInt32Array()
+
+	if byte_count == 4 and signed and _position % 8 == 0:
+		return _read_aligned(count * byte_count).to_int32_array()
+
+	var result := PackedInt32Array()
+	result.resize(count)
+
+	for i in range(count):
+		result[i] = _read_int(byte_count, signed)
+
+	return result
+
+
+## `read_int64_array` reads `count` integers of `byte_count` bytes (1, 2, 4, or 8) into
+## a `PackedInt64Array`. Byte-aligned 8-byte elements are read in bulk.
+func read_int64_array(count: int, byte_count: int, signed: bool) -> PackedInt64Array:
+	if not _can_read_array(count, byte_count):
+		return PackedInt64Array()
+
+	if byte_count == 8 and _position % 8 == 0:
+		return _read_aligned(count * byte_count).to_int64_array()
+
+	var result := PackedInt64Array()
+	result.resize(count)
+
+	for i in range(count):
+		result[i] = _read_int(byte_count, signed)
+
+	return result
+
+
+## `read_float32_array` reads `count` IEEE 754 single-precision floats into a
+## `PackedFloat32Array`. Byte-aligned arrays are read in bulk.
+func read_float32_array(count: int) -> PackedFloat32Array:
+	if not _can_read_array(count, 4):
+		return PackedFloat32Array()
+
+	if _position % 8 == 0:
+		return _read_aligned(count * 4).to_float32_array()
+
+	var result := PackedFloat32Array()
+	result.resize(count)
+
+	for i in range(count):
+		result[i] = read_f32()
+
+	return result
+
+
+## `read_float64_array` reads `count` IEEE 754 double-precision floats into a
+## `PackedFloat64Array`. Byte-aligned arrays are read in bulk.
+func read_float64_array(count: int) -> PackedFloat64Array:
+	if not _can_read_array(count, 8):
+		return PackedFloat64Array()
+
+	if _position % 8 == 0:
+		return _read_aligned(count * 8).to_float64_array()
+
+	var result := PackedFloat64Array()
+	result.resize(count)
+
+	for i in range(count):
+		result[i] = read_f64()
+
+	return result
+
+
+## `read_field_index` reads the index of the next field of a tagged message. Returns
+## `-1` once all fields have been read or if an error has occurred.
+func read_field_index() -> int:
+	if _error != OK:
+		return -1
+
+	var tag := read_varint_unsigned()
+	if _error != OK or tag <= 0:
+		return -1
+
+	return tag - 1
+
+
+## `read_field_end` reads the size of a tagged message field's value and returns the
+## position at which the value ends (see `skip_field`).
+func read_field_end() -> int:
+	var size := read_u32()
+	if _error != OK:
+		return _position
+
+	if not can_read_bits(size):
+		_set_error(ERR_FILE_EOF)
+		return _position
+
+	return _position + size
+
+
+## `skip_field` moves the cursor to `end`, the position at which a tagged message
+## field's value ends, skipping any of the value which wasn't read. This allows fields
+## unknown to the reader to be ignored.
+func skip_field(end: int) -> void:
+	if _error != OK:
+		return
+
+	if _position > end:
+		_set_error(ERR_INVALID_DATA)
+		return
+
+	_position = end
+
+
 # -- ENGINE METHODS (OVERRIDES) ------------------------------------------------------ #
 
 
 func _init(data: PackedByteArray) -> void:
-	_buffer = data
+	set_data(data)
+
+
+# -- PRIVATE METHODS ----------------------------------------------------------------- #
+
+
+## `_can_read_array` validates the parameters of a packed array read and verifies that
+## enough data remains, setting an error if not.
+func _can_read_array(count: int, byte_count: int) -> bool:
+	if count < 0 or byte_count not in [1, 2, 4, 8]:
+		_set_error(ERR_INVALID_PARAMETER)
+		return false
+
+	# NOTE: Divide rather than multiply so that large counts can't overflow.
+	@warning_ignore("integer_division")
+	var available := (get_capacity() - _position) / (byte_count * 8)
+	if count > available:
+		_set_error(ERR_FILE_EOF)
+		return false
+
+	return true
+
+
+## `_read_aligned` reads the specified number of raw bytes from the current
+## (byte-aligned) position.
+func _read_aligned(count: int) -> PackedByteArray:
+	@warning_ignore("integer_division")
+	var start := _position / 8
+	_position += count * 8
+
+	return _buffer.slice(start, start + count)
+
+
+## `_read_int` reads an integer of the specified number of bytes.
+func _read_int(byte_count: int, signed: bool) -> int:
+	if signed:
+		return read_bits_signed(byte_count * 8)
+
+	return read_bits(byte_count * 8)
@@ -147,6 +147,44 @@ func test_reader_f64_eof_restores_position() -> void:
 	# Then: The cursor position is reset.
 	assert_eq(reader.get_position(), start)
 
+func test_reader_transform3d_eof_restores_position() -> void:
+	# Given: A reader with only 16 bytes (Transform3D needs 48).
+	var data := PackedByteArray()
+	data.resize(16)
+	data.fill(0x00)
+	var reader := Reader.new(data)
+
+	# When: Reading a Transform3D that encounters EOF.
+	var start := reader.get_position()
+	var result := reader.read_transform3d()
+
+	# Then: There is an EOF error.
+	assert_eq(reader.get_error(), ERR_FILE_EOF)
+
+	# Then: The default value is returned.
+	assert_eq(result, Transform3D())
+
+	# Then: The cursor position is reset.
+	assert_eq(reader.get_position(), start)
+
+func test_reader_packed_array_eof_with_huge_count() -> void:
+	# Given: A reader with only 8 bytes.
+	var data := PackedByteArray()
+	data.resize(8)
+	var reader := Reader.new(data)
+
+	# When: Reading a packed array whose count would overflow its byte size.
+	var result := reader.read_int64_array(1 << 61, 8, true)
+
+	# Then: There is an EOF error.
+	assert_eq(reader.get_error(), ERR_FILE_EOF)
+
+	# Then: No data is returned.
+	assert_eq(result.size(), 0)
+
+	# Then: The cursor position is unchanged.
+	assert_eq(reader.get_position(), 0)
+
 
 func test_reader_string_eof_on_varint_restores_position() -> void:
 	# Given: A reader with incomplete varint for string length.
@@ -186,6 +224,307 @@ func test_reader_string_eof_on_data_preserves_position() -> void:
 	assert_eq(reader.get_position(), start)
 
 
+func test_reader_skip_padding() -> void:
+	# Given: A reader with a padded value.
+	var reader := Reader.new(PackedByteArray([0x01, 0x00, 0xFF]))
+
+	# When: Skipping padding between values.
+	assert_eq(reader.read_bits(4), 1)
+	reader.skip_padding(12)
+
+	# Then: The cursor is positioned after the padding.
+	assert_eq(reader.get_position(), 16)
+	assert_eq(reader.read_u8(), 0xFF)
+	assert_true(reader.is_valid())
+
+
+func test_reader_skip_padding_past_end() -> void:
+	# Given: A reader with 1 byte.
+	var reader := Reader.new(PackedByteArray([0x00]))
+
+	# When: Skipping more padding than available.
+	reader.skip_padding(9)
+
+	# Then: There is an EOF error.
+	assert_eq(reader.get_error(), ERR_FILE_EOF)
+
+	# Then: The cursor position is unchanged.
+	assert_eq(reader.get_position(), 0)
+
+
+func test_reader_skip_padding_validates_zero_bits() -> void:
+	# Given: A reader with non-zero padding bits and padding validation enabled.
+	var reader := Reader.new(PackedByteArray([0x00, 0x00, 0x80]))
+	reader.validate_padding = true
+
+	# When: Skipping padding which contains a set bit.
+	reader.skip_padding(24)
+
+	# Then: There is an invalid data error.
+	assert_eq(reader.get_error(), ERR_INVALID_DATA)
+
+	# Then: The cursor position is reset.
+	assert_eq(reader.get_position(), 0)
+
+
+func test_reader_skip_padding_validation_is_per_reader() -> void:
+	# Given: Two readers with non-zero padding bits, one validating padding.
+	var validating := Reader.new(PackedByteArray([0x80]))
+	validating.validate_padding = true
+	var reader := Reader.new(PackedByteArray([0x80]))
+
+	# When: Skipping the padding with both readers.
+	validating.skip_padding(8)
+	reader.skip_padding(8)
+
+	# Then: Only the validating reader reports an error.
+	assert_eq(validating.get_error(), ERR_INVALID_DATA)
+	assert_eq(reader.get_error(), OK)
+	assert_eq(reader.get_position(), 8)
+
+
+func test_reader_skip_padding_validates_wide_padding() -> void:
+	# Given: A reader with more than 64 bits of zero padding and padding validation
+	# enabled.
+	var data := PackedByteArray()
+	data.resize(10)
+	data.fill(0x00)
+	var reader := Reader.new(data)
+	reader.validate_padding = true
+
+	# When: Skipping the padding.
+	reader.skip_padding(80)
+
+	# Then: The padding is skipped without error.
+	assert_true(reader.is_valid())
+	assert_eq(reader.get_position(), 80)
+
+
+func test_reader_field_end_past_end() -> void:
+	# Given: A field whose size exceeds the remaining data.
+	var reader := Reader.new(PackedByteArray([0x01, 0xFF, 0x00, 0x00, 0x00, 0x00]))
+	assert_eq(reader.read_field_index(), 0)
+
+	# When: Reading the end of the field.
+	reader.read_field_end()
+
+	# Then: An error is set.
+	assert_eq(reader.get_error(), ERR_FILE_EOF)
+
+	# Then: No further fields are read.
+	assert_eq(reader.read_field_index(), -1)
+
+
+func test_reader_skip_field_overrun() -> void:
+	# Given: A field with an 8-bit value.
+	var reader := Reader.new(PackedByteArray([0x01, 0x08, 0x00, 0x00, 0x00, 0xAB, 0xCD]))
+	assert_eq(reader.read_field_index(), 0)
+	var end := reader.read_field_end()
+
+	# Given: More of the value was read than the field contains.
+	reader.read_u16()
+
+	# When: Skipping to the end of the field.
+	reader.skip_field(end)
+
+	# Then: An error is set.
+	assert_eq(reader.get_error(), ERR_INVALID_DATA)
+
+
+func test_reader_length_within_limit() -> void:
+	# Given: A collection length at the limit.
+	var reader := Reader.new(PackedByteArray([0xAC, 0x02]))
+	reader.max_collection_length = 300
+
+	# When: Reading the length.
+	var length := reader.read_length()
+
+	# Then: The length is returned.
+	assert_eq(length, 300)
+	assert_true(reader.is_valid())
+
+
+func test_reader_length_exceeds_limit() -> void:
+	# Given: A collection length above the limit.
+	var reader := Reader.new(PackedByteArray([0xAC, 0x02]))
+	reader.max_collection_length = 299
+
+	# When: Reading the length.
+	var length := reader.read_length()
+
+	# Then: No elements are read and the limit error is set.
+	assert_eq(length, 0)
+	assert_eq(reader.get_error(), Reader.ERR_LIMIT_EXCEEDED)
+
+
+func test_reader_length_overflow_exceeds_limit() -> void:
+	# Given: A collection length which overflows a signed integer.
+	var data := PackedByteArray()
+	data.resize(9)
+	data.fill(0xFF)
+	data.append(0x01)
+	var reader := Reader.new(data)
+
+	# When: Reading the length.
+	var length := reader.read_length()
+
+	# Then: No elements are read and the limit error is set.
+	assert_eq(length, 0)
+	assert_eq(reader.get_error(), Reader.ERR_LIMIT_EXCEEDED)
+
+
+func test_reader_length_prefixed_exceeds_limit() -> void:
+	# Given: A 16-bit collection length above the limit.
+	var reader := Reader.new(PackedByteArray([0x2C, 0x01]))
+	reader.max_collection_length = 299
+
+	# When: Reading the length.
+	var length := reader.read_length(16)
+
+	# Then: No elements are read and the limit error is set.
+	assert_eq(length, 0)
+	assert_eq(reader.get_error(), Reader.ERR_LIMIT_EXCEEDED)
+
+
+func test_reader_size_exceeds_max() -> void:
+	# Given: A 4-bit size above the field's bound.
+	var reader := Reader.new(PackedByteArray([0x0A]))
+
+	# When: Reading the size with a maximum of 9.
+	var size := reader.read_size(4, 9)
+
+	# Then: The size is rejected.
+	assert_eq(size, 0)
+	assert_eq(reader.get_error(), ERR_INVALID_DATA)
+
+
+func test_reader_string_exceeds_limit() -> void:
+	# Given: A string larger than the limit.
+	var reader := Reader.new(PackedByteArray([0x03, 0x61, 0x62, 0x63]))
+	reader.max_bytes_length = 2
+
+	# When: Reading the string.
+	var value := reader.read_string()
+
+	# Then: The string isn't read and the limit error is set.
+	assert_eq(value, "")
+	assert_eq(reader.get_error(), Reader.ERR_LIMIT_EXCEEDED)
+	assert_eq(reader.get_position(), 0)
+
+
+func test_reader_bytes_exceeds_limit() -> void:
+	# Given: A huge byte count.
+	var reader := Reader.new(PackedByteArray([0x00]))
+
+	# When: Reading the bytes.
+	var value := reader.read_bytes(1 << 40)
+
+	# Then: Nothing is allocated and the limit error is set.
+	assert_eq(value.size(), 0)
+	assert_eq(reader.get_error(), Reader.ERR_LIMIT_EXCEEDED)
+
+
+func test_reader_message_depth_exceeds_limit() -> void:
+	# Given: A maximum nesting depth.
+	var reader := Reader.new(PackedByteArray())
+	reader.max_depth = 2
+
+	# When: Entering messages up to the limit.
+	assert_eq(reader.enter_message(), OK)
+	assert_eq(reader.enter_message(), OK)
+
+	# Then: Entering another message sets the limit error.
+	assert_eq(reader.enter_message(), Reader.ERR_LIMIT_EXCEEDED)
+	assert_eq(reader.get_error(), Reader.ERR_LIMIT_EXCEEDED)
+
+
+func test_reader_message_depth_resets_on_exit() -> void:
+	# Given: A maximum nesting depth.
+	var reader := Reader.new(PackedByteArray())
+	reader.max_depth = 1
+
+	# When: Entering and exiting sibling messages.
+	for _i in range(3):
+		assert_eq(reader.enter_message(), OK)
+		reader.exit_message()
+
+	# Then: No error is set.
+	assert_true(reader.is_valid())
+
+
+func test_reader_message_size_exceeds_limit() -> void:
+	# Given: Data larger than the default maximum message size.
+	var data := PackedByteArray()
+	data.resize(Reader.DEFAULT_MAX_MESSAGE_SIZE + 1)
+
+	# When: Creating a reader.
+	var reader := Reader.new(data)
+
+	# Then: The limit error is set and nothing can be read.
+	assert_eq(reader.get_error(), Reader.ERR_LIMIT_EXCEEDED)
+	assert_eq(reader.read_u8(), 0)
+
+
+func test_reader_set_data_exceeds_message_size_limit() -> void:
+	# Given: A reader with a maximum message size of 2 bytes.
+	var reader := Reader.new(PackedByteArray())
+	reader.max_message_size = 2
+
+	# When: Loading data larger than the limit.
+	reader.set_data(PackedByteArray([0x01, 0x02, 0x03]))
+
+	# Then: The limit error is set and nothing can be read.
+	assert_eq(reader.get_error(), Reader.ERR_LIMIT_EXCEEDED)
+	assert_eq(reader.read_u8(), 0)
+
+	# When: Loading data within the limit.
+	reader.set_data(PackedByteArray([0x01, 0x02]))
+
+	# Then: The error is reset and the data can be read.
+	assert_true(reader.is_valid())
+	assert_eq(reader.read_u8(), 1)
+
+
+func test_reader_set_data_resets_depth() -> void:
+	# Given: A reader which stopped decoding within a nested message.
+	var reader := Reader.new(PackedByteArray())
+	reader.max_depth = 1
+	assert_eq(reader.enter_message(), OK)
+
+	# When: Loading new data.
+	reader.set_data(PackedByteArray([0x01]))
+
+	# Then: A message can be entered again.
+	assert_eq(reader.enter_message(), OK)
+	assert_true(reader.is_valid())
+
+
+func test_reader_limits_are_per_reader() -> void:
+	# Given: Two readers of the same data, one with a lowered limit.
+	var data := PackedByteArray([0x03, 0x61, 0x62, 0x63])
+	var limited := Reader.new(data)
+	limited.max_bytes_length = 2
+	var reader := Reader.new(data)
+
+	# When: Reading a string with each reader.
+	limited.read_string()
+	var value := reader.read_string()
+
+	# Then: Only the reader with the lowered limit is affected.
+	assert_eq(limited.get_error(), Reader.ERR_LIMIT_EXCEEDED)
+	assert_eq(value, "abc")
+	assert_true(reader.is_valid())
+
+
+func test_reader_limit_error_is_distinct() -> void:
+	# Given: The limit error.
+	var err := Reader.ERR_LIMIT_EXCEEDED
+
+	# Then: It lies outside the range of the engine's errors.
+	assert_ne(err, ERR_OUT_OF_MEMORY)
+	assert_gt(err, ERR_PRINTER_ON_FIRE)
+
+
 # -- TEST HOOKS ---------------------------------------------------------------------- #
 
 
@@ -0,0 +1,103 @@
+##
+## stream_test.gd
+##
+## Test suite for encoding messages on shared streams (see `write_to` and `read_from` in
+## generated messages).
+##
+
+extends GutTest
+
+# -- DEPENDENCIES -------------------------------------------------------------------- #
+
+const Reader := preload("res://runtime/reader.gd")
+const Writer := preload("res://runtime/writer.gd")
+
+# -- DEFINITIONS --------------------------------------------------------------------- #
+
+
+## `Flags` mirrors the stream methods of a generated message whose fields don't fill a
+## whole byte.
+class Flags:
+	extends RefCounted
+
+	var level: int = 0
+	var active: bool = false
+
+	func write_to(writer: Writer) -> Error:
+		_encode(writer)
+		return writer.get_error()
+
+	func read_from(reader: Reader) -> Error:
+		_decode(reader)
+		return reader.get_error()
+
+	func _encode(_writer: Writer) -> void:
+		_writer.write_bits(level, 3)
+		_writer.write_bool(active)
+
+	func _decode(_reader: Reader) -> Error:
+		level = _reader.read_bits(3)
+		active = _reader.read_bool()
+		return _reader.get_error()
+
+
+# -- TEST METHODS -------------------------------------------------------------------- #
+
+
+func test_write_to_read_from_shares_stream() -> void:
+	# Given: Two messages, each encoded in 4 bits.
+	var first := Flags.new()
+	first.level = 5
+	first.active = true
+	var second := Flags.new()
+	second.level = 2
+
+	# When: Both messages are written to the same writer.
+	var writer := Writer.new()
+	assert_eq(first.write_to(writer), OK)
+	assert_eq(writer.get_position(), 4)
+	assert_eq(second.write_to(writer), OK)
+
+	# Then: The messages are packed into a single byte.
+	var data := writer.to_bytes()
+	assert_eq(data.size(), 1)
+
+	# When: Both messages are read from the same reader.
+	var reader := Reader.new(data)
+	var first_read := Flags.new()
+	var first_err := first_read.read_from(reader)
+	var first_position := reader.get_position()
+	var second_read := Flags.new()
+	var second_err := second_read.read_from(reader)
+
+	# Then: Each message starts where the previous one ended, without byte alignment.
+	assert_eq(first_err, OK)
+	assert_eq(first_position, 4)
+	assert_eq(first_read.level, 5)
+	assert_true(first_read.active)
+	assert_eq(second_err, OK)
+	assert_eq(reader.get_position(), 8)
+	assert_eq(second_read.level, 2)
+	assert_false(second_read.active)
+
+
+func test_read_from_reports_bytes_read() -> void:
+	# Given: A message followed by unrelated data.
+	var message := Flags.new()
+	message.level = 7
+
+	var writer := Writer.new()
+	message.write_to(writer)
+	var data := writer.to_bytes()
+	data.append_array(PackedByteArray([0xff, 0xff]))
+
+	# When: The message is read.
+	var reader := Reader.new(data)
+	var decoded := Flags.new()
+	var err := decoded.read_from(reader)
+
+	# Then: Only the message's (rounded up) byte is reported as read.
+	assert_eq(err, OK)
+	assert_eq(decoded.level, 7)
+	assert_eq(reader.get_position(), 4)
+	assert_eq(reader.length(), 1)
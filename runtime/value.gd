@@ -0,0 +1,546 @@
+##
+## value.gd
+##
+## A shared library providing deep comparison, hashing, copying, and JSON conversion of
+## generated message values. Nested messages and enums are handled using their own
+## `equals`, `hash`, `clone`, and `to_dict`/`from_dict` methods, while arrays and
+## dictionaries are handled element-by-element.
+##
+
+extends RefCounted
+
+# -- DEFINITIONS --------------------------------------------------------------------- #
+
+## `HASH_MULTIPLIER` is the multiplier used when combining hashes of ordered elements.
+const HASH_MULTIPLIER := 31
+
+## `JSON_INT_MAX` is the largest integer which JSON parsers (which represent numbers as
+## double-precision floats) can represent exactly. Larger integers are converted to
+## strings by `to_json_value`.
+const JSON_INT_MAX := 1 << 53
+
+## `DEBUG_MAX_ELEMENTS` is the maximum number of collection elements (or bytes) which
+## `to_debug_string` includes before truncating the remainder.
+const DEBUG_MAX_ELEMENTS := 16
+
+## `BUILTIN_COMPONENTS` maps each supported Godot built-in math type to the number of
+## components in its JSON representation.
+const BUILTIN_COMPONENTS := {
+	TYPE_VECTOR2: 2,
+	TYPE_VECTOR2I: 2,
+	TYPE_VECTOR3: 3,
+	TYPE_VECTOR3I: 3,
+	TYPE_VECTOR4: 4,
+	TYPE_VECTOR4I: 4,
+	TYPE_QUATERNION: 4,
+	TYPE_COLOR: 4,
+	TYPE_TRANSFORM2D: 6,
+	TYPE_TRANSFORM3D: 12,
+}
+
+## `PACKED_ELEMENTS` maps each supported packed array type to the type of its elements.
+const PACKED_ELEMENTS := {
+	TYPE_PACKED_INT32_ARRAY: TYPE_INT,
+	TYPE_PACKED_INT64_ARRAY: TYPE_INT,
+	TYPE_PACKED_FLOAT32_ARRAY: TYPE_FLOAT,
+	TYPE_PACKED_FLOAT64_ARRAY: TYPE_FLOAT,
+	TYPE_PACKED_STRING_ARRAY: TYPE_STRING,
+	TYPE_PACKED_VECTOR2_ARRAY: TYPE_VECTOR2,
+	TYPE_PACKED_VECTOR3_ARRAY: TYPE_VECTOR3,
+	TYPE_PACKED_COLOR_ARRAY: TYPE_COLOR,
+}
+
+# -- PUBLIC METHODS ------------------------------------------------------------------ #
+
+
+## `equals` returns whether `a` and `b` are deeply equal. Objects are compared using
+## their `equals` method (if defined), arrays and dictionaries are compared by their
+## contents, and all other values are compared using `==`.
+static func equals(a: Variant, b: Variant) -> bool:
+	if typeof(a) != typeof(b):
+		return false
+
+	match typeof(a):
+		TYPE_OBJECT:
+			if a == null or b == null:
+				return a == b
+
+			if a.has_method("equals"):
+				return a.equals(b)
+
+			return a == b
+
+		TYPE_ARRAY:
+			if a.size() != b.size():
+				return false
+
+			for i in range(a.size()):
+				if not equals(a[i], b[i]):
+					return false
+
+			return true
+
+		TYPE_DICTIONARY:
+			if a.size() != b.size():
+				return false
+
+			for key in a:
+				if not b.has(key) or not equals(a[key], b[key]):
+					return false
+
+			return true
+
+	return a == b
+
+
+## `hash_value` returns a hash of `value` which is consistent with `equals`. Objects are
+## hashed using their `hash` method (if defined) and arrays and dictionaries are hashed
+## by their contents. The hash of a dictionary doesn't depend on its key order.
+static func hash_value(value: Variant) -> int:
+	match typeof(value):
+		TYPE_OBJECT:
+			if value != null and value.has_method("hash"):
+				return value.hash()
+
+		TYPE_ARRAY:
+			var result := 1
+			for element in value:
+				result = result * HASH_MULTIPLIER + hash_value(element)
+
+			return result
+
+		TYPE_DICTIONARY:
+			var result := 0
+			for key in value:
+				result += (hash_value(key) * HASH_MULTIPLIER) ^ hash_value(value[key])
+
+			return result
+
+	return hash(value)
+
+
+## `copy` returns a deep copy of `value`. Objects are copied using their `clone` method
+## (if defined), arrays and dictionaries are copied along with their contents (retaining
+## their element types), and all other values are returned as-is.
+static func copy(value: Variant) -> Variant:
+	match typeof(value):
+		TYPE_OBJECT:
+			if value != null and value.has_method("clone"):
+				return value.clone()
+
+			return value
+
+		TYPE_ARRAY:
+			var result: Array = value.duplicate()
+			for i in range(result.size()):
+				result[i] = copy(result[i])
+
+			return result
+
+		TYPE_DICTIONARY:
+			var result: Dictionary = value.duplicate()
+			for key in result:
+				result[key] = copy(result[key])
+
+			return result
+
+	# NOTE: Packed arrays are shared by reference, so they must be duplicated.
+	if typeof(value) > TYPE_ARRAY and typeof(value) < TYPE_MAX:
+		return value.duplicate()
+
+	return value
+
+
+## `to_json_value` converts `value` to a JSON-compatible representation. Objects are
+## converted using their `to_dict` method, integers which can't be represented exactly
+## by JSON are converted to strings, `PackedByteArray` values are base64-encoded, and
+## built-in math types are converted to arrays of their components.
+static func to_json_value(value: Variant) -> Variant:
+	match typeof(value):
+		TYPE_INT:
+			if value > JSON_INT_MAX or value < -JSON_INT_MAX:
+				return str(value)
+
+			return value
+
+		TYPE_FLOAT:
+			if is_nan(value):
+				return "NaN"
+			if is_inf(value):
+				return "Infinity" if value > 0 else "-Infinity"
+
+			return value
+
+		TYPE_OBJECT:
+			if value != null and value.has_method("to_dict"):
+				return value.to_dict()
+
+			return null
+
+		TYPE_PACKED_BYTE_ARRAY:
+			return Marshalls.raw_to_base64(value)
+
+		TYPE_DICTIONARY:
+			var result := {}
+			for key in value:
+				result[str(to_json_value(key))] = to_json_value(value[key])
+
+			return result
+
+		TYPE_VECTOR2, TYPE_VECTOR2I:
+			return [value.x, value.y]
+
+		TYPE_VECTOR3, TYPE_VECTOR3I:
+			return [value.x, value.y, value.z]
+
+		TYPE_VECTOR4, TYPE_VECTOR4I, TYPE_QUATERNION:
+			return [value.x, value.y, value.z, value.w]
+
+		TYPE_COLOR:
+			return [value.r, value.g, value.b, value.a]
+
+		TYPE_TRANSFORM2D:
+			return (
+				to_json_value(value.x)
+				+ to_json_value(value.y)
+				+ to_json_value(value.origin)
+			)
+
+		TYPE_TRANSFORM3D:
+			return (
+				to_json_value(value.basis.x)
+				+ to_json_value(value.basis.y)
+				+ to_json_value(value.basis.z)
+				+ to_json_value(value.origin)
+			)
+
+	# NOTE: This handles arrays along with any (non-byte) packed arrays.
+	if typeof(value) >= TYPE_ARRAY and typeof(value) < TYPE_MAX:
+		var result := []
+		for element in value:
+			result.append(to_json_value(element))
+
+		return result
+
+	return value
+
+
+## `from_json_value` converts `value`, a JSON-compatible representation produced by
+## `to_json_value`, to the type described by `spec` and stores the result in `out[0]`.
+## `spec` is either a `Variant.Type`, a generated message or enum script, an array
+## `[TYPE_ARRAY, element_spec]`, or an array `[TYPE_DICTIONARY, key_spec, value_spec]`.
+## Returns `ERR_INVALID_DATA` if `value` doesn't match `spec`.
+static func from_json_value(value: Variant, spec: Variant, out: Array) -> Error:
+	out.resize(1)
+	out[0] = null
+
+	if spec is Script:
+		return _message_from_json(value, spec, out)
+
+	if spec is Array:
+		match spec[0]:
+			TYPE_ARRAY:
+				return _array_from_json(value, spec[1], out)
+			TYPE_DICTIONARY:
+				return _dictionary_from_json(value, spec[1], spec[2], out)
+
+		return ERR_INVALID_PARAMETER
+
+	match spec:
+		TYPE_BOOL:
+			if value is bool:
+				out[0] = value
+				return OK
+
+			# NOTE: Dictionary keys are always strings in JSON.
+			if value is String and value in ["true", "false"]:
+				out[0] = value == "true"
+				return OK
+
+		TYPE_INT:
+			return _int_from_json(value, out)
+
+		TYPE_FLOAT:
+			return _float_from_json(value, out)
+
+		TYPE_STRING:
+			if value is String:
+				out[0] = value
+				return OK
+
+		TYPE_PACKED_BYTE_ARRAY:
+			if value is String:
+				out[0] = Marshalls.base64_to_raw(value)
+				return OK
+
+		_:
+			if spec in PACKED_ELEMENTS:
+				return _packed_from_json(value, spec, out)
+			if spec in BUILTIN_COMPONENTS:
+				return _builtin_from_json(value, spec, out)
+
+			return ERR_INVALID_PARAMETER
+
+	return ERR_INVALID_DATA
+
+
+## `sized_array` returns a new array containing `size` default elements, with the type
+## described by `spec` (see `from_json_value`). `spec` is either a packed array type,
+## `TYPE_PACKED_BYTE_ARRAY`, or an array `[TYPE_ARRAY, element_spec]` of non-object
+## elements, which is returned as a typed array.
+static func sized_array(spec: Variant, size: int) -> Variant:
+	var result: Variant
+	if spec is Array:
+		assert(spec[0] == TYPE_ARRAY, "Invalid argument; expected an array spec")
+		assert(not spec[1] is Script, "Invalid argument; unsupported element spec")
+
+		var element: int = spec[1][0] if spec[1] is Array else spec[1]
+		result = Array([], element, &"", null)
+	else:
+		assert(
+			spec == TYPE_PACKED_BYTE_ARRAY or spec in PACKED_ELEMENTS,
+			"Invalid argument; expected an array spec",
+		)
+
+		result = type_convert([], spec)
+
+	result.resize(size)
+	return result
+
+
+## `to_debug_string` returns a human-readable representation of `value` for debugging.
+## Strings are quoted, bytes are hex-encoded, and collections are formatted recursively;
+## bytes and collections longer than `DEBUG_MAX_ELEMENTS` are truncated.
+static func to_debug_string(value: Variant) -> String:
+	match typeof(value):
+		TYPE_STRING:
+			return '"%s"' % value.c_escape()
+
+		TYPE_PACKED_BYTE_ARRAY:
+			var text: String = "0x" + value.slice(0, DEBUG_MAX_ELEMENTS).hex_encode()
+			if value.size() > DEBUG_MAX_ELEMENTS:
+				text += "... (%d bytes)" % value.size()
+
+			return text
+
+		TYPE_DICTIONARY:
+			var entries := PackedStringArray()
+			for key in value:
+				if entries.size() == DEBUG_MAX_ELEMENTS:
+					entries.append("... +%d more" % (value.size() - DEBUG_MAX_ELEMENTS))
+					break
+
+				entries.append(
+					"%s: %s" % [to_debug_string(key), to_debug_string(value[key])]
+				)
+
+			return "{%s}" % ", ".join(entries)
+
+	# NOTE: This handles arrays along with any (non-byte) packed arrays.
+	if typeof(value) >= TYPE_ARRAY and typeof(value) < TYPE_MAX:
+		var elements := PackedStringArray()
+		for element in value.slice(0, DEBUG_MAX_ELEMENTS):
+			elements.append(to_debug_string(element))
+
+		if value.size() > DEBUG_MAX_ELEMENTS:
+			elements.append("... +%d more" % (value.size() - DEBUG_MAX_ELEMENTS))
+
+		return "[%s]" % ", ".join(elements)
+
+	return str(value)
+
+
+# -- ENGINE METHODS (OVERRIDES) ------------------------------------------------------ #
+
+
+func _init() -> void:
+	assert(false, "Invalid config; this 'Object' should not be instantiated!")
+
+
+# -- PRIVATE METHODS ----------------------------------------------------------------- #
+
+
+## `_array_from_json` converts a JSON array into an (untyped) array whose elements are
+## described by `element_spec`.
+static func _array_from_json(
+	value: Variant, element_spec: Variant, out: Array
+) -> Error:
+	if not value is Array:
+		return ERR_INVALID_DATA
+
+	var result := []
+	for element in value:
+		var err := from_json_value(element, element_spec, out)
+		if err != OK:
+			return err
+
+		result.append(out[0])
+
+	out[0] = result
+	return OK
+
+
+## `_builtin_from_json` converts a JSON array of components into a built-in math type.
+static func _builtin_from_json(value: Variant, spec: int, out: Array) -> Error:
+	if not value is Array or value.size() != BUILTIN_COMPONENTS[spec]:
+		return ERR_INVALID_DATA
+
+	var c := PackedFloat64Array()
+	for element in value:
+		var err := _float_from_json(element, out)
+		if err != OK:
+			return err
+
+		c.append(out[0])
+
+	match spec:
+		TYPE_VECTOR2:
+			out[0] = Vector2(c[0], c[1])
+		TYPE_VECTOR2I:
+			out[0] = Vector2i(int(c[0]), int(c[1]))
+		TYPE_VECTOR3:
+			out[0] = Vector3(c[0], c[1], c[2])
+		TYPE_VECTOR3I:
+			out[0] = Vector3i(int(c[0]), int(c[1]), int(c[2]))
+		TYPE_VECTOR4:
+			out[0] = Vector4(c[0], c[1], c[2], c[3])
+		TYPE_VECTOR4I:
+			out[0] = Vector4i(int(c[0]), int(c[1]), int(c[2]), int(c[3]))
+		TYPE_QUATERNION:
+			out[0] = Quaternion(c[0], c[1], c[2], c[3])
+		TYPE_COLOR:
+			out[0] = Color(c[0], c[1], c[2], c[3])
+		TYPE_TRANSFORM2D:
+			out[0] = Transform2D(
+				Vector2(c[0], c[1]), Vector2(c[2], c[3]), Vector2(c[4], c[5])
+			)
+		TYPE_TRANSFORM3D:
+			out[0] = Transform3D(
+				Vector3(c[0], c[1], c[2]),
+				Vector3(c[3], c[4], c[5]),
+				Vector3(c[6], c[7], c[8]),
+				Vector3(c[9], c[10], c[11])
+			)
+
+	return OK
+
+
+## `_dictionary_from_json` converts a JSON object into an (untyped) dictionary whose
+## keys and values are described by `key_spec` and `value_spec`, respectively.
+static func _dictionary_from_json(
+	value: Variant, key_spec: Variant, value_spec: Variant, out: Array
+) -> Error:
+	if not value is Dictionary:
+		return ERR_INVALID_DATA
+
+	var result := {}
+	for key in value:
+		var err := from_json_value(key, key_spec, out)
+		if err != OK:
+			return err
+
+		var converted_key: Variant = out[0]
+
+		err = from_json_value(value[key], value_spec, out)
+		if err != OK:
+			return err
+
+		result[converted_key] = out[0]
+
+	out[0] = result
+	return OK
+
+
+## `_float_from_json` converts a JSON number (or one of the strings "NaN", "Infinity",
+## and "-Infinity") into a float.
+static func _float_from_json(value: Variant, out: Array) -> Error:
+	match typeof(value):
+		TYPE_INT, TYPE_FLOAT:
+			out[0] = float(value)
+			return OK
+
+		TYPE_STRING:
+			match value:
+				"NaN":
+					out[0] = NAN
+					return OK
+				"Infinity":
+					out[0] = INF
+					return OK
+				"-Infinity":
+					out[0] = -INF
+					return OK
+
+			if value.is_valid_float():
+				out[0] = value.to_float()
+				return OK
+
+	return ERR_INVALID_DATA
+
+
+## `_int_from_json` converts a JSON number (which must be integral) or a string of
+## decimal digits into an integer.
+static func _int_from_json(value: Variant, out: Array) -> Error:
+	match typeof(value):
+		TYPE_INT:
+			out[0] = value
+			return OK
+
+		TYPE_FLOAT:
+			if value == floorf(value) and absf(value) <= JSON_INT_MAX:
+				out[0] = int(value)
+				return OK
+
+		TYPE_STRING:
+			if value.is_valid_int():
+				out[0] = value.to_int()
+				return OK
+
+	return ERR_INVALID_DATA
+
+
+## `_message_from_json` converts a JSON object (or `null`) into a new instance of the
+## generated message or enum `script`.
+static func _message_from_json(value: Variant, script: Script, out: Array) -> Error:
+	if value == null:
+		out[0] = null
+		return OK
+
+	if not value is Dictionary:
+		return ERR_INVALID_DATA
+
+	var message: Object = script.new()
+
+	var err: Error = message.from_dict(value)
+	if err != OK:
+		return err
+
+	out[0] = message
+	return OK
+
+
+## `_packed_from_json` converts a JSON array into the packed array type `spec`.
+static func _packed_from_json(value: Variant, spec: int, out: Array) -> Error:
+	var err := _array_from_json(value, PACKED_ELEMENTS[spec], out)
+	if err != OK:
+		return err
+
+	match spec:
+		TYPE_PACKED_INT32_ARRAY:
+			out[0] = PackedInt32Array(out[0])
+		TYPE_PACKED_INT64_ARRAY:
+			out[0] = PackedInt64Array(out[0])
+		TYPE_PACKED_FLOAT32_ARRAY:
+			out[0] = PackedFloat32Array(out[0])
+		TYPE_PACKED_FLOAT64_ARRAY:
+			out[0] = PackedFloat64Array(out[0])
+		TYPE_PACKED_STRING_ARRAY:
+			out[0] = PackedStringArray(out[0])
+		TYPE_PACKED_VECTOR2_ARRAY:
+			out[0] = PackedVector2Array(out[0])
+		TYPE_PACKED_VECTOR3_ARRAY:
+			out[0] = PackedVector3Array(out[0])
+		TYPE_PACKED_COLOR_ARRAY:
+			out[0] = PackedColorArray(out[0])
+
+	return OK
@@ -0,0 +1,292 @@
+##
+## value_test.gd
+##
+## Test suite for the value comparison, copying, conversion, and formatting utilities.
+##
+
+extends GutTest
+
+# -- DEPENDENCIES -------------------------------------------------------------------- #
+
+const Value := preload("res://runtime/value.gd")
+
+# -- DEFINITIONS --------------------------------------------------------------------- #
+
+
+class Point:
+	extends RefCounted
+
+	var x: int = 0
+
+	func _init(value: int) -> void:
+		x = value
+
+	func equals(other: Object) -> bool:
+		return other is Point and other.x == x
+
+	func hash() -> int:
+		return x
+
+	func clone() -> RefCounted:
+		return Point.new(x)
+
+
+# -- TEST METHODS -------------------------------------------------------------------- #
+
+
+func test_equals_primitives(
+	params = use_parameters(
+		[
+			[1, 1, true],
+			[1, 2, false],
+			[1, 1.0, false],
+			["a", "a", true],
+			[Vector2(1, 2), Vector2(1, 2), true],
+			[null, null, true],
+		]
+	)
+) -> void:
+	# Given: Two values.
+	var a: Variant = params[0]
+	var b: Variant = params[1]
+
+	# When: The values are compared.
+	var result := Value.equals(a, b)
+
+	# Then: The result matches expectations.
+	assert_eq(result, params[2])
+
+
+func test_equals_objects_uses_equals_method() -> void:
+	# Given: Two distinct objects with equal contents.
+	var a := Point.new(1)
+	var b := Point.new(1)
+
+	# When: The objects are compared.
+	var result := Value.equals(a, b)
+
+	# Then: The objects are equal.
+	assert_true(result)
+	assert_false(Value.equals(a, Point.new(2)))
+	assert_false(Value.equals(a, null))
+
+
+func test_equals_arrays_of_objects() -> void:
+	# Given: Two distinct arrays of objects with equal contents.
+	var a := [Point.new(1), Point.new(2)]
+	var b := [Point.new(1), Point.new(2)]
+
+	# When: The arrays are compared.
+	var result := Value.equals(a, b)
+
+	# Then: The arrays are equal.
+	assert_true(result)
+	assert_false(Value.equals(a, [Point.new(1)]))
+	assert_false(Value.equals(a, [Point.new(2), Point.new(1)]))
+
+
+func test_equals_dictionaries_ignores_order() -> void:
+	# Given: Two dictionaries with equal contents in a different order.
+	var a := {"a": Point.new(1), "b": Point.new(2)}
+	var b := {"b": Point.new(2), "a": Point.new(1)}
+
+	# When: The dictionaries are compared.
+	var result := Value.equals(a, b)
+
+	# Then: The dictionaries are equal.
+	assert_true(result)
+	assert_false(Value.equals(a, {"a": Point.new(1), "c": Point.new(2)}))
+
+
+func test_hash_value_consistent_with_equals() -> void:
+	# Given: Two distinct, but equal, nested values.
+	var a := {"a": [Point.new(1)], "b": [Point.new(2)]}
+	var b := {"b": [Point.new(2)], "a": [Point.new(1)]}
+
+	# When: The values are hashed.
+	var hash_a := Value.hash_value(a)
+	var hash_b := Value.hash_value(b)
+
+	# Then: The hashes match.
+	assert_eq(hash_a, hash_b)
+
+
+func test_hash_value_array_depends_on_order() -> void:
+	# Given: Two arrays with the same elements in a different order.
+	var a := [Point.new(1), Point.new(2)]
+	var b := [Point.new(2), Point.new(1)]
+
+	# When: The arrays are hashed.
+	var hash_a := Value.hash_value(a)
+	var hash_b := Value.hash_value(b)
+
+	# Then: The hashes differ.
+	assert_ne(hash_a, hash_b)
+
+
+func test_copy_objects_uses_clone_method() -> void:
+	# Given: A nested value containing objects.
+	var original := {"a": [Point.new(1)], "b": PackedInt32Array([1, 2])}
+
+	# When: The value is copied.
+	var copied: Dictionary = Value.copy(original)
+
+	# Then: The copy is equal to the original.
+	assert_true(Value.equals(copied, original))
+
+	# Then: The copy shares no references with the original.
+	copied["a"][0].x = 2
+	copied["b"].append(3)
+	assert_eq(original["a"][0].x, 1)
+	assert_eq(original["b"].size(), 2)
+
+
+func test_copy_retains_array_type() -> void:
+	# Given: A typed array.
+	var original: Array[int] = [1, 2, 3]
+
+	# When: The array is copied.
+	var copied: Array = Value.copy(original)
+
+	# Then: The copy has the same element type.
+	assert_eq(copied.get_typed_builtin(), TYPE_INT)
+	assert_eq(copied, original)
+
+
+func test_to_json_value_converts_large_integers_to_strings() -> void:
+	# Given: Integers on either side of the exactly-representable range.
+	var small := Value.JSON_INT_MAX
+	var large := Value.JSON_INT_MAX + 1
+
+	# When: The integers are converted to JSON values.
+	var small_json: Variant = Value.to_json_value(small)
+	var large_json: Variant = Value.to_json_value(large)
+
+	# Then: Only the large integer is converted to a string.
+	assert_eq(small_json, small)
+	assert_eq(large_json, str(large))
+	assert_eq(Value.to_json_value(-large), str(-large))
+
+
+func test_json_value_roundtrip(
+	params = use_parameters(
+		[
+			[9223372036854775807, TYPE_INT],
+			[1.5, TYPE_FLOAT],
+			[INF, TYPE_FLOAT],
+			[PackedByteArray([0, 1, 255]), TYPE_PACKED_BYTE_ARRAY],
+			[Vector3i(1, -2, 3), TYPE_VECTOR3I],
+			[Transform2D(0.5, Vector2(1, 2)), TYPE_TRANSFORM2D],
+			[PackedInt64Array([1, -(1 << 62)]), TYPE_PACKED_INT64_ARRAY],
+			[{1: "a", -2: "b"}, [TYPE_DICTIONARY, TYPE_INT, TYPE_STRING]],
+			[{true: [1.0, 2.0]}, [TYPE_DICTIONARY, TYPE_BOOL, [TYPE_ARRAY, TYPE_FLOAT]]],
+		]
+	)
+) -> void:
+	# Given: A value and its type specification.
+	var value: Variant = params[0]
+	var spec: Variant = params[1]
+
+	# Given: The value's JSON representation after passing through a JSON string.
+	var text := JSON.stringify(Value.to_json_value(value), "", false, true)
+	var parsed: Variant = JSON.parse_string(text)
+
+	# When: The JSON value is converted back.
+	var out := []
+	var err := Value.from_json_value(parsed, spec, out)
+
+	# Then: The original value is restored.
+	assert_eq(err, OK)
+	assert_true(Value.equals(out[0], value), "%s != %s" % [out[0], value])
+
+
+func test_from_json_value_rejects_mismatched_types(
+	params = use_parameters(
+		[
+			["1", TYPE_BOOL],
+			[1.5, TYPE_INT],
+			["abc", TYPE_INT],
+			[1, TYPE_STRING],
+			[[1, 2], TYPE_VECTOR3],
+			[{"a": 1}, [TYPE_ARRAY, TYPE_INT]],
+			[{"a": 1}, [TYPE_DICTIONARY, TYPE_INT, TYPE_INT]],
+			[[1], Point],
+		]
+	)
+) -> void:
+	# Given: A JSON value which doesn't match its type specification.
+	var value: Variant = params[0]
+	var spec: Variant = params[1]
+
+	# When: The JSON value is converted.
+	var out := []
+	var err := Value.from_json_value(value, spec, out)
+
+	# Then: An error is returned.
+	assert_eq(err, ERR_INVALID_DATA)
+	assert_null(out[0])
+
+
+func test_sized_array_contains_default_elements(
+	params = use_parameters(
+		[
+			[TYPE_PACKED_BYTE_ARRAY, PackedByteArray([0, 0, 0])],
+			[TYPE_PACKED_INT32_ARRAY, PackedInt32Array([0, 0, 0])],
+			[TYPE_PACKED_STRING_ARRAY, PackedStringArray(["", "", ""])],
+			[[TYPE_ARRAY, TYPE_BOOL], [false, false, false]],
+			[[TYPE_ARRAY, TYPE_VECTOR2I], [Vector2i(), Vector2i(), Vector2i()]],
+			[[TYPE_ARRAY, [TYPE_DICTIONARY, TYPE_STRING, TYPE_INT]], [{}, {}, {}]],
+		]
+	)
+) -> void:
+	# Given: An array type specification.
+	var spec: Variant = params[0]
+	var expected: Variant = params[1]
+
+	# When: A sized array is created.
+	var result: Variant = Value.sized_array(spec, 3)
+
+	# Then: The array contains default elements of the expected type.
+	assert_eq(typeof(result), typeof(expected))
+	assert_eq(result, expected)
+
+
+func test_sized_array_is_typed() -> void:
+	# Given: A fixed-length array field of booleans.
+	var flags: Array[bool] = []
+
+	# When: The field is assigned a sized array.
+	flags = Value.sized_array([TYPE_ARRAY, TYPE_BOOL], 2)
+
+	# Then: The array retains its element type.
+	assert_eq(flags.get_typed_builtin(), TYPE_BOOL)
+	assert_eq(flags, [false, false])
+
+
+func test_to_debug_string_formats_nested_values() -> void:
+	# Given: A nested value.
+	var value := {"a": ["x", 1], "b": PackedByteArray([1, 255])}
+
+	# When: The value is formatted.
+	var text := Value.to_debug_string(value)
+
+	# Then: The output matches expectations.
+	assert_eq(text, '{"a": ["x", 1], "b": 0x01ff}')
+
+
+func test_to_debug_string_truncates_large_values() -> void:
+	# Given: Values larger than the element limit.
+	var count := Value.DEBUG_MAX_ELEMENTS + 4
+	var bytes := PackedByteArray()
+	bytes.resize(count)
+	var elements := PackedInt32Array()
+	elements.resize(count)
+
+	# When: The values are formatted.
+	var bytes_text := Value.to_debug_string(bytes)
+	var elements_text := Value.to_debug_string(elements)
+
+	# Then: Only the first elements are included.
+	assert_eq(bytes_text, "0x" + "00".repeat(Value.DEBUG_MAX_ELEMENTS) + "... (20 bytes)")
+	assert_true(elements_text.ends_with(", 0, ... +4 more]"))
+	assert_eq(elements_text.count(", 0"), Value.DEBUG_MAX_ELEMENTS - 1)
@@ -67,6 +67,29 @@ func write_bits(value: int, count: int) -> void:
 		remaining -= bits_in_byte
 
 
+## `write_bits_signed` writes a signed value (two's complement) using the specified
+## number of bits (1-64). Values outside the representable range are truncated.
+func write_bits_signed(value: int, count: int) -> void:
+	if count < 1 or count > 64:
+		_set_error(ERR_INVALID_PARAMETER)
+		return
+
+	write_bits(value & ((1 << count) - 1) if count < 64 else value, count)
+
+
+## `write_padding` writes the specified number of zero bits.
+func write_padding(count: int) -> void:
+	if count < 0:
+		_set_error(ERR_INVALID_PARAMETER)
+		return
+
+	var remaining := count
+	while remaining > 0:
+		var bits := mini(remaining, 64)
+		write_bits(0, bits)
+		remaining -= bits
+
+
 ## `write_zigzag` writes a ZigZag-encoded signed value using fixed bits.
 func write_zigzag(value: int, bit_count: int) -> void:
 	write_bits(Encoding.zigzag_encode(value), bit_count)
@@ -87,6 +110,11 @@ func write_u32(value: int) -> void:
 	write_bits(value & 0xFFFFFFFF, 32)
 
 
+## `write_u64` writes an unsigned 64-bit integer.
+func write_u64(value: int) -> void:
+	write_bits(value, 64)
+
+
 ## `write_i8` writes a signed 8-bit integer (two's complement).
 func write_i8(value: int) -> void:
 	write_bits(value & 0xFF, 8)
@@ -123,6 +151,103 @@ func write_f64(value: float) -> void:
 	write_bits(hi, 32)
 
 
+## `write_quantized` writes a float within `[minimum, maximum]` as an unsigned integer
+## using the specified number of bits (1-63). Out-of-range values are clamped if
+## `clamp_range` is set; otherwise, they set `ERR_PARAMETER_RANGE_ERROR`.
+func write_quantized(
+	value: float,
+	minimum: float,
+	maximum: float,
+	bit_count: int,
+	clamp_range: bool = true,
+) -> void:
+	if bit_count < 1 or bit_count > 63 or not maximum > minimum:
+		_set_error(ERR_INVALID_PARAMETER)
+		return
+
+	if is_nan(value) or (not clamp_range and (value < minimum or value > maximum)):
+		_set_error(ERR_PARAMETER_RANGE_ERROR)
+		return
+
+	write_bits(Encoding.quantize(value, minimum, maximum, bit_count), bit_count)
+
+
+## `write_vector2` writes a `Vector2` as two single-precision floats.
+func write_vector2(value: Vector2) -> void:
+	write_f32(value.x)
+	write_f32(value.y)
+
+
+## `write_vector2i` writes a `Vector2i` as two signed 32-bit integers.
+func write_vector2i(value: Vector2i) -> void:
+	write_i32(value.x)
+	write_i32(value.y)
+
+
+## `write_vector3` writes a `Vector3` as three single-precision floats.
+func write_vector3(value: Vector3) -> void:
+	write_f32(value.x)
+	write_f32(value.y)
+	write_f32(value.z)
+
+
+## `write_vector3i` writes a `Vector3i` as three signed 32-bit integers.
+func write_vector3i(value: Vector3i) -> void:
+	write_i32(value.x)
+	write_i32(value.y)
+	write_i32(value.z)
+
+
+## `write_vector4` writes a `Vector4` as four single-precision floats.
+func write_vector4(value: Vector4) -> void:
+	write_f32(value.x)
+	write_f32(value.y)
+	write_f32(value.z)
+	write_f32(value.w)
+
+
+## `write_vector4i` writes a `Vector4i` as four signed 32-bit integers.
+func write_vector4i(value: Vector4i) -> void:
+	write_i32(value.x)
+	write_i32(value.y)
+	write_i32(value.z)
+	write_i32(value.w)
+
+
+## `write_quaternion` writes a `Quaternion` as four single-precision floats (x, y, z,
+## w).
+func write_quaternion(value: Quaternion) -> void:
+	write_f32(value.x)
+	write_f32(value.y)
+	write_f32(value.z)
+	write_f32(value.w)
+
+
+## `write_color` writes a `Color` as four single-precision floats (r, g, b, a).
+func write_color(value: Color) -> void:
+	write_f32(value.r)
+	write_f32(value.g)
+	write_f32(value.b)
+	write_f32(value.a)
+
+
+## `write_transform2d` writes a `Transform2D` as its basis columns followed by its
+## origin.
+func write_transform2d(value: Transform2D) -> void:
+	write_vector2(value.x)
+	write_vector2(value.y)
+	write_vector2(value.origin)
+
+
+## `write_transform3d` writes a `Transform3D` as its basis columns followed by its
+## origin.
+func write_transform3d(value: Transform3D) -> void:
+	write_vector3(value.basis.x)
+	write_vector3(value.basis.y)
+	write_vector3(value.basis.z)
+	write_vector3(value.origin)
+
+
 ## `write_varint_unsigned` writes an unsigned LEB128 varint.
 func write_varint_unsigned(value: int) -> void:
 	var val := value
@@ -159,15 +284,143 @@ func write_bytes(data: PackedByteArray) -> void:
 		write_bits(data[i], 8)
 
 
-## `write_string` writes a varint-prefixed UTF-8 string (byte-aligned).
-func write_string(value: String) -> void:
+## `write_size` writes the size of a string, byte array, or collection as an unsigned
+## integer of `prefix_bits` bits (1-64) or, if `prefix_bits` is `0`, as an unsigned
+## LEB128 varint. If `size` doesn't fit within `prefix_bits` bits, or `max_size` is
+## non-negative and `size` exceeds it, `ERR_PARAMETER_RANGE_ERROR` is set.
+func write_size(size: int, prefix_bits: int = 0, max_size: int = -1) -> void:
+	if max_size >= 0 and size > max_size:
+		_set_error(ERR_PARAMETER_RANGE_ERROR)
+		return
+
+	if prefix_bits == 0:
+		write_varint_unsigned(size)
+		return
+
+	if size < 0 or (prefix_bits < 64 and size >= 1 << prefix_bits):
+		_set_error(ERR_PARAMETER_RANGE_ERROR)
+		return
+
+	write_bits(size, prefix_bits)
+
+
+## `write_string` writes a UTF-8 string prefixed with its size in bytes (see
+## `write_size`).
+func write_string(value: String, prefix_bits: int = 0, max_size: int = -1) -> void:
 	var utf8 := value.to_utf8_buffer()
-	write_varint_unsigned(utf8.size())
+	write_size(utf8.size(), prefix_bits, max_size)
 	write_bytes(utf8)
 
 
+## `write_int32_array` writes each element of a `PackedInt32Array` as an integer of
+## `byte_count` bytes (1, 2, 4, or 8), truncating values which don't fit. Byte-aligned
+## 4-byte elements are written in bulk.
+func write_int32_array(values: PackedInt32Array, byte_count: int) -> void:
+	if byte_count == 4 and _position % 8 == 0:
+		_write_aligned(values.to_byte_array())
+		return
+
+	_write_int_array(values, byte_count)
+
+
+## `write_int64_array` writes each element of a `PackedInt64Array` as an integer of
+## `byte_count` bytes (1, 2, 4, or 8), truncating values which don't fit. Byte-aligned
+## 8-byte elements are written in bulk.
+func write_int64_array(values: PackedInt64Array, byte_count: int) -> void:
+	if byte_count == 8 and _position % 8 == 0:
+		_write_aligned(values.to_byte_array())
+		return
+
+	_write_int_array(values, byte_count)
+
+
+## `write_float32_array` writes each element of a `PackedFloat32Array` as an IEEE 754
+## single-precision float. Byte-aligned arrays are written in bulk.
+func write_float32_array(values: PackedFloat32Array) -> void:
+	if _position % 8 == 0:
+		_write_aligned(values.to_byte_array())
+		return
+
+	for value in values:
+		write_f32(value)
+
+
+## `write_float64_array` writes each element of a `PackedFloat64Array` as an IEEE 754
+## double-precision float. Byte-aligned arrays are written in bulk.
+func write_float64_array(values: PackedFloat64Array) -> void:
+	if _position % 8 == 0:
+		_write_aligned(values.to_byte_array())
+		return
+
+	for value in values:
+		write_f64(value)
+
+
+## `begin_field` starts a field of a tagged message by writing its index and a
+## placeholder for its size. The returned position must be passed to `end_field` once
+## the field's value has been written.
+func begin_field(index: int) -> int:
+	write_varint_unsigned(index + 1)
+	write_u32(0)
+
+	return _position
+
+
+## `end_field` completes the field started at `start` (see `begin_field`) by writing
+## the size, in bits, of the field's value.
+func end_field(start: int) -> void:
+	if start < 32 or start > _position:
+		_set_error(ERR_INVALID_PARAMETER)
+		return
+
+	var end := _position
+	if end - start > 0xFFFFFFFF:
+		_set_error(ERR_INVALID_DATA)
+		return
+
+	_position = start - 32
+	write_u32(end - start)
+	_position = end
+
+
+## `end_fields` terminates the fields of a tagged message.
+func end_fields() -> void:
+	write_varint_unsigned(0)
+
+
 # -- ENGINE METHODS (OVERRIDES) ------------------------------------------------------ #
 
 
 func _init() -> void:
 	_buffer = PackedByteArray()
+
+
+# -- PRIVATE METHODS ----------------------------------------------------------------- #
+
+
+## `_write_aligned` writes raw little-endian bytes at the current (byte-aligned)
+## position, preserving any data which follows them.
+func _write_aligned(data: PackedByteArray) -> void:
+	@warning_ignore("integer_division")
+	var start := _position / 8
+	var tail := _buffer.slice(start + data.size())
+
+	_buffer.resize(start)
+	_buffer.append_array(data)
+	_buffer.append_array(tail)
+
+	_position += data.size() * 8
+
+
+## `_write_int_array` writes each element of a packed integer array using the specified
+## number of bytes per element.
+func _write_int_array(values: Variant, byte_count: int) -> void:
+	if byte_count not in [1, 2, 4, 8]:
+		_set_error(ERR_INVALID_PARAMETER)
+		return
+
+	var bit_count := byte_count * 8
+	var mask := (1 << bit_count) - 1 if bit_count < 64 else -1
+
+	for value in values:
+		write_bits(value & mask, bit_count)
@@ -133,6 +133,75 @@ func test_writer_read_bits_64() -> void:
 	assert_true(reader.is_valid())
 
 
+func test_writer_read_bits_signed(
+	params = use_parameters([[0, 1], [-1, 1], [3, 3], [-4, 3], [-2048, 12], [2047, 12]])
+) -> void:
+	# Given: A writer.
+	var writer := Writer.new()
+	var value: int = params[0]
+	var count: int = params[1]
+
+	# When: Writing a signed value with a non-standard bit width.
+	writer.write_bits_signed(value, count)
+	var data := writer.to_bytes()
+
+	# Then: Reading returns the sign-extended value.
+	var reader := Reader.new(data)
+	assert_eq(reader.read_bits_signed(count), value)
+	assert_eq(reader.get_position(), count)
+	assert_true(reader.is_valid())
+
+
+func test_writer_read_bits_signed_64() -> void:
+	# Given: A writer.
+	var writer := Writer.new()
+
+	# When: Writing a negative 64-bit value.
+	writer.write_bits_signed(-9000000000000000000, 64)
+	var data := writer.to_bytes()
+
+	# Then: Reading returns the same value.
+	var reader := Reader.new(data)
+	assert_eq(reader.read_bits_signed(64), -9000000000000000000)
+	assert_true(reader.is_valid())
+
+
+func test_writer_read_bits_signed_truncates() -> void:
+	# Given: A writer.
+	var writer := Writer.new()
+
+	# When: Writing a negative value followed by an unsigned value.
+	writer.write_bits_signed(-1, 5)
+	writer.write_bits(0b101, 3)
+	var data := writer.to_bytes()
+
+	# Then: The signed value does not overwrite neighboring bits.
+	var reader := Reader.new(data)
+	assert_eq(reader.read_bits_signed(5), -1)
+	assert_eq(reader.read_bits(3), 0b101)
+	assert_true(reader.is_valid())
+
+
+func test_writer_write_padding() -> void:
+	# Given: A writer.
+	var writer := Writer.new()
+
+	# When: Writing padding between values.
+	writer.write_bits(0b111, 3)
+	writer.write_padding(69)
+	writer.write_bool(true)
+	var data := writer.to_bytes()
+
+	# Then: The padding is written as zero bits.
+	var reader := Reader.new(data)
+	assert_eq(reader.read_bits(3), 0b111)
+	assert_eq(reader.read_bits(64), 0)
+	assert_eq(reader.read_bits(5), 0)
+	assert_true(reader.read_bool())
+	assert_eq(writer.get_position(), 73)
+	assert_true(reader.is_valid())
+
+
 func test_writer_read_u8() -> void:
 	# Given: A writer.
 	var writer := Writer.new()
@@ -373,6 +442,126 @@ func test_writer_read_f64_negative_zero() -> void:
 	assert_true(reader.is_valid())
 
 
+func test_writer_read_quantized() -> void:
+	# Given: A writer.
+	var writer := Writer.new()
+
+	# When: Writing a quantized float.
+	writer.write_quantized(12.34, -100.0, 100.0, 16)
+	var data := writer.to_bytes()
+
+	# Then: Only the requested number of bits is used.
+	assert_eq(writer.get_position(), 16)
+
+	# Then: Reading returns the value within the quantization step.
+	var reader := Reader.new(data)
+	assert_almost_eq(reader.read_quantized(-100.0, 100.0, 16), 12.34, 200.0 / 65535.0)
+	assert_true(reader.is_valid())
+
+
+func test_writer_read_quantized_clamps_out_of_range() -> void:
+	# Given: A writer.
+	var writer := Writer.new()
+
+	# When: Writing quantized floats outside of the range.
+	writer.write_quantized(-5.0, 0.0, 1.0, 8)
+	writer.write_quantized(5.0, 0.0, 1.0, 8)
+	var data := writer.to_bytes()
+
+	# Then: The values are clamped to the range.
+	var reader := Reader.new(data)
+	assert_eq(reader.read_quantized(0.0, 1.0, 8), 0.0)
+	assert_eq(reader.read_quantized(0.0, 1.0, 8), 1.0)
+	assert_true(reader.is_valid())
+
+
+func test_writer_quantized_out_of_range_without_clamp() -> void:
+	# Given: A writer.
+	var writer := Writer.new()
+
+	# When: Writing an out-of-range quantized float without clamping.
+	writer.write_quantized(5.0, 0.0, 1.0, 8, false)
+
+	# Then: There is a range error.
+	assert_eq(writer.get_error(), ERR_PARAMETER_RANGE_ERROR)
+
+	# Then: Nothing is written.
+	assert_eq(writer.get_position(), 0)
+
+
+func test_writer_quantized_nan() -> void:
+	# Given: A writer.
+	var writer := Writer.new()
+
+	# When: Writing a NaN quantized float.
+	writer.write_quantized(NAN, 0.0, 1.0, 8)
+
+	# Then: There is a range error.
+	assert_eq(writer.get_error(), ERR_PARAMETER_RANGE_ERROR)
+
+func test_writer_read_vectors() -> void:
+	# Given: A writer.
+	var writer := Writer.new()
+
+	# When: Writing vectors of each dimension.
+	writer.write_vector2(Vector2(1.5, -2.0))
+	writer.write_vector2i(Vector2i(3, -4))
+	writer.write_vector3(Vector3(0.25, 0.5, -0.75))
+	writer.write_vector3i(Vector3i(-1, 0, 2147483647))
+	writer.write_vector4(Vector4(1.0, 2.0, 3.0, 4.0))
+	writer.write_vector4i(Vector4i(-5, 6, -7, 8))
+	var data := writer.to_bytes()
+
+	# Then: Each component uses 32 bits.
+	assert_eq(writer.get_position(), 32 * 18)
+
+	# Then: Reading returns the original values.
+	var reader := Reader.new(data)
+	assert_eq(reader.read_vector2(), Vector2(1.5, -2.0))
+	assert_eq(reader.read_vector2i(), Vector2i(3, -4))
+	assert_eq(reader.read_vector3(), Vector3(0.25, 0.5, -0.75))
+	assert_eq(reader.read_vector3i(), Vector3i(-1, 0, 2147483647))
+	assert_eq(reader.read_vector4(), Vector4(1.0, 2.0, 3.0, 4.0))
+	assert_eq(reader.read_vector4i(), Vector4i(-5, 6, -7, 8))
+	assert_true(reader.is_valid())
+
+
+func test_writer_read_quaternion_and_color() -> void:
+	# Given: A writer.
+	var writer := Writer.new()
+
+	# When: Writing a quaternion and a color.
+	writer.write_quaternion(Quaternion(0.0, 0.5, 0.0, 0.5))
+	writer.write_color(Color(0.25, 0.5, 0.75, 1.0))
+	var data := writer.to_bytes()
+
+	# Then: Reading returns the original values.
+	var reader := Reader.new(data)
+	assert_eq(reader.read_quaternion(), Quaternion(0.0, 0.5, 0.0, 0.5))
+	assert_eq(reader.read_color(), Color(0.25, 0.5, 0.75, 1.0))
+	assert_true(reader.is_valid())
+
+
+func test_writer_read_transforms() -> void:
+	# Given: A writer.
+	var writer := Writer.new()
+
+	# Given: Transforms with non-trivial bases and origins.
+	var t2 := Transform2D(Vector2(0.0, 1.0), Vector2(-1.0, 0.0), Vector2(10.0, -5.0))
+	var t3 := Transform3D(Basis.from_scale(Vector3.ONE * 2.0), Vector3(1.0, 2.0, 3.0))
+
+	# When: Writing the transforms.
+	writer.write_transform2d(t2)
+	writer.write_transform3d(t3)
+	var data := writer.to_bytes()
+
+	# Then: Reading returns the original values.
+	var reader := Reader.new(data)
+	assert_eq(reader.read_transform2d(), t2)
+	assert_eq(reader.read_transform3d(), t3)
+	assert_true(reader.is_valid())
+
+
 func test_writer_read_varint_unsigned_single_byte(
 	params = use_parameters([[0], [1], [127]])
 ) -> void:
@@ -486,6 +675,132 @@ func test_writer_varint_alignment() -> void:
 	assert_eq(reader.get_position(), 19)  # 3 + 2 * 8 = 19
 
 
+func test_writer_read_int32_array(params = use_parameters([[0], [3]])) -> void:
+	# Given: A writer, optionally offset from a byte boundary.
+	var writer := Writer.new()
+	var offset: int = params[0]
+	if offset > 0:
+		writer.write_bits(0, offset)
+
+	# Given: Values spanning the signed 32-bit range.
+	var values := PackedInt32Array([0, 1, -1, 2147483647, -2147483648])
+
+	# When: Writing the array.
+	writer.write_int32_array(values, 4)
+	var data := writer.to_bytes()
+
+	# Then: Each element uses 32 bits.
+	assert_eq(writer.get_position(), offset + 32 * values.size())
+
+	# Then: Reading returns the original values.
+	var reader := Reader.new(data)
+	if offset > 0:
+		reader.read_bits(offset)
+	assert_eq(reader.read_int32_array(values.size(), 4, true), values)
+	assert_true(reader.is_valid())
+
+
+func test_writer_read_int32_array_matches_scalar_encoding() -> void:
+	# Given: Values written in bulk and one at a time.
+	var values := PackedInt32Array([7, -300, 65535])
+	var bulk := Writer.new()
+	var scalar := Writer.new()
+
+	# When: Writing the values both ways.
+	bulk.write_int32_array(values, 4)
+	for value in values:
+		scalar.write_i32(value)
+
+	# Then: The encoded bytes are identical.
+	assert_eq(bulk.to_bytes(), scalar.to_bytes())
+
+
+func test_writer_read_int_array_narrow_elements(
+	params = use_parameters([[1, true], [1, false], [2, true], [2, false]])
+) -> void:
+	# Given: A writer.
+	var writer := Writer.new()
+	var byte_count: int = params[0]
+	var signed: bool = params[1]
+
+	# Given: Values which fit in the element width.
+	var values := PackedInt64Array([0, 1, 100])
+	if signed:
+		values.append(-1)
+		values.append(-100)
+
+	# When: Writing the array with narrow elements.
+	writer.write_int64_array(values, byte_count)
+	var data := writer.to_bytes()
+
+	# Then: Each element uses the requested number of bytes.
+	assert_eq(writer.get_position(), byte_count * 8 * values.size())
+
+	# Then: Reading returns the original values.
+	var reader := Reader.new(data)
+	assert_eq(reader.read_int64_array(values.size(), byte_count, signed), values)
+	assert_true(reader.is_valid())
+
+
+func test_writer_read_int64_array() -> void:
+	# Given: A writer.
+	var writer := Writer.new()
+
+	# Given: Values spanning the 64-bit range.
+	var values := PackedInt64Array([0, -1, 9223372036854775807, -9223372036854775808])
+
+	# When: Writing the array.
+	writer.write_int64_array(values, 8)
+	var data := writer.to_bytes()
+
+	# Then: Reading returns the original values.
+	var reader := Reader.new(data)
+	assert_eq(reader.read_int64_array(values.size(), 8, true), values)
+	assert_true(reader.is_valid())
+
+
+func test_writer_read_float_arrays(params = use_parameters([[0], [5]])) -> void:
+	# Given: A writer, optionally offset from a byte boundary.
+	var writer := Writer.new()
+	var offset: int = params[0]
+	if offset > 0:
+		writer.write_bits(0, offset)
+
+	# Given: Float arrays of both precisions.
+	var f32 := PackedFloat32Array([0.0, 1.5, -2.25, 1024.0])
+	var f64 := PackedFloat64Array([0.0, PI, -1e300])
+
+	# When: Writing the arrays.
+	writer.write_float32_array(f32)
+	writer.write_float64_array(f64)
+	var data := writer.to_bytes()
+
+	# Then: Reading returns the original values.
+	var reader := Reader.new(data)
+	if offset > 0:
+		reader.read_bits(offset)
+	assert_eq(reader.read_float32_array(f32.size()), f32)
+	assert_eq(reader.read_float64_array(f64.size()), f64)
+	assert_true(reader.is_valid())
+
+
+func test_writer_aligned_array_preserves_following_data() -> void:
+	# Given: A writer containing data after a rewound position.
+	var writer := Writer.new()
+	writer.write_u32(0)
+	writer.write_u32(0xDEADBEEF)
+	writer.seek(0)
+
+	# When: Writing a bulk array over the first value.
+	writer.write_int32_array(PackedInt32Array([42]), 4)
+	writer.seek(64)
+
+	# Then: The following data is preserved.
+	var reader := Reader.new(writer.to_bytes())
+	assert_eq(reader.read_i32(), 42)
+	assert_eq(reader.read_u32(), 0xDEADBEEF)
+
+
 func test_writer_read_bytes() -> void:
 	# Given: A writer and some bytes.
 	var writer := Writer.new()
@@ -566,6 +881,93 @@ func test_writer_read_string_unicode() -> void:
 	assert_true(reader.is_valid())
 
 
+func test_writer_read_string_prefixed() -> void:
+	# Given: A writer and a string.
+	var writer := Writer.new()
+	var original := "abc"
+
+	# When: Writing and reading the string with an 8-bit size prefix.
+	writer.write_string(original, 8)
+	var data := writer.to_bytes()
+	var reader := Reader.new(data)
+	var result := reader.read_string(8)
+
+	# Then: The strings match.
+	assert_eq(result, original)
+	assert_eq(data, PackedByteArray([0x03, 0x61, 0x62, 0x63]))
+	assert_true(reader.is_valid())
+
+
+func test_writer_read_size(
+	params = use_parameters(
+		[
+			[0, 0],
+			[300, 0],
+			[0, 1],
+			[255, 8],
+			[1 << 40, 41],
+			[9223372036854775807, 64],
+		]
+	)
+) -> void:
+	# Given: A writer, a size, and a prefix width.
+	var writer := Writer.new()
+	var size: int = params[0]
+	var prefix_bits: int = params[1]
+
+	# When: Writing and reading the size.
+	writer.write_size(size, prefix_bits)
+	var reader := Reader.new(writer.to_bytes())
+	var result := reader.read_size(prefix_bits)
+
+	# Then: The sizes match.
+	assert_eq(result, size)
+	assert_true(writer.is_valid())
+	assert_true(reader.is_valid())
+
+
+func test_writer_size_overflows_prefix(
+	params = use_parameters(
+		[
+			[256, 8],
+			[65536, 16],
+			[2, 1],
+			[-1, 16],
+		]
+	)
+) -> void:
+	# Given: A writer and a size which doesn't fit within the prefix.
+	var writer := Writer.new()
+
+	# When: Writing the size.
+	writer.write_size(params[0], params[1])
+
+	# Then: The range error is set.
+	assert_eq(writer.get_error(), ERR_PARAMETER_RANGE_ERROR)
+
+
+func test_writer_string_overflows_narrow_prefix() -> void:
+	# Given: A writer and a string longer than an 8-bit prefix can describe.
+	var writer := Writer.new()
+
+	# When: Writing the string with an 8-bit size prefix.
+	writer.write_string("a".repeat(256), 8)
+
+	# Then: The range error is set rather than truncating the size.
+	assert_eq(writer.get_error(), ERR_PARAMETER_RANGE_ERROR)
+
+
+func test_writer_size_exceeds_max() -> void:
+	# Given: A writer and a string larger than its bound.
+	var writer := Writer.new()
+
+	# When: Writing the string with a maximum size.
+	writer.write_string("abcd", 2, 3)
+
+	# Then: The range error is set.
+	assert_eq(writer.get_error(), ERR_PARAMETER_RANGE_ERROR)
+
+
 func test_writer_bytes_alignment() -> void:
 	# Given: A writer with bits before bytes.
 	var writer := Writer.new()
@@ -625,6 +1027,7 @@ func test_writer_roundtrip_all_integer_types() -> void:
 	writer.write_i16(-30000)
 	writer.write_i32(-2000000000)
 	writer.write_i64(-9000000000000000000)
+	writer.write_u64(0x7FFFFFFFFFFFFFFF)
 	var data := writer.to_bytes()
 
 	# When: Reading all values.
@@ -638,6 +1041,7 @@ func test_writer_roundtrip_all_integer_types() -> void:
 	assert_eq(reader.read_i16(), -30000)
 	assert_eq(reader.read_i32(), -2000000000)
 	assert_eq(reader.read_i64(), -9000000000000000000)
+	assert_eq(reader.read_u64(), 0x7FFFFFFFFFFFFFFF)
 	assert_true(reader.is_valid())
 
 
@@ -670,6 +1074,109 @@ func test_writer_roundtrip_nested_messages_pattern() -> void:
 	assert_true(reader.is_valid())
 
 
+func test_writer_roundtrip_tagged_fields() -> void:
+	# Given: A writer with tagged fields, including a sparse index.
+	var writer := Writer.new()
+
+	var start := writer.begin_field(0)
+	writer.write_bool(true)
+	writer.end_field(start)
+
+	start = writer.begin_field(7)
+	writer.write_string("hello")
+	writer.end_field(start)
+
+	writer.end_fields()
+	assert_true(writer.is_valid())
+
+	# When: Reading the fields back.
+	var reader := Reader.new(writer.to_bytes())
+
+	# Then: Each field is read in order.
+	assert_eq(reader.read_field_index(), 0)
+	var end := reader.read_field_end()
+	assert_true(reader.read_bool())
+	reader.skip_field(end)
+
+	assert_eq(reader.read_field_index(), 7)
+	end = reader.read_field_end()
+	assert_eq(reader.read_string(), "hello")
+	reader.skip_field(end)
+
+	# Then: The end of the fields is detected.
+	assert_eq(reader.read_field_index(), -1)
+	assert_true(reader.is_valid())
+
+
+func test_writer_tagged_unknown_field_is_skipped() -> void:
+	# Given: Data written by a newer schema with an additional field (index 1).
+	var writer := Writer.new()
+
+	var start := writer.begin_field(0)
+	writer.write_u16(1234)
+	writer.end_field(start)
+
+	start = writer.begin_field(1)
+	writer.write_string("unknown")
+	writer.write_f64(1.5)
+	writer.end_field(start)
+
+	start = writer.begin_field(2)
+	writer.write_bits(5, 3)
+	writer.end_field(start)
+
+	writer.end_fields()
+
+	# When: Reading the data with an older schema which only knows fields 0 and 2.
+	var reader := Reader.new(writer.to_bytes())
+	var values := {}
+
+	var index := reader.read_field_index()
+	while index >= 0:
+		var end := reader.read_field_end()
+		match index:
+			0:
+				values[0] = reader.read_u16()
+			2:
+				values[2] = reader.read_bits(3)
+		reader.skip_field(end)
+		index = reader.read_field_index()
+
+	# Then: The known fields are read and the unknown field is skipped.
+	assert_true(reader.is_valid())
+	assert_eq(values, {0: 1234, 2: 5})
+
+
+func test_writer_tagged_missing_field_is_absent() -> void:
+	# Given: Data written by an older schema which lacks field 1.
+	var writer := Writer.new()
+
+	var start := writer.begin_field(0)
+	writer.write_u8(42)
+	writer.end_field(start)
+
+	writer.end_fields()
+
+	# When: Reading the data with a newer schema which knows fields 0 and 1.
+	var reader := Reader.new(writer.to_bytes())
+	var values := {}
+
+	var index := reader.read_field_index()
+	while index >= 0:
+		var end := reader.read_field_end()
+		match index:
+			0:
+				values[0] = reader.read_u8()
+			1:
+				values[1] = reader.read_string()
+		reader.skip_field(end)
+		index = reader.read_field_index()
+
+	# Then: Only the field present in the data is read.
+	assert_true(reader.is_valid())
+	assert_eq(values, {0: 42})
+
+
 # -- TEST HOOKS ---------------------------------------------------------------------- #
 
 
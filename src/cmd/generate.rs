@@ -1,6 +1,6 @@
 use std::path::PathBuf;
 
-use crate::gdscript::GDScript;
+use crate::gdscript::{GDScript, Options};
 
 /* -------------------------------------------------------------------------- */
 /*                                Struct: Args                                */
@@ -18,6 +18,26 @@ pub struct Args {
     #[arg(short = 'I', long = "import_root", value_name = "DIR")]
     pub import_roots: Vec<PathBuf>,
 
+    /// Declare map fields as an untyped 'Dictionary' instead of a typed
+    /// 'Dictionary[K, V]'. Required for Godot versions prior to 4.4.
+    #[arg(long)]
+    pub untyped_dictionaries: bool,
+
+    /// Encode messages using the tagged wire format, which prefixes each field
+    /// with its index and size so that schemas can evolve compatibly.
+    #[arg(long)]
+    pub tagged: bool,
+
+    /// Register a global 'class_name' for each generated type, prefixed with its
+    /// package (e.g. 'GamePlayer' for 'game.Player').
+    #[arg(long)]
+    pub class_names: bool,
+
+    /// Default-construct required message and enum fields when a message is
+    /// instantiated, so that new messages can be serialized without setting them.
+    #[arg(long)]
+    pub instantiate_messages: bool,
+
     /// A path to a message definition file to compile.
     #[arg(value_name = "FILES", required = true, num_args = 1..)]
     pub files: Vec<PathBuf>,
@@ -32,5 +52,12 @@ pub struct Args {
 /// `args.out` directory.
 #[allow(unused)]
 pub fn handle(args: Args) -> anyhow::Result<()> {
-    baproto::compile(args.files, args.import_roots, args.out, GDScript)
+    let generator = GDScript::new(Options {
+        typed_dictionaries: !args.untyped_dictionaries,
+        tagged: args.tagged,
+        class_names: args.class_names,
+        instantiate_messages: args.instantiate_messages,
+    });
+
+    baproto::compile(args.files, args.import_roots, args.out, generator)
 }
@@ -71,7 +71,6 @@ impl Assignment {
 
     /// `param` creates a function parameter. To create one without a default
     /// value, see [`Assignment::param`].
-    #[allow(dead_code)]
     pub fn param_with_default<T, U, V>(name: T, hint: U, value: V) -> Self
     where
         T: AsRef<str>,
@@ -126,6 +125,8 @@ pub enum DeclarationKind {
     /// `Var` represents a `var` declaration.
     #[default]
     Var,
+    /// `StaticVar` represents a `static var` declaration.
+    StaticVar,
 }
 
 /* ----------------------------- Enum: ValueKind ---------------------------- */
@@ -186,6 +187,7 @@ impl Emit for Assignment {
             None => Ok(()),
             Some(DeclarationKind::Const) => cw.write(w, "const "),
             Some(DeclarationKind::Var) => cw.write(w, "var "),
+            Some(DeclarationKind::StaticVar) => cw.write(w, "static var "),
         }?;
 
         self.variable.emit(cw, w)?;
@@ -316,6 +318,33 @@ mod tests {
         assert_eq!(s.into_content(), "var count: int = 0");
     }
 
+    #[test]
+    fn test_assignment_static_var() {
+        // Given: A string to write to.
+        let mut s = StringWriter::default();
+
+        // Given: A code writer to write with.
+        let mut cw = GDScript::writer();
+
+        // Given: A static variable declaration.
+        let assignment = AssignmentBuilder::default()
+            .variable("Player".to_string())
+            .declaration(DeclarationKind::StaticVar)
+            .type_hint(TypeHint::Explicit("GDScript".to_string()))
+            .value(Expr::null())
+            .build()
+            .unwrap();
+
+        // When: The assignment is serialized to source code.
+        let result = assignment.emit(&mut cw, &mut s);
+
+        // Then: There was no error.
+        assert!(result.is_ok());
+
+        // Then: The output matches expectations.
+        assert_eq!(s.into_content(), "static var Player: GDScript = null");
+    }
+
     #[test]
     fn test_assignment_with_expr_value() {
         use crate::gdscript::ast::{Expr, Literal};
@@ -59,7 +59,7 @@ impl Emit for If {
 
         if let Some(else_body) = &self.else_body {
             cw.newline(w)?;
-            cw.write(w, "else:")?;
+            cw.write(w, &format!("{}else:", cw.get_indent()))?;
             cw.newline(w)?;
             else_body.emit(cw, w)?;
         }
@@ -112,6 +112,32 @@ impl Emit for Match {
     }
 }
 
+/* -------------------------------------------------------------------------- */
+/*                               Struct: While                                */
+/* -------------------------------------------------------------------------- */
+
+/// `While` represents a while loop.
+#[derive(Builder, Clone, Debug)]
+pub struct While {
+    #[builder(setter(into))]
+    pub condition: Expr,
+    #[builder(default, setter(into))]
+    pub body: Block,
+}
+
+/* ------------------------------- Impl: Emit ------------------------------- */
+
+impl Emit for While {
+    fn emit<W: Writer>(&self, cw: &mut CodeWriter, w: &mut W) -> anyhow::Result<()> {
+        cw.write(w, "while ")?;
+        self.condition.emit(cw, w)?;
+        cw.write(w, ":")?;
+        cw.newline(w)?;
+
+        self.body.emit(cw, w)
+    }
+}
+
 /* -------------------------------------------------------------------------- */
 /*                                 Mod: Tests                                 */
 /* -------------------------------------------------------------------------- */
@@ -121,6 +147,7 @@ mod tests {
     use baproto::StringWriter;
 
     use crate::gdscript::GDScript;
+    use crate::gdscript::ast::Operator;
 
     use super::*;
 
@@ -175,6 +202,41 @@ mod tests {
         assert_eq!(s.into_content(), "if ready:\n\tpass\nelse:\n\tpass");
     }
 
+    #[test]
+    fn test_if_with_else_nested() {
+        // Given: A string to write to.
+        let mut s = StringWriter::default();
+
+        // Given: A code writer to write with.
+        let mut cw = GDScript::writer();
+
+        // Given: An if-else statement nested within another if statement.
+        let if_stmt = IfBuilder::default()
+            .condition(Expr::from("outer"))
+            .then_body(Block::from(vec![
+                IfBuilder::default()
+                    .condition(Expr::from("inner"))
+                    .else_body(Block::default())
+                    .build()
+                    .unwrap()
+                    .into(),
+            ]))
+            .build()
+            .unwrap();
+
+        // When: The if statement is serialized to source code.
+        let result = if_stmt.emit(&mut cw, &mut s);
+
+        // Then: There was no error.
+        assert!(result.is_ok());
+
+        // Then: The else branch is indented to match its if statement.
+        assert_eq!(
+            s.into_content(),
+            "if outer:\n\tif inner:\n\t\tpass\n\telse:\n\t\tpass"
+        );
+    }
+
     /* --------------------------- Tests: Match ----------------------------- */
 
     #[test]
@@ -240,4 +302,30 @@ mod tests {
         // Then: The output matches expectations.
         assert_eq!(s.into_content(), "for item in items:\n\tpass");
     }
+
+    /* ---------------------------- Tests: While ---------------------------- */
+
+    #[test]
+    fn test_while_loop() {
+        // Given: A string to write to.
+        let mut s = StringWriter::default();
+
+        // Given: A code writer to write with.
+        let mut cw = GDScript::writer();
+
+        // Given: A while loop.
+        let while_loop = WhileBuilder::default()
+            .condition(Expr::binary_op("index", Operator::Ge, "0"))
+            .build()
+            .unwrap();
+
+        // When: The while loop is serialized to source code.
+        let result = while_loop.emit(&mut cw, &mut s);
+
+        // Then: There was no error.
+        assert!(result.is_ok());
+
+        // Then: The output matches expectations.
+        assert_eq!(s.into_content(), "while index >= 0:\n\tpass");
+    }
 }
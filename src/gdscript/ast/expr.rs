@@ -12,6 +12,8 @@ use super::Emit;
 pub enum Expr {
     /// `BinaryOp` is a binary operator expression.
     BinaryOp(BinaryOp),
+    /// `BitNot` is a bitwise negation expression.
+    BitNot(Box<Expr>),
     /// `FnCall` is a function call expression.
     FnCall(FnCall),
     /// `FieldAccess` is a property access expression.
@@ -22,6 +24,8 @@ pub enum Expr {
     IndexAccess(IndexAccess),
     /// `Literal` is a type-safe literal value.
     Literal(Literal),
+    /// `Not` is a logical negation expression.
+    Not(Box<Expr>),
 }
 
 /* ------------------------------- Impl: Expr ------------------------------- */
@@ -33,7 +37,6 @@ impl Expr {
     }
 
     /// `field` creates a field access expression.
-    #[allow(dead_code)]
     pub fn field<T: Into<Expr>, U: AsRef<str>>(receiver: T, field: U) -> Expr {
         Expr::FieldAccess(FieldAccess {
             receiver: Box::new(receiver.into()),
@@ -64,6 +67,16 @@ impl Expr {
         Expr::Identifier("null".to_string())
     }
 
+    /// `not` creates a logical negation of the provided expression.
+    pub fn not<T: Into<Expr>>(expr: T) -> Expr {
+        Expr::Not(Box::new(expr.into()))
+    }
+
+    /// `bit_not` creates a bitwise negation of the provided expression.
+    pub fn bit_not<T: Into<Expr>>(expr: T) -> Expr {
+        Expr::BitNot(Box::new(expr.into()))
+    }
+
     /// `binary_op` creates a binary operation expression.
     pub fn binary_op<T: Into<Expr>, U: Into<Expr>>(left: T, op: Operator, right: U) -> Expr {
         Expr::BinaryOp(BinaryOp {
@@ -128,11 +141,19 @@ impl Emit for Expr {
     fn emit<W: Writer>(&self, cw: &mut CodeWriter, w: &mut W) -> anyhow::Result<()> {
         match self {
             Self::BinaryOp(b) => b.emit(cw, w),
+            Self::BitNot(e) => {
+                cw.write(w, "~")?;
+                e.emit(cw, w)
+            }
             Self::FnCall(f) => f.emit(cw, w),
             Self::FieldAccess(f) => f.emit(cw, w),
             Self::Identifier(name) => cw.write(w, name),
             Self::IndexAccess(i) => i.emit(cw, w),
             Self::Literal(l) => l.emit(cw, w),
+            Self::Not(e) => {
+                cw.write(w, "not ")?;
+                e.emit(cw, w)
+            }
         }
     }
 }
@@ -178,6 +199,22 @@ pub enum Operator {
     NotEq,
     /// `Add` is the addition operator.
     Add,
+    /// `Gt` is the greater-than operator.
+    Gt,
+    /// `Ge` is the greater-than-or-equal operator.
+    Ge,
+    /// `Le` is the less-than-or-equal operator.
+    Le,
+    /// `BitAnd` is the bitwise AND operator.
+    BitAnd,
+    /// `BitOr` is the bitwise OR operator.
+    BitOr,
+    /// `Or` is the logical OR operator.
+    Or,
+    /// `And` is the logical AND operator.
+    And,
+    /// `Is` is the type-checking operator.
+    Is,
 }
 
 /* ------------------------------- Impl: Emit ------------------------------- */
@@ -188,6 +225,14 @@ impl Emit for Operator {
             Self::Eq => "==",
             Self::NotEq => "!=",
             Self::Add => "+",
+            Self::Gt => ">",
+            Self::Ge => ">=",
+            Self::Le => "<=",
+            Self::BitAnd => "&",
+            Self::BitOr => "|",
+            Self::Or => "or",
+            Self::And => "and",
+            Self::Is => "is",
         };
         cw.write(w, s)
     }
@@ -252,8 +297,9 @@ pub enum Literal {
     Bool(bool),
     /// `Int` is an integer literal.
     Int(i64),
-    /// `Float` is a floating-point literal.
-    Float(f32),
+    /// `Float` is a floating-point literal. GDScript floats are 64-bit, so the value is
+    /// stored (and emitted) at full precision.
+    Float(f64),
     /// `String` is a string literal.
     String(String),
     /// `Array` is an array literal.
@@ -278,10 +324,10 @@ impl From<i64> for Literal {
     }
 }
 
-/* ----------------------------- Impl: From<f32> ---------------------------- */
+/* ----------------------------- Impl: From<f64> ---------------------------- */
 
-impl From<f32> for Literal {
-    fn from(value: f32) -> Self {
+impl From<f64> for Literal {
+    fn from(value: f64) -> Self {
         Self::Float(value)
     }
 }
@@ -318,7 +364,7 @@ impl Emit for Literal {
             Self::Bool(b) => cw.write(w, if *b { "true" } else { "false" }),
             Self::Int(i) => cw.write(w, &i.to_string()),
             Self::Float(f) => {
-                let s = if f.fract().abs() < f32::EPSILON {
+                let s = if f.fract() == 0.0 {
                     &format!("{:.1}", f)
                 } else {
                     &f.to_string()
@@ -815,6 +861,27 @@ mod tests {
         assert_eq!(s.into_content(), "3.5");
     }
 
+    #[test]
+    fn test_literal_float_full_precision() {
+        // Given: A string to write to.
+        let mut s = StringWriter::default();
+
+        // Given: A code writer to write with.
+        let mut cw = GDScript::writer();
+
+        // Given: A float literal which isn't representable as a 32-bit float.
+        let expr = Expr::Literal(Literal::Float(16777217.25));
+
+        // When: The expression is serialized to source code.
+        let result = expr.emit(&mut cw, &mut s);
+
+        // Then: There was no error.
+        assert!(result.is_ok());
+
+        // Then: The output matches expectations.
+        assert_eq!(s.into_content(), "16777217.25");
+    }
+
     #[test]
     fn test_literal_string() {
         // Given: A string to write to.
@@ -933,6 +1000,56 @@ mod tests {
         assert_eq!(s.into_content(), "{\"name\": \"John\", \"age\": 30}");
     }
 
+    /* ----------------------------- Tests: Not ----------------------------- */
+
+    #[test]
+    fn test_not_with_method_call() {
+        // Given: A string to write to.
+        let mut s = StringWriter::default();
+
+        // Given: A code writer to write with.
+        let mut cw = GDScript::writer();
+
+        // Given: A negated method call.
+        let expr = Expr::not(FnCall::method_args(
+            Expr::ident("a"),
+            "equals",
+            vec![Expr::ident("b")],
+        ));
+
+        // When: The expression is serialized to source code.
+        let result = expr.emit(&mut cw, &mut s);
+
+        // Then: There was no error.
+        assert!(result.is_ok());
+
+        // Then: The output matches expectations.
+        assert_eq!(s.into_content(), "not a.equals(b)");
+    }
+
+    /* --------------------------- Tests: BitNot --------------------------- */
+
+    #[test]
+    fn test_bit_not_with_literal() {
+        // Given: A string to write to.
+        let mut s = StringWriter::default();
+
+        // Given: A code writer to write with.
+        let mut cw = GDScript::writer();
+
+        // Given: A bitwise negated integer literal.
+        let expr = Expr::bit_not(Expr::Literal(4.into()));
+
+        // When: The expression is serialized to source code.
+        let result = expr.emit(&mut cw, &mut s);
+
+        // Then: There was no error.
+        assert!(result.is_ok());
+
+        // Then: The output matches expectations.
+        assert_eq!(s.into_content(), "~4");
+    }
+
     /* -------------------------- Tests: BinaryOp --------------------------- */
 
     #[test]
@@ -21,6 +21,10 @@ pub struct FnDef {
     #[builder(default, setter(into, strip_option))]
     pub comment: Option<Comment>,
 
+    /// `is_static` is whether the function is declared `static`.
+    #[builder(default)]
+    pub is_static: bool,
+
     /// `name` is the name of the function.
     pub name: String,
 
@@ -53,6 +57,10 @@ impl Emit for FnDef {
             comment.emit(cw, w)?;
         }
 
+        if self.is_static {
+            cw.write(w, "static ")?;
+        }
+
         cw.write(w, &format!("func {}(", self.name))?;
 
         for (i, param) in self.params.iter().enumerate() {
@@ -181,6 +189,7 @@ mod tests {
         // Given: A function with no parameters or return type.
         let func = FnDef {
             comment: None,
+            is_static: false,
             name: "_ready".to_string(),
             params: vec![],
             type_hint: None,
@@ -209,6 +218,7 @@ mod tests {
         // Given: A function with parameters.
         let func = FnDef {
             comment: None,
+            is_static: false,
             name: "add".to_string(),
             params: vec![
                 Assignment::param_with_default("a", "int", Literal::Int(0)),
@@ -243,6 +253,7 @@ mod tests {
         // Given: A function with explicit return type.
         let func = FnDef {
             comment: None,
+            is_static: false,
             name: "get_value".to_string(),
             params: vec![],
             type_hint: Some(TypeHint::Explicit("int".to_string())),
@@ -273,6 +284,7 @@ mod tests {
         // Given: A function with a return value.
         let func = FnDef {
             comment: None,
+            is_static: false,
             name: "get_five".to_string(),
             params: vec![],
             type_hint: Some(TypeHint::Explicit("int".to_string())),
@@ -292,4 +304,29 @@ mod tests {
             "func get_five() -> int:\n\tpass\n\treturn 5\n"
         );
     }
+
+    #[test]
+    fn test_fn_def_static() {
+        // Given: A string to write to.
+        let mut s = StringWriter::default();
+
+        // Given: A code writer to write with.
+        let mut cw = GDScript::writer();
+
+        // Given: A static function.
+        let func = FnDefBuilder::default()
+            .is_static(true)
+            .name("create")
+            .build()
+            .unwrap();
+
+        // When: The function is serialized to source code.
+        let result = func.emit(&mut cw, &mut s);
+
+        // Then: There was no error.
+        assert!(result.is_ok());
+
+        // Then: The output matches expectations.
+        assert_eq!(s.into_content(), "static func create() -> void:\n\tpass\n");
+    }
 }
@@ -7,6 +7,7 @@ use super::FnDef;
 use super::ForIn;
 use super::If;
 use super::Match;
+use super::While;
 
 /* -------------------------------------------------------------------------- */
 /*                                 Enum: Item                                 */
@@ -38,6 +39,9 @@ pub enum Item {
 
     /// Early return statement.
     Return(Option<Expr>),
+
+    /// While loop.
+    While(While),
 }
 
 /* ------------------------- Impl: From<Assignment> ------------------------- */
@@ -80,6 +84,14 @@ impl From<Match> for Item {
     }
 }
 
+/* --------------------------- Impl: From<While> ---------------------------- */
+
+impl From<While> for Item {
+    fn from(value: While) -> Self {
+        Self::While(value)
+    }
+}
+
 /* ------------------------- Impl: From<EnumDecl> --------------------------- */
 
 impl From<EnumDecl> for Item {
@@ -107,6 +119,7 @@ impl super::Emit for Item {
             Item::ForIn(f) => f.emit(cw, w),
             Item::If(i) => i.emit(cw, w),
             Item::Match(m) => m.emit(cw, w),
+            Item::While(l) => l.emit(cw, w),
             Item::FnDef(f) => f.emit(cw, w),
             Item::Return(expr) => {
                 cw.write(w, "return")?;
@@ -0,0 +1,338 @@
+use baproto::{Descriptor, Message, NativeType, WireFormat};
+
+/* -------------------------------------------------------------------------- */
+/*                              Const: PACKAGE                                */
+/* -------------------------------------------------------------------------- */
+
+/// `PACKAGE` is the schema package whose messages are recognized as Godot built-in
+/// types (e.g. `godot.Vector3`).
+const PACKAGE: &str = "godot";
+
+/* -------------------------------------------------------------------------- */
+/*                             Enum: BuiltinType                              */
+/* -------------------------------------------------------------------------- */
+
+/// `BuiltinType` is a Godot built-in math type which a well-known schema message maps
+/// to. Fields of these types are generated as native GDScript values (rather than as
+/// generated message scripts) and use compact codecs from the runtime.
+///
+/// The schema message must declare the type's components, in order, with the layout
+/// reported by [`BuiltinType::layout`]. This keeps the wire format identical to that of
+/// backends which treat the type as an ordinary message.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BuiltinType {
+    Vector2,
+    Vector2i,
+    Vector3,
+    Vector3i,
+    Vector4,
+    Vector4i,
+    Quaternion,
+    Color,
+    Transform2D,
+    Transform3D,
+}
+
+/* --------------------------- Enum: Component ------------------------------ */
+
+/// `Component` is the expected type of each field of a built-in type's message.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Component {
+    /// `F32` is a single-precision float (`f32`).
+    F32,
+    /// `I32` is a signed 32-bit integer (`i32`).
+    I32,
+    /// `Builtin` is another built-in type.
+    Builtin(BuiltinType),
+}
+
+/* -------------------------- Impl: BuiltinType ----------------------------- */
+
+impl BuiltinType {
+    const ALL: &[BuiltinType] = &[
+        BuiltinType::Vector2,
+        BuiltinType::Vector2i,
+        BuiltinType::Vector3,
+        BuiltinType::Vector3i,
+        BuiltinType::Vector4,
+        BuiltinType::Vector4i,
+        BuiltinType::Quaternion,
+        BuiltinType::Color,
+        BuiltinType::Transform2D,
+        BuiltinType::Transform3D,
+    ];
+
+    /// `from_descriptor` returns the built-in type for a message descriptor, if any.
+    pub fn from_descriptor(descriptor: &Descriptor) -> Option<BuiltinType> {
+        if descriptor.package.len() != 1 || descriptor.package[0] != PACKAGE {
+            return None;
+        }
+
+        let [name] = descriptor.path.as_slice() else {
+            return None;
+        };
+
+        Self::ALL.iter().find(|b| b.name() == name).copied()
+    }
+
+    /// `from_native` returns the built-in type for a native type, if any.
+    pub fn from_native(native: &NativeType) -> Option<BuiltinType> {
+        match native {
+            NativeType::Message { descriptor } => Self::from_descriptor(descriptor),
+            _ => None,
+        }
+    }
+
+    /// `name` returns the GDScript type name.
+    pub fn name(&self) -> &'static str {
+        match self {
+            BuiltinType::Vector2 => "Vector2",
+            BuiltinType::Vector2i => "Vector2i",
+            BuiltinType::Vector3 => "Vector3",
+            BuiltinType::Vector3i => "Vector3i",
+            BuiltinType::Vector4 => "Vector4",
+            BuiltinType::Vector4i => "Vector4i",
+            BuiltinType::Quaternion => "Quaternion",
+            BuiltinType::Color => "Color",
+            BuiltinType::Transform2D => "Transform2D",
+            BuiltinType::Transform3D => "Transform3D",
+        }
+    }
+
+    /// `method_suffix` returns the suffix of the runtime's reader/writer methods for
+    /// this type (e.g. `vector3` for `write_vector3`).
+    pub fn method_suffix(&self) -> String {
+        self.name().to_lowercase()
+    }
+
+    /// `layout` returns the expected component names and type of the schema message.
+    pub fn layout(&self) -> (&'static [&'static str], Component) {
+        match self {
+            BuiltinType::Vector2 => (&["x", "y"], Component::F32),
+            BuiltinType::Vector2i => (&["x", "y"], Component::I32),
+            BuiltinType::Vector3 => (&["x", "y", "z"], Component::F32),
+            BuiltinType::Vector3i => (&["x", "y", "z"], Component::I32),
+            BuiltinType::Vector4 => (&["x", "y", "z", "w"], Component::F32),
+            BuiltinType::Vector4i => (&["x", "y", "z", "w"], Component::I32),
+            BuiltinType::Quaternion => (&["x", "y", "z", "w"], Component::F32),
+            BuiltinType::Color => (&["r", "g", "b", "a"], Component::F32),
+            BuiltinType::Transform2D => (
+                &["x", "y", "origin"],
+                Component::Builtin(BuiltinType::Vector2),
+            ),
+            BuiltinType::Transform3D => (
+                &["x", "y", "z", "origin"],
+                Component::Builtin(BuiltinType::Vector3),
+            ),
+        }
+    }
+
+    /// `validate` verifies that a schema message declares this type's layout (its
+    /// component names, in order, and their encoding), so that the runtime codec matches
+    /// the message's wire format.
+    pub fn validate(&self, msg: &Message) -> anyhow::Result<()> {
+        let (names, component) = self.layout();
+
+        let matches = msg.fields.len() == names.len()
+            && msg.fields.iter().zip(names).all(|(field, name)| {
+                field.name == *name
+                    && field.encoding.transforms.is_empty()
+                    && field.encoding.padding_bits.unwrap_or(0) == 0
+                    && match component {
+                        Component::F32 => {
+                            matches!(field.encoding.native, NativeType::Float { bits: 32 })
+                                && matches!(field.encoding.wire, WireFormat::Bits { count: 32 })
+                        }
+                        Component::I32 => {
+                            matches!(
+                                field.encoding.native,
+                                NativeType::Int {
+                                    bits: 32,
+                                    signed: true
+                                }
+                            ) && matches!(field.encoding.wire, WireFormat::Bits { count: 32 })
+                        }
+                        Component::Builtin(builtin) => {
+                            BuiltinType::from_native(&field.encoding.native) == Some(builtin)
+                        }
+                    }
+            });
+
+        if !matches {
+            let expected = match component {
+                Component::F32 => "f32".to_string(),
+                Component::I32 => "i32".to_string(),
+                Component::Builtin(builtin) => format!("{}.{}", PACKAGE, builtin.name()),
+            };
+
+            anyhow::bail!(
+                "Invalid layout for built-in type '{}.{}': expected fields {:?} of type '{}'",
+                PACKAGE,
+                self.name(),
+                names,
+                expected
+            );
+        }
+
+        Ok(())
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                 Mod: Tests                                 */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+    use baproto::{DescriptorBuilder, Encoding, Field, PackageName};
+
+    use super::*;
+
+    fn descriptor(pkg: Vec<&str>, name: &str) -> Descriptor {
+        DescriptorBuilder::default()
+            .package(PackageName::try_from(pkg).unwrap())
+            .path(vec![name.to_string()])
+            .build()
+            .unwrap()
+    }
+
+    fn field(name: &str, native: NativeType) -> Field {
+        Field {
+            name: name.to_string(),
+            index: 0,
+            encoding: Encoding {
+                wire: WireFormat::Bits { count: 32 },
+                native,
+                transforms: vec![],
+                padding_bits: None,
+            },
+            doc: None,
+        }
+    }
+
+    fn message(name: &str, fields: Vec<Field>) -> Message {
+        Message {
+            descriptor: descriptor(vec![PACKAGE], name),
+            doc: None,
+            fields,
+            messages: vec![],
+            enums: vec![],
+        }
+    }
+
+    /* ----------------------- Tests: from_descriptor ----------------------- */
+
+    #[test]
+    fn test_from_descriptor_builtin() {
+        // Given: A descriptor for a well-known message.
+        let d = descriptor(vec!["godot"], "Vector3i");
+
+        // When: Resolving the built-in type.
+        let result = BuiltinType::from_descriptor(&d);
+
+        // Then: The built-in type is found.
+        assert_eq!(result, Some(BuiltinType::Vector3i));
+    }
+
+    #[test]
+    fn test_from_descriptor_other_package() {
+        // Given: A descriptor for a same-named message in another package.
+        let d = descriptor(vec!["game"], "Vector3");
+
+        // When: Resolving the built-in type.
+        let result = BuiltinType::from_descriptor(&d);
+
+        // Then: No built-in type is found.
+        assert_eq!(result, None);
+    }
+
+    /* --------------------------- Tests: validate -------------------------- */
+
+    #[test]
+    fn test_validate_vector_layout() {
+        // Given: A schema message declaring the Vector2 layout.
+        let msg = message(
+            "Vector2",
+            vec![
+                field("x", NativeType::Float { bits: 32 }),
+                field("y", NativeType::Float { bits: 32 }),
+            ],
+        );
+
+        // When: Validating the message.
+        let result = BuiltinType::Vector2.validate(&msg);
+
+        // Then: The layout is accepted.
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_validate_vector_wrong_component_type() {
+        // Given: A schema message declaring Vector2 with 64-bit components.
+        let msg = message(
+            "Vector2",
+            vec![
+                field("x", NativeType::Float { bits: 64 }),
+                field("y", NativeType::Float { bits: 64 }),
+            ],
+        );
+
+        // When: Validating the message.
+        let result = BuiltinType::Vector2.validate(&msg);
+
+        // Then: An error is returned.
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_validate_vector_wrong_component_names() {
+        // Given: Schema messages declaring Vector3 with reordered and renamed components.
+        let reordered = message(
+            "Vector3",
+            vec![
+                field("z", NativeType::Float { bits: 32 }),
+                field("y", NativeType::Float { bits: 32 }),
+                field("x", NativeType::Float { bits: 32 }),
+            ],
+        );
+        let renamed = message(
+            "Vector3",
+            vec![
+                field("a", NativeType::Float { bits: 32 }),
+                field("b", NativeType::Float { bits: 32 }),
+                field("c", NativeType::Float { bits: 32 }),
+            ],
+        );
+
+        // When: Validating the messages.
+        let reordered_result = BuiltinType::Vector3.validate(&reordered);
+        let renamed_result = BuiltinType::Vector3.validate(&renamed);
+
+        // Then: Both are rejected with the expected layout.
+        let err = reordered_result.unwrap_err().to_string();
+        assert!(err.contains(r#"["x", "y", "z"]"#), "{}", err);
+        assert!(renamed_result.is_err());
+    }
+
+    #[test]
+    fn test_validate_transform_layout() {
+        // Given: A schema message declaring the Transform2D layout.
+        let vector2 = NativeType::Message {
+            descriptor: descriptor(vec!["godot"], "Vector2"),
+        };
+        let msg = message(
+            "Transform2D",
+            vec![
+                field("x", vector2.clone()),
+                field("y", vector2.clone()),
+                field("origin", vector2),
+            ],
+        );
+
+        // When: Validating the message.
+        let result = BuiltinType::Transform2D.validate(&msg);
+
+        // Then: The layout is accepted.
+        assert!(result.is_ok());
+    }
+}
@@ -1,36 +1,204 @@
+use std::collections::HashSet;
+
 use baproto::{Encoding, NativeType};
 
-use crate::gdscript::ast::{Assignment, Expr, FnCall, ForInBuilder, IfBuilder, Item, Operator};
+use crate::gdscript::ast::{
+    Assignment, AssignmentBuilder, DeclarationKind, Expr, FnCall, ForInBuilder, IfBuilder, Item,
+    Operator, TypeHint, ValueKind,
+};
+use crate::gdscript::builtin::BuiltinType;
+use crate::gdscript::collect::qualified_name;
+use crate::gdscript::types::{array_default_value, element_type_name};
+
+use crate::gdscript::directive::{FieldDirectives, Length, Quantize};
 
-use super::wire::get_read_method;
+use super::wire::{
+    Size, get_bulk_read_method, get_quantized_read_method, get_read_method, get_size,
+    validate_optional, validate_transforms,
+};
 
 /* -------------------------------------------------------------------------- */
 /*                            Fn: gen_decode_stmts                            */
 /* -------------------------------------------------------------------------- */
 
-/// `gen_decode_stmts` generates decode statements for a field.
-pub fn gen_decode_stmts(field_name: &str, encoding: &Encoding) -> anyhow::Result<Vec<Item>> {
-    match &encoding.native {
-        // Primitives: Bool, Int, Float, String
-        NativeType::Bool
-        | NativeType::Int { .. }
-        | NativeType::Float { .. }
-        | NativeType::String => gen_decode_primitive(field_name, encoding),
+/// `gen_decode_stmts` generates decode statements for a field, followed by skipping any
+/// padding bits requested by the field's encoding. Types in `lazy` are loaded lazily
+/// (see [`element_type_name`]).
+pub fn gen_decode_stmts(
+    field_name: &str,
+    encoding: &Encoding,
+    lazy: &HashSet<String>,
+) -> anyhow::Result<Vec<Item>> {
+    validate_transforms(encoding)?;
+
+    gen_decode_sized_stmts(field_name, encoding, None, lazy)
+}
+
+/* ----------------------- Fn: gen_decode_sized_stmts ----------------------- */
+
+/// `gen_decode_sized_stmts` generates decode statements for a field, applying the
+/// field's `length` directive, if any, to the size of a string, bytes, or array value.
+fn gen_decode_sized_stmts(
+    field_name: &str,
+    encoding: &Encoding,
+    length: Option<&Length>,
+    lazy: &HashSet<String>,
+) -> anyhow::Result<Vec<Item>> {
+    let mut stmts = match &encoding.native {
+        // String
+        NativeType::String => gen_decode_string(field_name, &get_size(encoding, length)?),
 
         // Bytes
-        NativeType::Bytes => gen_decode_bytes(field_name),
+        NativeType::Bytes => gen_decode_bytes(field_name, &get_size(encoding, length)?),
 
         // Array
-        NativeType::Array { element } => gen_decode_array(field_name, element),
+        NativeType::Array { element } => {
+            gen_decode_array(field_name, &get_size(encoding, length)?, element, lazy)
+        }
 
         // Map
-        NativeType::Map { key, value } => gen_decode_map(field_name, key, value),
+        NativeType::Map { key, value } => {
+            gen_decode_map(field_name, &get_size(encoding, length)?, key, value, lazy)
+        }
+
+        // Only strings, bytes, and arrays support a length directive.
+        _ if length.is_some() => {
+            anyhow::bail!("Unsupported length directive: native={:?}", encoding.native)
+        }
+
+        // Primitives: Bool, Int, Float
+        NativeType::Bool | NativeType::Int { .. } | NativeType::Float { .. } => {
+            gen_decode_primitive(field_name, encoding)
+        }
+
+        // Built-in Godot types (must come before messages)
+        native if BuiltinType::from_native(native).is_some() => {
+            gen_decode_primitive(field_name, encoding)
+        }
 
         // Message
         NativeType::Message { descriptor } => gen_decode_message(field_name, descriptor),
 
         // Enum
         NativeType::Enum { descriptor } => gen_decode_enum(field_name, descriptor),
+    }?;
+
+    stmts.extend(gen_decode_padding(encoding));
+
+    Ok(stmts)
+}
+
+/* ----------------------- Fn: gen_decode_field_stmts ----------------------- */
+
+/// `gen_decode_field_stmts` generates decode statements for a message field, applying
+/// any GDScript-specific field directives.
+pub fn gen_decode_field_stmts(
+    field_name: &str,
+    encoding: &Encoding,
+    directives: &FieldDirectives,
+    lazy: &HashSet<String>,
+) -> anyhow::Result<Vec<Item>> {
+    validate_transforms(encoding)?;
+
+    match &directives.quantize {
+        Some(quantize) => gen_decode_quantized(field_name, encoding, quantize),
+        None if directives.optional => gen_decode_optional(field_name, encoding, lazy),
+        None => gen_decode_sized_stmts(field_name, encoding, directives.length.as_ref(), lazy),
+    }
+}
+
+/* ------------------------ Fn: gen_decode_optional ------------------------- */
+
+/// `gen_decode_optional` generates decoding for an optional message or enum field. The
+/// value is only decoded if its presence bit is set; otherwise, it's set to `null`.
+///
+/// # Generated GDScript
+/// ```gdscript
+/// if _reader.read_bool():
+///     player = Player.new()
+///     player._decode(_reader)
+///     if _reader.get_error() != OK:
+///         return _reader.get_error()
+/// else:
+///     player = null
+/// if _reader.get_error() != OK:
+///     return _reader.get_error()
+/// ```
+fn gen_decode_optional(
+    field_name: &str,
+    encoding: &Encoding,
+    lazy: &HashSet<String>,
+) -> anyhow::Result<Vec<Item>> {
+    validate_optional(encoding)?;
+
+    let read_value = Item::If(
+        IfBuilder::default()
+            .condition(FnCall::method(Expr::ident("_reader"), "read_bool"))
+            .then_body(gen_decode_sized_stmts(field_name, encoding, None, lazy)?.into())
+            .else_body(
+                vec![Item::Assignment(Assignment::reassign(
+                    field_name,
+                    Expr::null(),
+                ))]
+                .into(),
+            )
+            .build()
+            .unwrap(),
+    );
+
+    Ok(vec![read_value, gen_reader_error_check()])
+}
+
+/* ------------------------ Fn: gen_decode_quantized ------------------------ */
+
+/// `gen_decode_quantized` generates decoding for a quantized float field.
+///
+/// # Generated GDScript
+/// ```gdscript
+/// field_name = _reader.read_quantized(-1.0, 1.0, 10)
+/// if _reader.get_error() != OK:
+///     return _reader.get_error()
+/// ```
+fn gen_decode_quantized(
+    field_name: &str,
+    encoding: &Encoding,
+    quantize: &Quantize,
+) -> anyhow::Result<Vec<Item>> {
+    let method = get_quantized_read_method(encoding, quantize)?;
+
+    let call = FnCall::method_args(Expr::ident("_reader"), &method.method, method.extra_args);
+
+    let mut stmts = vec![
+        Item::Assignment(Assignment::reassign(field_name, call)),
+        gen_reader_error_check(),
+    ];
+    stmts.extend(gen_decode_padding(encoding));
+
+    Ok(stmts)
+}
+
+/* ------------------------- Fn: gen_decode_padding ------------------------- */
+
+/// `gen_decode_padding` generates a skip over padding bits for an encoding which
+/// requests it. No statements are generated if the encoding has no padding.
+///
+/// # Generated GDScript
+/// ```gdscript
+/// _reader.skip_padding(4)
+/// if _reader.get_error() != OK:
+///     return _reader.get_error()
+/// ```
+fn gen_decode_padding(encoding: &Encoding) -> Vec<Item> {
+    match encoding.padding_bits {
+        Some(bits) if bits > 0 => vec![
+            Item::Expr(FnCall::method_args(
+                Expr::ident("_reader"),
+                "skip_padding",
+                vec![Expr::Literal((bits as i64).into())],
+            )),
+            gen_reader_error_check(),
+        ],
+        _ => vec![],
     }
 }
 
@@ -54,19 +222,37 @@ fn gen_decode_primitive(field_name: &str, encoding: &Encoding) -> anyhow::Result
     Ok(vec![Item::Assignment(assignment), gen_reader_error_check()])
 }
 
+/* ------------------------- Fn: gen_decode_string -------------------------- */
+
+/// `gen_decode_string` generates decoding for a string field.
+///
+/// # Generated GDScript
+/// ```gdscript
+/// name = _reader.read_string(4, 9)
+/// if _reader.get_error() != OK:
+///     return _reader.get_error()
+/// ```
+fn gen_decode_string(field_name: &str, size: &Size) -> anyhow::Result<Vec<Item>> {
+    let call = FnCall::method_args(Expr::ident("_reader"), "read_string", size.prefix_args());
+
+    let assignment = Assignment::reassign(field_name, call);
+
+    Ok(vec![Item::Assignment(assignment), gen_reader_error_check()])
+}
+
 /* -------------------------- Fn: gen_decode_bytes -------------------------- */
 
 /// `gen_decode_bytes` generates decoding for a bytes field.
 ///
 /// # Generated GDScript
 /// ```gdscript
-/// data = _reader.read_bytes(_reader.read_varint_unsigned())
+/// data = _reader.read_bytes(_reader.read_size(16))
 /// if _reader.get_error() != OK:
 ///     return _reader.get_error()
 /// ```
-fn gen_decode_bytes(field_name: &str) -> anyhow::Result<Vec<Item>> {
+fn gen_decode_bytes(field_name: &str, size: &Size) -> anyhow::Result<Vec<Item>> {
     // Read length
-    let length_call = FnCall::method(Expr::ident("_reader"), "read_varint_unsigned");
+    let length_call = gen_read_size(size, "read_size");
 
     // Read bytes with length
     let read_call = FnCall::method_args(Expr::ident("_reader"), "read_bytes", vec![length_call]);
@@ -79,36 +265,83 @@ fn gen_decode_bytes(field_name: &str) -> anyhow::Result<Vec<Item>> {
 /* -------------------------- Fn: gen_decode_array -------------------------- */
 
 /// `gen_decode_array` generates decoding for an array field.
-fn gen_decode_array(field_name: &str, element: &Encoding) -> anyhow::Result<Vec<Item>> {
+fn gen_decode_array(
+    field_name: &str,
+    size: &Size,
+    element: &Encoding,
+    lazy: &HashSet<String>,
+) -> anyhow::Result<Vec<Item>> {
     match &element.native {
+        // Built-in Godot types are decoded like primitives
+        native if BuiltinType::from_native(native).is_some() => {
+            gen_decode_array_primitive(field_name, size, element, lazy)
+        }
+
         // Array of messages or enums requires construction
         NativeType::Message { .. } | NativeType::Enum { .. } => {
-            gen_decode_array_message(field_name, element)
+            gen_decode_array_message(field_name, size, element, lazy)
         }
 
         // All other types (primitives, bytes, etc.) can be decoded directly
-        _ => gen_decode_array_primitive(field_name, element),
+        _ => gen_decode_array_primitive(field_name, size, element, lazy),
     }
 }
 
 /* --------------------- Fn: gen_decode_array_primitive --------------------- */
 
-/// `gen_decode_array_primitive` generates decoding for an array of primitives.
+/// `gen_decode_array_primitive` generates decoding for an array of primitives. Packed
+/// arrays of fixed-width, byte-sized elements are read in bulk.
 ///
 /// # Generated GDScript
 /// ```gdscript
 /// items = []
-/// for _i in range(_reader.read_varint_unsigned()):
-///     items.append(_reader.read_xxx())
+/// for _i in range(_reader.read_length(16)):
+///     var _temp: T
+///     _temp = _reader.read_xxx()
 ///     if _reader.get_error() != OK:
 ///         return _reader.get_error()
+///     items.append(_temp)
+/// ```
+///
+/// or, for bulk-decodable elements:
+///
+/// ```gdscript
+/// items = _reader.read_int32_array(_reader.read_length(16), 4, true)
+/// if _reader.get_error() != OK:
+///     return _reader.get_error()
 /// ```
-fn gen_decode_array_primitive(field_name: &str, element: &Encoding) -> anyhow::Result<Vec<Item>> {
+fn gen_decode_array_primitive(
+    field_name: &str,
+    size: &Size,
+    element: &Encoding,
+    lazy: &HashSet<String>,
+) -> anyhow::Result<Vec<Item>> {
+    // Read all elements at once, if supported
+    if let Some(bulk) = get_bulk_read_method(element) {
+        let length_call = gen_read_size(size, "read_length");
+
+        let mut args = vec![length_call];
+        args.extend(bulk.extra_args);
+
+        let read_call = FnCall::method_args(Expr::ident("_reader"), &bulk.method, args);
+        let assignment = Assignment::reassign(field_name, read_call);
+
+        return Ok(vec![Item::Assignment(assignment), gen_reader_error_check()]);
+    }
+
     // Initialize empty array
-    let init = Assignment::reassign(field_name, Expr::empty_array());
+    let init = Assignment::reassign(field_name, array_default_value(&element.native));
+
+    // Declare element: var _temp: T
+    let declare_temp = AssignmentBuilder::default()
+        .declaration(DeclarationKind::Var)
+        .variable("_temp")
+        .type_hint(TypeHint::Explicit(element_type_name(&element.native, lazy)))
+        .build()
+        .unwrap();
 
     // Read element
-    let element_stmts = gen_decode_stmts("_temp", element)?;
+    let element_stmts = gen_decode_sized_stmts("_temp", element, None, lazy)?;
 
     // Append element to array
     let append_call = Item::Expr(FnCall::method_args(
@@ -118,11 +351,12 @@ fn gen_decode_array_primitive(field_name: &str, element: &Encoding) -> anyhow::R
     ));
 
     // Combine element read statements with append
-    let mut loop_body = element_stmts;
+    let mut loop_body = vec![Item::Assignment(declare_temp)];
+    loop_body.extend(element_stmts);
     loop_body.push(append_call);
 
     // Read length and create range
-    let length_call = FnCall::method(Expr::ident("_reader"), "read_varint_unsigned");
+    let length_call = gen_read_size(size, "read_length");
     let range_call = FnCall::function_args("range", vec![length_call]);
 
     // Create for loop
@@ -145,14 +379,19 @@ fn gen_decode_array_primitive(field_name: &str, element: &Encoding) -> anyhow::R
 /// # Generated GDScript
 /// ```gdscript
 /// items = []
-/// for _i in range(_reader.read_varint_unsigned()):
+/// for _i in range(_reader.read_length(16)):
 ///     var _item := Player.new()
 ///     _item._decode(_reader)
 ///     if _reader.get_error() != OK:
 ///         return _reader.get_error()
 ///     items.append(_item)
 /// ```
-fn gen_decode_array_message(field_name: &str, element: &Encoding) -> anyhow::Result<Vec<Item>> {
+fn gen_decode_array_message(
+    field_name: &str,
+    size: &Size,
+    element: &Encoding,
+    lazy: &HashSet<String>,
+) -> anyhow::Result<Vec<Item>> {
     // Initialize empty array
     let init = Assignment::reassign(field_name, Expr::empty_array());
 
@@ -166,7 +405,7 @@ fn gen_decode_array_message(field_name: &str, element: &Encoding) -> anyhow::Res
 
     // Create message instance: var _item := MessageType.new()
     let new_call = FnCall::method(Expr::ident(&type_name), "new");
-    let declare_item = Assignment::var("_item", new_call);
+    let declare_item = gen_instance_decl("_item", new_call, &element.native, lazy);
 
     // Call _item._decode(_reader)
     let decode_call = Item::Expr(FnCall::method_args(
@@ -186,7 +425,7 @@ fn gen_decode_array_message(field_name: &str, element: &Encoding) -> anyhow::Res
     ));
 
     // Read length and create range
-    let length_call = FnCall::method(Expr::ident("_reader"), "read_varint_unsigned");
+    let length_call = gen_read_size(size, "read_length");
     let range_call = FnCall::function_args("range", vec![length_call]);
 
     // Create for loop
@@ -194,12 +433,14 @@ fn gen_decode_array_message(field_name: &str, element: &Encoding) -> anyhow::Res
         ForInBuilder::default()
             .variable("_i")
             .iterable(range_call)
-            .body(vec![
-                Item::Assignment(declare_item),
-                decode_call,
-                error_check,
-                append_call,
-            ])
+            .body(
+                [
+                    vec![Item::Assignment(declare_item), decode_call, error_check],
+                    gen_decode_padding(element),
+                    vec![append_call],
+                ]
+                .concat(),
+            )
             .build()
             .unwrap(),
     );
@@ -280,15 +521,26 @@ fn gen_decode_enum(
 /* --------------------------- Fn: gen_decode_map --------------------------- */
 
 /// `gen_decode_map` generates decoding for a map field.
-fn gen_decode_map(field_name: &str, key: &Encoding, value: &Encoding) -> anyhow::Result<Vec<Item>> {
+fn gen_decode_map(
+    field_name: &str,
+    size: &Size,
+    key: &Encoding,
+    value: &Encoding,
+    lazy: &HashSet<String>,
+) -> anyhow::Result<Vec<Item>> {
     match &value.native {
+        // Built-in Godot types are decoded like primitives
+        native if BuiltinType::from_native(native).is_some() => {
+            gen_decode_map_primitive(field_name, size, key, value)
+        }
+
         // Map of messages or enums requires construction
         NativeType::Message { .. } | NativeType::Enum { .. } => {
-            gen_decode_map_message(field_name, key, value)
+            gen_decode_map_message(field_name, size, key, value, lazy)
         }
 
         // All other types (primitives, bytes, etc.) can be decoded directly
-        _ => gen_decode_map_primitive(field_name, key, value),
+        _ => gen_decode_map_primitive(field_name, size, key, value),
     }
 }
 
@@ -299,7 +551,7 @@ fn gen_decode_map(field_name: &str, key: &Encoding, value: &Encoding) -> anyhow:
 /// # Generated GDScript
 /// ```gdscript
 /// stats = {}
-/// for _i in range(_reader.read_varint_unsigned()):
+/// for _i in range(_reader.read_length(16)):
 ///     var _key := _reader.read_xxx()
 ///     if _reader.get_error() != OK:
 ///         return _reader.get_error()
@@ -309,6 +561,7 @@ fn gen_decode_map(field_name: &str, key: &Encoding, value: &Encoding) -> anyhow:
 /// ```
 fn gen_decode_map_primitive(
     field_name: &str,
+    size: &Size,
     key: &Encoding,
     value: &Encoding,
 ) -> anyhow::Result<Vec<Item>> {
@@ -341,15 +594,16 @@ fn gen_decode_map_primitive(
     let value_error_check = gen_reader_error_check();
 
     // Build loop body
-    let loop_body = vec![
-        Item::Assignment(declare_key),
-        key_error_check,
-        Item::Assignment(assign_value),
-        value_error_check,
-    ];
+    let loop_body = [
+        vec![Item::Assignment(declare_key), key_error_check],
+        gen_decode_padding(key),
+        vec![Item::Assignment(assign_value), value_error_check],
+        gen_decode_padding(value),
+    ]
+    .concat();
 
     // Read length and create range
-    let length_call = FnCall::method(Expr::ident("_reader"), "read_varint_unsigned");
+    let length_call = gen_read_size(size, "read_length");
     let range_call = FnCall::function_args("range", vec![length_call]);
 
     // Create for loop
@@ -372,20 +626,22 @@ fn gen_decode_map_primitive(
 /// # Generated GDScript
 /// ```gdscript
 /// players = {}
-/// for _i in range(_reader.read_varint_unsigned()):
+/// for _i in range(_reader.read_length(16)):
 ///     var _key := _reader.read_string()
 ///     if _reader.get_error() != OK:
 ///         return _reader.get_error()
-///     var _value := Player.new()
-///     _value._decode(_reader)
+///     var _entry := Player.new()
+///     _entry._decode(_reader)
 ///     if _reader.get_error() != OK:
 ///         return _reader.get_error()
-///     players[_key] = _value
+///     players[_key] = _entry
 /// ```
 fn gen_decode_map_message(
     field_name: &str,
+    size: &Size,
     key: &Encoding,
     value: &Encoding,
+    lazy: &HashSet<String>,
 ) -> anyhow::Result<Vec<Item>> {
     // Initialize empty dict
     let init = Assignment::reassign(field_name, Expr::empty_dict());
@@ -410,13 +666,13 @@ fn gen_decode_map_message(
         _ => anyhow::bail!("Expected message or enum type"),
     };
 
-    // Create message instance: var _value := MessageType.new()
+    // Create message instance: var _entry := MessageType.new()
     let new_call = FnCall::method(Expr::ident(&type_name), "new");
-    let declare_value = Assignment::var("_value", new_call);
+    let declare_value = gen_instance_decl("_entry", new_call, &value.native, lazy);
 
-    // Call _value._decode(_reader)
+    // Call _entry._decode(_reader)
     let decode_call = Item::Expr(FnCall::method_args(
-        Expr::ident("_value"),
+        Expr::ident("_entry"),
         "_decode",
         vec![Expr::ident("_reader")],
     ));
@@ -424,22 +680,26 @@ fn gen_decode_map_message(
     // Error check after decode
     let decode_error_check = gen_reader_error_check();
 
-    // Assign to map: players[_key] = _value
+    // Assign to map: players[_key] = _entry
     let map_index = Expr::index(Expr::ident(field_name), Expr::ident("_key"));
-    let assign_to_map = Assignment::reassign(map_index, Expr::ident("_value"));
+    let assign_to_map = Assignment::reassign(map_index, Expr::ident("_entry"));
 
     // Build loop body
-    let loop_body = vec![
-        Item::Assignment(declare_key),
-        key_error_check,
-        Item::Assignment(declare_value),
-        decode_call,
-        decode_error_check,
-        Item::Assignment(assign_to_map),
-    ];
+    let loop_body = [
+        vec![Item::Assignment(declare_key), key_error_check],
+        gen_decode_padding(key),
+        vec![
+            Item::Assignment(declare_value),
+            decode_call,
+            decode_error_check,
+        ],
+        gen_decode_padding(value),
+        vec![Item::Assignment(assign_to_map)],
+    ]
+    .concat();
 
     // Read length and create range
-    let length_call = FnCall::method(Expr::ident("_reader"), "read_varint_unsigned");
+    let length_call = gen_read_size(size, "read_length");
     let range_call = FnCall::function_args("range", vec![length_call]);
 
     // Create for loop
@@ -455,6 +715,66 @@ fn gen_decode_map_message(
     Ok(vec![Item::Assignment(init), for_loop])
 }
 
+/* -------------------------------------------------------------------------- */
+/*                           Fn: gen_instance_decl                            */
+/* -------------------------------------------------------------------------- */
+
+/// `gen_instance_decl` generates a declaration of a local `variable` holding a new
+/// message or enum instance. A lazily-loaded type isn't known at parse time, so its
+/// type can't be inferred and is declared explicitly instead.
+///
+/// # Generated GDScript
+/// ```gdscript
+/// var _item := Player.new()
+/// var _item: RefCounted = Player.new()
+/// ```
+fn gen_instance_decl(
+    variable: &str,
+    new_call: Expr,
+    native: &NativeType,
+    lazy: &HashSet<String>,
+) -> Assignment {
+    let is_lazy = match native {
+        NativeType::Message { descriptor } | NativeType::Enum { descriptor } => {
+            lazy.contains(&qualified_name(descriptor))
+        }
+        _ => false,
+    };
+
+    if !is_lazy {
+        return Assignment::var(variable, new_call);
+    }
+
+    AssignmentBuilder::default()
+        .declaration(DeclarationKind::Var)
+        .variable(variable)
+        .type_hint(TypeHint::Explicit(element_type_name(native, lazy)))
+        .value(ValueKind::Expr(new_call))
+        .build()
+        .unwrap()
+}
+
+/* -------------------------------------------------------------------------- */
+/*                             Fn: gen_read_size                              */
+/* -------------------------------------------------------------------------- */
+
+/// `gen_read_size` generates a call to the reader `method` (either `read_size` or the
+/// limit-checked `read_length`) which reads the size of a bytes, array, or map field.
+/// A fixed size isn't read, so its value is used directly.
+///
+/// # Generated GDScript
+/// ```gdscript
+/// _reader.read_length(16)
+/// ```
+fn gen_read_size(size: &Size, method: &str) -> Expr {
+    match size {
+        Size::Fixed(fixed) => Expr::Literal((*fixed).into()),
+        Size::Prefixed { .. } => {
+            FnCall::method_args(Expr::ident("_reader"), method, size.prefix_args())
+        }
+    }
+}
+
 /* -------------------------------------------------------------------------- */
 /*                         Fn: gen_reader_error_check                         */
 /* -------------------------------------------------------------------------- */
@@ -466,7 +786,7 @@ fn gen_decode_map_message(
 /// if _reader.get_error() != OK:
 ///     return _reader.get_error()
 /// ```
-fn gen_reader_error_check() -> Item {
+pub(super) fn gen_reader_error_check() -> Item {
     let condition = Expr::binary_op(
         FnCall::method(Expr::ident("_reader"), "get_error"),
         Operator::NotEq,
@@ -524,6 +844,31 @@ if _reader.get_error() != OK:
         assert_eq!(actual, expected);
     }
 
+    /* -------------------- Tests: gen_decode_padding -------------------- */
+
+    #[test]
+    fn test_gen_decode_padding_after_value() {
+        // Given: A bool encoding with padding.
+        let encoding = Encoding {
+            wire: WireFormat::Bits { count: 1 },
+            native: NativeType::Bool,
+            transforms: vec![],
+            padding_bits: Some(7),
+        };
+
+        // When: Generating decode statements.
+        let stmts = gen_decode_stmts("active", &encoding, &HashSet::new()).unwrap();
+
+        // Then: Four statements are generated (value + check, padding + check).
+        assert_eq!(stmts.len(), 4);
+
+        // Then: The padding is skipped after the value.
+        let mut s = StringWriter::default();
+        let mut cw = GDScript::writer();
+        stmts[2].emit(&mut cw, &mut s).unwrap();
+        assert_eq!(s.into_content(), "_reader.skip_padding(7)");
+    }
+
     /* ------------------ Tests: gen_decode_primitive ------------------- */
 
     #[test]
@@ -537,7 +882,7 @@ if _reader.get_error() != OK:
         };
 
         // When: Generating decode statements.
-        let stmts = gen_decode_stmts("active", &encoding).unwrap();
+        let stmts = gen_decode_stmts("active", &encoding, &HashSet::new()).unwrap();
 
         // Then: Two statements are generated (assignment + error check).
         assert_eq!(stmts.len(), 2);
@@ -573,7 +918,7 @@ if _reader.get_error() != OK:
         };
 
         // When: Generating decode statements.
-        let stmts = gen_decode_stmts("score", &encoding).unwrap();
+        let stmts = gen_decode_stmts("score", &encoding, &HashSet::new()).unwrap();
 
         // Then: Two statements are generated.
         assert_eq!(stmts.len(), 2);
@@ -599,7 +944,7 @@ if _reader.get_error() != OK:
         };
 
         // When: Generating decode statements.
-        let stmts = gen_decode_stmts("value", &encoding).unwrap();
+        let stmts = gen_decode_stmts("value", &encoding, &HashSet::new()).unwrap();
 
         // Then: Two statements are generated.
         assert_eq!(stmts.len(), 2);
@@ -625,7 +970,7 @@ if _reader.get_error() != OK:
         };
 
         // When: Generating decode statements.
-        let stmts = gen_decode_stmts("value", &encoding).unwrap();
+        let stmts = gen_decode_stmts("value", &encoding, &HashSet::new()).unwrap();
 
         // Then: Two statements are generated.
         assert_eq!(stmts.len(), 2);
@@ -651,7 +996,7 @@ if _reader.get_error() != OK:
         };
 
         // When: Generating decode statements.
-        let stmts = gen_decode_stmts("value", &encoding).unwrap();
+        let stmts = gen_decode_stmts("value", &encoding, &HashSet::new()).unwrap();
 
         // Then: Two statements are generated.
         assert_eq!(stmts.len(), 2);
@@ -677,7 +1022,7 @@ if _reader.get_error() != OK:
         };
 
         // When: Generating decode statements.
-        let stmts = gen_decode_stmts("value", &encoding).unwrap();
+        let stmts = gen_decode_stmts("value", &encoding, &HashSet::new()).unwrap();
 
         // Then: Two statements are generated.
         assert_eq!(stmts.len(), 2);
@@ -703,7 +1048,7 @@ if _reader.get_error() != OK:
         };
 
         // When: Generating decode statements.
-        let stmts = gen_decode_stmts("value", &encoding).unwrap();
+        let stmts = gen_decode_stmts("value", &encoding, &HashSet::new()).unwrap();
 
         // Then: Two statements are generated.
         assert_eq!(stmts.len(), 2);
@@ -729,7 +1074,7 @@ if _reader.get_error() != OK:
         };
 
         // When: Generating decode statements.
-        let stmts = gen_decode_stmts("value", &encoding).unwrap();
+        let stmts = gen_decode_stmts("value", &encoding, &HashSet::new()).unwrap();
 
         // Then: Two statements are generated.
         assert_eq!(stmts.len(), 2);
@@ -755,7 +1100,7 @@ if _reader.get_error() != OK:
         };
 
         // When: Generating decode statements.
-        let stmts = gen_decode_stmts("value", &encoding).unwrap();
+        let stmts = gen_decode_stmts("value", &encoding, &HashSet::new()).unwrap();
 
         // Then: Two statements are generated.
         assert_eq!(stmts.len(), 2);
@@ -778,7 +1123,7 @@ if _reader.get_error() != OK:
         };
 
         // When: Generating decode statements.
-        let stmts = gen_decode_stmts("value", &encoding).unwrap();
+        let stmts = gen_decode_stmts("value", &encoding, &HashSet::new()).unwrap();
 
         // Then: Two statements are generated.
         assert_eq!(stmts.len(), 2);
@@ -801,7 +1146,7 @@ if _reader.get_error() != OK:
         };
 
         // When: Generating decode statements.
-        let stmts = gen_decode_stmts("value", &encoding).unwrap();
+        let stmts = gen_decode_stmts("value", &encoding, &HashSet::new()).unwrap();
 
         // Then: Two statements are generated.
         assert_eq!(stmts.len(), 2);
@@ -824,7 +1169,7 @@ if _reader.get_error() != OK:
         };
 
         // When: Generating decode statements.
-        let stmts = gen_decode_stmts("name", &encoding).unwrap();
+        let stmts = gen_decode_stmts("name", &encoding, &HashSet::new()).unwrap();
 
         // Then: Two statements are generated.
         assert_eq!(stmts.len(), 2);
@@ -833,7 +1178,7 @@ if _reader.get_error() != OK:
         let mut s = StringWriter::default();
         let mut cw = GDScript::writer();
         stmts[0].emit(&mut cw, &mut s).unwrap();
-        assert_eq!(s.into_content(), "name = _reader.read_string()");
+        assert_eq!(s.into_content(), "name = _reader.read_string(64)");
     }
 
     #[test]
@@ -850,7 +1195,7 @@ if _reader.get_error() != OK:
         };
 
         // When: Generating decode statements.
-        let stmts = gen_decode_stmts("value", &encoding).unwrap();
+        let stmts = gen_decode_stmts("value", &encoding, &HashSet::new()).unwrap();
 
         // Then: Two statements are generated.
         assert_eq!(stmts.len(), 2);
@@ -876,7 +1221,7 @@ if _reader.get_error() != OK:
         };
 
         // When: Generating decode statements.
-        let stmts = gen_decode_stmts("value", &encoding).unwrap();
+        let stmts = gen_decode_stmts("value", &encoding, &HashSet::new()).unwrap();
 
         // Then: Two statements are generated.
         assert_eq!(stmts.len(), 2);
@@ -902,7 +1247,7 @@ if _reader.get_error() != OK:
         };
 
         // When: Generating decode statements.
-        let stmts = gen_decode_stmts("value", &encoding).unwrap();
+        let stmts = gen_decode_stmts("value", &encoding, &HashSet::new()).unwrap();
 
         // Then: Two statements are generated.
         assert_eq!(stmts.len(), 2);
@@ -927,7 +1272,7 @@ if _reader.get_error() != OK:
         };
 
         // When: Generating decode statements.
-        let stmts = gen_decode_stmts("data", &encoding).unwrap();
+        let stmts = gen_decode_stmts("data", &encoding, &HashSet::new()).unwrap();
 
         // Then: Two statements are generated (assignment + error check).
         assert_eq!(stmts.len(), 2);
@@ -937,10 +1282,7 @@ if _reader.get_error() != OK:
         let mut cw = GDScript::writer();
         stmts[0].emit(&mut cw, &mut s).unwrap();
         let actual = s.into_content();
-        assert_eq!(
-            actual,
-            "data = _reader.read_bytes(_reader.read_varint_unsigned())"
-        );
+        assert_eq!(actual, "data = _reader.read_bytes(_reader.read_size(64))");
 
         // Then: Second statement is error check.
         let mut s2 = StringWriter::default();
@@ -976,24 +1318,71 @@ if _reader.get_error() != OK:
         };
 
         // When: Generating decode statements.
-        let stmts = gen_decode_stmts("items", &encoding).unwrap();
+        let stmts = gen_decode_stmts("items", &encoding, &HashSet::new()).unwrap();
+
+        // Then: Two statements are generated (bulk read + error check).
+        assert_eq!(stmts.len(), 2);
+
+        // Then: First statement reads all elements at once.
+        let mut s1 = StringWriter::default();
+        let mut cw = GDScript::writer();
+        stmts[0].emit(&mut cw, &mut s1).unwrap();
+        assert_eq!(
+            s1.into_content(),
+            "items = _reader.read_int32_array(_reader.read_length(64), 4, true)"
+        );
+
+        // Then: Second statement is error check.
+        let mut s2 = StringWriter::default();
+        stmts[1].emit(&mut cw, &mut s2).unwrap();
+        let actual2 = s2.into_content();
+
+        let expected2 = r#"if _reader.get_error() != OK:
+	return _reader.get_error()"#;
+
+        assert_eq!(actual2, expected2);
+    }
+
+    #[test]
+    fn test_gen_decode_array_primitive_varint() {
+        // Given: An array of varint-encoded i32 encoding.
+        let encoding = Encoding {
+            wire: WireFormat::LengthPrefixed { prefix_bits: 64 },
+            native: NativeType::Array {
+                element: Box::new(Encoding {
+                    wire: WireFormat::LengthPrefixed { prefix_bits: 64 },
+                    native: NativeType::Int {
+                        bits: 32,
+                        signed: true,
+                    },
+                    transforms: vec![Transform::ZigZag],
+                    padding_bits: None,
+                }),
+            },
+            transforms: vec![],
+            padding_bits: None,
+        };
+
+        // When: Generating decode statements.
+        let stmts = gen_decode_stmts("items", &encoding, &HashSet::new()).unwrap();
 
         // Then: Two statements are generated (init + for loop).
         assert_eq!(stmts.len(), 2);
 
-        // Then: First statement initializes empty array.
+        // Then: First statement initializes an empty packed array.
         let mut s1 = StringWriter::default();
         let mut cw = GDScript::writer();
         stmts[0].emit(&mut cw, &mut s1).unwrap();
-        assert_eq!(s1.into_content(), "items = []");
+        assert_eq!(s1.into_content(), "items = PackedInt32Array()");
 
         // Then: Second statement is for loop.
         let mut s2 = StringWriter::default();
         stmts[1].emit(&mut cw, &mut s2).unwrap();
         let actual = s2.into_content();
 
-        let expected = r#"for _i in range(_reader.read_varint_unsigned()):
-	_temp = _reader.read_i32()
+        let expected = r#"for _i in range(_reader.read_length(64)):
+	var _temp: int
+	_temp = _reader.read_varint_signed()
 	if _reader.get_error() != OK:
 		return _reader.get_error()
 	items.append(_temp)"#;
@@ -1027,7 +1416,7 @@ if _reader.get_error() != OK:
         };
 
         // When: Generating decode statements.
-        let stmts = gen_decode_stmts("players", &encoding).unwrap();
+        let stmts = gen_decode_stmts("players", &encoding, &HashSet::new()).unwrap();
 
         // Then: Two statements are generated (init + for loop).
         assert_eq!(stmts.len(), 2);
@@ -1043,7 +1432,7 @@ if _reader.get_error() != OK:
         stmts[1].emit(&mut cw, &mut s2).unwrap();
         let actual = s2.into_content();
 
-        let expected = r#"for _i in range(_reader.read_varint_unsigned()):
+        let expected = r#"for _i in range(_reader.read_length(64)):
 	var _item := Player.new()
 	_item._decode(_reader)
 	if _reader.get_error() != OK:
@@ -1082,7 +1471,7 @@ if _reader.get_error() != OK:
         };
 
         // When: Generating decode statements.
-        let stmts = gen_decode_stmts("stats", &encoding).unwrap();
+        let stmts = gen_decode_stmts("stats", &encoding, &HashSet::new()).unwrap();
 
         // Then: Two statements are generated (init + for loop).
         assert_eq!(stmts.len(), 2);
@@ -1098,8 +1487,8 @@ if _reader.get_error() != OK:
         stmts[1].emit(&mut cw, &mut s2).unwrap();
         let actual = s2.into_content();
 
-        let expected = r#"for _i in range(_reader.read_varint_unsigned()):
-	var _key := _reader.read_string()
+        let expected = r#"for _i in range(_reader.read_length(64)):
+	var _key := _reader.read_string(64)
 	if _reader.get_error() != OK:
 		return _reader.get_error()
 	stats[_key] = _reader.read_i32()
@@ -1141,7 +1530,7 @@ if _reader.get_error() != OK:
         };
 
         // When: Generating decode statements.
-        let stmts = gen_decode_stmts("players", &encoding).unwrap();
+        let stmts = gen_decode_stmts("players", &encoding, &HashSet::new()).unwrap();
 
         // Then: Two statements are generated (init + for loop).
         assert_eq!(stmts.len(), 2);
@@ -1157,15 +1546,15 @@ if _reader.get_error() != OK:
         stmts[1].emit(&mut cw, &mut s2).unwrap();
         let actual = s2.into_content();
 
-        let expected = r#"for _i in range(_reader.read_varint_unsigned()):
-	var _key := _reader.read_string()
+        let expected = r#"for _i in range(_reader.read_length(64)):
+	var _key := _reader.read_string(64)
 	if _reader.get_error() != OK:
 		return _reader.get_error()
-	var _value := Player.new()
-	_value._decode(_reader)
+	var _entry := Player.new()
+	_entry._decode(_reader)
 	if _reader.get_error() != OK:
 		return _reader.get_error()
-	players[_key] = _value"#;
+	players[_key] = _entry"#;
 
         assert_eq!(actual, expected);
     }
@@ -1191,7 +1580,7 @@ if _reader.get_error() != OK:
         };
 
         // When: Generating decode statements.
-        let stmts = gen_decode_stmts("player", &encoding).unwrap();
+        let stmts = gen_decode_stmts("player", &encoding, &HashSet::new()).unwrap();
 
         // Then: Three statements are generated (assignment + decode + error check).
         assert_eq!(stmts.len(), 3);
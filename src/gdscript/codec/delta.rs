@@ -0,0 +1,540 @@
+use std::collections::HashSet;
+
+use baproto::{Field, NativeType};
+
+use crate::gdscript::ast::{
+    Assignment, AssignmentBuilder, Block, DeclarationKind, Expr, FnCall, ForInBuilder, IfBuilder,
+    Item, Literal, Operator, TypeHint, ValueKind,
+};
+use crate::gdscript::builtin::BuiltinType;
+use crate::gdscript::directive::FieldDirectives;
+use crate::gdscript::options::Options;
+use crate::gdscript::types::{escape_keyword, is_scalar, requires_deep_equals};
+
+use super::decode::gen_reader_error_check;
+use super::{gen_decode_field_stmts, gen_encode_field_stmts, presence_mask};
+
+/* -------------------------------------------------------------------------- */
+/*                         Fn: gen_delta_encode_stmts                         */
+/* -------------------------------------------------------------------------- */
+
+/// `gen_delta_encode_stmts` generates statements which encode a message's fields
+/// relative to `baseline`. A bitmask of changed fields (one bit per field) is written
+/// first, followed by only the changed fields. Changed message fields are themselves
+/// delta-encoded when both values are set, which is signaled by a leading bit.
+///
+/// # Generated GDScript
+/// ```gdscript
+/// var _changed: Array[bool] = [score != baseline.score]
+/// for _c in _changed:
+///     _writer.write_bool(_c)
+/// if _changed[0]:
+///     _writer.write_i32(score)
+/// ```
+pub fn gen_delta_encode_stmts(
+    fields: &[Field],
+    lazy: &HashSet<String>,
+) -> anyhow::Result<Vec<Item>> {
+    if fields.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let mut stmts = vec![
+        AssignmentBuilder::default()
+            .declaration(DeclarationKind::Var)
+            .variable("_changed")
+            .type_hint(TypeHint::Explicit("Array[bool]".to_owned()))
+            .value(ValueKind::Expr(Expr::Literal(Literal::Array(
+                fields.iter().map(gen_changed_condition).collect(),
+            ))))
+            .build()
+            .unwrap()
+            .into(),
+        ForInBuilder::default()
+            .variable("_c")
+            .iterable(Expr::ident("_changed"))
+            .body(vec![
+                FnCall::method_args(
+                    Expr::ident("_writer"),
+                    "write_bool",
+                    vec![Expr::ident("_c")],
+                )
+                .into(),
+            ])
+            .build()
+            .unwrap()
+            .into(),
+    ];
+
+    for (position, field) in fields.iter().enumerate() {
+        let field_name = escape_keyword(&field.name);
+        let directives = FieldDirectives::parse(field.doc.as_deref())?;
+
+        let encode_stmts = gen_encode_field_stmts(&field_name, &field.encoding, &directives, lazy)?;
+
+        let body = if is_delta_message(&field.encoding.native) {
+            let baseline = Expr::field(Expr::ident("baseline"), &field_name);
+
+            let write_flag = |value: bool| -> Item {
+                FnCall::method_args(
+                    Expr::ident("_writer"),
+                    "write_bool",
+                    vec![Expr::Literal(Literal::Bool(value))],
+                )
+                .into()
+            };
+
+            let mut full = vec![write_flag(false)];
+            full.extend(encode_stmts);
+
+            vec![
+                IfBuilder::default()
+                    .condition(Expr::binary_op(
+                        Expr::binary_op(Expr::ident(&field_name), Operator::NotEq, Expr::null()),
+                        Operator::And,
+                        Expr::binary_op(baseline.clone(), Operator::NotEq, Expr::null()),
+                    ))
+                    .then_body(Block::from(vec![
+                        write_flag(true),
+                        FnCall::method_args(
+                            Expr::ident(&field_name),
+                            "_encode_delta",
+                            vec![baseline, Expr::ident("_writer")],
+                        )
+                        .into(),
+                    ]))
+                    .else_body(Block::from(full))
+                    .build()
+                    .unwrap()
+                    .into(),
+            ]
+        } else {
+            encode_stmts
+        };
+
+        stmts.push(
+            IfBuilder::default()
+                .condition(gen_changed_at(position))
+                .then_body(Block::from(body))
+                .build()
+                .unwrap()
+                .into(),
+        );
+    }
+
+    Ok(stmts)
+}
+
+/* -------------------------------------------------------------------------- */
+/*                         Fn: gen_delta_decode_stmts                         */
+/* -------------------------------------------------------------------------- */
+
+/// `gen_delta_decode_stmts` generates statements which decode a message's fields
+/// relative to `baseline`, reversing [`gen_delta_encode_stmts`]. Unchanged fields are
+/// copied from `baseline`.
+///
+/// # Generated GDScript
+/// ```gdscript
+/// var _changed: Array[bool] = []
+/// for _i in range(1):
+///     _changed.append(_reader.read_bool())
+/// if _reader.get_error() != OK:
+///     return _reader.get_error()
+/// if _changed[0]:
+///     score = _reader.read_i32()
+///     if _reader.get_error() != OK:
+///         return _reader.get_error()
+/// else:
+///     score = baseline.score
+/// ```
+pub fn gen_delta_decode_stmts(
+    fields: &[Field],
+    options: &Options,
+    lazy: &HashSet<String>,
+) -> anyhow::Result<Vec<Item>> {
+    let mut stmts = Vec::new();
+
+    if options.tagged && !fields.is_empty() {
+        stmts.push(
+            Assignment::reassign(
+                "_presence",
+                Expr::field(Expr::ident("baseline"), "_presence"),
+            )
+            .into(),
+        );
+    }
+
+    if fields.is_empty() {
+        return Ok(stmts);
+    }
+
+    stmts.push(
+        AssignmentBuilder::default()
+            .declaration(DeclarationKind::Var)
+            .variable("_changed")
+            .type_hint(TypeHint::Explicit("Array[bool]".to_owned()))
+            .value(ValueKind::Expr(Expr::empty_array()))
+            .build()
+            .unwrap()
+            .into(),
+    );
+    stmts.push(
+        ForInBuilder::default()
+            .variable("_i")
+            .iterable(FnCall::function_args(
+                "range",
+                vec![Expr::Literal(Literal::Int(fields.len() as i64))],
+            ))
+            .body(vec![
+                FnCall::method_args(
+                    Expr::ident("_changed"),
+                    "append",
+                    vec![FnCall::method(Expr::ident("_reader"), "read_bool")],
+                )
+                .into(),
+            ])
+            .build()
+            .unwrap()
+            .into(),
+    );
+    stmts.push(gen_reader_error_check());
+
+    for (position, field) in fields.iter().enumerate() {
+        let field_name = escape_keyword(&field.name);
+        let directives = FieldDirectives::parse(field.doc.as_deref())?;
+        let baseline = Expr::field(Expr::ident("baseline"), &field_name);
+
+        let decode_stmts = gen_decode_field_stmts(&field_name, &field.encoding, &directives, lazy)?;
+
+        let mut changed = match &field.encoding.native {
+            NativeType::Message { descriptor } if is_delta_message(&field.encoding.native) => vec![
+                IfBuilder::default()
+                    .condition(FnCall::method(Expr::ident("_reader"), "read_bool"))
+                    .then_body(Block::from(vec![
+                        // NOTE: A corrupt (or mismatched) baseline has no value to patch.
+                        IfBuilder::default()
+                            .condition(Expr::binary_op(
+                                baseline.clone(),
+                                Operator::Eq,
+                                Expr::null(),
+                            ))
+                            .then_body(Block::from(vec![
+                                FnCall::method_args(
+                                    Expr::ident("_reader"),
+                                    "set_error",
+                                    vec![Expr::ident("ERR_INVALID_DATA")],
+                                )
+                                .into(),
+                                Item::Return(Some(FnCall::method(
+                                    Expr::ident("_reader"),
+                                    "get_error",
+                                ))),
+                            ]))
+                            .build()
+                            .unwrap()
+                            .into(),
+                        Assignment::reassign(
+                            &field_name,
+                            FnCall::method(Expr::ident(descriptor.path.join("_")), "new"),
+                        )
+                        .into(),
+                        FnCall::method_args(
+                            Expr::ident(&field_name),
+                            "_decode_delta",
+                            vec![baseline.clone(), Expr::ident("_reader")],
+                        )
+                        .into(),
+                        gen_reader_error_check(),
+                    ]))
+                    .else_body(Block::from(decode_stmts))
+                    .build()
+                    .unwrap()
+                    .into(),
+            ],
+            _ => decode_stmts,
+        };
+
+        if options.tagged {
+            changed.push(
+                Assignment::reassign(
+                    "_presence",
+                    Expr::binary_op(
+                        Expr::ident("_presence"),
+                        Operator::BitOr,
+                        Expr::Literal(Literal::Int(presence_mask(position))),
+                    ),
+                )
+                .into(),
+            );
+        }
+
+        let unchanged = if is_scalar(&field.encoding.native) {
+            baseline
+        } else {
+            FnCall::method_args(Expr::ident("_Value"), "copy", vec![baseline])
+        };
+
+        stmts.push(
+            IfBuilder::default()
+                .condition(gen_changed_at(position))
+                .then_body(Block::from(changed))
+                .else_body(Block::from(vec![
+                    Assignment::reassign(&field_name, unchanged).into(),
+                ]))
+                .build()
+                .unwrap()
+                .into(),
+        );
+    }
+
+    Ok(stmts)
+}
+
+/* ------------------------- Fn: gen_changed_condition ---------------------- */
+
+/// `gen_changed_condition` returns an expression evaluating whether a field differs
+/// from its value in `baseline`.
+fn gen_changed_condition(field: &Field) -> Expr {
+    let field_name = escape_keyword(&field.name);
+    let baseline = Expr::field(Expr::ident("baseline"), &field_name);
+
+    if requires_deep_equals(&field.encoding.native) {
+        Expr::not(FnCall::method_args(
+            Expr::ident("_Value"),
+            "equals",
+            vec![Expr::ident(&field_name), baseline],
+        ))
+    } else {
+        Expr::binary_op(Expr::ident(&field_name), Operator::NotEq, baseline)
+    }
+}
+
+/* ---------------------------- Fn: gen_changed_at -------------------------- */
+
+fn gen_changed_at(position: usize) -> Expr {
+    Expr::index(
+        Expr::ident("_changed"),
+        Expr::Literal(Literal::Int(position as i64)),
+    )
+}
+
+/* --------------------------- Fn: is_delta_message ------------------------- */
+
+/// `is_delta_message` returns whether a field's value is a generated message, which
+/// can itself be delta-encoded.
+fn is_delta_message(native: &NativeType) -> bool {
+    matches!(native, NativeType::Message { .. }) && BuiltinType::from_native(native).is_none()
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                 Mod: Tests                                 */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+    use baproto::{DescriptorBuilder, Encoding, PackageName, StringWriter, WireFormat};
+
+    use crate::gdscript::GDScript;
+    use crate::gdscript::ast::Emit;
+
+    use super::*;
+
+    fn field(name: &str, index: u32) -> Field {
+        Field {
+            name: name.to_string(),
+            index,
+            encoding: Encoding {
+                wire: WireFormat::Bits { count: 1 },
+                native: NativeType::Bool,
+                transforms: vec![],
+                padding_bits: None,
+            },
+            doc: None,
+        }
+    }
+
+    fn message_field(name: &str, index: u32) -> Field {
+        Field {
+            name: name.to_string(),
+            index,
+            encoding: Encoding {
+                wire: WireFormat::LengthPrefixed { prefix_bits: 64 },
+                native: NativeType::Message {
+                    descriptor: DescriptorBuilder::default()
+                        .package(PackageName::try_from(vec!["test"]).unwrap())
+                        .path(vec!["Stats".to_string()])
+                        .build()
+                        .unwrap(),
+                },
+                transforms: vec![],
+                padding_bits: None,
+            },
+            doc: None,
+        }
+    }
+
+    fn emit(items: &[Item]) -> String {
+        let mut cw = GDScript::writer();
+
+        items
+            .iter()
+            .map(|item| {
+                let mut s = StringWriter::default();
+                item.emit(&mut cw, &mut s).unwrap();
+                s.into_content()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /* --------------------- Tests: gen_delta_encode_stmts ------------------ */
+
+    #[test]
+    fn test_gen_delta_encode_stmts_no_fields() {
+        // Given: A message without fields.
+        let fields = vec![];
+
+        // When: Generating delta encoding statements.
+        let result = gen_delta_encode_stmts(&fields, &HashSet::new());
+
+        // Then: Nothing is written.
+        assert!(result.unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_gen_delta_encode_stmts_scalar_fields() {
+        // Given: A message with multiple scalar fields.
+        let fields = vec![field("a", 0), field("b", 1)];
+
+        // When: Generating delta encoding statements.
+        let result = gen_delta_encode_stmts(&fields, &HashSet::new()).unwrap();
+
+        // Then: The bitmask is followed by one conditional write per field.
+        let expected = r#"var _changed: Array[bool] = [a != baseline.a, b != baseline.b]
+for _c in _changed:
+	_writer.write_bool(_c)
+if _changed[0]:
+	_writer.write_bool(a)
+if _changed[1]:
+	_writer.write_bool(b)"#;
+
+        assert_eq!(emit(&result), expected);
+    }
+
+    #[test]
+    fn test_gen_delta_encode_stmts_message_field() {
+        // Given: A message with a nested message field.
+        let fields = vec![message_field("stats", 0)];
+
+        // When: Generating delta encoding statements.
+        let result = gen_delta_encode_stmts(&fields, &HashSet::new()).unwrap();
+
+        // Then: The nested message is delta-encoded against the baseline's value when
+        // both are set, which is signaled by a leading bit.
+        let expected = r#"var _changed: Array[bool] = [not _Value.equals(stats, baseline.stats)]
+for _c in _changed:
+	_writer.write_bool(_c)
+if _changed[0]:
+	if stats != null and baseline.stats != null:
+		_writer.write_bool(true)
+		stats._encode_delta(baseline.stats, _writer)
+	else:
+		_writer.write_bool(false)
+		if stats == null:
+			_writer.set_error(ERR_INVALID_DATA)
+			return
+		stats._encode(_writer)"#;
+
+        assert_eq!(emit(&result), expected);
+    }
+
+    /* --------------------- Tests: gen_delta_decode_stmts ------------------ */
+
+    #[test]
+    fn test_gen_delta_decode_stmts_scalar_fields() {
+        // Given: A message with multiple scalar fields.
+        let fields = vec![field("a", 0), field("b", 1)];
+
+        // When: Generating delta decoding statements.
+        let result = gen_delta_decode_stmts(&fields, &Options::default(), &HashSet::new()).unwrap();
+
+        // Then: Changed fields are read and unchanged fields are copied from the
+        // baseline.
+        let expected = r#"var _changed: Array[bool] = []
+for _i in range(2):
+	_changed.append(_reader.read_bool())
+if _reader.get_error() != OK:
+	return _reader.get_error()
+if _changed[0]:
+	a = _reader.read_bool()
+	if _reader.get_error() != OK:
+		return _reader.get_error()
+else:
+	a = baseline.a
+if _changed[1]:
+	b = _reader.read_bool()
+	if _reader.get_error() != OK:
+		return _reader.get_error()
+else:
+	b = baseline.b"#;
+
+        assert_eq!(emit(&result), expected);
+    }
+
+    #[test]
+    fn test_gen_delta_decode_stmts_message_field() {
+        // Given: A message with a nested message field.
+        let fields = vec![message_field("stats", 0)];
+
+        // When: Generating delta decoding statements.
+        let result = gen_delta_decode_stmts(&fields, &Options::default(), &HashSet::new()).unwrap();
+
+        // Then: A delta-encoded nested message is decoded against the baseline's value,
+        // which must be set; otherwise, the message is decoded in full.
+        let expected = r#"var _changed: Array[bool] = []
+for _i in range(1):
+	_changed.append(_reader.read_bool())
+if _reader.get_error() != OK:
+	return _reader.get_error()
+if _changed[0]:
+	if _reader.read_bool():
+		if baseline.stats == null:
+			_reader.set_error(ERR_INVALID_DATA)
+			return _reader.get_error()
+		stats = Stats.new()
+		stats._decode_delta(baseline.stats, _reader)
+		if _reader.get_error() != OK:
+			return _reader.get_error()
+	else:
+		stats = Stats.new()
+		stats._decode(_reader)
+		if _reader.get_error() != OK:
+			return _reader.get_error()
+else:
+	stats = _Value.copy(baseline.stats)"#;
+
+        assert_eq!(emit(&result), expected);
+    }
+
+    #[test]
+    fn test_gen_delta_decode_stmts_tagged_copies_presence() {
+        // Given: A tagged message with a field.
+        let fields = vec![field("a", 0)];
+        let options = Options {
+            tagged: true,
+            ..Default::default()
+        };
+
+        // When: Generating delta decoding statements.
+        let result = gen_delta_decode_stmts(&fields, &options, &HashSet::new()).unwrap();
+
+        // Then: Presence is first copied from the baseline.
+        assert!(matches!(
+            result.first(),
+            Some(Item::Assignment(a)) if a.variable == Expr::ident("_presence")
+        ));
+
+        // Then: Changed fields are marked present.
+        assert!(emit(&result).contains("\t_presence = _presence | 1\n"));
+    }
+}
@@ -1,36 +1,200 @@
+use std::collections::HashSet;
+
 use baproto::{Encoding, NativeType};
 
-use crate::gdscript::ast::{Assignment, Expr, FnCall, ForInBuilder, IfBuilder, Item, Operator};
+use crate::gdscript::ast::{
+    AssignmentBuilder, DeclarationKind, Expr, FnCall, ForInBuilder, IfBuilder, Item, Operator,
+    TypeHint,
+};
+use crate::gdscript::builtin::BuiltinType;
+use crate::gdscript::types::element_type_name;
+
+use crate::gdscript::directive::{FieldDirectives, Length, Quantize};
 
-use super::wire::get_write_method;
+use super::wire::{
+    Size, get_bulk_write_method, get_quantized_write_method, get_size, get_write_method,
+    validate_optional, validate_transforms,
+};
 
 /* -------------------------------------------------------------------------- */
 /*                            Fn: gen_encode_stmts                            */
 /* -------------------------------------------------------------------------- */
 
-/// `gen_encode_stmts` generates encode statements for a field.
-pub fn gen_encode_stmts(field_name: &str, encoding: &Encoding) -> anyhow::Result<Vec<Item>> {
-    match &encoding.native {
-        // Primitives: Bool, Int, Float, String
-        NativeType::Bool
-        | NativeType::Int { .. }
-        | NativeType::Float { .. }
-        | NativeType::String => gen_encode_primitive(field_name, encoding),
+/// `gen_encode_stmts` generates encode statements for a field, followed by any padding
+/// bits requested by the field's encoding. Types in `lazy` are loaded lazily (see
+/// [`element_type_name`]).
+pub fn gen_encode_stmts(
+    field_name: &str,
+    encoding: &Encoding,
+    lazy: &HashSet<String>,
+) -> anyhow::Result<Vec<Item>> {
+    validate_transforms(encoding)?;
+
+    gen_encode_sized_stmts(field_name, encoding, None, lazy)
+}
+
+/* ----------------------- Fn: gen_encode_sized_stmts ----------------------- */
+
+/// `gen_encode_sized_stmts` generates encode statements for a field, applying the
+/// field's `length` directive, if any, to the size of a string, bytes, or array value.
+fn gen_encode_sized_stmts(
+    field_name: &str,
+    encoding: &Encoding,
+    length: Option<&Length>,
+    lazy: &HashSet<String>,
+) -> anyhow::Result<Vec<Item>> {
+    let mut stmts = match &encoding.native {
+        // String
+        NativeType::String => gen_encode_string(field_name, &get_size(encoding, length)?),
 
         // Bytes
-        NativeType::Bytes => gen_encode_bytes(field_name),
+        NativeType::Bytes => gen_encode_bytes(field_name, &get_size(encoding, length)?),
 
         // Array
-        NativeType::Array { element } => gen_encode_array(field_name, element),
+        NativeType::Array { element } => {
+            gen_encode_array(field_name, &get_size(encoding, length)?, element, lazy)
+        }
 
         // Map
-        NativeType::Map { key, value } => gen_encode_map(field_name, key, value),
+        NativeType::Map { key, value } => {
+            gen_encode_map(field_name, &get_size(encoding, length)?, key, value, lazy)
+        }
+
+        // Only strings, bytes, and arrays support a length directive.
+        _ if length.is_some() => {
+            anyhow::bail!("Unsupported length directive: native={:?}", encoding.native)
+        }
+
+        // Primitives: Bool, Int, Float
+        NativeType::Bool | NativeType::Int { .. } | NativeType::Float { .. } => {
+            gen_encode_primitive(field_name, encoding)
+        }
+
+        // Built-in Godot types (must come before messages)
+        native if BuiltinType::from_native(native).is_some() => {
+            gen_encode_primitive(field_name, encoding)
+        }
 
         // Message
         NativeType::Message { .. } => gen_encode_message(field_name),
 
         // Enum
         NativeType::Enum { .. } => gen_encode_enum(field_name),
+    }?;
+
+    stmts.extend(gen_encode_padding(encoding));
+
+    Ok(stmts)
+}
+
+/* ----------------------- Fn: gen_encode_field_stmts ----------------------- */
+
+/// `gen_encode_field_stmts` generates encode statements for a message field, applying
+/// any GDScript-specific field directives.
+pub fn gen_encode_field_stmts(
+    field_name: &str,
+    encoding: &Encoding,
+    directives: &FieldDirectives,
+    lazy: &HashSet<String>,
+) -> anyhow::Result<Vec<Item>> {
+    validate_transforms(encoding)?;
+
+    match &directives.quantize {
+        Some(quantize) => gen_encode_quantized(field_name, encoding, quantize),
+        None if directives.optional => gen_encode_optional(field_name, encoding),
+        None => gen_encode_sized_stmts(field_name, encoding, directives.length.as_ref(), lazy),
+    }
+}
+
+/* ------------------------ Fn: gen_encode_optional ------------------------- */
+
+/// `gen_encode_optional` generates encoding for an optional message or enum field. A
+/// presence bit is written first, followed by the value only if it's set.
+///
+/// # Generated GDScript
+/// ```gdscript
+/// _writer.write_bool(player != null)
+/// if player != null:
+///     player._encode(_writer)
+/// ```
+fn gen_encode_optional(field_name: &str, encoding: &Encoding) -> anyhow::Result<Vec<Item>> {
+    validate_optional(encoding)?;
+
+    let is_set = Expr::binary_op(Expr::ident(field_name), Operator::NotEq, Expr::null());
+
+    let write_presence = Item::Expr(FnCall::method_args(
+        Expr::ident("_writer"),
+        "write_bool",
+        vec![is_set.clone()],
+    ));
+
+    // Call field._encode(_writer)
+    let encode_call = Item::Expr(FnCall::method_args(
+        Expr::ident(field_name),
+        "_encode",
+        vec![Expr::ident("_writer")],
+    ));
+
+    let write_value = Item::If(
+        IfBuilder::default()
+            .condition(is_set)
+            .then_body(
+                [vec![encode_call], gen_encode_padding(encoding)]
+                    .concat()
+                    .into(),
+            )
+            .build()
+            .unwrap(),
+    );
+
+    Ok(vec![write_presence, write_value])
+}
+
+/* ------------------------ Fn: gen_encode_quantized ------------------------ */
+
+/// `gen_encode_quantized` generates encoding for a quantized float field.
+///
+/// # Generated GDScript
+/// ```gdscript
+/// _writer.write_quantized(field_name, -1.0, 1.0, 10)
+/// ```
+fn gen_encode_quantized(
+    field_name: &str,
+    encoding: &Encoding,
+    quantize: &Quantize,
+) -> anyhow::Result<Vec<Item>> {
+    let method = get_quantized_write_method(encoding, quantize)?;
+
+    let mut args = vec![Expr::ident(field_name)];
+    args.extend(method.extra_args);
+
+    let mut stmts = vec![Item::Expr(FnCall::method_args(
+        Expr::ident("_writer"),
+        &method.method,
+        args,
+    ))];
+    stmts.extend(gen_encode_padding(encoding));
+
+    Ok(stmts)
+}
+
+/* ------------------------- Fn: gen_encode_padding ------------------------- */
+
+/// `gen_encode_padding` generates zero padding for an encoding which requests it. No
+/// statements are generated if the encoding has no padding.
+///
+/// # Generated GDScript
+/// ```gdscript
+/// _writer.write_padding(4)
+/// ```
+fn gen_encode_padding(encoding: &Encoding) -> Vec<Item> {
+    match encoding.padding_bits {
+        Some(bits) if bits > 0 => vec![Item::Expr(FnCall::method_args(
+            Expr::ident("_writer"),
+            "write_padding",
+            vec![Expr::Literal((bits as i64).into())],
+        ))],
+        _ => vec![],
     }
 }
 
@@ -53,23 +217,35 @@ fn gen_encode_primitive(field_name: &str, encoding: &Encoding) -> anyhow::Result
     Ok(vec![Item::Expr(call)])
 }
 
+/* ------------------------- Fn: gen_encode_string -------------------------- */
+
+/// `gen_encode_string` generates encoding for a string field.
+///
+/// # Generated GDScript
+/// ```gdscript
+/// _writer.write_string(name, 4, 9)
+/// ```
+fn gen_encode_string(field_name: &str, size: &Size) -> anyhow::Result<Vec<Item>> {
+    let mut args = vec![Expr::ident(field_name)];
+    args.extend(size.prefix_args());
+
+    let call = FnCall::method_args(Expr::ident("_writer"), "write_string", args);
+
+    Ok(vec![Item::Expr(call)])
+}
+
 /* -------------------------- Fn: gen_encode_bytes -------------------------- */
 
 /// `gen_encode_bytes` generates encoding for a bytes field.
 ///
 /// # Generated GDScript
 /// ```gdscript
-/// _writer.write_varint_unsigned(data.size())
+/// _writer.write_size(data.size(), 16)
 /// _writer.write_bytes(data)
 /// ```
-fn gen_encode_bytes(field_name: &str) -> anyhow::Result<Vec<Item>> {
+fn gen_encode_bytes(field_name: &str, size: &Size) -> anyhow::Result<Vec<Item>> {
     // Write length prefix
-    let size_call = FnCall::method(Expr::ident(field_name), "size");
-    let write_length = FnCall::method_args(
-        Expr::ident("_writer"),
-        "write_varint_unsigned",
-        vec![size_call],
-    );
+    let write_length = gen_write_size(field_name, size);
 
     // Write bytes
     let write_bytes = FnCall::method_args(
@@ -78,45 +254,73 @@ fn gen_encode_bytes(field_name: &str) -> anyhow::Result<Vec<Item>> {
         vec![Expr::ident(field_name)],
     );
 
-    Ok(vec![Item::Expr(write_length), Item::Expr(write_bytes)])
+    Ok(vec![write_length, Item::Expr(write_bytes)])
 }
 
 /* -------------------------- Fn: gen_encode_array -------------------------- */
 
 /// `gen_encode_array` generates encoding for an array field.
-fn gen_encode_array(field_name: &str, element: &Encoding) -> anyhow::Result<Vec<Item>> {
+fn gen_encode_array(
+    field_name: &str,
+    size: &Size,
+    element: &Encoding,
+    lazy: &HashSet<String>,
+) -> anyhow::Result<Vec<Item>> {
     match &element.native {
+        // Built-in Godot types are encoded like primitives
+        native if BuiltinType::from_native(native).is_some() => {
+            gen_encode_array_primitive(field_name, size, element, lazy)
+        }
+
         // Array of messages or enums requires null checks
         NativeType::Message { .. } | NativeType::Enum { .. } => {
-            gen_encode_array_message(field_name, element)
+            gen_encode_array_message(field_name, size, element)
         }
 
         // All other types (primitives, bytes, etc.) can be encoded directly
-        _ => gen_encode_array_primitive(field_name, element),
+        _ => gen_encode_array_primitive(field_name, size, element, lazy),
     }
 }
 
 /* --------------------- Fn: gen_encode_array_primitive --------------------- */
 
-/// `gen_encode_array_primitive` generates encoding for an array of primitives.
+/// `gen_encode_array_primitive` generates encoding for an array of primitives. Packed
+/// arrays of fixed-width, byte-sized elements are written in bulk.
 ///
 /// # Generated GDScript
 /// ```gdscript
-/// _writer.write_varint_unsigned(items.size())
+/// _writer.write_size(items.size(), 16)
 /// for _item in items:
 ///     _writer.write_xxx(_item)
 /// ```
-fn gen_encode_array_primitive(field_name: &str, element: &Encoding) -> anyhow::Result<Vec<Item>> {
+///
+/// or, for bulk-encodable elements:
+///
+/// ```gdscript
+/// _writer.write_size(items.size(), 16)
+/// _writer.write_int32_array(items, 4)
+/// ```
+fn gen_encode_array_primitive(
+    field_name: &str,
+    size: &Size,
+    element: &Encoding,
+    lazy: &HashSet<String>,
+) -> anyhow::Result<Vec<Item>> {
     // Write array length
-    let size_call = FnCall::method(Expr::ident(field_name), "size");
-    let write_length = FnCall::method_args(
-        Expr::ident("_writer"),
-        "write_varint_unsigned",
-        vec![size_call],
-    );
+    let write_length = gen_write_size(field_name, size);
+
+    // Write all elements at once, if supported
+    if let Some(bulk) = get_bulk_write_method(element) {
+        let mut args = vec![Expr::ident(field_name)];
+        args.extend(bulk.extra_args);
+
+        let write_elements = FnCall::method_args(Expr::ident("_writer"), &bulk.method, args);
+
+        return Ok(vec![write_length, Item::Expr(write_elements)]);
+    }
 
     // Generate encoding statements for element
-    let element_stmts = gen_encode_stmts("_item", element)?;
+    let element_stmts = gen_encode_sized_stmts("_item", element, None, lazy)?;
 
     // Create for loop
     let for_loop = Item::ForIn(
@@ -128,7 +332,7 @@ fn gen_encode_array_primitive(field_name: &str, element: &Encoding) -> anyhow::R
             .unwrap(),
     );
 
-    Ok(vec![Item::Expr(write_length), for_loop])
+    Ok(vec![write_length, for_loop])
 }
 
 /* ---------------------- Fn: gen_encode_array_message ---------------------- */
@@ -137,21 +341,20 @@ fn gen_encode_array_primitive(field_name: &str, element: &Encoding) -> anyhow::R
 ///
 /// # Generated GDScript
 /// ```gdscript
-/// _writer.write_varint_unsigned(items.size())
+/// _writer.write_size(items.size(), 16)
 /// for _item in items:
 ///     if _item == null:
 ///         _writer.set_error(ERR_INVALID_DATA)
 ///         return
 ///     _item._encode(_writer)
 /// ```
-fn gen_encode_array_message(field_name: &str, _element: &Encoding) -> anyhow::Result<Vec<Item>> {
+fn gen_encode_array_message(
+    field_name: &str,
+    size: &Size,
+    element: &Encoding,
+) -> anyhow::Result<Vec<Item>> {
     // Write array length
-    let size_call = FnCall::method(Expr::ident(field_name), "size");
-    let write_length = FnCall::method_args(
-        Expr::ident("_writer"),
-        "write_varint_unsigned",
-        vec![size_call],
-    );
+    let write_length = gen_write_size(field_name, size);
 
     // Null check for message
     let null_check = gen_null_check("_item");
@@ -168,12 +371,12 @@ fn gen_encode_array_message(field_name: &str, _element: &Encoding) -> anyhow::Re
         ForInBuilder::default()
             .variable("_item")
             .iterable(Expr::ident(field_name))
-            .body(vec![null_check, encode_call])
+            .body([vec![null_check, encode_call], gen_encode_padding(element)].concat())
             .build()
             .unwrap(),
     );
 
-    Ok(vec![Item::Expr(write_length), for_loop])
+    Ok(vec![write_length, for_loop])
 }
 
 /* ------------------------- Fn: gen_encode_message ------------------------- */
@@ -229,15 +432,26 @@ fn gen_encode_enum(field_name: &str) -> anyhow::Result<Vec<Item>> {
 /* --------------------------- Fn: gen_encode_map --------------------------- */
 
 /// `gen_encode_map` generates encoding for a map field.
-fn gen_encode_map(field_name: &str, key: &Encoding, value: &Encoding) -> anyhow::Result<Vec<Item>> {
+fn gen_encode_map(
+    field_name: &str,
+    size: &Size,
+    key: &Encoding,
+    value: &Encoding,
+    lazy: &HashSet<String>,
+) -> anyhow::Result<Vec<Item>> {
     match &value.native {
+        // Built-in Godot types are encoded like primitives
+        native if BuiltinType::from_native(native).is_some() => {
+            gen_encode_map_primitive(field_name, size, key, value, lazy)
+        }
+
         // Map of messages or enums requires null checks
         NativeType::Message { .. } | NativeType::Enum { .. } => {
-            gen_encode_map_message(field_name, key, value)
+            gen_encode_map_message(field_name, size, key, value, lazy)
         }
 
         // All other types (primitives, bytes, etc.) can be encoded directly
-        _ => gen_encode_map_primitive(field_name, key, value),
+        _ => gen_encode_map_primitive(field_name, size, key, value, lazy),
     }
 }
 
@@ -247,26 +461,23 @@ fn gen_encode_map(field_name: &str, key: &Encoding, value: &Encoding) -> anyhow:
 ///
 /// # Generated GDScript
 /// ```gdscript
-/// _writer.write_varint_unsigned(stats.size())
+/// _writer.write_size(stats.size(), 16)
 /// for _key in stats:
 ///     _writer.write_xxx(_key)
 ///     _writer.write_yyy(stats[_key])
 /// ```
 fn gen_encode_map_primitive(
     field_name: &str,
+    size: &Size,
     key: &Encoding,
     value: &Encoding,
+    lazy: &HashSet<String>,
 ) -> anyhow::Result<Vec<Item>> {
     // Write map size
-    let size_call = FnCall::method(Expr::ident(field_name), "size");
-    let write_length = FnCall::method_args(
-        Expr::ident("_writer"),
-        "write_varint_unsigned",
-        vec![size_call],
-    );
+    let write_length = gen_write_size(field_name, size);
 
     // Generate encoding statements for key
-    let key_stmts = gen_encode_stmts("_key", key)?;
+    let key_stmts = gen_encode_sized_stmts("_key", key, None, lazy)?;
 
     // Generate encoding for value: _writer.write_xxx(field_name[_key])
     let value_method = get_write_method(value)?;
@@ -284,6 +495,7 @@ fn gen_encode_map_primitive(
     // Build loop body
     let mut loop_body = key_stmts;
     loop_body.push(value_write);
+    loop_body.extend(gen_encode_padding(value));
 
     // Create for loop
     let for_loop = Item::ForIn(
@@ -295,7 +507,7 @@ fn gen_encode_map_primitive(
             .unwrap(),
     );
 
-    Ok(vec![Item::Expr(write_length), for_loop])
+    Ok(vec![write_length, for_loop])
 }
 
 /* ----------------------- Fn: gen_encode_map_message ----------------------- */
@@ -304,41 +516,44 @@ fn gen_encode_map_primitive(
 ///
 /// # Generated GDScript
 /// ```gdscript
-/// _writer.write_varint_unsigned(players.size())
+/// _writer.write_size(players.size(), 16)
 /// for _key in players:
 ///     _writer.write_xxx(_key)
-///     var _value := players[_key]
-///     if _value == null:
+///     var _entry: Player = players[_key]
+///     if _entry == null:
 ///         _writer.set_error(ERR_INVALID_DATA)
 ///         return
-///     _value._encode(_writer)
+///     _entry._encode(_writer)
 /// ```
 fn gen_encode_map_message(
     field_name: &str,
+    size: &Size,
     key: &Encoding,
-    _value: &Encoding,
+    value: &Encoding,
+    lazy: &HashSet<String>,
 ) -> anyhow::Result<Vec<Item>> {
     // Write map size
-    let size_call = FnCall::method(Expr::ident(field_name), "size");
-    let write_length = FnCall::method_args(
-        Expr::ident("_writer"),
-        "write_varint_unsigned",
-        vec![size_call],
-    );
+    let write_length = gen_write_size(field_name, size);
 
     // Generate encoding statements for key
-    let key_stmts = gen_encode_stmts("_key", key)?;
+    let key_stmts = gen_encode_sized_stmts("_key", key, None, lazy)?;
 
-    // Declare value variable: var _value := field_name[_key]
+    // Declare value variable: var _entry: MessageType = field_name[_key]
     let value_access = Expr::index(Expr::ident(field_name), Expr::ident("_key"));
-    let declare_value = Assignment::var("_value", value_access);
+    let declare_value = AssignmentBuilder::default()
+        .declaration(DeclarationKind::Var)
+        .variable("_entry")
+        .type_hint(TypeHint::Explicit(element_type_name(&value.native, lazy)))
+        .value(value_access)
+        .build()
+        .unwrap();
 
     // Null check for message value
-    let null_check = gen_null_check("_value");
+    let null_check = gen_null_check("_entry");
 
-    // Call _value._encode(_writer)
+    // Call _entry._encode(_writer)
     let encode_call = Item::Expr(FnCall::method_args(
-        Expr::ident("_value"),
+        Expr::ident("_entry"),
         "_encode",
         vec![Expr::ident("_writer")],
     ));
@@ -348,6 +563,7 @@ fn gen_encode_map_message(
     loop_body.push(Item::Assignment(declare_value));
     loop_body.push(null_check);
     loop_body.push(encode_call);
+    loop_body.extend(gen_encode_padding(value));
 
     // Create for loop
     let for_loop = Item::ForIn(
@@ -359,7 +575,63 @@ fn gen_encode_map_message(
             .unwrap(),
     );
 
-    Ok(vec![Item::Expr(write_length), for_loop])
+    Ok(vec![write_length, for_loop])
+}
+
+/* -------------------------------------------------------------------------- */
+/*                            Fn: gen_write_size                              */
+/* -------------------------------------------------------------------------- */
+
+/// `gen_write_size` generates a statement which writes the size of a bytes, array, or
+/// map field. A fixed size isn't written; instead, the field's size is validated.
+///
+/// # Generated GDScript
+/// ```gdscript
+/// _writer.write_size(items.size(), 16)
+/// ```
+///
+/// or, for a fixed size:
+///
+/// ```gdscript
+/// if items.size() != 9:
+///     _writer.set_error(ERR_PARAMETER_RANGE_ERROR)
+///     return
+/// ```
+fn gen_write_size(field_name: &str, size: &Size) -> Item {
+    let size_call = FnCall::method(Expr::ident(field_name), "size");
+
+    if let Size::Fixed(fixed) = size {
+        return Item::If(
+            IfBuilder::default()
+                .condition(Expr::binary_op(
+                    size_call,
+                    Operator::NotEq,
+                    Expr::Literal((*fixed).into()),
+                ))
+                .then_body(
+                    vec![
+                        Item::Expr(FnCall::method_args(
+                            Expr::ident("_writer"),
+                            "set_error",
+                            vec![Expr::ident("ERR_PARAMETER_RANGE_ERROR")],
+                        )),
+                        Item::Return(None),
+                    ]
+                    .into(),
+                )
+                .build()
+                .unwrap(),
+        );
+    }
+
+    let mut args = vec![size_call];
+    args.extend(size.prefix_args());
+
+    Item::Expr(FnCall::method_args(
+        Expr::ident("_writer"),
+        "write_size",
+        args,
+    ))
 }
 
 /* -------------------------------------------------------------------------- */
@@ -436,6 +708,48 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
+    /* -------------------- Tests: gen_encode_padding -------------------- */
+
+    #[test]
+    fn test_gen_encode_padding_after_value() {
+        // Given: A bool encoding with padding.
+        let encoding = Encoding {
+            wire: WireFormat::Bits { count: 1 },
+            native: NativeType::Bool,
+            transforms: vec![],
+            padding_bits: Some(7),
+        };
+
+        // When: Generating encode statements.
+        let stmts = gen_encode_stmts("active", &encoding, &HashSet::new()).unwrap();
+
+        // Then: Two statements are generated (value + padding).
+        assert_eq!(stmts.len(), 2);
+
+        // Then: The padding is written after the value.
+        let mut s = StringWriter::default();
+        let mut cw = GDScript::writer();
+        stmts[1].emit(&mut cw, &mut s).unwrap();
+        assert_eq!(s.into_content(), "_writer.write_padding(7)");
+    }
+
+    #[test]
+    fn test_gen_encode_padding_zero() {
+        // Given: A bool encoding with zero padding.
+        let encoding = Encoding {
+            wire: WireFormat::Bits { count: 1 },
+            native: NativeType::Bool,
+            transforms: vec![],
+            padding_bits: Some(0),
+        };
+
+        // When: Generating encode statements.
+        let stmts = gen_encode_stmts("active", &encoding, &HashSet::new()).unwrap();
+
+        // Then: No padding is written.
+        assert_eq!(stmts.len(), 1);
+    }
+
     /* ------------------ Tests: gen_encode_primitive ------------------- */
 
     #[test]
@@ -449,7 +763,7 @@ mod tests {
         };
 
         // When: Generating encode statements.
-        let stmts = gen_encode_stmts("active", &encoding).unwrap();
+        let stmts = gen_encode_stmts("active", &encoding, &HashSet::new()).unwrap();
 
         // Then: A single method call is generated.
         assert_eq!(stmts.len(), 1);
@@ -475,7 +789,7 @@ mod tests {
         };
 
         // When: Generating encode statements.
-        let stmts = gen_encode_stmts("count", &encoding).unwrap();
+        let stmts = gen_encode_stmts("count", &encoding, &HashSet::new()).unwrap();
 
         // Then: A single method call is generated.
         assert_eq!(stmts.len(), 1);
@@ -498,7 +812,7 @@ mod tests {
         };
 
         // When: Generating encode statements.
-        let stmts = gen_encode_stmts("name", &encoding).unwrap();
+        let stmts = gen_encode_stmts("name", &encoding, &HashSet::new()).unwrap();
 
         // Then: A single method call is generated.
         assert_eq!(stmts.len(), 1);
@@ -507,7 +821,7 @@ mod tests {
         let mut s = StringWriter::default();
         let mut cw = GDScript::writer();
         stmts[0].emit(&mut cw, &mut s).unwrap();
-        assert_eq!(s.into_content(), "_writer.write_string(name)");
+        assert_eq!(s.into_content(), "_writer.write_string(name, 64)");
     }
 
     #[test]
@@ -524,7 +838,7 @@ mod tests {
         };
 
         // When: Generating encode statements.
-        let stmts = gen_encode_stmts("value", &encoding).unwrap();
+        let stmts = gen_encode_stmts("value", &encoding, &HashSet::new()).unwrap();
 
         // Then: A single method call is generated.
         assert_eq!(stmts.len(), 1);
@@ -550,7 +864,7 @@ mod tests {
         };
 
         // When: Generating encode statements.
-        let stmts = gen_encode_stmts("value", &encoding).unwrap();
+        let stmts = gen_encode_stmts("value", &encoding, &HashSet::new()).unwrap();
 
         // Then: A single method call is generated.
         assert_eq!(stmts.len(), 1);
@@ -576,7 +890,7 @@ mod tests {
         };
 
         // When: Generating encode statements.
-        let stmts = gen_encode_stmts("value", &encoding).unwrap();
+        let stmts = gen_encode_stmts("value", &encoding, &HashSet::new()).unwrap();
 
         // Then: A single method call is generated.
         assert_eq!(stmts.len(), 1);
@@ -602,7 +916,7 @@ mod tests {
         };
 
         // When: Generating encode statements.
-        let stmts = gen_encode_stmts("value", &encoding).unwrap();
+        let stmts = gen_encode_stmts("value", &encoding, &HashSet::new()).unwrap();
 
         // Then: A single method call is generated.
         assert_eq!(stmts.len(), 1);
@@ -628,7 +942,7 @@ mod tests {
         };
 
         // When: Generating encode statements.
-        let stmts = gen_encode_stmts("value", &encoding).unwrap();
+        let stmts = gen_encode_stmts("value", &encoding, &HashSet::new()).unwrap();
 
         // Then: A single method call is generated.
         assert_eq!(stmts.len(), 1);
@@ -654,7 +968,7 @@ mod tests {
         };
 
         // When: Generating encode statements.
-        let stmts = gen_encode_stmts("value", &encoding).unwrap();
+        let stmts = gen_encode_stmts("value", &encoding, &HashSet::new()).unwrap();
 
         // Then: A single method call is generated.
         assert_eq!(stmts.len(), 1);
@@ -680,7 +994,7 @@ mod tests {
         };
 
         // When: Generating encode statements.
-        let stmts = gen_encode_stmts("value", &encoding).unwrap();
+        let stmts = gen_encode_stmts("value", &encoding, &HashSet::new()).unwrap();
 
         // Then: A single method call is generated.
         assert_eq!(stmts.len(), 1);
@@ -703,7 +1017,7 @@ mod tests {
         };
 
         // When: Generating encode statements.
-        let stmts = gen_encode_stmts("value", &encoding).unwrap();
+        let stmts = gen_encode_stmts("value", &encoding, &HashSet::new()).unwrap();
 
         // Then: A single method call is generated.
         assert_eq!(stmts.len(), 1);
@@ -726,7 +1040,7 @@ mod tests {
         };
 
         // When: Generating encode statements.
-        let stmts = gen_encode_stmts("value", &encoding).unwrap();
+        let stmts = gen_encode_stmts("value", &encoding, &HashSet::new()).unwrap();
 
         // Then: A single method call is generated.
         assert_eq!(stmts.len(), 1);
@@ -752,7 +1066,7 @@ mod tests {
         };
 
         // When: Generating encode statements.
-        let stmts = gen_encode_stmts("value", &encoding).unwrap();
+        let stmts = gen_encode_stmts("value", &encoding, &HashSet::new()).unwrap();
 
         // Then: A single method call is generated.
         assert_eq!(stmts.len(), 1);
@@ -778,7 +1092,7 @@ mod tests {
         };
 
         // When: Generating encode statements.
-        let stmts = gen_encode_stmts("value", &encoding).unwrap();
+        let stmts = gen_encode_stmts("value", &encoding, &HashSet::new()).unwrap();
 
         // Then: A single method call is generated.
         assert_eq!(stmts.len(), 1);
@@ -804,7 +1118,7 @@ mod tests {
         };
 
         // When: Generating encode statements.
-        let stmts = gen_encode_stmts("value", &encoding).unwrap();
+        let stmts = gen_encode_stmts("value", &encoding, &HashSet::new()).unwrap();
 
         // Then: A single method call is generated.
         assert_eq!(stmts.len(), 1);
@@ -829,7 +1143,7 @@ mod tests {
         };
 
         // When: Generating encode statements.
-        let stmts = gen_encode_stmts("data", &encoding).unwrap();
+        let stmts = gen_encode_stmts("data", &encoding, &HashSet::new()).unwrap();
 
         // Then: Two statements are generated (length + bytes).
         assert_eq!(stmts.len(), 2);
@@ -839,7 +1153,7 @@ mod tests {
         let mut cw = GDScript::writer();
         stmts[0].emit(&mut cw, &mut s1).unwrap();
         let actual1 = s1.into_content();
-        assert_eq!(actual1, "_writer.write_varint_unsigned(data.size())");
+        assert_eq!(actual1, "_writer.write_size(data.size(), 64)");
 
         // Then: Second statement writes the bytes.
         let mut s2 = StringWriter::default();
@@ -870,9 +1184,9 @@ mod tests {
         };
 
         // When: Generating encode statements.
-        let stmts = gen_encode_stmts("items", &encoding).unwrap();
+        let stmts = gen_encode_stmts("items", &encoding, &HashSet::new()).unwrap();
 
-        // Then: Two statements are generated (length + for loop).
+        // Then: Two statements are generated (length + bulk write).
         assert_eq!(stmts.len(), 2);
 
         // Then: First statement writes array length.
@@ -880,17 +1194,51 @@ mod tests {
         let mut cw = GDScript::writer();
         stmts[0].emit(&mut cw, &mut s1).unwrap();
         let actual1 = s1.into_content();
-        assert_eq!(actual1, "_writer.write_varint_unsigned(items.size())");
+        assert_eq!(actual1, "_writer.write_size(items.size(), 64)");
 
-        // Then: Second statement is for loop.
+        // Then: Second statement writes all elements at once.
         let mut s2 = StringWriter::default();
         stmts[1].emit(&mut cw, &mut s2).unwrap();
         let actual2 = s2.into_content();
+        assert_eq!(actual2, "_writer.write_int32_array(items, 4)");
+    }
+
+    #[test]
+    fn test_gen_encode_array_primitive_varint() {
+        // Given: An array of varint-encoded i32 encoding.
+        let encoding = Encoding {
+            wire: WireFormat::LengthPrefixed { prefix_bits: 64 },
+            native: NativeType::Array {
+                element: Box::new(Encoding {
+                    wire: WireFormat::LengthPrefixed { prefix_bits: 64 },
+                    native: NativeType::Int {
+                        bits: 32,
+                        signed: true,
+                    },
+                    transforms: vec![Transform::ZigZag],
+                    padding_bits: None,
+                }),
+            },
+            transforms: vec![],
+            padding_bits: None,
+        };
 
-        let expected2 = r#"for _item in items:
-	_writer.write_i32(_item)"#;
+        // When: Generating encode statements.
+        let stmts = gen_encode_stmts("items", &encoding, &HashSet::new()).unwrap();
 
-        assert_eq!(actual2, expected2);
+        // Then: Two statements are generated (length + for loop).
+        assert_eq!(stmts.len(), 2);
+
+        // Then: Second statement is a for loop, since varints can't be written in bulk.
+        let mut s = StringWriter::default();
+        let mut cw = GDScript::writer();
+        stmts[1].emit(&mut cw, &mut s).unwrap();
+        let actual = s.into_content();
+
+        let expected = r#"for _item in items:
+	_writer.write_varint_signed(_item)"#;
+
+        assert_eq!(actual, expected);
     }
 
     #[test]
@@ -919,7 +1267,7 @@ mod tests {
         };
 
         // When: Generating encode statements.
-        let stmts = gen_encode_stmts("players", &encoding).unwrap();
+        let stmts = gen_encode_stmts("players", &encoding, &HashSet::new()).unwrap();
 
         // Then: Two statements are generated (length + for loop).
         assert_eq!(stmts.len(), 2);
@@ -968,7 +1316,7 @@ mod tests {
         };
 
         // When: Generating encode statements.
-        let stmts = gen_encode_stmts("stats", &encoding).unwrap();
+        let stmts = gen_encode_stmts("stats", &encoding, &HashSet::new()).unwrap();
 
         // Then: Two statements are generated (length + for loop).
         assert_eq!(stmts.len(), 2);
@@ -978,7 +1326,7 @@ mod tests {
         let mut cw = GDScript::writer();
         stmts[0].emit(&mut cw, &mut s1).unwrap();
         let actual1 = s1.into_content();
-        assert_eq!(actual1, "_writer.write_varint_unsigned(stats.size())");
+        assert_eq!(actual1, "_writer.write_size(stats.size(), 64)");
 
         // Then: Second statement is for loop.
         let mut s2 = StringWriter::default();
@@ -986,7 +1334,7 @@ mod tests {
         let actual2 = s2.into_content();
 
         let expected2 = r#"for _key in stats:
-	_writer.write_string(_key)
+	_writer.write_string(_key, 64)
 	_writer.write_i32(stats[_key])"#;
 
         assert_eq!(actual2, expected2);
@@ -1024,7 +1372,7 @@ mod tests {
         };
 
         // When: Generating encode statements.
-        let stmts = gen_encode_stmts("players", &encoding).unwrap();
+        let stmts = gen_encode_stmts("players", &encoding, &HashSet::new()).unwrap();
 
         // Then: Two statements are generated (length + for loop).
         assert_eq!(stmts.len(), 2);
@@ -1036,12 +1384,12 @@ mod tests {
         let actual = s.into_content();
 
         let expected = r#"for _key in players:
-	_writer.write_string(_key)
-	var _value := players[_key]
-	if _value == null:
+	_writer.write_string(_key, 64)
+	var _entry: Player = players[_key]
+	if _entry == null:
 		_writer.set_error(ERR_INVALID_DATA)
 		return
-	_value._encode(_writer)"#;
+	_entry._encode(_writer)"#;
 
         assert_eq!(actual, expected);
     }
@@ -1067,7 +1415,7 @@ mod tests {
         };
 
         // When: Generating encode statements.
-        let stmts = gen_encode_stmts("player", &encoding).unwrap();
+        let stmts = gen_encode_stmts("player", &encoding, &HashSet::new()).unwrap();
 
         // Then: Two statements are generated (null check + encode call).
         assert_eq!(stmts.len(), 2);
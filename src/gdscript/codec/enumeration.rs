@@ -1,10 +1,16 @@
-use baproto::Variant;
+use std::collections::HashSet;
+
+use baproto::{Encoding, NativeType, Variant};
 
 use crate::gdscript::ast::{
-    Assignment, Block, Expr, FnCall, IfBuilder, Item, Match, MatchArm, Operator,
+    Assignment, AssignmentBuilder, Block, DeclarationKind, Expr, FnCall, IfBuilder, Item, Match,
+    MatchArm, Operator, TypeHint,
 };
+use crate::gdscript::directive::FieldDirectives;
+use crate::gdscript::options::Options;
+use crate::gdscript::types::type_name;
 
-use super::wire::get_write_method;
+use super::{gen_decode_field_stmts, gen_decode_stmts, gen_encode_field_stmts, gen_encode_stmts};
 
 /* -------------------------------------------------------------------------- */
 /*                          Fn: gen_enum_encode_stmts                         */
@@ -17,14 +23,24 @@ use super::wire::get_write_method;
 /// if _discriminant == NONE:
 ///     _writer.set_error(ERR_INVALID_DATA)
 ///     return null
-/// _writer.write_varint_signed(_discriminant)
+/// _writer.write_xxx(_discriminant)
 /// match _discriminant:
 ///     UNIT_VARIANT:
 ///         pass
 ///     FIELD_VARIANT:
 ///         _writer.write_xxx(_value)
 /// ```
-pub fn gen_enum_encode_stmts(variants: &[Variant]) -> anyhow::Result<Vec<Item>> {
+///
+/// Field variant payloads are encoded with [`gen_encode_field_stmts`], so any type
+/// (and any field directive) which is supported on a message field is supported on a
+/// variant payload.
+pub fn gen_enum_encode_stmts(
+    discriminant: &Encoding,
+    variants: &[Variant],
+    lazy: &HashSet<String>,
+) -> anyhow::Result<Vec<Item>> {
+    check_discriminant_encoding(discriminant)?;
+
     let mut stmts = Vec::new();
 
     // Validate discriminant is not NONE
@@ -48,12 +64,7 @@ pub fn gen_enum_encode_stmts(variants: &[Variant]) -> anyhow::Result<Vec<Item>>
     stmts.push(none_check.into());
 
     // Write discriminant
-    let write_discriminant = FnCall::method_args(
-        Expr::ident("_writer"),
-        "write_varint_signed",
-        vec![Expr::ident("_discriminant")],
-    );
-    stmts.push(write_discriminant.into());
+    stmts.extend(gen_encode_stmts("_discriminant", discriminant, lazy)?);
 
     // Match on discriminant to write value for field variants
     if !variants.is_empty() {
@@ -70,16 +81,13 @@ pub fn gen_enum_encode_stmts(variants: &[Variant]) -> anyhow::Result<Vec<Item>>
                 }
                 Variant::Field { name, field, .. } => {
                     // Field variants: write the value
-                    let method = get_write_method(&field.encoding)?;
-                    let mut args = vec![Expr::ident("_value")];
-                    args.extend(method.extra_args);
-
+                    let directives = FieldDirectives::parse(field.doc.as_deref())?;
                     let write_value =
-                        FnCall::method_args(Expr::ident("_writer"), &method.method, args);
+                        gen_encode_field_stmts("_value", &field.encoding, &directives, lazy)?;
 
                     match_arms.push(MatchArm {
                         pattern: Expr::ident(name),
-                        body: Block::from(vec![write_value.into()]),
+                        body: Block::from(write_value),
                     });
                 }
             }
@@ -100,67 +108,67 @@ pub fn gen_enum_encode_stmts(variants: &[Variant]) -> anyhow::Result<Vec<Item>>
 /*                          Fn: gen_enum_decode_stmts                         */
 /* -------------------------------------------------------------------------- */
 
-/// `gen_enum_decode_stmts` generates decoding statements for an enum.
+/// `gen_enum_decode_stmts` generates decoding statements for an enum. Like messages,
+/// enums count towards the reader's nesting depth, since their payloads may nest the
+/// enum itself.
 ///
 /// # Generated GDScript
 /// ```gdscript
-/// _discriminant = _reader.read_varint_signed()
+/// if _reader.enter_message() != OK:
+///     return _reader.get_error()
+/// _discriminant = _reader.read_xxx()
 /// if _reader.get_error() != OK:
 ///     return _reader.get_error()
 /// match _discriminant:
-///     NONE:
-///         _reader.set_error(ERR_INVALID_DATA)
-///         return _reader.get_error()
 ///     UNIT_VARIANT:
 ///         _value = null
 ///     FIELD_VARIANT:
 ///         _value = _reader.read_xxx()
 ///         if _reader.get_error() != OK:
 ///             return _reader.get_error()
+///     _:
+///         _reader.set_error(ERR_INVALID_DATA)
+///         return _reader.get_error()
+/// _reader.exit_message()
 /// return _reader.get_error()
 /// ```
-pub fn gen_enum_decode_stmts(variants: &[Variant]) -> anyhow::Result<Vec<Item>> {
+///
+/// Discriminants which don't match any variant (including `NONE`) are rejected with
+/// `ERR_INVALID_DATA`. Field variant payloads are decoded with
+/// [`gen_decode_field_stmts`]; see [`gen_variant_decode_stmts`] for details.
+pub fn gen_enum_decode_stmts(
+    discriminant: &Encoding,
+    variants: &[Variant],
+    options: &Options,
+    lazy: &HashSet<String>,
+) -> anyhow::Result<Vec<Item>> {
+    check_discriminant_encoding(discriminant)?;
+
     let mut stmts = Vec::new();
 
-    // Read discriminant
-    let read_discriminant = Assignment::reassign(
-        "_discriminant",
-        FnCall::method(Expr::ident("_reader"), "read_varint_signed"),
+    // Track nesting depth so that readers can reject deeply-nested data.
+    stmts.push(
+        IfBuilder::default()
+            .condition(Expr::binary_op(
+                FnCall::method(Expr::ident("_reader"), "enter_message"),
+                Operator::NotEq,
+                Expr::ident("OK"),
+            ))
+            .then_body(Block::from(vec![Item::Return(Some(FnCall::method(
+                Expr::ident("_reader"),
+                "get_error",
+            )))]))
+            .build()
+            .unwrap()
+            .into(),
     );
-    stmts.push(read_discriminant.into());
 
-    // Check for read error
-    let error_check = IfBuilder::default()
-        .condition(Expr::binary_op(
-            FnCall::method(Expr::ident("_reader"), "get_error"),
-            Operator::NotEq,
-            Expr::ident("OK"),
-        ))
-        .then_body(Block::from(vec![Item::Return(Some(FnCall::method(
-            Expr::ident("_reader"),
-            "get_error",
-        )))]))
-        .build()?;
-
-    stmts.push(error_check.into());
+    // Read discriminant (includes the error check)
+    stmts.extend(gen_decode_stmts("_discriminant", discriminant, lazy)?);
 
     // Match on discriminant
     let mut match_arms = Vec::new();
 
-    // NONE case - reject with error
-    match_arms.push(MatchArm {
-        pattern: Expr::ident("NONE"),
-        body: Block::from(vec![
-            FnCall::method_args(
-                Expr::ident("_reader"),
-                "set_error",
-                vec![Expr::ident("ERR_INVALID_DATA")],
-            )
-            .into(),
-            Item::Return(Some(FnCall::method(Expr::ident("_reader"), "get_error"))),
-        ]),
-    });
-
     // Variant cases
     for variant in variants {
         match variant {
@@ -173,44 +181,39 @@ pub fn gen_enum_decode_stmts(variants: &[Variant]) -> anyhow::Result<Vec<Item>>
             }
             Variant::Field { name, field, .. } => {
                 // Field variants: read the value
-                let method = get_write_method(&field.encoding)?;
-                let read_method = method.method.replace("write", "read");
-
-                let read_args = method.extra_args.clone();
-                let read_call = if read_args.is_empty() {
-                    FnCall::method(Expr::ident("_reader"), read_method.clone())
-                } else {
-                    FnCall::method_args(Expr::ident("_reader"), read_method.clone(), read_args)
-                };
-
-                let assign_value = Assignment::reassign("_value", read_call);
-
-                let error_check = IfBuilder::default()
-                    .condition(Expr::binary_op(
-                        FnCall::method(Expr::ident("_reader"), "get_error"),
-                        Operator::NotEq,
-                        Expr::ident("OK"),
-                    ))
-                    .then_body(Block::from(vec![Item::Return(Some(FnCall::method(
-                        Expr::ident("_reader"),
-                        "get_error",
-                    )))]))
-                    .build()?;
+                let directives = FieldDirectives::parse(field.doc.as_deref())?;
+                let read_value =
+                    gen_variant_decode_stmts(&field.encoding, &directives, options, lazy)?;
 
                 match_arms.push(MatchArm {
                     pattern: Expr::ident(name),
-                    body: Block::from(vec![assign_value.into(), error_check.into()]),
+                    body: Block::from(read_value),
                 });
             }
         }
     }
 
+    // Unknown discriminants (including NONE) - reject with error
+    match_arms.push(MatchArm {
+        pattern: Expr::ident("_"),
+        body: Block::from(vec![
+            FnCall::method_args(
+                Expr::ident("_reader"),
+                "set_error",
+                vec![Expr::ident("ERR_INVALID_DATA")],
+            )
+            .into(),
+            Item::Return(Some(FnCall::method(Expr::ident("_reader"), "get_error"))),
+        ]),
+    });
+
     let match_stmt = Match {
         scrutinee: Expr::ident("_discriminant"),
         arms: match_arms,
     };
 
     stmts.push(match_stmt.into());
+    stmts.push(FnCall::method(Expr::ident("_reader"), "exit_message").into());
 
     // Final return
     stmts.push(Item::Return(Some(FnCall::method(
@@ -220,3 +223,443 @@ pub fn gen_enum_decode_stmts(variants: &[Variant]) -> anyhow::Result<Vec<Item>>
 
     Ok(stmts)
 }
+
+/* ---------------------- Fn: gen_variant_decode_stmts ---------------------- */
+
+/// `gen_variant_decode_stmts` generates decoding statements for a field variant's
+/// payload. Arrays and maps are decoded into a typed local variable before being stored
+/// in the untyped `_value`, so that typed accessors return correctly-typed containers.
+///
+/// # Generated GDScript
+/// ```gdscript
+/// var _payload: Array[int]
+/// _payload = []
+/// for _i in range(_reader.read_length()):
+///     ...
+/// _value = _payload
+/// ```
+fn gen_variant_decode_stmts(
+    encoding: &Encoding,
+    directives: &FieldDirectives,
+    options: &Options,
+    lazy: &HashSet<String>,
+) -> anyhow::Result<Vec<Item>> {
+    match &encoding.native {
+        NativeType::Array { .. } | NativeType::Map { .. } => {
+            let declare_payload = AssignmentBuilder::default()
+                .declaration(DeclarationKind::Var)
+                .variable("_payload")
+                .type_hint(TypeHint::Explicit(type_name(
+                    &encoding.native,
+                    options,
+                    lazy,
+                )))
+                .build()
+                .unwrap();
+
+            let mut stmts = vec![Item::Assignment(declare_payload)];
+            stmts.extend(gen_decode_field_stmts(
+                "_payload", encoding, directives, lazy,
+            )?);
+            stmts.push(Assignment::reassign("_value", Expr::ident("_payload")).into());
+
+            Ok(stmts)
+        }
+        _ => gen_decode_field_stmts("_value", encoding, directives, lazy),
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+/*                       Fn: check_discriminant_encoding                      */
+/* -------------------------------------------------------------------------- */
+
+/// `check_discriminant_encoding` validates that an enum's discriminant is encoded as an
+/// integer, which is required to match against the generated variant constants.
+fn check_discriminant_encoding(discriminant: &Encoding) -> anyhow::Result<()> {
+    match &discriminant.native {
+        NativeType::Int { .. } => Ok(()),
+        native => anyhow::bail!("Unsupported enum discriminant type: {:?}", native),
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                 Mod: Tests                                 */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+    use baproto::{
+        Descriptor, DescriptorBuilder, Field, PackageName, StringWriter, Variant, WireFormat,
+    };
+
+    use crate::gdscript::GDScript;
+    use crate::gdscript::ast::Emit;
+
+    use super::*;
+
+    fn descriptor(name: &str) -> Descriptor {
+        DescriptorBuilder::default()
+            .package(PackageName::try_from(vec!["test"]).unwrap())
+            .path(vec![name.to_string()])
+            .build()
+            .unwrap()
+    }
+
+    fn discriminant() -> Encoding {
+        Encoding {
+            wire: WireFormat::Bits { count: 8 },
+            native: NativeType::Int {
+                bits: 8,
+                signed: false,
+            },
+            transforms: vec![],
+            padding_bits: None,
+        }
+    }
+
+    fn variant(name: &str, index: u32, native: NativeType) -> Variant {
+        Variant::Field {
+            name: name.to_string(),
+            index,
+            field: Field {
+                name: name.to_string(),
+                index,
+                encoding: Encoding {
+                    wire: WireFormat::LengthPrefixed { prefix_bits: 64 },
+                    native,
+                    transforms: vec![],
+                    padding_bits: None,
+                },
+                doc: None,
+            },
+            doc: None,
+        }
+    }
+
+    fn with_doc(mut variant: Variant, doc: &str) -> Variant {
+        if let Variant::Field { field, .. } = &mut variant {
+            field.doc = Some(doc.to_string());
+        }
+
+        variant
+    }
+
+    fn directed_variants() -> Vec<Variant> {
+        let mut variants = variants();
+        let names = variants.remove(2);
+        let stats = variants.remove(0);
+
+        vec![
+            with_doc(stats, "@gdscript optional"),
+            with_doc(names, "@gdscript length(max = 4)"),
+            with_doc(
+                Variant::Field {
+                    name: "Speed".to_string(),
+                    index: 4,
+                    field: Field {
+                        name: "Speed".to_string(),
+                        index: 4,
+                        encoding: Encoding {
+                            wire: WireFormat::Bits { count: 32 },
+                            native: NativeType::Float { bits: 32 },
+                            transforms: vec![],
+                            padding_bits: None,
+                        },
+                        doc: None,
+                    },
+                    doc: None,
+                },
+                "Speed in m/s.\n@gdscript quantize(min = 0, max = 64, bits = 10)",
+            ),
+        ]
+    }
+
+    fn element(native: NativeType) -> Box<Encoding> {
+        Box::new(Encoding {
+            wire: WireFormat::LengthPrefixed { prefix_bits: 64 },
+            native,
+            transforms: vec![],
+            padding_bits: None,
+        })
+    }
+
+    fn variants() -> Vec<Variant> {
+        vec![
+            variant(
+                "Stats",
+                0,
+                NativeType::Message {
+                    descriptor: descriptor("Stats"),
+                },
+            ),
+            variant(
+                "Mode",
+                1,
+                NativeType::Enum {
+                    descriptor: descriptor("Mode"),
+                },
+            ),
+            variant(
+                "Names",
+                2,
+                NativeType::Array {
+                    element: element(NativeType::String),
+                },
+            ),
+            variant(
+                "Scores",
+                3,
+                NativeType::Map {
+                    key: element(NativeType::String),
+                    value: element(NativeType::Message {
+                        descriptor: descriptor("Stats"),
+                    }),
+                },
+            ),
+        ]
+    }
+
+    fn emit(items: &[Item]) -> String {
+        let mut cw = GDScript::writer();
+
+        items
+            .iter()
+            .map(|item| {
+                let mut s = StringWriter::default();
+                item.emit(&mut cw, &mut s).unwrap();
+                s.into_content()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /* --------------------- Tests: gen_enum_encode_stmts ------------------- */
+
+    #[test]
+    fn test_gen_enum_encode_stmts_field_variants() {
+        // Given: An enum with message, enum, array, and map variants.
+        let variants = variants();
+
+        // When: Generating encoding statements.
+        let result = gen_enum_encode_stmts(&discriminant(), &variants, &HashSet::new());
+
+        // Then: Each variant's payload is encoded like a message field of its type.
+        let expected = r#"if _discriminant == NONE:
+	_writer.set_error(ERR_INVALID_DATA)
+	return
+_writer.write_u8(_discriminant)
+match _discriminant:
+	Stats:
+		if _value == null:
+			_writer.set_error(ERR_INVALID_DATA)
+			return
+		_value._encode(_writer)
+	Mode:
+		if _value == null:
+			_writer.set_error(ERR_INVALID_DATA)
+			return
+		_value._encode(_writer)
+	Names:
+		_writer.write_size(_value.size(), 64)
+		for _item in _value:
+			_writer.write_string(_item, 64)
+	Scores:
+		_writer.write_size(_value.size(), 64)
+		for _key in _value:
+			_writer.write_string(_key, 64)
+			var _entry: Stats = _value[_key]
+			if _entry == null:
+				_writer.set_error(ERR_INVALID_DATA)
+				return
+			_entry._encode(_writer)
+"#;
+
+        assert_eq!(emit(&result.unwrap()), expected);
+    }
+
+    #[test]
+    fn test_gen_enum_encode_stmts_applies_variant_directives() {
+        // Given: An enum with optional, length-bounded, and quantized variants.
+        let variants = directed_variants();
+
+        // When: Generating encoding statements.
+        let result = gen_enum_encode_stmts(&discriminant(), &variants, &HashSet::new());
+
+        // Then: Each variant's payload is encoded according to its directives.
+        let expected = r#"if _discriminant == NONE:
+	_writer.set_error(ERR_INVALID_DATA)
+	return
+_writer.write_u8(_discriminant)
+match _discriminant:
+	Stats:
+		_writer.write_bool(_value != null)
+		if _value != null:
+			_value._encode(_writer)
+	Names:
+		_writer.write_size(_value.size(), 3, 4)
+		for _item in _value:
+			_writer.write_string(_item, 64)
+	Speed:
+		_writer.write_quantized(_value, 0.0, 64.0, 10)
+"#;
+
+        assert_eq!(emit(&result.unwrap()), expected);
+    }
+
+    #[test]
+    fn test_gen_enum_encode_stmts_rejects_invalid_variant_directive() {
+        // Given: An enum with a variant whose doc contains an unknown directive.
+        let variants = vec![with_doc(variants().remove(0), "@gdscript unknown")];
+
+        // When: Generating encoding and decoding statements.
+        let encode = gen_enum_encode_stmts(&discriminant(), &variants, &HashSet::new());
+        let decode = gen_enum_decode_stmts(
+            &discriminant(),
+            &variants,
+            &Options::default(),
+            &HashSet::new(),
+        );
+
+        // Then: Both fail rather than ignoring the directive.
+        assert!(encode.is_err());
+        assert!(decode.is_err());
+    }
+
+    /* --------------------- Tests: gen_enum_decode_stmts ------------------- */
+
+    #[test]
+    fn test_gen_enum_decode_stmts_field_variants() {
+        // Given: An enum with message, enum, array, and map variants.
+        let variants = variants();
+
+        // When: Generating decoding statements.
+        let result = gen_enum_decode_stmts(
+            &discriminant(),
+            &variants,
+            &Options::default(),
+            &HashSet::new(),
+        );
+
+        // Then: Messages and enums are decoded into `_value`, while arrays and maps are
+        // decoded into a typed `_payload` local first.
+        let expected = r#"if _reader.enter_message() != OK:
+	return _reader.get_error()
+_discriminant = _reader.read_u8()
+if _reader.get_error() != OK:
+	return _reader.get_error()
+match _discriminant:
+	Stats:
+		_value = Stats.new()
+		_value._decode(_reader)
+		if _reader.get_error() != OK:
+			return _reader.get_error()
+	Mode:
+		_value = Mode.new()
+		_value._decode(_reader)
+		if _reader.get_error() != OK:
+			return _reader.get_error()
+	Names:
+		var _payload: PackedStringArray
+		_payload = PackedStringArray()
+		for _i in range(_reader.read_length(64)):
+			var _temp: String
+			_temp = _reader.read_string(64)
+			if _reader.get_error() != OK:
+				return _reader.get_error()
+			_payload.append(_temp)
+		_value = _payload
+	Scores:
+		var _payload: Dictionary[String, Stats]
+		_payload = {}
+		for _i in range(_reader.read_length(64)):
+			var _key := _reader.read_string(64)
+			if _reader.get_error() != OK:
+				return _reader.get_error()
+			var _entry := Stats.new()
+			_entry._decode(_reader)
+			if _reader.get_error() != OK:
+				return _reader.get_error()
+			_payload[_key] = _entry
+		_value = _payload
+	_:
+		_reader.set_error(ERR_INVALID_DATA)
+		return _reader.get_error()
+
+_reader.exit_message()
+return _reader.get_error()"#;
+
+        assert_eq!(emit(&result.unwrap()), expected);
+    }
+
+    #[test]
+    fn test_gen_enum_decode_stmts_applies_variant_directives() {
+        // Given: An enum with optional, length-bounded, and quantized variants.
+        let variants = directed_variants();
+
+        // When: Generating decoding statements.
+        let result = gen_enum_decode_stmts(
+            &discriminant(),
+            &variants,
+            &Options::default(),
+            &HashSet::new(),
+        );
+
+        // Then: Each variant's payload is decoded according to its directives.
+        let expected = r#"if _reader.enter_message() != OK:
+	return _reader.get_error()
+_discriminant = _reader.read_u8()
+if _reader.get_error() != OK:
+	return _reader.get_error()
+match _discriminant:
+	Stats:
+		if _reader.read_bool():
+			_value = Stats.new()
+			_value._decode(_reader)
+			if _reader.get_error() != OK:
+				return _reader.get_error()
+		else:
+			_value = null
+		if _reader.get_error() != OK:
+			return _reader.get_error()
+	Names:
+		var _payload: PackedStringArray
+		_payload = PackedStringArray()
+		for _i in range(_reader.read_length(3, 4)):
+			var _temp: String
+			_temp = _reader.read_string(64)
+			if _reader.get_error() != OK:
+				return _reader.get_error()
+			_payload.append(_temp)
+		_value = _payload
+	Speed:
+		_value = _reader.read_quantized(0.0, 64.0, 10)
+		if _reader.get_error() != OK:
+			return _reader.get_error()
+	_:
+		_reader.set_error(ERR_INVALID_DATA)
+		return _reader.get_error()
+
+_reader.exit_message()
+return _reader.get_error()"#;
+
+        assert_eq!(emit(&result.unwrap()), expected);
+    }
+
+    #[test]
+    fn test_gen_enum_decode_stmts_untyped_dictionary_payload() {
+        // Given: An enum with a map variant.
+        let variants = variants().split_off(3);
+
+        // Given: Options which disable typed dictionaries.
+        let options = Options {
+            typed_dictionaries: false,
+            ..Default::default()
+        };
+
+        // When: Generating decoding statements.
+        let result = gen_enum_decode_stmts(&discriminant(), &variants, &options, &HashSet::new());
+
+        // Then: The map is decoded into an untyped `_payload` local.
+        assert!(emit(&result.unwrap()).contains("\tvar _payload: Dictionary\n"));
+    }
+}
@@ -1,3 +1,8 @@
+/* ------------------------------- Mod: Delta ------------------------------- */
+
+mod delta;
+pub use delta::*;
+
 /* ------------------------------- Mod: Decode ------------------------------ */
 
 mod decode;
@@ -13,6 +18,11 @@ pub use encode::*;
 mod enumeration;
 pub use enumeration::*;
 
+/* ------------------------------- Mod: Tagged ------------------------------ */
+
+mod tagged;
+pub use tagged::*;
+
 /* -------------------------------- Mod: Wire ------------------------------- */
 
 mod wire;
@@ -27,7 +37,7 @@ mod integration_tests {
 
     use baproto::*;
 
-    use crate::gdscript::GDScript;
+    use crate::gdscript::{GDScript, Options};
 
     /* ----------------------- Tests: codec_generation ---------------------- */
 
@@ -102,7 +112,7 @@ mod integration_tests {
         };
 
         // When: Generating GDScript code.
-        let output = GDScript.generate(&schema).unwrap();
+        let output = GDScript::default().generate(&schema).unwrap();
 
         // Then: The Player message file matches expected content exactly.
         let actual = output.files.get(Path::new("test/player.gd")).unwrap();
@@ -113,7 +123,9 @@ extends RefCounted
 
 # -- DEPENDENCIES -------------------------------------------------------------------- #
 
+const _Framing := preload("res://addons/baproto/runtime/framing.gd")
 const _Reader := preload("res://addons/baproto/runtime/reader.gd")
+const _Value := preload("res://addons/baproto/runtime/value.gd")
 const _Writer := preload("res://addons/baproto/runtime/writer.gd")
 
 # -- TYPES --------------------------------------------------------------------------- #
@@ -141,6 +153,170 @@ func deserialize(data: PackedByteArray) -> Error:
 	_decode(_reader)
 	return _reader.get_error()
 
+## `write_to` writes this message to `writer`, starting at its current bit position.
+## Returns the writer's error state.
+func write_to(writer: _Writer) -> Error:
+	_encode(writer)
+	return writer.get_error()
+
+## `read_from` reads this message from `reader`, starting at its current bit position.
+## Afterwards, `reader.get_position()` is the bit position following the message.
+func read_from(reader: _Reader) -> Error:
+	_decode(reader)
+	return reader.get_error()
+
+## `serialize_delimited` writes this message to a `PackedByteArray`, prefixed with its
+## length so that it can be followed by other data.
+func serialize_delimited(out: PackedByteArray) -> Error:
+	var _writer := _Writer.new()
+	_encode(_writer)
+	_Framing.write_frame(_writer.to_bytes(), out)
+	return _writer.get_error()
+
+## `deserialize_delimited` reads this message from the length-prefixed data in `data`
+## starting at `offset`. Returns the number of bytes consumed, `_Framing.INCOMPLETE`
+## if `data` ends before the message's data does, or `_Framing.INVALID` on error.
+func deserialize_delimited(data: PackedByteArray, offset: int = 0) -> int:
+	var _frame := [null]
+	var _size := _Framing.read_frame(data, offset, _frame)
+	if _size <= 0:
+		return _size
+	if deserialize(_frame[0]) != OK:
+		return _Framing.INVALID
+	return _size
+
+## `serialize_delta` writes the fields of this message which differ from `baseline`
+## to a `PackedByteArray`. The data can only be read by `deserialize_delta` using an
+## equal baseline.
+func serialize_delta(baseline: Object, out: PackedByteArray) -> Error:
+	if baseline == null:
+		baseline = get_script().new()
+	assert(is_instance_of(baseline, get_script()), "Invalid argument; mismatched message type")
+	var _writer := _Writer.new()
+	_encode_delta(baseline, _writer)
+	out.append_array(_writer.to_bytes())
+	return _writer.get_error()
+
+## `deserialize_delta` reads this message from a `PackedByteArray` written by
+## `serialize_delta`, copying unchanged fields from `baseline`.
+func deserialize_delta(baseline: Object, data: PackedByteArray) -> Error:
+	if baseline == null:
+		baseline = get_script().new()
+	assert(is_instance_of(baseline, get_script()), "Invalid argument; mismatched message type")
+	var _reader := _Reader.new(data)
+	_decode_delta(baseline, _reader)
+	return _reader.get_error()
+
+## `equals` returns whether `other` is a message of this type with equal fields.
+func equals(other: Object) -> bool:
+	if not is_instance_of(other, get_script()):
+		return false
+	if active != other.active:
+		return false
+	if id != other.id:
+		return false
+	if score != other.score:
+		return false
+	if name != other.name:
+		return false
+	return true
+
+## `hash` returns a hash of this message's fields consistent with `equals`.
+func hash() -> int:
+	return _Value.hash_value([active, id, score, name])
+
+## `is_default` returns whether all fields are set to their default values.
+func is_default() -> bool:
+	if active != false:
+		return false
+	if id != 0:
+		return false
+	if score != 0.0:
+		return false
+	if name != "":
+		return false
+	return true
+
+## `clone` returns a deep copy of this message.
+func clone() -> RefCounted:
+	var _copy: RefCounted = get_script().new()
+	_copy.copy_from(self)
+	return _copy
+
+## `copy_from` replaces this message's fields with deep copies of `other`'s.
+func copy_from(other: Object) -> void:
+	assert(is_instance_of(other, get_script()), "Invalid argument; mismatched message type")
+	active = other.active
+	id = other.id
+	score = other.score
+	name = other.name
+
+## `merge_from` merges `other` into this message. Set scalar fields overwrite this
+## message's values, repeated fields are appended (and truncated to any maximum
+## length), map entries are inserted, and nested messages are merged recursively.
+func merge_from(other: Object) -> void:
+	assert(is_instance_of(other, get_script()), "Invalid argument; mismatched message type")
+	if other.active != false:
+		active = other.active
+	if other.id != 0:
+		id = other.id
+	if other.score != 0.0:
+		score = other.score
+	if other.name != "":
+		name = other.name
+
+## `to_dict` converts this message into a JSON-compatible `Dictionary` keyed by
+## field name.
+func to_dict() -> Dictionary:
+	var _data := {}
+	_data["active"] = _Value.to_json_value(active)
+	_data["id"] = _Value.to_json_value(id)
+	_data["score"] = _Value.to_json_value(score)
+	_data["name"] = _Value.to_json_value(name)
+	return _data
+
+## `from_dict` replaces this message's fields with those in `data`, which has the
+## format produced by `to_dict`. Missing fields are reset to their defaults and
+## unknown keys are ignored.
+func from_dict(data: Dictionary) -> Error:
+	active = false
+	id = 0
+	score = 0.0
+	name = ""
+	var _out := [null]
+	if data.has("active"):
+		var _err := _Value.from_json_value(data["active"], TYPE_BOOL, _out)
+		if _err != OK:
+			return _err
+		active = _out[0]
+	if data.has("id"):
+		var _err := _Value.from_json_value(data["id"], TYPE_INT, _out)
+		if _err != OK:
+			return _err
+		id = _out[0]
+	if data.has("score"):
+		var _err := _Value.from_json_value(data["score"], TYPE_FLOAT, _out)
+		if _err != OK:
+			return _err
+		score = _out[0]
+	if data.has("name"):
+		var _err := _Value.from_json_value(data["name"], TYPE_STRING, _out)
+		if _err != OK:
+			return _err
+		name = _out[0]
+	return OK
+
+## `to_json` converts this message into a JSON string.
+func to_json() -> String:
+	return JSON.stringify(to_dict(), "", false, true)
+
+## `from_json` replaces this message's contents with those in the JSON string `text`.
+func from_json(text: String) -> Error:
+	var _data: Variant = JSON.parse_string(text)
+	if not _data is Dictionary:
+		return ERR_PARSE_ERROR
+	return from_dict(_data)
+
 # -- PRIVATE METHODS ----------------------------------------------------------------- #
 
 ## `_encode` serializes fields to the writer.
@@ -148,10 +324,12 @@ func _encode(_writer: _Writer) -> void:
 	_writer.write_bool(active)
 	_writer.write_u32(id)
 	_writer.write_f32(score)
-	_writer.write_string(name)
+	_writer.write_string(name, 64)
 
 ## `_decode` deserializes fields from the reader.
 func _decode(_reader: _Reader) -> Error:
+	if _reader.enter_message() != OK:
+		return _reader.get_error()
 	active = _reader.read_bool()
 	if _reader.get_error() != OK:
 		return _reader.get_error()
@@ -161,11 +339,67 @@ func _decode(_reader: _Reader) -> Error:
 	score = _reader.read_f32()
 	if _reader.get_error() != OK:
 		return _reader.get_error()
-	name = _reader.read_string()
+	name = _reader.read_string(64)
+	if _reader.get_error() != OK:
+		return _reader.get_error()
+	_reader.exit_message()
+	return _reader.get_error()
+
+## `_encode_delta` serializes fields which differ from `baseline` to the writer.
+func _encode_delta(baseline: Object, _writer: _Writer) -> void:
+	var _changed: Array[bool] = [active != baseline.active, id != baseline.id, score != baseline.score, name != baseline.name]
+	for _c in _changed:
+		_writer.write_bool(_c)
+	if _changed[0]:
+		_writer.write_bool(active)
+	if _changed[1]:
+		_writer.write_u32(id)
+	if _changed[2]:
+		_writer.write_f32(score)
+	if _changed[3]:
+		_writer.write_string(name, 64)
+
+## `_decode_delta` deserializes fields which differ from `baseline` from the reader.
+func _decode_delta(baseline: Object, _reader: _Reader) -> Error:
+	if _reader.enter_message() != OK:
+		return _reader.get_error()
+	var _changed: Array[bool] = []
+	for _i in range(4):
+		_changed.append(_reader.read_bool())
 	if _reader.get_error() != OK:
 		return _reader.get_error()
+	if _changed[0]:
+		active = _reader.read_bool()
+		if _reader.get_error() != OK:
+			return _reader.get_error()
+	else:
+		active = baseline.active
+	if _changed[1]:
+		id = _reader.read_u32()
+		if _reader.get_error() != OK:
+			return _reader.get_error()
+	else:
+		id = baseline.id
+	if _changed[2]:
+		score = _reader.read_f32()
+		if _reader.get_error() != OK:
+			return _reader.get_error()
+	else:
+		score = baseline.score
+	if _changed[3]:
+		name = _reader.read_string(64)
+		if _reader.get_error() != OK:
+			return _reader.get_error()
+	else:
+		name = baseline.name
+	_reader.exit_message()
 	return _reader.get_error()
 
+# -- DEBUGGING ----------------------------------------------------------------------- #
+
+func _to_string() -> String:
+	return "Player(active=" + _Value.to_debug_string(active) + ", id=" + _Value.to_debug_string(id) + ", score=" + _Value.to_debug_string(score) + ", name=" + _Value.to_debug_string(name) + ")"
+
 "#;
 
         assert_eq!(actual, expected);
@@ -204,7 +438,7 @@ func _decode(_reader: _Reader) -> Error:
         };
 
         // When: Generating GDScript code.
-        let output = GDScript.generate(&schema).unwrap();
+        let output = GDScript::default().generate(&schema).unwrap();
 
         // Then: The Data message file matches expected content exactly.
         let actual = output.files.get(Path::new("test/data.gd")).unwrap();
@@ -215,7 +449,9 @@ extends RefCounted
 
 # -- DEPENDENCIES -------------------------------------------------------------------- #
 
+const _Framing := preload("res://addons/baproto/runtime/framing.gd")
 const _Reader := preload("res://addons/baproto/runtime/reader.gd")
+const _Value := preload("res://addons/baproto/runtime/value.gd")
 const _Writer := preload("res://addons/baproto/runtime/writer.gd")
 
 # -- TYPES --------------------------------------------------------------------------- #
@@ -240,20 +476,177 @@ func deserialize(data: PackedByteArray) -> Error:
 	_decode(_reader)
 	return _reader.get_error()
 
+## `write_to` writes this message to `writer`, starting at its current bit position.
+## Returns the writer's error state.
+func write_to(writer: _Writer) -> Error:
+	_encode(writer)
+	return writer.get_error()
+
+## `read_from` reads this message from `reader`, starting at its current bit position.
+## Afterwards, `reader.get_position()` is the bit position following the message.
+func read_from(reader: _Reader) -> Error:
+	_decode(reader)
+	return reader.get_error()
+
+## `serialize_delimited` writes this message to a `PackedByteArray`, prefixed with its
+## length so that it can be followed by other data.
+func serialize_delimited(out: PackedByteArray) -> Error:
+	var _writer := _Writer.new()
+	_encode(_writer)
+	_Framing.write_frame(_writer.to_bytes(), out)
+	return _writer.get_error()
+
+## `deserialize_delimited` reads this message from the length-prefixed data in `data`
+## starting at `offset`. Returns the number of bytes consumed, `_Framing.INCOMPLETE`
+## if `data` ends before the message's data does, or `_Framing.INVALID` on error.
+func deserialize_delimited(data: PackedByteArray, offset: int = 0) -> int:
+	var _frame := [null]
+	var _size := _Framing.read_frame(data, offset, _frame)
+	if _size <= 0:
+		return _size
+	if deserialize(_frame[0]) != OK:
+		return _Framing.INVALID
+	return _size
+
+## `serialize_delta` writes the fields of this message which differ from `baseline`
+## to a `PackedByteArray`. The data can only be read by `deserialize_delta` using an
+## equal baseline.
+func serialize_delta(baseline: Object, out: PackedByteArray) -> Error:
+	if baseline == null:
+		baseline = get_script().new()
+	assert(is_instance_of(baseline, get_script()), "Invalid argument; mismatched message type")
+	var _writer := _Writer.new()
+	_encode_delta(baseline, _writer)
+	out.append_array(_writer.to_bytes())
+	return _writer.get_error()
+
+## `deserialize_delta` reads this message from a `PackedByteArray` written by
+## `serialize_delta`, copying unchanged fields from `baseline`.
+func deserialize_delta(baseline: Object, data: PackedByteArray) -> Error:
+	if baseline == null:
+		baseline = get_script().new()
+	assert(is_instance_of(baseline, get_script()), "Invalid argument; mismatched message type")
+	var _reader := _Reader.new(data)
+	_decode_delta(baseline, _reader)
+	return _reader.get_error()
+
+## `equals` returns whether `other` is a message of this type with equal fields.
+func equals(other: Object) -> bool:
+	if not is_instance_of(other, get_script()):
+		return false
+	if payload != other.payload:
+		return false
+	return true
+
+## `hash` returns a hash of this message's fields consistent with `equals`.
+func hash() -> int:
+	return _Value.hash_value([payload])
+
+## `is_default` returns whether all fields are set to their default values.
+func is_default() -> bool:
+	if not payload.is_empty():
+		return false
+	return true
+
+## `clone` returns a deep copy of this message.
+func clone() -> RefCounted:
+	var _copy: RefCounted = get_script().new()
+	_copy.copy_from(self)
+	return _copy
+
+## `copy_from` replaces this message's fields with deep copies of `other`'s.
+func copy_from(other: Object) -> void:
+	assert(is_instance_of(other, get_script()), "Invalid argument; mismatched message type")
+	payload = _Value.copy(other.payload)
+
+## `merge_from` merges `other` into this message. Set scalar fields overwrite this
+## message's values, repeated fields are appended (and truncated to any maximum
+## length), map entries are inserted, and nested messages are merged recursively.
+func merge_from(other: Object) -> void:
+	assert(is_instance_of(other, get_script()), "Invalid argument; mismatched message type")
+	if not other.payload.is_empty():
+		payload = _Value.copy(other.payload)
+
+## `to_dict` converts this message into a JSON-compatible `Dictionary` keyed by
+## field name.
+func to_dict() -> Dictionary:
+	var _data := {}
+	_data["payload"] = _Value.to_json_value(payload)
+	return _data
+
+## `from_dict` replaces this message's fields with those in `data`, which has the
+## format produced by `to_dict`. Missing fields are reset to their defaults and
+## unknown keys are ignored.
+func from_dict(data: Dictionary) -> Error:
+	payload = PackedByteArray()
+	var _out := [null]
+	if data.has("payload"):
+		var _err := _Value.from_json_value(data["payload"], TYPE_PACKED_BYTE_ARRAY, _out)
+		if _err != OK:
+			return _err
+		payload = _out[0]
+	return OK
+
+## `to_json` converts this message into a JSON string.
+func to_json() -> String:
+	return JSON.stringify(to_dict(), "", false, true)
+
+## `from_json` replaces this message's contents with those in the JSON string `text`.
+func from_json(text: String) -> Error:
+	var _data: Variant = JSON.parse_string(text)
+	if not _data is Dictionary:
+		return ERR_PARSE_ERROR
+	return from_dict(_data)
+
 # -- PRIVATE METHODS ----------------------------------------------------------------- #
 
 ## `_encode` serializes fields to the writer.
 func _encode(_writer: _Writer) -> void:
-	_writer.write_varint_unsigned(payload.size())
+	_writer.write_size(payload.size(), 64)
 	_writer.write_bytes(payload)
 
 ## `_decode` deserializes fields from the reader.
 func _decode(_reader: _Reader) -> Error:
-	payload = _reader.read_bytes(_reader.read_varint_unsigned())
+	if _reader.enter_message() != OK:
+		return _reader.get_error()
+	payload = _reader.read_bytes(_reader.read_size(64))
+	if _reader.get_error() != OK:
+		return _reader.get_error()
+	_reader.exit_message()
+	return _reader.get_error()
+
+## `_encode_delta` serializes fields which differ from `baseline` to the writer.
+func _encode_delta(baseline: Object, _writer: _Writer) -> void:
+	var _changed: Array[bool] = [payload != baseline.payload]
+	for _c in _changed:
+		_writer.write_bool(_c)
+	if _changed[0]:
+		_writer.write_size(payload.size(), 64)
+		_writer.write_bytes(payload)
+
+## `_decode_delta` deserializes fields which differ from `baseline` from the reader.
+func _decode_delta(baseline: Object, _reader: _Reader) -> Error:
+	if _reader.enter_message() != OK:
+		return _reader.get_error()
+	var _changed: Array[bool] = []
+	for _i in range(1):
+		_changed.append(_reader.read_bool())
 	if _reader.get_error() != OK:
 		return _reader.get_error()
+	if _changed[0]:
+		payload = _reader.read_bytes(_reader.read_size(64))
+		if _reader.get_error() != OK:
+			return _reader.get_error()
+	else:
+		payload = _Value.copy(baseline.payload)
+	_reader.exit_message()
 	return _reader.get_error()
 
+# -- DEBUGGING ----------------------------------------------------------------------- #
+
+func _to_string() -> String:
+	return "Data(payload=" + _Value.to_debug_string(payload) + ")"
+
 "#;
 
         assert_eq!(actual, expected);
@@ -302,7 +695,7 @@ func _decode(_reader: _Reader) -> Error:
         };
 
         // When: Generating GDScript code.
-        let output = GDScript.generate(&schema).unwrap();
+        let output = GDScript::default().generate(&schema).unwrap();
 
         // Then: The Stats message file matches expected content exactly.
         let actual = output.files.get(Path::new("test/stats.gd")).unwrap();
@@ -313,7 +706,9 @@ extends RefCounted
 
 # -- DEPENDENCIES -------------------------------------------------------------------- #
 
+const _Framing := preload("res://addons/baproto/runtime/framing.gd")
 const _Reader := preload("res://addons/baproto/runtime/reader.gd")
+const _Value := preload("res://addons/baproto/runtime/value.gd")
 const _Writer := preload("res://addons/baproto/runtime/writer.gd")
 
 # -- TYPES --------------------------------------------------------------------------- #
@@ -321,7 +716,7 @@ const _Writer := preload("res://addons/baproto/runtime/writer.gd")
 
 # -- INITIALIZATION ------------------------------------------------------------------ #
 
-var scores: Array[int] = []
+var scores: PackedInt32Array = PackedInt32Array()
 
 # -- PUBLIC METHODS ------------------------------------------------------------------ #
 
@@ -338,24 +733,176 @@ func deserialize(data: PackedByteArray) -> Error:
 	_decode(_reader)
 	return _reader.get_error()
 
+## `write_to` writes this message to `writer`, starting at its current bit position.
+## Returns the writer's error state.
+func write_to(writer: _Writer) -> Error:
+	_encode(writer)
+	return writer.get_error()
+
+## `read_from` reads this message from `reader`, starting at its current bit position.
+## Afterwards, `reader.get_position()` is the bit position following the message.
+func read_from(reader: _Reader) -> Error:
+	_decode(reader)
+	return reader.get_error()
+
+## `serialize_delimited` writes this message to a `PackedByteArray`, prefixed with its
+## length so that it can be followed by other data.
+func serialize_delimited(out: PackedByteArray) -> Error:
+	var _writer := _Writer.new()
+	_encode(_writer)
+	_Framing.write_frame(_writer.to_bytes(), out)
+	return _writer.get_error()
+
+## `deserialize_delimited` reads this message from the length-prefixed data in `data`
+## starting at `offset`. Returns the number of bytes consumed, `_Framing.INCOMPLETE`
+## if `data` ends before the message's data does, or `_Framing.INVALID` on error.
+func deserialize_delimited(data: PackedByteArray, offset: int = 0) -> int:
+	var _frame := [null]
+	var _size := _Framing.read_frame(data, offset, _frame)
+	if _size <= 0:
+		return _size
+	if deserialize(_frame[0]) != OK:
+		return _Framing.INVALID
+	return _size
+
+## `serialize_delta` writes the fields of this message which differ from `baseline`
+## to a `PackedByteArray`. The data can only be read by `deserialize_delta` using an
+## equal baseline.
+func serialize_delta(baseline: Object, out: PackedByteArray) -> Error:
+	if baseline == null:
+		baseline = get_script().new()
+	assert(is_instance_of(baseline, get_script()), "Invalid argument; mismatched message type")
+	var _writer := _Writer.new()
+	_encode_delta(baseline, _writer)
+	out.append_array(_writer.to_bytes())
+	return _writer.get_error()
+
+## `deserialize_delta` reads this message from a `PackedByteArray` written by
+## `serialize_delta`, copying unchanged fields from `baseline`.
+func deserialize_delta(baseline: Object, data: PackedByteArray) -> Error:
+	if baseline == null:
+		baseline = get_script().new()
+	assert(is_instance_of(baseline, get_script()), "Invalid argument; mismatched message type")
+	var _reader := _Reader.new(data)
+	_decode_delta(baseline, _reader)
+	return _reader.get_error()
+
+## `equals` returns whether `other` is a message of this type with equal fields.
+func equals(other: Object) -> bool:
+	if not is_instance_of(other, get_script()):
+		return false
+	if scores != other.scores:
+		return false
+	return true
+
+## `hash` returns a hash of this message's fields consistent with `equals`.
+func hash() -> int:
+	return _Value.hash_value([scores])
+
+## `is_default` returns whether all fields are set to their default values.
+func is_default() -> bool:
+	if not scores.is_empty():
+		return false
+	return true
+
+## `clone` returns a deep copy of this message.
+func clone() -> RefCounted:
+	var _copy: RefCounted = get_script().new()
+	_copy.copy_from(self)
+	return _copy
+
+## `copy_from` replaces this message's fields with deep copies of `other`'s.
+func copy_from(other: Object) -> void:
+	assert(is_instance_of(other, get_script()), "Invalid argument; mismatched message type")
+	scores = _Value.copy(other.scores)
+
+## `merge_from` merges `other` into this message. Set scalar fields overwrite this
+## message's values, repeated fields are appended (and truncated to any maximum
+## length), map entries are inserted, and nested messages are merged recursively.
+func merge_from(other: Object) -> void:
+	assert(is_instance_of(other, get_script()), "Invalid argument; mismatched message type")
+	scores.append_array(_Value.copy(other.scores))
+
+## `to_dict` converts this message into a JSON-compatible `Dictionary` keyed by
+## field name.
+func to_dict() -> Dictionary:
+	var _data := {}
+	_data["scores"] = _Value.to_json_value(scores)
+	return _data
+
+## `from_dict` replaces this message's fields with those in `data`, which has the
+## format produced by `to_dict`. Missing fields are reset to their defaults and
+## unknown keys are ignored.
+func from_dict(data: Dictionary) -> Error:
+	scores = PackedInt32Array()
+	var _out := [null]
+	if data.has("scores"):
+		var _err := _Value.from_json_value(data["scores"], TYPE_PACKED_INT32_ARRAY, _out)
+		if _err != OK:
+			return _err
+		scores = _out[0]
+	return OK
+
+## `to_json` converts this message into a JSON string.
+func to_json() -> String:
+	return JSON.stringify(to_dict(), "", false, true)
+
+## `from_json` replaces this message's contents with those in the JSON string `text`.
+func from_json(text: String) -> Error:
+	var _data: Variant = JSON.parse_string(text)
+	if not _data is Dictionary:
+		return ERR_PARSE_ERROR
+	return from_dict(_data)
+
 # -- PRIVATE METHODS ----------------------------------------------------------------- #
 
 ## `_encode` serializes fields to the writer.
 func _encode(_writer: _Writer) -> void:
-	_writer.write_varint_unsigned(scores.size())
-	for _item in scores:
-		_writer.write_i32(_item)
+	_writer.write_size(scores.size(), 64)
+	_writer.write_int32_array(scores, 4)
 
 ## `_decode` deserializes fields from the reader.
 func _decode(_reader: _Reader) -> Error:
-	scores = []
-	for _i in range(_reader.read_varint_unsigned()):
-		_temp = _reader.read_i32()
+	if _reader.enter_message() != OK:
+		return _reader.get_error()
+	scores = _reader.read_int32_array(_reader.read_length(64), 4, true)
+	if _reader.get_error() != OK:
+		return _reader.get_error()
+	_reader.exit_message()
+	return _reader.get_error()
+
+## `_encode_delta` serializes fields which differ from `baseline` to the writer.
+func _encode_delta(baseline: Object, _writer: _Writer) -> void:
+	var _changed: Array[bool] = [scores != baseline.scores]
+	for _c in _changed:
+		_writer.write_bool(_c)
+	if _changed[0]:
+		_writer.write_size(scores.size(), 64)
+		_writer.write_int32_array(scores, 4)
+
+## `_decode_delta` deserializes fields which differ from `baseline` from the reader.
+func _decode_delta(baseline: Object, _reader: _Reader) -> Error:
+	if _reader.enter_message() != OK:
+		return _reader.get_error()
+	var _changed: Array[bool] = []
+	for _i in range(1):
+		_changed.append(_reader.read_bool())
+	if _reader.get_error() != OK:
+		return _reader.get_error()
+	if _changed[0]:
+		scores = _reader.read_int32_array(_reader.read_length(64), 4, true)
 		if _reader.get_error() != OK:
 			return _reader.get_error()
-		scores.append(_temp)
+	else:
+		scores = _Value.copy(baseline.scores)
+	_reader.exit_message()
 	return _reader.get_error()
 
+# -- DEBUGGING ----------------------------------------------------------------------- #
+
+func _to_string() -> String:
+	return "Stats(scores=" + _Value.to_debug_string(scores) + ")"
+
 "#;
 
         assert_eq!(actual, expected);
@@ -432,7 +979,7 @@ func _decode(_reader: _Reader) -> Error:
         };
 
         // When: Generating GDScript code.
-        let output = GDScript.generate(&schema).unwrap();
+        let output = GDScript::default().generate(&schema).unwrap();
 
         // Then: The Container message file matches expected content exactly.
         let actual = output.files.get(Path::new("test/container.gd")).unwrap();
@@ -443,7 +990,9 @@ extends RefCounted
 
 # -- DEPENDENCIES -------------------------------------------------------------------- #
 
+const _Framing := preload("res://addons/baproto/runtime/framing.gd")
 const _Reader := preload("res://addons/baproto/runtime/reader.gd")
+const _Value := preload("res://addons/baproto/runtime/value.gd")
 const _Writer := preload("res://addons/baproto/runtime/writer.gd")
 const Item := preload("./item.gd")
 
@@ -469,11 +1018,132 @@ func deserialize(data: PackedByteArray) -> Error:
 	_decode(_reader)
 	return _reader.get_error()
 
+## `write_to` writes this message to `writer`, starting at its current bit position.
+## Returns the writer's error state.
+func write_to(writer: _Writer) -> Error:
+	_encode(writer)
+	return writer.get_error()
+
+## `read_from` reads this message from `reader`, starting at its current bit position.
+## Afterwards, `reader.get_position()` is the bit position following the message.
+func read_from(reader: _Reader) -> Error:
+	_decode(reader)
+	return reader.get_error()
+
+## `serialize_delimited` writes this message to a `PackedByteArray`, prefixed with its
+## length so that it can be followed by other data.
+func serialize_delimited(out: PackedByteArray) -> Error:
+	var _writer := _Writer.new()
+	_encode(_writer)
+	_Framing.write_frame(_writer.to_bytes(), out)
+	return _writer.get_error()
+
+## `deserialize_delimited` reads this message from the length-prefixed data in `data`
+## starting at `offset`. Returns the number of bytes consumed, `_Framing.INCOMPLETE`
+## if `data` ends before the message's data does, or `_Framing.INVALID` on error.
+func deserialize_delimited(data: PackedByteArray, offset: int = 0) -> int:
+	var _frame := [null]
+	var _size := _Framing.read_frame(data, offset, _frame)
+	if _size <= 0:
+		return _size
+	if deserialize(_frame[0]) != OK:
+		return _Framing.INVALID
+	return _size
+
+## `serialize_delta` writes the fields of this message which differ from `baseline`
+## to a `PackedByteArray`. The data can only be read by `deserialize_delta` using an
+## equal baseline.
+func serialize_delta(baseline: Object, out: PackedByteArray) -> Error:
+	if baseline == null:
+		baseline = get_script().new()
+	assert(is_instance_of(baseline, get_script()), "Invalid argument; mismatched message type")
+	var _writer := _Writer.new()
+	_encode_delta(baseline, _writer)
+	out.append_array(_writer.to_bytes())
+	return _writer.get_error()
+
+## `deserialize_delta` reads this message from a `PackedByteArray` written by
+## `serialize_delta`, copying unchanged fields from `baseline`.
+func deserialize_delta(baseline: Object, data: PackedByteArray) -> Error:
+	if baseline == null:
+		baseline = get_script().new()
+	assert(is_instance_of(baseline, get_script()), "Invalid argument; mismatched message type")
+	var _reader := _Reader.new(data)
+	_decode_delta(baseline, _reader)
+	return _reader.get_error()
+
+## `equals` returns whether `other` is a message of this type with equal fields.
+func equals(other: Object) -> bool:
+	if not is_instance_of(other, get_script()):
+		return false
+	if not _Value.equals(items, other.items):
+		return false
+	return true
+
+## `hash` returns a hash of this message's fields consistent with `equals`.
+func hash() -> int:
+	return _Value.hash_value([items])
+
+## `is_default` returns whether all fields are set to their default values.
+func is_default() -> bool:
+	if not items.is_empty():
+		return false
+	return true
+
+## `clone` returns a deep copy of this message.
+func clone() -> RefCounted:
+	var _copy: RefCounted = get_script().new()
+	_copy.copy_from(self)
+	return _copy
+
+## `copy_from` replaces this message's fields with deep copies of `other`'s.
+func copy_from(other: Object) -> void:
+	assert(is_instance_of(other, get_script()), "Invalid argument; mismatched message type")
+	items = _Value.copy(other.items)
+
+## `merge_from` merges `other` into this message. Set scalar fields overwrite this
+## message's values, repeated fields are appended (and truncated to any maximum
+## length), map entries are inserted, and nested messages are merged recursively.
+func merge_from(other: Object) -> void:
+	assert(is_instance_of(other, get_script()), "Invalid argument; mismatched message type")
+	items.append_array(_Value.copy(other.items))
+
+## `to_dict` converts this message into a JSON-compatible `Dictionary` keyed by
+## field name.
+func to_dict() -> Dictionary:
+	var _data := {}
+	_data["items"] = _Value.to_json_value(items)
+	return _data
+
+## `from_dict` replaces this message's fields with those in `data`, which has the
+## format produced by `to_dict`. Missing fields are reset to their defaults and
+## unknown keys are ignored.
+func from_dict(data: Dictionary) -> Error:
+	items = []
+	var _out := [null]
+	if data.has("items"):
+		var _err := _Value.from_json_value(data["items"], [TYPE_ARRAY, Item], _out)
+		if _err != OK:
+			return _err
+		items.assign(_out[0])
+	return OK
+
+## `to_json` converts this message into a JSON string.
+func to_json() -> String:
+	return JSON.stringify(to_dict(), "", false, true)
+
+## `from_json` replaces this message's contents with those in the JSON string `text`.
+func from_json(text: String) -> Error:
+	var _data: Variant = JSON.parse_string(text)
+	if not _data is Dictionary:
+		return ERR_PARSE_ERROR
+	return from_dict(_data)
+
 # -- PRIVATE METHODS ----------------------------------------------------------------- #
 
 ## `_encode` serializes fields to the writer.
 func _encode(_writer: _Writer) -> void:
-	_writer.write_varint_unsigned(items.size())
+	_writer.write_size(items.size(), 64)
 	for _item in items:
 		if _item == null:
 			_writer.set_error(ERR_INVALID_DATA)
@@ -482,15 +1152,58 @@ func _encode(_writer: _Writer) -> void:
 
 ## `_decode` deserializes fields from the reader.
 func _decode(_reader: _Reader) -> Error:
+	if _reader.enter_message() != OK:
+		return _reader.get_error()
 	items = []
-	for _i in range(_reader.read_varint_unsigned()):
+	for _i in range(_reader.read_length(64)):
 		var _item := Item.new()
 		_item._decode(_reader)
 		if _reader.get_error() != OK:
 			return _reader.get_error()
 		items.append(_item)
+	_reader.exit_message()
+	return _reader.get_error()
+
+## `_encode_delta` serializes fields which differ from `baseline` to the writer.
+func _encode_delta(baseline: Object, _writer: _Writer) -> void:
+	var _changed: Array[bool] = [not _Value.equals(items, baseline.items)]
+	for _c in _changed:
+		_writer.write_bool(_c)
+	if _changed[0]:
+		_writer.write_size(items.size(), 64)
+		for _item in items:
+			if _item == null:
+				_writer.set_error(ERR_INVALID_DATA)
+				return
+			_item._encode(_writer)
+
+## `_decode_delta` deserializes fields which differ from `baseline` from the reader.
+func _decode_delta(baseline: Object, _reader: _Reader) -> Error:
+	if _reader.enter_message() != OK:
+		return _reader.get_error()
+	var _changed: Array[bool] = []
+	for _i in range(1):
+		_changed.append(_reader.read_bool())
+	if _reader.get_error() != OK:
+		return _reader.get_error()
+	if _changed[0]:
+		items = []
+		for _i in range(_reader.read_length(64)):
+			var _item := Item.new()
+			_item._decode(_reader)
+			if _reader.get_error() != OK:
+				return _reader.get_error()
+			items.append(_item)
+	else:
+		items = _Value.copy(baseline.items)
+	_reader.exit_message()
 	return _reader.get_error()
 
+# -- DEBUGGING ----------------------------------------------------------------------- #
+
+func _to_string() -> String:
+	return "Container(items=" + _Value.to_debug_string(items) + ")"
+
 "#;
 
         assert_eq!(actual, expected);
@@ -545,7 +1258,7 @@ func _decode(_reader: _Reader) -> Error:
         };
 
         // When: Generating GDScript code.
-        let output = GDScript.generate(&schema).unwrap();
+        let output = GDScript::default().generate(&schema).unwrap();
 
         // Then: The Config message file matches expected content exactly.
         let actual = output.files.get(Path::new("test/config.gd")).unwrap();
@@ -556,7 +1269,9 @@ extends RefCounted
 
 # -- DEPENDENCIES -------------------------------------------------------------------- #
 
+const _Framing := preload("res://addons/baproto/runtime/framing.gd")
 const _Reader := preload("res://addons/baproto/runtime/reader.gd")
+const _Value := preload("res://addons/baproto/runtime/value.gd")
 const _Writer := preload("res://addons/baproto/runtime/writer.gd")
 
 # -- TYPES --------------------------------------------------------------------------- #
@@ -564,7 +1279,7 @@ const _Writer := preload("res://addons/baproto/runtime/writer.gd")
 
 # -- INITIALIZATION ------------------------------------------------------------------ #
 
-var settings: Dictionary = {}
+var settings: Dictionary[String, int] = {}
 
 # -- PUBLIC METHODS ------------------------------------------------------------------ #
 
@@ -581,27 +1296,190 @@ func deserialize(data: PackedByteArray) -> Error:
 	_decode(_reader)
 	return _reader.get_error()
 
+## `write_to` writes this message to `writer`, starting at its current bit position.
+## Returns the writer's error state.
+func write_to(writer: _Writer) -> Error:
+	_encode(writer)
+	return writer.get_error()
+
+## `read_from` reads this message from `reader`, starting at its current bit position.
+## Afterwards, `reader.get_position()` is the bit position following the message.
+func read_from(reader: _Reader) -> Error:
+	_decode(reader)
+	return reader.get_error()
+
+## `serialize_delimited` writes this message to a `PackedByteArray`, prefixed with its
+## length so that it can be followed by other data.
+func serialize_delimited(out: PackedByteArray) -> Error:
+	var _writer := _Writer.new()
+	_encode(_writer)
+	_Framing.write_frame(_writer.to_bytes(), out)
+	return _writer.get_error()
+
+## `deserialize_delimited` reads this message from the length-prefixed data in `data`
+## starting at `offset`. Returns the number of bytes consumed, `_Framing.INCOMPLETE`
+## if `data` ends before the message's data does, or `_Framing.INVALID` on error.
+func deserialize_delimited(data: PackedByteArray, offset: int = 0) -> int:
+	var _frame := [null]
+	var _size := _Framing.read_frame(data, offset, _frame)
+	if _size <= 0:
+		return _size
+	if deserialize(_frame[0]) != OK:
+		return _Framing.INVALID
+	return _size
+
+## `serialize_delta` writes the fields of this message which differ from `baseline`
+## to a `PackedByteArray`. The data can only be read by `deserialize_delta` using an
+## equal baseline.
+func serialize_delta(baseline: Object, out: PackedByteArray) -> Error:
+	if baseline == null:
+		baseline = get_script().new()
+	assert(is_instance_of(baseline, get_script()), "Invalid argument; mismatched message type")
+	var _writer := _Writer.new()
+	_encode_delta(baseline, _writer)
+	out.append_array(_writer.to_bytes())
+	return _writer.get_error()
+
+## `deserialize_delta` reads this message from a `PackedByteArray` written by
+## `serialize_delta`, copying unchanged fields from `baseline`.
+func deserialize_delta(baseline: Object, data: PackedByteArray) -> Error:
+	if baseline == null:
+		baseline = get_script().new()
+	assert(is_instance_of(baseline, get_script()), "Invalid argument; mismatched message type")
+	var _reader := _Reader.new(data)
+	_decode_delta(baseline, _reader)
+	return _reader.get_error()
+
+## `equals` returns whether `other` is a message of this type with equal fields.
+func equals(other: Object) -> bool:
+	if not is_instance_of(other, get_script()):
+		return false
+	if not _Value.equals(settings, other.settings):
+		return false
+	return true
+
+## `hash` returns a hash of this message's fields consistent with `equals`.
+func hash() -> int:
+	return _Value.hash_value([settings])
+
+## `is_default` returns whether all fields are set to their default values.
+func is_default() -> bool:
+	if not settings.is_empty():
+		return false
+	return true
+
+## `clone` returns a deep copy of this message.
+func clone() -> RefCounted:
+	var _copy: RefCounted = get_script().new()
+	_copy.copy_from(self)
+	return _copy
+
+## `copy_from` replaces this message's fields with deep copies of `other`'s.
+func copy_from(other: Object) -> void:
+	assert(is_instance_of(other, get_script()), "Invalid argument; mismatched message type")
+	settings = _Value.copy(other.settings)
+
+## `merge_from` merges `other` into this message. Set scalar fields overwrite this
+## message's values, repeated fields are appended (and truncated to any maximum
+## length), map entries are inserted, and nested messages are merged recursively.
+func merge_from(other: Object) -> void:
+	assert(is_instance_of(other, get_script()), "Invalid argument; mismatched message type")
+	settings.merge(_Value.copy(other.settings), true)
+
+## `to_dict` converts this message into a JSON-compatible `Dictionary` keyed by
+## field name.
+func to_dict() -> Dictionary:
+	var _data := {}
+	_data["settings"] = _Value.to_json_value(settings)
+	return _data
+
+## `from_dict` replaces this message's fields with those in `data`, which has the
+## format produced by `to_dict`. Missing fields are reset to their defaults and
+## unknown keys are ignored.
+func from_dict(data: Dictionary) -> Error:
+	settings = {}
+	var _out := [null]
+	if data.has("settings"):
+		var _err := _Value.from_json_value(data["settings"], [TYPE_DICTIONARY, TYPE_STRING, TYPE_INT], _out)
+		if _err != OK:
+			return _err
+		settings.merge(_out[0])
+	return OK
+
+## `to_json` converts this message into a JSON string.
+func to_json() -> String:
+	return JSON.stringify(to_dict(), "", false, true)
+
+## `from_json` replaces this message's contents with those in the JSON string `text`.
+func from_json(text: String) -> Error:
+	var _data: Variant = JSON.parse_string(text)
+	if not _data is Dictionary:
+		return ERR_PARSE_ERROR
+	return from_dict(_data)
+
 # -- PRIVATE METHODS ----------------------------------------------------------------- #
 
 ## `_encode` serializes fields to the writer.
 func _encode(_writer: _Writer) -> void:
-	_writer.write_varint_unsigned(settings.size())
+	_writer.write_size(settings.size(), 64)
 	for _key in settings:
-		_writer.write_string(_key)
+		_writer.write_string(_key, 64)
 		_writer.write_i32(settings[_key])
 
 ## `_decode` deserializes fields from the reader.
 func _decode(_reader: _Reader) -> Error:
+	if _reader.enter_message() != OK:
+		return _reader.get_error()
 	settings = {}
-	for _i in range(_reader.read_varint_unsigned()):
-		var _key := _reader.read_string()
+	for _i in range(_reader.read_length(64)):
+		var _key := _reader.read_string(64)
 		if _reader.get_error() != OK:
 			return _reader.get_error()
 		settings[_key] = _reader.read_i32()
 		if _reader.get_error() != OK:
 			return _reader.get_error()
+	_reader.exit_message()
+	return _reader.get_error()
+
+## `_encode_delta` serializes fields which differ from `baseline` to the writer.
+func _encode_delta(baseline: Object, _writer: _Writer) -> void:
+	var _changed: Array[bool] = [not _Value.equals(settings, baseline.settings)]
+	for _c in _changed:
+		_writer.write_bool(_c)
+	if _changed[0]:
+		_writer.write_size(settings.size(), 64)
+		for _key in settings:
+			_writer.write_string(_key, 64)
+			_writer.write_i32(settings[_key])
+
+## `_decode_delta` deserializes fields which differ from `baseline` from the reader.
+func _decode_delta(baseline: Object, _reader: _Reader) -> Error:
+	if _reader.enter_message() != OK:
+		return _reader.get_error()
+	var _changed: Array[bool] = []
+	for _i in range(1):
+		_changed.append(_reader.read_bool())
+	if _reader.get_error() != OK:
+		return _reader.get_error()
+	if _changed[0]:
+		settings = {}
+		for _i in range(_reader.read_length(64)):
+			var _key := _reader.read_string(64)
+			if _reader.get_error() != OK:
+				return _reader.get_error()
+			settings[_key] = _reader.read_i32()
+			if _reader.get_error() != OK:
+				return _reader.get_error()
+	else:
+		settings = _Value.copy(baseline.settings)
+	_reader.exit_message()
 	return _reader.get_error()
 
+# -- DEBUGGING ----------------------------------------------------------------------- #
+
+func _to_string() -> String:
+	return "Config(settings=" + _Value.to_debug_string(settings) + ")"
+
 "#;
 
         assert_eq!(actual, expected);
@@ -684,7 +1562,7 @@ func _decode(_reader: _Reader) -> Error:
         };
 
         // When: Generating GDScript code.
-        let output = GDScript.generate(&schema).unwrap();
+        let output = GDScript::default().generate(&schema).unwrap();
 
         // Then: The Game message file matches expected content exactly.
         let actual = output.files.get(Path::new("test/game.gd")).unwrap();
@@ -695,7 +1573,9 @@ extends RefCounted
 
 # -- DEPENDENCIES -------------------------------------------------------------------- #
 
+const _Framing := preload("res://addons/baproto/runtime/framing.gd")
 const _Reader := preload("res://addons/baproto/runtime/reader.gd")
+const _Value := preload("res://addons/baproto/runtime/value.gd")
 const _Writer := preload("res://addons/baproto/runtime/writer.gd")
 const Player := preload("./player.gd")
 
@@ -704,7 +1584,7 @@ const Player := preload("./player.gd")
 
 # -- INITIALIZATION ------------------------------------------------------------------ #
 
-var players: Dictionary = {}
+var players: Dictionary[String, Player] = {}
 
 # -- PUBLIC METHODS ------------------------------------------------------------------ #
 
@@ -721,33 +1601,202 @@ func deserialize(data: PackedByteArray) -> Error:
 	_decode(_reader)
 	return _reader.get_error()
 
+## `write_to` writes this message to `writer`, starting at its current bit position.
+## Returns the writer's error state.
+func write_to(writer: _Writer) -> Error:
+	_encode(writer)
+	return writer.get_error()
+
+## `read_from` reads this message from `reader`, starting at its current bit position.
+## Afterwards, `reader.get_position()` is the bit position following the message.
+func read_from(reader: _Reader) -> Error:
+	_decode(reader)
+	return reader.get_error()
+
+## `serialize_delimited` writes this message to a `PackedByteArray`, prefixed with its
+## length so that it can be followed by other data.
+func serialize_delimited(out: PackedByteArray) -> Error:
+	var _writer := _Writer.new()
+	_encode(_writer)
+	_Framing.write_frame(_writer.to_bytes(), out)
+	return _writer.get_error()
+
+## `deserialize_delimited` reads this message from the length-prefixed data in `data`
+## starting at `offset`. Returns the number of bytes consumed, `_Framing.INCOMPLETE`
+## if `data` ends before the message's data does, or `_Framing.INVALID` on error.
+func deserialize_delimited(data: PackedByteArray, offset: int = 0) -> int:
+	var _frame := [null]
+	var _size := _Framing.read_frame(data, offset, _frame)
+	if _size <= 0:
+		return _size
+	if deserialize(_frame[0]) != OK:
+		return _Framing.INVALID
+	return _size
+
+## `serialize_delta` writes the fields of this message which differ from `baseline`
+## to a `PackedByteArray`. The data can only be read by `deserialize_delta` using an
+## equal baseline.
+func serialize_delta(baseline: Object, out: PackedByteArray) -> Error:
+	if baseline == null:
+		baseline = get_script().new()
+	assert(is_instance_of(baseline, get_script()), "Invalid argument; mismatched message type")
+	var _writer := _Writer.new()
+	_encode_delta(baseline, _writer)
+	out.append_array(_writer.to_bytes())
+	return _writer.get_error()
+
+## `deserialize_delta` reads this message from a `PackedByteArray` written by
+## `serialize_delta`, copying unchanged fields from `baseline`.
+func deserialize_delta(baseline: Object, data: PackedByteArray) -> Error:
+	if baseline == null:
+		baseline = get_script().new()
+	assert(is_instance_of(baseline, get_script()), "Invalid argument; mismatched message type")
+	var _reader := _Reader.new(data)
+	_decode_delta(baseline, _reader)
+	return _reader.get_error()
+
+## `equals` returns whether `other` is a message of this type with equal fields.
+func equals(other: Object) -> bool:
+	if not is_instance_of(other, get_script()):
+		return false
+	if not _Value.equals(players, other.players):
+		return false
+	return true
+
+## `hash` returns a hash of this message's fields consistent with `equals`.
+func hash() -> int:
+	return _Value.hash_value([players])
+
+## `is_default` returns whether all fields are set to their default values.
+func is_default() -> bool:
+	if not players.is_empty():
+		return false
+	return true
+
+## `clone` returns a deep copy of this message.
+func clone() -> RefCounted:
+	var _copy: RefCounted = get_script().new()
+	_copy.copy_from(self)
+	return _copy
+
+## `copy_from` replaces this message's fields with deep copies of `other`'s.
+func copy_from(other: Object) -> void:
+	assert(is_instance_of(other, get_script()), "Invalid argument; mismatched message type")
+	players = _Value.copy(other.players)
+
+## `merge_from` merges `other` into this message. Set scalar fields overwrite this
+## message's values, repeated fields are appended (and truncated to any maximum
+## length), map entries are inserted, and nested messages are merged recursively.
+func merge_from(other: Object) -> void:
+	assert(is_instance_of(other, get_script()), "Invalid argument; mismatched message type")
+	players.merge(_Value.copy(other.players), true)
+
+## `to_dict` converts this message into a JSON-compatible `Dictionary` keyed by
+## field name.
+func to_dict() -> Dictionary:
+	var _data := {}
+	_data["players"] = _Value.to_json_value(players)
+	return _data
+
+## `from_dict` replaces this message's fields with those in `data`, which has the
+## format produced by `to_dict`. Missing fields are reset to their defaults and
+## unknown keys are ignored.
+func from_dict(data: Dictionary) -> Error:
+	players = {}
+	var _out := [null]
+	if data.has("players"):
+		var _err := _Value.from_json_value(data["players"], [TYPE_DICTIONARY, TYPE_STRING, Player], _out)
+		if _err != OK:
+			return _err
+		players.merge(_out[0])
+	return OK
+
+## `to_json` converts this message into a JSON string.
+func to_json() -> String:
+	return JSON.stringify(to_dict(), "", false, true)
+
+## `from_json` replaces this message's contents with those in the JSON string `text`.
+func from_json(text: String) -> Error:
+	var _data: Variant = JSON.parse_string(text)
+	if not _data is Dictionary:
+		return ERR_PARSE_ERROR
+	return from_dict(_data)
+
 # -- PRIVATE METHODS ----------------------------------------------------------------- #
 
 ## `_encode` serializes fields to the writer.
 func _encode(_writer: _Writer) -> void:
-	_writer.write_varint_unsigned(players.size())
+	_writer.write_size(players.size(), 64)
 	for _key in players:
-		_writer.write_string(_key)
-		var _value := players[_key]
-		if _value == null:
+		_writer.write_string(_key, 64)
+		var _entry: Player = players[_key]
+		if _entry == null:
 			_writer.set_error(ERR_INVALID_DATA)
 			return
-		_value._encode(_writer)
+		_entry._encode(_writer)
 
 ## `_decode` deserializes fields from the reader.
 func _decode(_reader: _Reader) -> Error:
+	if _reader.enter_message() != OK:
+		return _reader.get_error()
 	players = {}
-	for _i in range(_reader.read_varint_unsigned()):
-		var _key := _reader.read_string()
+	for _i in range(_reader.read_length(64)):
+		var _key := _reader.read_string(64)
 		if _reader.get_error() != OK:
 			return _reader.get_error()
-		var _value := Player.new()
-		_value._decode(_reader)
+		var _entry := Player.new()
+		_entry._decode(_reader)
 		if _reader.get_error() != OK:
 			return _reader.get_error()
-		players[_key] = _value
+		players[_key] = _entry
+	_reader.exit_message()
+	return _reader.get_error()
+
+## `_encode_delta` serializes fields which differ from `baseline` to the writer.
+func _encode_delta(baseline: Object, _writer: _Writer) -> void:
+	var _changed: Array[bool] = [not _Value.equals(players, baseline.players)]
+	for _c in _changed:
+		_writer.write_bool(_c)
+	if _changed[0]:
+		_writer.write_size(players.size(), 64)
+		for _key in players:
+			_writer.write_string(_key, 64)
+			var _entry: Player = players[_key]
+			if _entry == null:
+				_writer.set_error(ERR_INVALID_DATA)
+				return
+			_entry._encode(_writer)
+
+## `_decode_delta` deserializes fields which differ from `baseline` from the reader.
+func _decode_delta(baseline: Object, _reader: _Reader) -> Error:
+	if _reader.enter_message() != OK:
+		return _reader.get_error()
+	var _changed: Array[bool] = []
+	for _i in range(1):
+		_changed.append(_reader.read_bool())
+	if _reader.get_error() != OK:
+		return _reader.get_error()
+	if _changed[0]:
+		players = {}
+		for _i in range(_reader.read_length(64)):
+			var _key := _reader.read_string(64)
+			if _reader.get_error() != OK:
+				return _reader.get_error()
+			var _entry := Player.new()
+			_entry._decode(_reader)
+			if _reader.get_error() != OK:
+				return _reader.get_error()
+			players[_key] = _entry
+	else:
+		players = _Value.copy(baseline.players)
+	_reader.exit_message()
 	return _reader.get_error()
 
+# -- DEBUGGING ----------------------------------------------------------------------- #
+
+func _to_string() -> String:
+	return "Game(players=" + _Value.to_debug_string(players) + ")"
+
 "#;
 
         assert_eq!(actual, expected);
@@ -817,7 +1866,7 @@ func _decode(_reader: _Reader) -> Error:
         };
 
         // When: Generating GDScript code.
-        let output = GDScript.generate(&schema).unwrap();
+        let output = GDScript::default().generate(&schema).unwrap();
 
         // Then: The Player message file matches expected content exactly.
         let actual = output.files.get(Path::new("test/player.gd")).unwrap();
@@ -828,7 +1877,9 @@ extends RefCounted
 
 # -- DEPENDENCIES -------------------------------------------------------------------- #
 
+const _Framing := preload("res://addons/baproto/runtime/framing.gd")
 const _Reader := preload("res://addons/baproto/runtime/reader.gd")
+const _Value := preload("res://addons/baproto/runtime/value.gd")
 const _Writer := preload("res://addons/baproto/runtime/writer.gd")
 const Stats := preload("./stats.gd")
 
@@ -854,6 +1905,131 @@ func deserialize(data: PackedByteArray) -> Error:
 	_decode(_reader)
 	return _reader.get_error()
 
+## `write_to` writes this message to `writer`, starting at its current bit position.
+## Returns the writer's error state.
+func write_to(writer: _Writer) -> Error:
+	_encode(writer)
+	return writer.get_error()
+
+## `read_from` reads this message from `reader`, starting at its current bit position.
+## Afterwards, `reader.get_position()` is the bit position following the message.
+func read_from(reader: _Reader) -> Error:
+	_decode(reader)
+	return reader.get_error()
+
+## `serialize_delimited` writes this message to a `PackedByteArray`, prefixed with its
+## length so that it can be followed by other data.
+func serialize_delimited(out: PackedByteArray) -> Error:
+	var _writer := _Writer.new()
+	_encode(_writer)
+	_Framing.write_frame(_writer.to_bytes(), out)
+	return _writer.get_error()
+
+## `deserialize_delimited` reads this message from the length-prefixed data in `data`
+## starting at `offset`. Returns the number of bytes consumed, `_Framing.INCOMPLETE`
+## if `data` ends before the message's data does, or `_Framing.INVALID` on error.
+func deserialize_delimited(data: PackedByteArray, offset: int = 0) -> int:
+	var _frame := [null]
+	var _size := _Framing.read_frame(data, offset, _frame)
+	if _size <= 0:
+		return _size
+	if deserialize(_frame[0]) != OK:
+		return _Framing.INVALID
+	return _size
+
+## `serialize_delta` writes the fields of this message which differ from `baseline`
+## to a `PackedByteArray`. The data can only be read by `deserialize_delta` using an
+## equal baseline.
+func serialize_delta(baseline: Object, out: PackedByteArray) -> Error:
+	if baseline == null:
+		baseline = get_script().new()
+	assert(is_instance_of(baseline, get_script()), "Invalid argument; mismatched message type")
+	var _writer := _Writer.new()
+	_encode_delta(baseline, _writer)
+	out.append_array(_writer.to_bytes())
+	return _writer.get_error()
+
+## `deserialize_delta` reads this message from a `PackedByteArray` written by
+## `serialize_delta`, copying unchanged fields from `baseline`.
+func deserialize_delta(baseline: Object, data: PackedByteArray) -> Error:
+	if baseline == null:
+		baseline = get_script().new()
+	assert(is_instance_of(baseline, get_script()), "Invalid argument; mismatched message type")
+	var _reader := _Reader.new(data)
+	_decode_delta(baseline, _reader)
+	return _reader.get_error()
+
+## `equals` returns whether `other` is a message of this type with equal fields.
+func equals(other: Object) -> bool:
+	if not is_instance_of(other, get_script()):
+		return false
+	if not _Value.equals(stats, other.stats):
+		return false
+	return true
+
+## `hash` returns a hash of this message's fields consistent with `equals`.
+func hash() -> int:
+	return _Value.hash_value([stats])
+
+## `is_default` returns whether all fields are set to their default values.
+func is_default() -> bool:
+	if stats != null:
+		return false
+	return true
+
+## `clone` returns a deep copy of this message.
+func clone() -> RefCounted:
+	var _copy: RefCounted = get_script().new()
+	_copy.copy_from(self)
+	return _copy
+
+## `copy_from` replaces this message's fields with deep copies of `other`'s.
+func copy_from(other: Object) -> void:
+	assert(is_instance_of(other, get_script()), "Invalid argument; mismatched message type")
+	stats = _Value.copy(other.stats)
+
+## `merge_from` merges `other` into this message. Set scalar fields overwrite this
+## message's values, repeated fields are appended (and truncated to any maximum
+## length), map entries are inserted, and nested messages are merged recursively.
+func merge_from(other: Object) -> void:
+	assert(is_instance_of(other, get_script()), "Invalid argument; mismatched message type")
+	if other.stats != null:
+		if stats == null:
+			stats = other.stats.clone()
+		else:
+			stats.merge_from(other.stats)
+
+## `to_dict` converts this message into a JSON-compatible `Dictionary` keyed by
+## field name.
+func to_dict() -> Dictionary:
+	var _data := {}
+	_data["stats"] = _Value.to_json_value(stats)
+	return _data
+
+## `from_dict` replaces this message's fields with those in `data`, which has the
+## format produced by `to_dict`. Missing fields are reset to their defaults and
+## unknown keys are ignored.
+func from_dict(data: Dictionary) -> Error:
+	stats = null
+	var _out := [null]
+	if data.has("stats"):
+		var _err := _Value.from_json_value(data["stats"], Stats, _out)
+		if _err != OK:
+			return _err
+		stats = _out[0]
+	return OK
+
+## `to_json` converts this message into a JSON string.
+func to_json() -> String:
+	return JSON.stringify(to_dict(), "", false, true)
+
+## `from_json` replaces this message's contents with those in the JSON string `text`.
+func from_json(text: String) -> Error:
+	var _data: Variant = JSON.parse_string(text)
+	if not _data is Dictionary:
+		return ERR_PARSE_ERROR
+	return from_dict(_data)
+
 # -- PRIVATE METHODS ----------------------------------------------------------------- #
 
 ## `_encode` serializes fields to the writer.
@@ -865,87 +2041,143 @@ func _encode(_writer: _Writer) -> void:
 
 ## `_decode` deserializes fields from the reader.
 func _decode(_reader: _Reader) -> Error:
+	if _reader.enter_message() != OK:
+		return _reader.get_error()
 	stats = Stats.new()
 	stats._decode(_reader)
 	if _reader.get_error() != OK:
 		return _reader.get_error()
+	_reader.exit_message()
 	return _reader.get_error()
 
+## `_encode_delta` serializes fields which differ from `baseline` to the writer.
+func _encode_delta(baseline: Object, _writer: _Writer) -> void:
+	var _changed: Array[bool] = [not _Value.equals(stats, baseline.stats)]
+	for _c in _changed:
+		_writer.write_bool(_c)
+	if _changed[0]:
+		if stats != null and baseline.stats != null:
+			_writer.write_bool(true)
+			stats._encode_delta(baseline.stats, _writer)
+		else:
+			_writer.write_bool(false)
+			if stats == null:
+				_writer.set_error(ERR_INVALID_DATA)
+				return
+			stats._encode(_writer)
+
+## `_decode_delta` deserializes fields which differ from `baseline` from the reader.
+func _decode_delta(baseline: Object, _reader: _Reader) -> Error:
+	if _reader.enter_message() != OK:
+		return _reader.get_error()
+	var _changed: Array[bool] = []
+	for _i in range(1):
+		_changed.append(_reader.read_bool())
+	if _reader.get_error() != OK:
+		return _reader.get_error()
+	if _changed[0]:
+		if _reader.read_bool():
+			if baseline.stats == null:
+				_reader.set_error(ERR_INVALID_DATA)
+				return _reader.get_error()
+			stats = Stats.new()
+			stats._decode_delta(baseline.stats, _reader)
+			if _reader.get_error() != OK:
+				return _reader.get_error()
+		else:
+			stats = Stats.new()
+			stats._decode(_reader)
+			if _reader.get_error() != OK:
+				return _reader.get_error()
+	else:
+		stats = _Value.copy(baseline.stats)
+	_reader.exit_message()
+	return _reader.get_error()
+
+# -- DEBUGGING ----------------------------------------------------------------------- #
+
+func _to_string() -> String:
+	return "Player(stats=" + _Value.to_debug_string(stats) + ")"
+
 "#;
 
         assert_eq!(actual, expected);
     }
 
     #[test]
-    fn test_codec_generation_for_multiple_encoding_variations() {
-        // Given: A schema with fields using different encoding variations.
+    fn test_codec_generation_for_tagged_nested_message_field() {
+        // Given: A schema with a message containing another message as a field.
         let pkg = PackageName::try_from(vec!["test"]).unwrap();
+
+        let stats_descriptor = DescriptorBuilder::default()
+            .package(pkg.clone())
+            .path(vec!["Stats".to_string()])
+            .build()
+            .unwrap();
+
         let schema = Schema {
             packages: vec![Package {
                 name: pkg.clone(),
-                messages: vec![Message {
-                    descriptor: DescriptorBuilder::default()
-                        .package(pkg)
-                        .path(vec!["Encoded".to_string()])
-                        .build()
-                        .unwrap(),
-                    doc: None,
-                    fields: vec![
-                        Field {
-                            name: "varint_signed".to_string(),
+                messages: vec![
+                    Message {
+                        descriptor: stats_descriptor.clone(),
+                        doc: None,
+                        fields: vec![Field {
+                            name: "level".to_string(),
                             index: 0,
                             encoding: Encoding {
-                                wire: WireFormat::LengthPrefixed { prefix_bits: 64 },
+                                wire: WireFormat::Bits { count: 32 },
                                 native: NativeType::Int {
-                                    bits: 64,
-                                    signed: true,
+                                    bits: 32,
+                                    signed: false,
                                 },
                                 transforms: vec![],
                                 padding_bits: None,
                             },
                             doc: None,
-                        },
-                        Field {
-                            name: "varint_unsigned".to_string(),
-                            index: 1,
+                        }],
+                        messages: vec![],
+                        enums: vec![],
+                    },
+                    Message {
+                        descriptor: DescriptorBuilder::default()
+                            .package(pkg)
+                            .path(vec!["Player".to_string()])
+                            .build()
+                            .unwrap(),
+                        doc: None,
+                        fields: vec![Field {
+                            name: "stats".to_string(),
+                            index: 0,
                             encoding: Encoding {
                                 wire: WireFormat::LengthPrefixed { prefix_bits: 64 },
-                                native: NativeType::Int {
-                                    bits: 64,
-                                    signed: false,
+                                native: NativeType::Message {
+                                    descriptor: stats_descriptor,
                                 },
                                 transforms: vec![],
                                 padding_bits: None,
                             },
                             doc: None,
-                        },
-                        Field {
-                            name: "zigzag".to_string(),
-                            index: 2,
-                            encoding: Encoding {
-                                wire: WireFormat::Bits { count: 16 },
-                                native: NativeType::Int {
-                                    bits: 16,
-                                    signed: true,
-                                },
-                                transforms: vec![Transform::ZigZag],
-                                padding_bits: None,
-                            },
-                            doc: None,
-                        },
-                    ],
-                    messages: vec![],
-                    enums: vec![],
-                }],
+                        }],
+                        messages: vec![],
+                        enums: vec![],
+                    },
+                ],
                 enums: vec![],
             }],
         };
 
+        // Given: A generator configured to use the tagged wire format.
+        let generator = GDScript::new(Options {
+            tagged: true,
+            ..Default::default()
+        });
+
         // When: Generating GDScript code.
-        let output = GDScript.generate(&schema).unwrap();
+        let output = generator.generate(&schema).unwrap();
 
-        // Then: The Encoded message file matches expected content exactly.
-        let actual = output.files.get(Path::new("test/encoded.gd")).unwrap();
+        // Then: The Player message file matches expected content exactly.
+        let actual = output.files.get(Path::new("test/player.gd")).unwrap();
 
         let expected = r#"## DO NOT EDIT: Generated by 'baproto-gdscript'
 
@@ -953,17 +2185,20 @@ extends RefCounted
 
 # -- DEPENDENCIES -------------------------------------------------------------------- #
 
+const _Framing := preload("res://addons/baproto/runtime/framing.gd")
 const _Reader := preload("res://addons/baproto/runtime/reader.gd")
+const _Value := preload("res://addons/baproto/runtime/value.gd")
 const _Writer := preload("res://addons/baproto/runtime/writer.gd")
+const Stats := preload("./stats.gd")
 
 # -- TYPES --------------------------------------------------------------------------- #
 
 
 # -- INITIALIZATION ------------------------------------------------------------------ #
 
-var varint_signed: int = 0
-var varint_unsigned: int = 0
-var zigzag: int = 0
+var stats: Stats = null
+## `_presence` tracks which fields were present in decoded data.
+var _presence: int = 0
 
 # -- PUBLIC METHODS ------------------------------------------------------------------ #
 
@@ -980,40 +2215,245 @@ func deserialize(data: PackedByteArray) -> Error:
 	_decode(_reader)
 	return _reader.get_error()
 
-# -- PRIVATE METHODS ----------------------------------------------------------------- #
+## `write_to` writes this message to `writer`, starting at its current bit position.
+## Returns the writer's error state.
+func write_to(writer: _Writer) -> Error:
+	_encode(writer)
+	return writer.get_error()
+
+## `read_from` reads this message from `reader`, starting at its current bit position.
+## Afterwards, `reader.get_position()` is the bit position following the message.
+func read_from(reader: _Reader) -> Error:
+	_decode(reader)
+	return reader.get_error()
+
+## `serialize_delimited` writes this message to a `PackedByteArray`, prefixed with its
+## length so that it can be followed by other data.
+func serialize_delimited(out: PackedByteArray) -> Error:
+	var _writer := _Writer.new()
+	_encode(_writer)
+	_Framing.write_frame(_writer.to_bytes(), out)
+	return _writer.get_error()
 
-## `_encode` serializes fields to the writer.
-func _encode(_writer: _Writer) -> void:
-	_writer.write_varint_signed(varint_signed)
-	_writer.write_varint_unsigned(varint_unsigned)
-	_writer.write_zigzag(zigzag, 16)
+## `deserialize_delimited` reads this message from the length-prefixed data in `data`
+## starting at `offset`. Returns the number of bytes consumed, `_Framing.INCOMPLETE`
+## if `data` ends before the message's data does, or `_Framing.INVALID` on error.
+func deserialize_delimited(data: PackedByteArray, offset: int = 0) -> int:
+	var _frame := [null]
+	var _size := _Framing.read_frame(data, offset, _frame)
+	if _size <= 0:
+		return _size
+	if deserialize(_frame[0]) != OK:
+		return _Framing.INVALID
+	return _size
+
+## `serialize_delta` writes the fields of this message which differ from `baseline`
+## to a `PackedByteArray`. The data can only be read by `deserialize_delta` using an
+## equal baseline.
+func serialize_delta(baseline: Object, out: PackedByteArray) -> Error:
+	if baseline == null:
+		baseline = get_script().new()
+	assert(is_instance_of(baseline, get_script()), "Invalid argument; mismatched message type")
+	var _writer := _Writer.new()
+	_encode_delta(baseline, _writer)
+	out.append_array(_writer.to_bytes())
+	return _writer.get_error()
+
+## `deserialize_delta` reads this message from a `PackedByteArray` written by
+## `serialize_delta`, copying unchanged fields from `baseline`.
+func deserialize_delta(baseline: Object, data: PackedByteArray) -> Error:
+	if baseline == null:
+		baseline = get_script().new()
+	assert(is_instance_of(baseline, get_script()), "Invalid argument; mismatched message type")
+	var _reader := _Reader.new(data)
+	_decode_delta(baseline, _reader)
+	return _reader.get_error()
+
+## `equals` returns whether `other` is a message of this type with equal fields.
+func equals(other: Object) -> bool:
+	if not is_instance_of(other, get_script()):
+		return false
+	if not _Value.equals(stats, other.stats):
+		return false
+	return true
+
+## `hash` returns a hash of this message's fields consistent with `equals`.
+func hash() -> int:
+	return _Value.hash_value([stats])
+
+## `is_default` returns whether all fields are set to their default values.
+func is_default() -> bool:
+	if stats != null:
+		return false
+	return true
+
+## `clone` returns a deep copy of this message.
+func clone() -> RefCounted:
+	var _copy: RefCounted = get_script().new()
+	_copy.copy_from(self)
+	return _copy
+
+## `copy_from` replaces this message's fields with deep copies of `other`'s.
+func copy_from(other: Object) -> void:
+	assert(is_instance_of(other, get_script()), "Invalid argument; mismatched message type")
+	stats = _Value.copy(other.stats)
+	_presence = other._presence
+
+## `merge_from` merges `other` into this message. Set scalar fields overwrite this
+## message's values, repeated fields are appended (and truncated to any maximum
+## length), map entries are inserted, and nested messages are merged recursively.
+func merge_from(other: Object) -> void:
+	assert(is_instance_of(other, get_script()), "Invalid argument; mismatched message type")
+	if other.stats != null:
+		if stats == null:
+			stats = other.stats.clone()
+		else:
+			stats.merge_from(other.stats)
+	_presence = _presence | other._presence
+
+## `to_dict` converts this message into a JSON-compatible `Dictionary` keyed by
+## field name.
+func to_dict() -> Dictionary:
+	var _data := {}
+	_data["stats"] = _Value.to_json_value(stats)
+	return _data
+
+## `from_dict` replaces this message's fields with those in `data`, which has the
+## format produced by `to_dict`. Missing fields are reset to their defaults and
+## unknown keys are ignored.
+func from_dict(data: Dictionary) -> Error:
+	stats = null
+	_presence = 0
+	var _out := [null]
+	if data.has("stats"):
+		var _err := _Value.from_json_value(data["stats"], Stats, _out)
+		if _err != OK:
+			return _err
+		stats = _out[0]
+		_presence = _presence | 1
+	return OK
+
+## `to_json` converts this message into a JSON string.
+func to_json() -> String:
+	return JSON.stringify(to_dict(), "", false, true)
+
+## `from_json` replaces this message's contents with those in the JSON string `text`.
+func from_json(text: String) -> Error:
+	var _data: Variant = JSON.parse_string(text)
+	if not _data is Dictionary:
+		return ERR_PARSE_ERROR
+	return from_dict(_data)
+
+## `has_stats` returns whether `stats` was decoded or differs from its default.
+func has_stats() -> bool:
+	return _presence & 1 != 0 or stats != null
+
+## `clear_stats` resets `stats` to its default and marks it as not decoded.
+func clear_stats() -> void:
+	stats = null
+	_presence = _presence & ~1
+
+# -- PRIVATE METHODS ----------------------------------------------------------------- #
+
+## `_encode` serializes fields to the writer.
+func _encode(_writer: _Writer) -> void:
+	if has_stats():
+		var _start := _writer.begin_field(0)
+		if stats == null:
+			_writer.set_error(ERR_INVALID_DATA)
+			return
+		stats._encode(_writer)
+		_writer.end_field(_start)
+	_writer.end_fields()
 
 ## `_decode` deserializes fields from the reader.
 func _decode(_reader: _Reader) -> Error:
-	varint_signed = _reader.read_varint_signed()
-	if _reader.get_error() != OK:
+	if _reader.enter_message() != OK:
 		return _reader.get_error()
-	varint_unsigned = _reader.read_varint_unsigned()
-	if _reader.get_error() != OK:
+	stats = null
+	_presence = 0
+	var _index := _reader.read_field_index()
+	while _index >= 0:
+		var _end := _reader.read_field_end()
+		if _reader.get_error() != OK:
+			return _reader.get_error()
+		match _index:
+			0:
+				stats = Stats.new()
+				stats._decode(_reader)
+				if _reader.get_error() != OK:
+					return _reader.get_error()
+				_presence = _presence | 1
+
+		_reader.skip_field(_end)
+		_index = _reader.read_field_index()
+	_reader.exit_message()
+	return _reader.get_error()
+
+## `_encode_delta` serializes fields which differ from `baseline` to the writer.
+func _encode_delta(baseline: Object, _writer: _Writer) -> void:
+	var _changed: Array[bool] = [not _Value.equals(stats, baseline.stats)]
+	for _c in _changed:
+		_writer.write_bool(_c)
+	if _changed[0]:
+		if stats != null and baseline.stats != null:
+			_writer.write_bool(true)
+			stats._encode_delta(baseline.stats, _writer)
+		else:
+			_writer.write_bool(false)
+			if stats == null:
+				_writer.set_error(ERR_INVALID_DATA)
+				return
+			stats._encode(_writer)
+
+## `_decode_delta` deserializes fields which differ from `baseline` from the reader.
+func _decode_delta(baseline: Object, _reader: _Reader) -> Error:
+	if _reader.enter_message() != OK:
 		return _reader.get_error()
-	zigzag = _reader.read_zigzag(16)
+	_presence = baseline._presence
+	var _changed: Array[bool] = []
+	for _i in range(1):
+		_changed.append(_reader.read_bool())
 	if _reader.get_error() != OK:
 		return _reader.get_error()
+	if _changed[0]:
+		if _reader.read_bool():
+			if baseline.stats == null:
+				_reader.set_error(ERR_INVALID_DATA)
+				return _reader.get_error()
+			stats = Stats.new()
+			stats._decode_delta(baseline.stats, _reader)
+			if _reader.get_error() != OK:
+				return _reader.get_error()
+		else:
+			stats = Stats.new()
+			stats._decode(_reader)
+			if _reader.get_error() != OK:
+				return _reader.get_error()
+		_presence = _presence | 1
+	else:
+		stats = _Value.copy(baseline.stats)
+	_reader.exit_message()
 	return _reader.get_error()
 
+# -- DEBUGGING ----------------------------------------------------------------------- #
+
+func _to_string() -> String:
+	return "Player(stats=" + _Value.to_debug_string(stats) + ")"
+
 "#;
 
         assert_eq!(actual, expected);
     }
 
     #[test]
-    fn test_codec_generation_for_complex_schema() {
-        // Given: A schema with a message containing multiple field types.
+    fn test_codec_generation_for_optional_message_field() {
+        // Given: A schema with a message containing an optional message field.
         let pkg = PackageName::try_from(vec!["test"]).unwrap();
 
-        let item_descriptor = DescriptorBuilder::default()
+        let stats_descriptor = DescriptorBuilder::default()
             .package(pkg.clone())
-            .path(vec!["Item".to_string()])
+            .path(vec!["Stats".to_string()])
             .build()
             .unwrap();
 
@@ -1022,10 +2462,10 @@ func _decode(_reader: _Reader) -> Error:
                 name: pkg.clone(),
                 messages: vec![
                     Message {
-                        descriptor: item_descriptor.clone(),
+                        descriptor: stats_descriptor.clone(),
                         doc: None,
                         fields: vec![Field {
-                            name: "id".to_string(),
+                            name: "level".to_string(),
                             index: 0,
                             encoding: Encoding {
                                 wire: WireFormat::Bits { count: 32 },
@@ -1044,115 +2484,23 @@ func _decode(_reader: _Reader) -> Error:
                     Message {
                         descriptor: DescriptorBuilder::default()
                             .package(pkg)
-                            .path(vec!["Complex".to_string()])
+                            .path(vec!["Player".to_string()])
                             .build()
                             .unwrap(),
                         doc: None,
-                        fields: vec![
-                            Field {
-                                name: "enabled".to_string(),
-                                index: 0,
-                                encoding: Encoding {
-                                    wire: WireFormat::Bits { count: 1 },
-                                    native: NativeType::Bool,
-                                    transforms: vec![],
-                                    padding_bits: None,
-                                },
-                                doc: None,
-                            },
-                            Field {
-                                name: "data".to_string(),
-                                index: 1,
-                                encoding: Encoding {
-                                    wire: WireFormat::LengthPrefixed { prefix_bits: 64 },
-                                    native: NativeType::Bytes,
-                                    transforms: vec![],
-                                    padding_bits: None,
-                                },
-                                doc: None,
-                            },
-                            Field {
-                                name: "values".to_string(),
-                                index: 2,
-                                encoding: Encoding {
-                                    wire: WireFormat::LengthPrefixed { prefix_bits: 64 },
-                                    native: NativeType::Array {
-                                        element: Box::new(Encoding {
-                                            wire: WireFormat::Bits { count: 32 },
-                                            native: NativeType::Int {
-                                                bits: 32,
-                                                signed: true,
-                                            },
-                                            transforms: vec![],
-                                            padding_bits: None,
-                                        }),
-                                    },
-                                    transforms: vec![],
-                                    padding_bits: None,
-                                },
-                                doc: None,
-                            },
-                            Field {
-                                name: "items".to_string(),
-                                index: 3,
-                                encoding: Encoding {
-                                    wire: WireFormat::LengthPrefixed { prefix_bits: 64 },
-                                    native: NativeType::Array {
-                                        element: Box::new(Encoding {
-                                            wire: WireFormat::LengthPrefixed { prefix_bits: 64 },
-                                            native: NativeType::Message {
-                                                descriptor: item_descriptor.clone(),
-                                            },
-                                            transforms: vec![],
-                                            padding_bits: None,
-                                        }),
-                                    },
-                                    transforms: vec![],
-                                    padding_bits: None,
-                                },
-                                doc: None,
-                            },
-                            Field {
-                                name: "metadata".to_string(),
-                                index: 4,
-                                encoding: Encoding {
-                                    wire: WireFormat::LengthPrefixed { prefix_bits: 64 },
-                                    native: NativeType::Map {
-                                        key: Box::new(Encoding {
-                                            wire: WireFormat::LengthPrefixed { prefix_bits: 64 },
-                                            native: NativeType::String,
-                                            transforms: vec![],
-                                            padding_bits: None,
-                                        }),
-                                        value: Box::new(Encoding {
-                                            wire: WireFormat::Bits { count: 32 },
-                                            native: NativeType::Int {
-                                                bits: 32,
-                                                signed: false,
-                                            },
-                                            transforms: vec![],
-                                            padding_bits: None,
-                                        }),
-                                    },
-                                    transforms: vec![],
-                                    padding_bits: None,
-                                },
-                                doc: None,
-                            },
-                            Field {
-                                name: "nested".to_string(),
-                                index: 5,
-                                encoding: Encoding {
-                                    wire: WireFormat::LengthPrefixed { prefix_bits: 64 },
-                                    native: NativeType::Message {
-                                        descriptor: item_descriptor,
-                                    },
-                                    transforms: vec![],
-                                    padding_bits: None,
+                        fields: vec![Field {
+                            name: "stats".to_string(),
+                            index: 0,
+                            encoding: Encoding {
+                                wire: WireFormat::LengthPrefixed { prefix_bits: 64 },
+                                native: NativeType::Message {
+                                    descriptor: stats_descriptor,
                                 },
-                                doc: None,
+                                transforms: vec![],
+                                padding_bits: None,
                             },
-                        ],
+                            doc: Some("@gdscript optional".to_string()),
+                        }],
                         messages: vec![],
                         enums: vec![],
                     },
@@ -1162,10 +2510,10 @@ func _decode(_reader: _Reader) -> Error:
         };
 
         // When: Generating GDScript code.
-        let output = GDScript.generate(&schema).unwrap();
+        let output = GDScript::default().generate(&schema).unwrap();
 
-        // Then: The Complex message file matches expected content exactly.
-        let actual = output.files.get(Path::new("test/complex.gd")).unwrap();
+        // Then: The Player message file matches expected content exactly.
+        let actual = output.files.get(Path::new("test/player.gd")).unwrap();
 
         let expected = r#"## DO NOT EDIT: Generated by 'baproto-gdscript'
 
@@ -1173,21 +2521,18 @@ extends RefCounted
 
 # -- DEPENDENCIES -------------------------------------------------------------------- #
 
+const _Framing := preload("res://addons/baproto/runtime/framing.gd")
 const _Reader := preload("res://addons/baproto/runtime/reader.gd")
+const _Value := preload("res://addons/baproto/runtime/value.gd")
 const _Writer := preload("res://addons/baproto/runtime/writer.gd")
-const Item := preload("./item.gd")
+const Stats := preload("./stats.gd")
 
 # -- TYPES --------------------------------------------------------------------------- #
 
 
 # -- INITIALIZATION ------------------------------------------------------------------ #
 
-var enabled: bool = false
-var data: PackedByteArray = PackedByteArray()
-var values: Array[int] = []
-var items: Array[Item] = []
-var metadata: Dictionary = {}
-var nested: Item = null
+var stats: Stats = null
 
 # -- PUBLIC METHODS ------------------------------------------------------------------ #
 
@@ -1204,66 +2549,4299 @@ func deserialize(data: PackedByteArray) -> Error:
 	_decode(_reader)
 	return _reader.get_error()
 
+## `write_to` writes this message to `writer`, starting at its current bit position.
+## Returns the writer's error state.
+func write_to(writer: _Writer) -> Error:
+	_encode(writer)
+	return writer.get_error()
+
+## `read_from` reads this message from `reader`, starting at its current bit position.
+## Afterwards, `reader.get_position()` is the bit position following the message.
+func read_from(reader: _Reader) -> Error:
+	_decode(reader)
+	return reader.get_error()
+
+## `serialize_delimited` writes this message to a `PackedByteArray`, prefixed with its
+## length so that it can be followed by other data.
+func serialize_delimited(out: PackedByteArray) -> Error:
+	var _writer := _Writer.new()
+	_encode(_writer)
+	_Framing.write_frame(_writer.to_bytes(), out)
+	return _writer.get_error()
+
+## `deserialize_delimited` reads this message from the length-prefixed data in `data`
+## starting at `offset`. Returns the number of bytes consumed, `_Framing.INCOMPLETE`
+## if `data` ends before the message's data does, or `_Framing.INVALID` on error.
+func deserialize_delimited(data: PackedByteArray, offset: int = 0) -> int:
+	var _frame := [null]
+	var _size := _Framing.read_frame(data, offset, _frame)
+	if _size <= 0:
+		return _size
+	if deserialize(_frame[0]) != OK:
+		return _Framing.INVALID
+	return _size
+
+## `serialize_delta` writes the fields of this message which differ from `baseline`
+## to a `PackedByteArray`. The data can only be read by `deserialize_delta` using an
+## equal baseline.
+func serialize_delta(baseline: Object, out: PackedByteArray) -> Error:
+	if baseline == null:
+		baseline = get_script().new()
+	assert(is_instance_of(baseline, get_script()), "Invalid argument; mismatched message type")
+	var _writer := _Writer.new()
+	_encode_delta(baseline, _writer)
+	out.append_array(_writer.to_bytes())
+	return _writer.get_error()
+
+## `deserialize_delta` reads this message from a `PackedByteArray` written by
+## `serialize_delta`, copying unchanged fields from `baseline`.
+func deserialize_delta(baseline: Object, data: PackedByteArray) -> Error:
+	if baseline == null:
+		baseline = get_script().new()
+	assert(is_instance_of(baseline, get_script()), "Invalid argument; mismatched message type")
+	var _reader := _Reader.new(data)
+	_decode_delta(baseline, _reader)
+	return _reader.get_error()
+
+## `equals` returns whether `other` is a message of this type with equal fields.
+func equals(other: Object) -> bool:
+	if not is_instance_of(other, get_script()):
+		return false
+	if not _Value.equals(stats, other.stats):
+		return false
+	return true
+
+## `hash` returns a hash of this message's fields consistent with `equals`.
+func hash() -> int:
+	return _Value.hash_value([stats])
+
+## `is_default` returns whether all fields are set to their default values.
+func is_default() -> bool:
+	if stats != null:
+		return false
+	return true
+
+## `clone` returns a deep copy of this message.
+func clone() -> RefCounted:
+	var _copy: RefCounted = get_script().new()
+	_copy.copy_from(self)
+	return _copy
+
+## `copy_from` replaces this message's fields with deep copies of `other`'s.
+func copy_from(other: Object) -> void:
+	assert(is_instance_of(other, get_script()), "Invalid argument; mismatched message type")
+	stats = _Value.copy(other.stats)
+
+## `merge_from` merges `other` into this message. Set scalar fields overwrite this
+## message's values, repeated fields are appended (and truncated to any maximum
+## length), map entries are inserted, and nested messages are merged recursively.
+func merge_from(other: Object) -> void:
+	assert(is_instance_of(other, get_script()), "Invalid argument; mismatched message type")
+	if other.stats != null:
+		if stats == null:
+			stats = other.stats.clone()
+		else:
+			stats.merge_from(other.stats)
+
+## `to_dict` converts this message into a JSON-compatible `Dictionary` keyed by
+## field name.
+func to_dict() -> Dictionary:
+	var _data := {}
+	_data["stats"] = _Value.to_json_value(stats)
+	return _data
+
+## `from_dict` replaces this message's fields with those in `data`, which has the
+## format produced by `to_dict`. Missing fields are reset to their defaults and
+## unknown keys are ignored.
+func from_dict(data: Dictionary) -> Error:
+	stats = null
+	var _out := [null]
+	if data.has("stats"):
+		var _err := _Value.from_json_value(data["stats"], Stats, _out)
+		if _err != OK:
+			return _err
+		stats = _out[0]
+	return OK
+
+## `to_json` converts this message into a JSON string.
+func to_json() -> String:
+	return JSON.stringify(to_dict(), "", false, true)
+
+## `from_json` replaces this message's contents with those in the JSON string `text`.
+func from_json(text: String) -> Error:
+	var _data: Variant = JSON.parse_string(text)
+	if not _data is Dictionary:
+		return ERR_PARSE_ERROR
+	return from_dict(_data)
+
+## `has_stats` returns whether the optional `stats` is set.
+func has_stats() -> bool:
+	return stats != null
+
+## `clear_stats` unsets the optional `stats`.
+func clear_stats() -> void:
+	stats = null
+
 # -- PRIVATE METHODS ----------------------------------------------------------------- #
 
 ## `_encode` serializes fields to the writer.
 func _encode(_writer: _Writer) -> void:
-	_writer.write_bool(enabled)
-	_writer.write_varint_unsigned(data.size())
-	_writer.write_bytes(data)
-	_writer.write_varint_unsigned(values.size())
-	for _item in values:
-		_writer.write_i32(_item)
-	_writer.write_varint_unsigned(items.size())
-	for _item in items:
-		if _item == null:
-			_writer.set_error(ERR_INVALID_DATA)
-			return
-		_item._encode(_writer)
-	_writer.write_varint_unsigned(metadata.size())
-	for _key in metadata:
-		_writer.write_string(_key)
-		_writer.write_u32(metadata[_key])
-	if nested == null:
-		_writer.set_error(ERR_INVALID_DATA)
-		return
-	nested._encode(_writer)
+	_writer.write_bool(stats != null)
+	if stats != null:
+		stats._encode(_writer)
 
 ## `_decode` deserializes fields from the reader.
 func _decode(_reader: _Reader) -> Error:
-	enabled = _reader.read_bool()
-	if _reader.get_error() != OK:
-		return _reader.get_error()
-	data = _reader.read_bytes(_reader.read_varint_unsigned())
-	if _reader.get_error() != OK:
+	if _reader.enter_message() != OK:
 		return _reader.get_error()
-	values = []
-	for _i in range(_reader.read_varint_unsigned()):
-		_temp = _reader.read_i32()
-		if _reader.get_error() != OK:
-			return _reader.get_error()
-		values.append(_temp)
-	items = []
-	for _i in range(_reader.read_varint_unsigned()):
-		var _item := Item.new()
-		_item._decode(_reader)
-		if _reader.get_error() != OK:
-			return _reader.get_error()
-		items.append(_item)
-	metadata = {}
-	for _i in range(_reader.read_varint_unsigned()):
-		var _key := _reader.read_string()
+	if _reader.read_bool():
+		stats = Stats.new()
+		stats._decode(_reader)
 		if _reader.get_error() != OK:
 			return _reader.get_error()
-		metadata[_key] = _reader.read_u32()
-		if _reader.get_error() != OK:
-			return _reader.get_error()
-	nested = Item.new()
-	nested._decode(_reader)
+	else:
+		stats = null
+	if _reader.get_error() != OK:
+		return _reader.get_error()
+	_reader.exit_message()
+	return _reader.get_error()
+
+## `_encode_delta` serializes fields which differ from `baseline` to the writer.
+func _encode_delta(baseline: Object, _writer: _Writer) -> void:
+	var _changed: Array[bool] = [not _Value.equals(stats, baseline.stats)]
+	for _c in _changed:
+		_writer.write_bool(_c)
+	if _changed[0]:
+		if stats != null and baseline.stats != null:
+			_writer.write_bool(true)
+			stats._encode_delta(baseline.stats, _writer)
+		else:
+			_writer.write_bool(false)
+			_writer.write_bool(stats != null)
+			if stats != null:
+				stats._encode(_writer)
+
+## `_decode_delta` deserializes fields which differ from `baseline` from the reader.
+func _decode_delta(baseline: Object, _reader: _Reader) -> Error:
+	if _reader.enter_message() != OK:
+		return _reader.get_error()
+	var _changed: Array[bool] = []
+	for _i in range(1):
+		_changed.append(_reader.read_bool())
 	if _reader.get_error() != OK:
 		return _reader.get_error()
+	if _changed[0]:
+		if _reader.read_bool():
+			if baseline.stats == null:
+				_reader.set_error(ERR_INVALID_DATA)
+				return _reader.get_error()
+			stats = Stats.new()
+			stats._decode_delta(baseline.stats, _reader)
+			if _reader.get_error() != OK:
+				return _reader.get_error()
+		else:
+			if _reader.read_bool():
+				stats = Stats.new()
+				stats._decode(_reader)
+				if _reader.get_error() != OK:
+					return _reader.get_error()
+			else:
+				stats = null
+			if _reader.get_error() != OK:
+				return _reader.get_error()
+	else:
+		stats = _Value.copy(baseline.stats)
+	_reader.exit_message()
 	return _reader.get_error()
 
+# -- DEBUGGING ----------------------------------------------------------------------- #
+
+func _to_string() -> String:
+	return "Player(stats=" + _Value.to_debug_string(stats) + ")"
+
+"#;
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_codec_generation_for_instantiated_message_fields() {
+        // Given: A schema with a message containing required message and enum fields,
+        // as well as a field referencing its own message type.
+        let pkg = PackageName::try_from(vec!["test"]).unwrap();
+
+        let stats_descriptor = DescriptorBuilder::default()
+            .package(pkg.clone())
+            .path(vec!["Stats".to_string()])
+            .build()
+            .unwrap();
+        let status_descriptor = DescriptorBuilder::default()
+            .package(pkg.clone())
+            .path(vec!["Status".to_string()])
+            .build()
+            .unwrap();
+        let player_descriptor = DescriptorBuilder::default()
+            .package(pkg.clone())
+            .path(vec!["Player".to_string()])
+            .build()
+            .unwrap();
+
+        let message_field = |name: &str, index: u32, descriptor: &Descriptor| Field {
+            name: name.to_string(),
+            index,
+            encoding: Encoding {
+                wire: WireFormat::LengthPrefixed { prefix_bits: 64 },
+                native: NativeType::Message {
+                    descriptor: descriptor.clone(),
+                },
+                transforms: vec![],
+                padding_bits: None,
+            },
+            doc: None,
+        };
+
+        let schema = Schema {
+            packages: vec![Package {
+                name: pkg,
+                messages: vec![
+                    Message {
+                        descriptor: stats_descriptor.clone(),
+                        doc: None,
+                        fields: vec![],
+                        messages: vec![],
+                        enums: vec![],
+                    },
+                    Message {
+                        descriptor: player_descriptor.clone(),
+                        doc: None,
+                        fields: vec![
+                            message_field("stats", 0, &stats_descriptor),
+                            Field {
+                                name: "status".to_string(),
+                                index: 1,
+                                encoding: Encoding {
+                                    wire: WireFormat::LengthPrefixed { prefix_bits: 64 },
+                                    native: NativeType::Enum {
+                                        descriptor: status_descriptor.clone(),
+                                    },
+                                    transforms: vec![],
+                                    padding_bits: None,
+                                },
+                                doc: None,
+                            },
+                            message_field("next", 2, &player_descriptor),
+                        ],
+                        messages: vec![],
+                        enums: vec![],
+                    },
+                ],
+                enums: vec![Enum {
+                    descriptor: status_descriptor,
+                    discriminant: Encoding {
+                        wire: WireFormat::Bits { count: 8 },
+                        native: NativeType::Int {
+                            bits: 8,
+                            signed: false,
+                        },
+                        transforms: vec![],
+                        padding_bits: None,
+                    },
+                    doc: None,
+                    variants: vec![
+                        Variant::Field {
+                            name: "Active".to_string(),
+                            index: 0,
+                            field: message_field("Active", 0, &stats_descriptor),
+                            doc: None,
+                        },
+                        Variant::Unit {
+                            name: "Idle".to_string(),
+                            index: 1,
+                            doc: None,
+                        },
+                    ],
+                }],
+            }],
+        };
+
+        // When: Generating GDScript code with message instantiation enabled.
+        let output = GDScript::new(Options {
+            instantiate_messages: true,
+            ..Default::default()
+        })
+        .generate(&schema)
+        .unwrap();
+
+        // Then: The Status enum is initialized to its first variant.
+        let status = output.files.get(Path::new("test/status.gd")).unwrap();
+        assert!(
+            status.contains(
+                "func _init() -> void:\n\t_discriminant = Active\n\t_value = Stats.new()\n"
+            )
+        );
+
+        // Then: The Player message file matches expected content exactly.
+        let actual = output.files.get(Path::new("test/player.gd")).unwrap();
+
+        let expected = r#"## DO NOT EDIT: Generated by 'baproto-gdscript'
+
+extends RefCounted
+
+# -- DEPENDENCIES -------------------------------------------------------------------- #
+
+const _Framing := preload("res://addons/baproto/runtime/framing.gd")
+const _Reader := preload("res://addons/baproto/runtime/reader.gd")
+const _Value := preload("res://addons/baproto/runtime/value.gd")
+const _Writer := preload("res://addons/baproto/runtime/writer.gd")
+const Stats := preload("./stats.gd")
+const Status := preload("./status.gd")
+static var Player: GDScript = null
+
+# -- TYPES --------------------------------------------------------------------------- #
+
+
+# -- INITIALIZATION ------------------------------------------------------------------ #
+
+var stats: Stats = null
+var status: Status = null
+var next: RefCounted = null
+
+# -- PUBLIC METHODS ------------------------------------------------------------------ #
+
+## `serialize` writes this message to a `PackedByteArray`.
+func serialize(out: PackedByteArray) -> Error:
+	var _writer := _Writer.new()
+	_encode(_writer)
+	out.append_array(_writer.to_bytes())
+	return _writer.get_error()
+
+## `deserialize` reads this message from a `PackedByteArray`.
+func deserialize(data: PackedByteArray) -> Error:
+	var _reader := _Reader.new(data)
+	_decode(_reader)
+	return _reader.get_error()
+
+## `write_to` writes this message to `writer`, starting at its current bit position.
+## Returns the writer's error state.
+func write_to(writer: _Writer) -> Error:
+	_encode(writer)
+	return writer.get_error()
+
+## `read_from` reads this message from `reader`, starting at its current bit position.
+## Afterwards, `reader.get_position()` is the bit position following the message.
+func read_from(reader: _Reader) -> Error:
+	_decode(reader)
+	return reader.get_error()
+
+## `serialize_delimited` writes this message to a `PackedByteArray`, prefixed with its
+## length so that it can be followed by other data.
+func serialize_delimited(out: PackedByteArray) -> Error:
+	var _writer := _Writer.new()
+	_encode(_writer)
+	_Framing.write_frame(_writer.to_bytes(), out)
+	return _writer.get_error()
+
+## `deserialize_delimited` reads this message from the length-prefixed data in `data`
+## starting at `offset`. Returns the number of bytes consumed, `_Framing.INCOMPLETE`
+## if `data` ends before the message's data does, or `_Framing.INVALID` on error.
+func deserialize_delimited(data: PackedByteArray, offset: int = 0) -> int:
+	var _frame := [null]
+	var _size := _Framing.read_frame(data, offset, _frame)
+	if _size <= 0:
+		return _size
+	if deserialize(_frame[0]) != OK:
+		return _Framing.INVALID
+	return _size
+
+## `serialize_delta` writes the fields of this message which differ from `baseline`
+## to a `PackedByteArray`. The data can only be read by `deserialize_delta` using an
+## equal baseline.
+func serialize_delta(baseline: Object, out: PackedByteArray) -> Error:
+	if baseline == null:
+		baseline = get_script().new()
+	assert(is_instance_of(baseline, get_script()), "Invalid argument; mismatched message type")
+	var _writer := _Writer.new()
+	_encode_delta(baseline, _writer)
+	out.append_array(_writer.to_bytes())
+	return _writer.get_error()
+
+## `deserialize_delta` reads this message from a `PackedByteArray` written by
+## `serialize_delta`, copying unchanged fields from `baseline`.
+func deserialize_delta(baseline: Object, data: PackedByteArray) -> Error:
+	if baseline == null:
+		baseline = get_script().new()
+	assert(is_instance_of(baseline, get_script()), "Invalid argument; mismatched message type")
+	var _reader := _Reader.new(data)
+	_decode_delta(baseline, _reader)
+	return _reader.get_error()
+
+## `equals` returns whether `other` is a message of this type with equal fields.
+func equals(other: Object) -> bool:
+	if not is_instance_of(other, get_script()):
+		return false
+	if not _Value.equals(stats, other.stats):
+		return false
+	if not _Value.equals(status, other.status):
+		return false
+	if not _Value.equals(next, other.next):
+		return false
+	return true
+
+## `hash` returns a hash of this message's fields consistent with `equals`.
+func hash() -> int:
+	return _Value.hash_value([stats, status, next])
+
+## `is_default` returns whether all fields are set to their default values.
+func is_default() -> bool:
+	if not _Value.equals(stats, Stats.new()):
+		return false
+	if not _Value.equals(status, Status.new()):
+		return false
+	if next != null:
+		return false
+	return true
+
+## `clone` returns a deep copy of this message.
+func clone() -> RefCounted:
+	var _copy: RefCounted = get_script().new()
+	_copy.copy_from(self)
+	return _copy
+
+## `copy_from` replaces this message's fields with deep copies of `other`'s.
+func copy_from(other: Object) -> void:
+	assert(is_instance_of(other, get_script()), "Invalid argument; mismatched message type")
+	stats = _Value.copy(other.stats)
+	status = _Value.copy(other.status)
+	next = _Value.copy(other.next)
+
+## `merge_from` merges `other` into this message. Set scalar fields overwrite this
+## message's values, repeated fields are appended (and truncated to any maximum
+## length), map entries are inserted, and nested messages are merged recursively.
+func merge_from(other: Object) -> void:
+	assert(is_instance_of(other, get_script()), "Invalid argument; mismatched message type")
+	if other.stats != null:
+		if stats == null:
+			stats = other.stats.clone()
+		else:
+			stats.merge_from(other.stats)
+	if other.status != null:
+		if status == null:
+			status = other.status.clone()
+		else:
+			status.merge_from(other.status)
+	if other.next != null:
+		if next == null:
+			next = other.next.clone()
+		else:
+			next.merge_from(other.next)
+
+## `to_dict` converts this message into a JSON-compatible `Dictionary` keyed by
+## field name.
+func to_dict() -> Dictionary:
+	var _data := {}
+	_data["stats"] = _Value.to_json_value(stats)
+	_data["status"] = _Value.to_json_value(status)
+	_data["next"] = _Value.to_json_value(next)
+	return _data
+
+## `from_dict` replaces this message's fields with those in `data`, which has the
+## format produced by `to_dict`. Missing fields are reset to their defaults and
+## unknown keys are ignored.
+func from_dict(data: Dictionary) -> Error:
+	stats = Stats.new()
+	status = Status.new()
+	next = null
+	var _out := [null]
+	if data.has("stats"):
+		var _err := _Value.from_json_value(data["stats"], Stats, _out)
+		if _err != OK:
+			return _err
+		stats = _out[0]
+	if data.has("status"):
+		var _err := _Value.from_json_value(data["status"], Status, _out)
+		if _err != OK:
+			return _err
+		status = _out[0]
+	if data.has("next"):
+		var _err := _Value.from_json_value(data["next"], Player, _out)
+		if _err != OK:
+			return _err
+		next = _out[0]
+	return OK
+
+## `to_json` converts this message into a JSON string.
+func to_json() -> String:
+	return JSON.stringify(to_dict(), "", false, true)
+
+## `from_json` replaces this message's contents with those in the JSON string `text`.
+func from_json(text: String) -> Error:
+	var _data: Variant = JSON.parse_string(text)
+	if not _data is Dictionary:
+		return ERR_PARSE_ERROR
+	return from_dict(_data)
+
+# -- PRIVATE METHODS ----------------------------------------------------------------- #
+
+## `_encode` serializes fields to the writer.
+func _encode(_writer: _Writer) -> void:
+	if stats == null:
+		_writer.set_error(ERR_INVALID_DATA)
+		return
+	stats._encode(_writer)
+	if status == null:
+		_writer.set_error(ERR_INVALID_DATA)
+		return
+	status._encode(_writer)
+	if next == null:
+		_writer.set_error(ERR_INVALID_DATA)
+		return
+	next._encode(_writer)
+
+## `_decode` deserializes fields from the reader.
+func _decode(_reader: _Reader) -> Error:
+	if _reader.enter_message() != OK:
+		return _reader.get_error()
+	stats = Stats.new()
+	stats._decode(_reader)
+	if _reader.get_error() != OK:
+		return _reader.get_error()
+	status = Status.new()
+	status._decode(_reader)
+	if _reader.get_error() != OK:
+		return _reader.get_error()
+	next = Player.new()
+	next._decode(_reader)
+	if _reader.get_error() != OK:
+		return _reader.get_error()
+	_reader.exit_message()
+	return _reader.get_error()
+
+## `_encode_delta` serializes fields which differ from `baseline` to the writer.
+func _encode_delta(baseline: Object, _writer: _Writer) -> void:
+	var _changed: Array[bool] = [not _Value.equals(stats, baseline.stats), not _Value.equals(status, baseline.status), not _Value.equals(next, baseline.next)]
+	for _c in _changed:
+		_writer.write_bool(_c)
+	if _changed[0]:
+		if stats != null and baseline.stats != null:
+			_writer.write_bool(true)
+			stats._encode_delta(baseline.stats, _writer)
+		else:
+			_writer.write_bool(false)
+			if stats == null:
+				_writer.set_error(ERR_INVALID_DATA)
+				return
+			stats._encode(_writer)
+	if _changed[1]:
+		if status == null:
+			_writer.set_error(ERR_INVALID_DATA)
+			return
+		status._encode(_writer)
+	if _changed[2]:
+		if next != null and baseline.next != null:
+			_writer.write_bool(true)
+			next._encode_delta(baseline.next, _writer)
+		else:
+			_writer.write_bool(false)
+			if next == null:
+				_writer.set_error(ERR_INVALID_DATA)
+				return
+			next._encode(_writer)
+
+## `_decode_delta` deserializes fields which differ from `baseline` from the reader.
+func _decode_delta(baseline: Object, _reader: _Reader) -> Error:
+	if _reader.enter_message() != OK:
+		return _reader.get_error()
+	var _changed: Array[bool] = []
+	for _i in range(3):
+		_changed.append(_reader.read_bool())
+	if _reader.get_error() != OK:
+		return _reader.get_error()
+	if _changed[0]:
+		if _reader.read_bool():
+			if baseline.stats == null:
+				_reader.set_error(ERR_INVALID_DATA)
+				return _reader.get_error()
+			stats = Stats.new()
+			stats._decode_delta(baseline.stats, _reader)
+			if _reader.get_error() != OK:
+				return _reader.get_error()
+		else:
+			stats = Stats.new()
+			stats._decode(_reader)
+			if _reader.get_error() != OK:
+				return _reader.get_error()
+	else:
+		stats = _Value.copy(baseline.stats)
+	if _changed[1]:
+		status = Status.new()
+		status._decode(_reader)
+		if _reader.get_error() != OK:
+			return _reader.get_error()
+	else:
+		status = _Value.copy(baseline.status)
+	if _changed[2]:
+		if _reader.read_bool():
+			if baseline.next == null:
+				_reader.set_error(ERR_INVALID_DATA)
+				return _reader.get_error()
+			next = Player.new()
+			next._decode_delta(baseline.next, _reader)
+			if _reader.get_error() != OK:
+				return _reader.get_error()
+		else:
+			next = Player.new()
+			next._decode(_reader)
+			if _reader.get_error() != OK:
+				return _reader.get_error()
+	else:
+		next = _Value.copy(baseline.next)
+	_reader.exit_message()
+	return _reader.get_error()
+
+# -- ENGINE METHODS (OVERRIDES) ------------------------------------------------------ #
+
+func _init() -> void:
+	if Player == null:
+		Player = load(get_script().resource_path.get_base_dir().path_join("player.gd"))
+	stats = Stats.new()
+	status = Status.new()
+
+# -- DEBUGGING ----------------------------------------------------------------------- #
+
+func _to_string() -> String:
+	return "Player(stats=" + _Value.to_debug_string(stats) + ", status=" + _Value.to_debug_string(status) + ", next=" + _Value.to_debug_string(next) + ")"
+
+"#;
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_codec_generation_for_multiple_encoding_variations() {
+        // Given: A schema with fields using different encoding variations.
+        let pkg = PackageName::try_from(vec!["test"]).unwrap();
+        let schema = Schema {
+            packages: vec![Package {
+                name: pkg.clone(),
+                messages: vec![Message {
+                    descriptor: DescriptorBuilder::default()
+                        .package(pkg)
+                        .path(vec!["Encoded".to_string()])
+                        .build()
+                        .unwrap(),
+                    doc: None,
+                    fields: vec![
+                        Field {
+                            name: "varint_signed".to_string(),
+                            index: 0,
+                            encoding: Encoding {
+                                wire: WireFormat::LengthPrefixed { prefix_bits: 64 },
+                                native: NativeType::Int {
+                                    bits: 64,
+                                    signed: true,
+                                },
+                                transforms: vec![],
+                                padding_bits: None,
+                            },
+                            doc: None,
+                        },
+                        Field {
+                            name: "varint_unsigned".to_string(),
+                            index: 1,
+                            encoding: Encoding {
+                                wire: WireFormat::LengthPrefixed { prefix_bits: 64 },
+                                native: NativeType::Int {
+                                    bits: 64,
+                                    signed: false,
+                                },
+                                transforms: vec![],
+                                padding_bits: None,
+                            },
+                            doc: None,
+                        },
+                        Field {
+                            name: "zigzag".to_string(),
+                            index: 2,
+                            encoding: Encoding {
+                                wire: WireFormat::Bits { count: 16 },
+                                native: NativeType::Int {
+                                    bits: 16,
+                                    signed: true,
+                                },
+                                transforms: vec![Transform::ZigZag],
+                                padding_bits: None,
+                            },
+                            doc: None,
+                        },
+                    ],
+                    messages: vec![],
+                    enums: vec![],
+                }],
+                enums: vec![],
+            }],
+        };
+
+        // When: Generating GDScript code.
+        let output = GDScript::default().generate(&schema).unwrap();
+
+        // Then: The Encoded message file matches expected content exactly.
+        let actual = output.files.get(Path::new("test/encoded.gd")).unwrap();
+
+        let expected = r#"## DO NOT EDIT: Generated by 'baproto-gdscript'
+
+extends RefCounted
+
+# -- DEPENDENCIES -------------------------------------------------------------------- #
+
+const _Framing := preload("res://addons/baproto/runtime/framing.gd")
+const _Reader := preload("res://addons/baproto/runtime/reader.gd")
+const _Value := preload("res://addons/baproto/runtime/value.gd")
+const _Writer := preload("res://addons/baproto/runtime/writer.gd")
+
+# -- TYPES --------------------------------------------------------------------------- #
+
+
+# -- INITIALIZATION ------------------------------------------------------------------ #
+
+var varint_signed: int = 0
+var varint_unsigned: int = 0
+var zigzag: int = 0
+
+# -- PUBLIC METHODS ------------------------------------------------------------------ #
+
+## `serialize` writes this message to a `PackedByteArray`.
+func serialize(out: PackedByteArray) -> Error:
+	var _writer := _Writer.new()
+	_encode(_writer)
+	out.append_array(_writer.to_bytes())
+	return _writer.get_error()
+
+## `deserialize` reads this message from a `PackedByteArray`.
+func deserialize(data: PackedByteArray) -> Error:
+	var _reader := _Reader.new(data)
+	_decode(_reader)
+	return _reader.get_error()
+
+## `write_to` writes this message to `writer`, starting at its current bit position.
+## Returns the writer's error state.
+func write_to(writer: _Writer) -> Error:
+	_encode(writer)
+	return writer.get_error()
+
+## `read_from` reads this message from `reader`, starting at its current bit position.
+## Afterwards, `reader.get_position()` is the bit position following the message.
+func read_from(reader: _Reader) -> Error:
+	_decode(reader)
+	return reader.get_error()
+
+## `serialize_delimited` writes this message to a `PackedByteArray`, prefixed with its
+## length so that it can be followed by other data.
+func serialize_delimited(out: PackedByteArray) -> Error:
+	var _writer := _Writer.new()
+	_encode(_writer)
+	_Framing.write_frame(_writer.to_bytes(), out)
+	return _writer.get_error()
+
+## `deserialize_delimited` reads this message from the length-prefixed data in `data`
+## starting at `offset`. Returns the number of bytes consumed, `_Framing.INCOMPLETE`
+## if `data` ends before the message's data does, or `_Framing.INVALID` on error.
+func deserialize_delimited(data: PackedByteArray, offset: int = 0) -> int:
+	var _frame := [null]
+	var _size := _Framing.read_frame(data, offset, _frame)
+	if _size <= 0:
+		return _size
+	if deserialize(_frame[0]) != OK:
+		return _Framing.INVALID
+	return _size
+
+## `serialize_delta` writes the fields of this message which differ from `baseline`
+## to a `PackedByteArray`. The data can only be read by `deserialize_delta` using an
+## equal baseline.
+func serialize_delta(baseline: Object, out: PackedByteArray) -> Error:
+	if baseline == null:
+		baseline = get_script().new()
+	assert(is_instance_of(baseline, get_script()), "Invalid argument; mismatched message type")
+	var _writer := _Writer.new()
+	_encode_delta(baseline, _writer)
+	out.append_array(_writer.to_bytes())
+	return _writer.get_error()
+
+## `deserialize_delta` reads this message from a `PackedByteArray` written by
+## `serialize_delta`, copying unchanged fields from `baseline`.
+func deserialize_delta(baseline: Object, data: PackedByteArray) -> Error:
+	if baseline == null:
+		baseline = get_script().new()
+	assert(is_instance_of(baseline, get_script()), "Invalid argument; mismatched message type")
+	var _reader := _Reader.new(data)
+	_decode_delta(baseline, _reader)
+	return _reader.get_error()
+
+## `equals` returns whether `other` is a message of this type with equal fields.
+func equals(other: Object) -> bool:
+	if not is_instance_of(other, get_script()):
+		return false
+	if varint_signed != other.varint_signed:
+		return false
+	if varint_unsigned != other.varint_unsigned:
+		return false
+	if zigzag != other.zigzag:
+		return false
+	return true
+
+## `hash` returns a hash of this message's fields consistent with `equals`.
+func hash() -> int:
+	return _Value.hash_value([varint_signed, varint_unsigned, zigzag])
+
+## `is_default` returns whether all fields are set to their default values.
+func is_default() -> bool:
+	if varint_signed != 0:
+		return false
+	if varint_unsigned != 0:
+		return false
+	if zigzag != 0:
+		return false
+	return true
+
+## `clone` returns a deep copy of this message.
+func clone() -> RefCounted:
+	var _copy: RefCounted = get_script().new()
+	_copy.copy_from(self)
+	return _copy
+
+## `copy_from` replaces this message's fields with deep copies of `other`'s.
+func copy_from(other: Object) -> void:
+	assert(is_instance_of(other, get_script()), "Invalid argument; mismatched message type")
+	varint_signed = other.varint_signed
+	varint_unsigned = other.varint_unsigned
+	zigzag = other.zigzag
+
+## `merge_from` merges `other` into this message. Set scalar fields overwrite this
+## message's values, repeated fields are appended (and truncated to any maximum
+## length), map entries are inserted, and nested messages are merged recursively.
+func merge_from(other: Object) -> void:
+	assert(is_instance_of(other, get_script()), "Invalid argument; mismatched message type")
+	if other.varint_signed != 0:
+		varint_signed = other.varint_signed
+	if other.varint_unsigned != 0:
+		varint_unsigned = other.varint_unsigned
+	if other.zigzag != 0:
+		zigzag = other.zigzag
+
+## `to_dict` converts this message into a JSON-compatible `Dictionary` keyed by
+## field name.
+func to_dict() -> Dictionary:
+	var _data := {}
+	_data["varint_signed"] = _Value.to_json_value(varint_signed)
+	_data["varint_unsigned"] = _Value.to_json_value(varint_unsigned)
+	_data["zigzag"] = _Value.to_json_value(zigzag)
+	return _data
+
+## `from_dict` replaces this message's fields with those in `data`, which has the
+## format produced by `to_dict`. Missing fields are reset to their defaults and
+## unknown keys are ignored.
+func from_dict(data: Dictionary) -> Error:
+	varint_signed = 0
+	varint_unsigned = 0
+	zigzag = 0
+	var _out := [null]
+	if data.has("varint_signed"):
+		var _err := _Value.from_json_value(data["varint_signed"], TYPE_INT, _out)
+		if _err != OK:
+			return _err
+		varint_signed = _out[0]
+	if data.has("varint_unsigned"):
+		var _err := _Value.from_json_value(data["varint_unsigned"], TYPE_INT, _out)
+		if _err != OK:
+			return _err
+		varint_unsigned = _out[0]
+	if data.has("zigzag"):
+		var _err := _Value.from_json_value(data["zigzag"], TYPE_INT, _out)
+		if _err != OK:
+			return _err
+		zigzag = _out[0]
+	return OK
+
+## `to_json` converts this message into a JSON string.
+func to_json() -> String:
+	return JSON.stringify(to_dict(), "", false, true)
+
+## `from_json` replaces this message's contents with those in the JSON string `text`.
+func from_json(text: String) -> Error:
+	var _data: Variant = JSON.parse_string(text)
+	if not _data is Dictionary:
+		return ERR_PARSE_ERROR
+	return from_dict(_data)
+
+# -- PRIVATE METHODS ----------------------------------------------------------------- #
+
+## `_encode` serializes fields to the writer.
+func _encode(_writer: _Writer) -> void:
+	_writer.write_varint_signed(varint_signed)
+	_writer.write_varint_unsigned(varint_unsigned)
+	_writer.write_zigzag(zigzag, 16)
+
+## `_decode` deserializes fields from the reader.
+func _decode(_reader: _Reader) -> Error:
+	if _reader.enter_message() != OK:
+		return _reader.get_error()
+	varint_signed = _reader.read_varint_signed()
+	if _reader.get_error() != OK:
+		return _reader.get_error()
+	varint_unsigned = _reader.read_varint_unsigned()
+	if _reader.get_error() != OK:
+		return _reader.get_error()
+	zigzag = _reader.read_zigzag(16)
+	if _reader.get_error() != OK:
+		return _reader.get_error()
+	_reader.exit_message()
+	return _reader.get_error()
+
+## `_encode_delta` serializes fields which differ from `baseline` to the writer.
+func _encode_delta(baseline: Object, _writer: _Writer) -> void:
+	var _changed: Array[bool] = [varint_signed != baseline.varint_signed, varint_unsigned != baseline.varint_unsigned, zigzag != baseline.zigzag]
+	for _c in _changed:
+		_writer.write_bool(_c)
+	if _changed[0]:
+		_writer.write_varint_signed(varint_signed)
+	if _changed[1]:
+		_writer.write_varint_unsigned(varint_unsigned)
+	if _changed[2]:
+		_writer.write_zigzag(zigzag, 16)
+
+## `_decode_delta` deserializes fields which differ from `baseline` from the reader.
+func _decode_delta(baseline: Object, _reader: _Reader) -> Error:
+	if _reader.enter_message() != OK:
+		return _reader.get_error()
+	var _changed: Array[bool] = []
+	for _i in range(3):
+		_changed.append(_reader.read_bool())
+	if _reader.get_error() != OK:
+		return _reader.get_error()
+	if _changed[0]:
+		varint_signed = _reader.read_varint_signed()
+		if _reader.get_error() != OK:
+			return _reader.get_error()
+	else:
+		varint_signed = baseline.varint_signed
+	if _changed[1]:
+		varint_unsigned = _reader.read_varint_unsigned()
+		if _reader.get_error() != OK:
+			return _reader.get_error()
+	else:
+		varint_unsigned = baseline.varint_unsigned
+	if _changed[2]:
+		zigzag = _reader.read_zigzag(16)
+		if _reader.get_error() != OK:
+			return _reader.get_error()
+	else:
+		zigzag = baseline.zigzag
+	_reader.exit_message()
+	return _reader.get_error()
+
+# -- DEBUGGING ----------------------------------------------------------------------- #
+
+func _to_string() -> String:
+	return "Encoded(varint_signed=" + _Value.to_debug_string(varint_signed) + ", varint_unsigned=" + _Value.to_debug_string(varint_unsigned) + ", zigzag=" + _Value.to_debug_string(zigzag) + ")"
+
+"#;
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_codec_generation_for_padded_fields() {
+        // Given: A schema with fields that request alignment padding.
+        let pkg = PackageName::try_from(vec!["test"]).unwrap();
+        let schema = Schema {
+            packages: vec![Package {
+                name: pkg.clone(),
+                messages: vec![Message {
+                    descriptor: DescriptorBuilder::default()
+                        .package(pkg)
+                        .path(vec!["Padded".to_string()])
+                        .build()
+                        .unwrap(),
+                    doc: None,
+                    fields: vec![
+                        Field {
+                            name: "flag".to_string(),
+                            index: 0,
+                            encoding: Encoding {
+                                wire: WireFormat::Bits { count: 1 },
+                                native: NativeType::Bool,
+                                transforms: vec![],
+                                padding_bits: Some(7),
+                            },
+                            doc: None,
+                        },
+                        Field {
+                            name: "value".to_string(),
+                            index: 1,
+                            encoding: Encoding {
+                                wire: WireFormat::Bits { count: 12 },
+                                native: NativeType::Int {
+                                    bits: 16,
+                                    signed: false,
+                                },
+                                transforms: vec![],
+                                padding_bits: Some(4),
+                            },
+                            doc: None,
+                        },
+                        Field {
+                            name: "unpadded".to_string(),
+                            index: 2,
+                            encoding: Encoding {
+                                wire: WireFormat::Bits { count: 8 },
+                                native: NativeType::Int {
+                                    bits: 8,
+                                    signed: true,
+                                },
+                                transforms: vec![],
+                                padding_bits: Some(0),
+                            },
+                            doc: None,
+                        },
+                    ],
+                    messages: vec![],
+                    enums: vec![],
+                }],
+                enums: vec![],
+            }],
+        };
+
+        // When: Generating GDScript code.
+        let output = GDScript::default().generate(&schema).unwrap();
+
+        // Then: The Padded message file matches expected content exactly.
+        let actual = output.files.get(Path::new("test/padded.gd")).unwrap();
+
+        let expected = r#"## DO NOT EDIT: Generated by 'baproto-gdscript'
+
+extends RefCounted
+
+# -- DEPENDENCIES -------------------------------------------------------------------- #
+
+const _Framing := preload("res://addons/baproto/runtime/framing.gd")
+const _Reader := preload("res://addons/baproto/runtime/reader.gd")
+const _Value := preload("res://addons/baproto/runtime/value.gd")
+const _Writer := preload("res://addons/baproto/runtime/writer.gd")
+
+# -- TYPES --------------------------------------------------------------------------- #
+
+
+# -- INITIALIZATION ------------------------------------------------------------------ #
+
+var flag: bool = false
+var value: int = 0
+var unpadded: int = 0
+
+# -- PUBLIC METHODS ------------------------------------------------------------------ #
+
+## `serialize` writes this message to a `PackedByteArray`.
+func serialize(out: PackedByteArray) -> Error:
+	var _writer := _Writer.new()
+	_encode(_writer)
+	out.append_array(_writer.to_bytes())
+	return _writer.get_error()
+
+## `deserialize` reads this message from a `PackedByteArray`.
+func deserialize(data: PackedByteArray) -> Error:
+	var _reader := _Reader.new(data)
+	_decode(_reader)
+	return _reader.get_error()
+
+## `write_to` writes this message to `writer`, starting at its current bit position.
+## Returns the writer's error state.
+func write_to(writer: _Writer) -> Error:
+	_encode(writer)
+	return writer.get_error()
+
+## `read_from` reads this message from `reader`, starting at its current bit position.
+## Afterwards, `reader.get_position()` is the bit position following the message.
+func read_from(reader: _Reader) -> Error:
+	_decode(reader)
+	return reader.get_error()
+
+## `serialize_delimited` writes this message to a `PackedByteArray`, prefixed with its
+## length so that it can be followed by other data.
+func serialize_delimited(out: PackedByteArray) -> Error:
+	var _writer := _Writer.new()
+	_encode(_writer)
+	_Framing.write_frame(_writer.to_bytes(), out)
+	return _writer.get_error()
+
+## `deserialize_delimited` reads this message from the length-prefixed data in `data`
+## starting at `offset`. Returns the number of bytes consumed, `_Framing.INCOMPLETE`
+## if `data` ends before the message's data does, or `_Framing.INVALID` on error.
+func deserialize_delimited(data: PackedByteArray, offset: int = 0) -> int:
+	var _frame := [null]
+	var _size := _Framing.read_frame(data, offset, _frame)
+	if _size <= 0:
+		return _size
+	if deserialize(_frame[0]) != OK:
+		return _Framing.INVALID
+	return _size
+
+## `serialize_delta` writes the fields of this message which differ from `baseline`
+## to a `PackedByteArray`. The data can only be read by `deserialize_delta` using an
+## equal baseline.
+func serialize_delta(baseline: Object, out: PackedByteArray) -> Error:
+	if baseline == null:
+		baseline = get_script().new()
+	assert(is_instance_of(baseline, get_script()), "Invalid argument; mismatched message type")
+	var _writer := _Writer.new()
+	_encode_delta(baseline, _writer)
+	out.append_array(_writer.to_bytes())
+	return _writer.get_error()
+
+## `deserialize_delta` reads this message from a `PackedByteArray` written by
+## `serialize_delta`, copying unchanged fields from `baseline`.
+func deserialize_delta(baseline: Object, data: PackedByteArray) -> Error:
+	if baseline == null:
+		baseline = get_script().new()
+	assert(is_instance_of(baseline, get_script()), "Invalid argument; mismatched message type")
+	var _reader := _Reader.new(data)
+	_decode_delta(baseline, _reader)
+	return _reader.get_error()
+
+## `equals` returns whether `other` is a message of this type with equal fields.
+func equals(other: Object) -> bool:
+	if not is_instance_of(other, get_script()):
+		return false
+	if flag != other.flag:
+		return false
+	if value != other.value:
+		return false
+	if unpadded != other.unpadded:
+		return false
+	return true
+
+## `hash` returns a hash of this message's fields consistent with `equals`.
+func hash() -> int:
+	return _Value.hash_value([flag, value, unpadded])
+
+## `is_default` returns whether all fields are set to their default values.
+func is_default() -> bool:
+	if flag != false:
+		return false
+	if value != 0:
+		return false
+	if unpadded != 0:
+		return false
+	return true
+
+## `clone` returns a deep copy of this message.
+func clone() -> RefCounted:
+	var _copy: RefCounted = get_script().new()
+	_copy.copy_from(self)
+	return _copy
+
+## `copy_from` replaces this message's fields with deep copies of `other`'s.
+func copy_from(other: Object) -> void:
+	assert(is_instance_of(other, get_script()), "Invalid argument; mismatched message type")
+	flag = other.flag
+	value = other.value
+	unpadded = other.unpadded
+
+## `merge_from` merges `other` into this message. Set scalar fields overwrite this
+## message's values, repeated fields are appended (and truncated to any maximum
+## length), map entries are inserted, and nested messages are merged recursively.
+func merge_from(other: Object) -> void:
+	assert(is_instance_of(other, get_script()), "Invalid argument; mismatched message type")
+	if other.flag != false:
+		flag = other.flag
+	if other.value != 0:
+		value = other.value
+	if other.unpadded != 0:
+		unpadded = other.unpadded
+
+## `to_dict` converts this message into a JSON-compatible `Dictionary` keyed by
+## field name.
+func to_dict() -> Dictionary:
+	var _data := {}
+	_data["flag"] = _Value.to_json_value(flag)
+	_data["value"] = _Value.to_json_value(value)
+	_data["unpadded"] = _Value.to_json_value(unpadded)
+	return _data
+
+## `from_dict` replaces this message's fields with those in `data`, which has the
+## format produced by `to_dict`. Missing fields are reset to their defaults and
+## unknown keys are ignored.
+func from_dict(data: Dictionary) -> Error:
+	flag = false
+	value = 0
+	unpadded = 0
+	var _out := [null]
+	if data.has("flag"):
+		var _err := _Value.from_json_value(data["flag"], TYPE_BOOL, _out)
+		if _err != OK:
+			return _err
+		flag = _out[0]
+	if data.has("value"):
+		var _err := _Value.from_json_value(data["value"], TYPE_INT, _out)
+		if _err != OK:
+			return _err
+		value = _out[0]
+	if data.has("unpadded"):
+		var _err := _Value.from_json_value(data["unpadded"], TYPE_INT, _out)
+		if _err != OK:
+			return _err
+		unpadded = _out[0]
+	return OK
+
+## `to_json` converts this message into a JSON string.
+func to_json() -> String:
+	return JSON.stringify(to_dict(), "", false, true)
+
+## `from_json` replaces this message's contents with those in the JSON string `text`.
+func from_json(text: String) -> Error:
+	var _data: Variant = JSON.parse_string(text)
+	if not _data is Dictionary:
+		return ERR_PARSE_ERROR
+	return from_dict(_data)
+
+# -- PRIVATE METHODS ----------------------------------------------------------------- #
+
+## `_encode` serializes fields to the writer.
+func _encode(_writer: _Writer) -> void:
+	_writer.write_bool(flag)
+	_writer.write_padding(7)
+	_writer.write_bits(value, 12)
+	_writer.write_padding(4)
+	_writer.write_i8(unpadded)
+
+## `_decode` deserializes fields from the reader.
+func _decode(_reader: _Reader) -> Error:
+	if _reader.enter_message() != OK:
+		return _reader.get_error()
+	flag = _reader.read_bool()
+	if _reader.get_error() != OK:
+		return _reader.get_error()
+	_reader.skip_padding(7)
+	if _reader.get_error() != OK:
+		return _reader.get_error()
+	value = _reader.read_bits(12)
+	if _reader.get_error() != OK:
+		return _reader.get_error()
+	_reader.skip_padding(4)
+	if _reader.get_error() != OK:
+		return _reader.get_error()
+	unpadded = _reader.read_i8()
+	if _reader.get_error() != OK:
+		return _reader.get_error()
+	_reader.exit_message()
+	return _reader.get_error()
+
+## `_encode_delta` serializes fields which differ from `baseline` to the writer.
+func _encode_delta(baseline: Object, _writer: _Writer) -> void:
+	var _changed: Array[bool] = [flag != baseline.flag, value != baseline.value, unpadded != baseline.unpadded]
+	for _c in _changed:
+		_writer.write_bool(_c)
+	if _changed[0]:
+		_writer.write_bool(flag)
+		_writer.write_padding(7)
+	if _changed[1]:
+		_writer.write_bits(value, 12)
+		_writer.write_padding(4)
+	if _changed[2]:
+		_writer.write_i8(unpadded)
+
+## `_decode_delta` deserializes fields which differ from `baseline` from the reader.
+func _decode_delta(baseline: Object, _reader: _Reader) -> Error:
+	if _reader.enter_message() != OK:
+		return _reader.get_error()
+	var _changed: Array[bool] = []
+	for _i in range(3):
+		_changed.append(_reader.read_bool())
+	if _reader.get_error() != OK:
+		return _reader.get_error()
+	if _changed[0]:
+		flag = _reader.read_bool()
+		if _reader.get_error() != OK:
+			return _reader.get_error()
+		_reader.skip_padding(7)
+		if _reader.get_error() != OK:
+			return _reader.get_error()
+	else:
+		flag = baseline.flag
+	if _changed[1]:
+		value = _reader.read_bits(12)
+		if _reader.get_error() != OK:
+			return _reader.get_error()
+		_reader.skip_padding(4)
+		if _reader.get_error() != OK:
+			return _reader.get_error()
+	else:
+		value = baseline.value
+	if _changed[2]:
+		unpadded = _reader.read_i8()
+		if _reader.get_error() != OK:
+			return _reader.get_error()
+	else:
+		unpadded = baseline.unpadded
+	_reader.exit_message()
+	return _reader.get_error()
+
+# -- DEBUGGING ----------------------------------------------------------------------- #
+
+func _to_string() -> String:
+	return "Padded(flag=" + _Value.to_debug_string(flag) + ", value=" + _Value.to_debug_string(value) + ", unpadded=" + _Value.to_debug_string(unpadded) + ")"
+
+"#;
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_codec_generation_for_quantized_floats() {
+        // Given: A schema with float fields declaring quantization directives.
+        let pkg = PackageName::try_from(vec!["test"]).unwrap();
+        let schema = Schema {
+            packages: vec![Package {
+                name: pkg.clone(),
+                messages: vec![Message {
+                    descriptor: DescriptorBuilder::default()
+                        .package(pkg)
+                        .path(vec!["Snapshot".to_string()])
+                        .build()
+                        .unwrap(),
+                    doc: None,
+                    fields: vec![
+                        Field {
+                            name: "x".to_string(),
+                            index: 0,
+                            encoding: Encoding {
+                                wire: WireFormat::Bits { count: 32 },
+                                native: NativeType::Float { bits: 32 },
+                                transforms: vec![],
+                                padding_bits: None,
+                            },
+                            doc: Some(
+                                "Horizontal position.\n@gdscript quantize(min = -1024, max = 1024, precision = 0.01)"
+                                    .to_string(),
+                            ),
+                        },
+                        Field {
+                            name: "angle".to_string(),
+                            index: 1,
+                            encoding: Encoding {
+                                wire: WireFormat::Bits { count: 32 },
+                                native: NativeType::Float { bits: 32 },
+                                transforms: vec![],
+                                padding_bits: None,
+                            },
+                            doc: Some(
+                                "@gdscript quantize(min = 0, max = 360, bits = 12, clamp = false)"
+                                    .to_string(),
+                            ),
+                        },
+                    ],
+                    messages: vec![],
+                    enums: vec![],
+                }],
+                enums: vec![],
+            }],
+        };
+
+        // When: Generating GDScript code.
+        let output = GDScript::default().generate(&schema).unwrap();
+
+        // Then: The Snapshot message file matches expected content exactly.
+        let actual = output.files.get(Path::new("test/snapshot.gd")).unwrap();
+
+        let expected = r#"## DO NOT EDIT: Generated by 'baproto-gdscript'
+
+extends RefCounted
+
+# -- DEPENDENCIES -------------------------------------------------------------------- #
+
+const _Framing := preload("res://addons/baproto/runtime/framing.gd")
+const _Reader := preload("res://addons/baproto/runtime/reader.gd")
+const _Value := preload("res://addons/baproto/runtime/value.gd")
+const _Writer := preload("res://addons/baproto/runtime/writer.gd")
+
+# -- TYPES --------------------------------------------------------------------------- #
+
+
+# -- INITIALIZATION ------------------------------------------------------------------ #
+
+## Horizontal position.
+var x: float = 0.0
+var angle: float = 0.0
+
+# -- PUBLIC METHODS ------------------------------------------------------------------ #
+
+## `serialize` writes this message to a `PackedByteArray`.
+func serialize(out: PackedByteArray) -> Error:
+	var _writer := _Writer.new()
+	_encode(_writer)
+	out.append_array(_writer.to_bytes())
+	return _writer.get_error()
+
+## `deserialize` reads this message from a `PackedByteArray`.
+func deserialize(data: PackedByteArray) -> Error:
+	var _reader := _Reader.new(data)
+	_decode(_reader)
+	return _reader.get_error()
+
+## `write_to` writes this message to `writer`, starting at its current bit position.
+## Returns the writer's error state.
+func write_to(writer: _Writer) -> Error:
+	_encode(writer)
+	return writer.get_error()
+
+## `read_from` reads this message from `reader`, starting at its current bit position.
+## Afterwards, `reader.get_position()` is the bit position following the message.
+func read_from(reader: _Reader) -> Error:
+	_decode(reader)
+	return reader.get_error()
+
+## `serialize_delimited` writes this message to a `PackedByteArray`, prefixed with its
+## length so that it can be followed by other data.
+func serialize_delimited(out: PackedByteArray) -> Error:
+	var _writer := _Writer.new()
+	_encode(_writer)
+	_Framing.write_frame(_writer.to_bytes(), out)
+	return _writer.get_error()
+
+## `deserialize_delimited` reads this message from the length-prefixed data in `data`
+## starting at `offset`. Returns the number of bytes consumed, `_Framing.INCOMPLETE`
+## if `data` ends before the message's data does, or `_Framing.INVALID` on error.
+func deserialize_delimited(data: PackedByteArray, offset: int = 0) -> int:
+	var _frame := [null]
+	var _size := _Framing.read_frame(data, offset, _frame)
+	if _size <= 0:
+		return _size
+	if deserialize(_frame[0]) != OK:
+		return _Framing.INVALID
+	return _size
+
+## `serialize_delta` writes the fields of this message which differ from `baseline`
+## to a `PackedByteArray`. The data can only be read by `deserialize_delta` using an
+## equal baseline.
+func serialize_delta(baseline: Object, out: PackedByteArray) -> Error:
+	if baseline == null:
+		baseline = get_script().new()
+	assert(is_instance_of(baseline, get_script()), "Invalid argument; mismatched message type")
+	var _writer := _Writer.new()
+	_encode_delta(baseline, _writer)
+	out.append_array(_writer.to_bytes())
+	return _writer.get_error()
+
+## `deserialize_delta` reads this message from a `PackedByteArray` written by
+## `serialize_delta`, copying unchanged fields from `baseline`.
+func deserialize_delta(baseline: Object, data: PackedByteArray) -> Error:
+	if baseline == null:
+		baseline = get_script().new()
+	assert(is_instance_of(baseline, get_script()), "Invalid argument; mismatched message type")
+	var _reader := _Reader.new(data)
+	_decode_delta(baseline, _reader)
+	return _reader.get_error()
+
+## `equals` returns whether `other` is a message of this type with equal fields.
+func equals(other: Object) -> bool:
+	if not is_instance_of(other, get_script()):
+		return false
+	if x != other.x:
+		return false
+	if angle != other.angle:
+		return false
+	return true
+
+## `hash` returns a hash of this message's fields consistent with `equals`.
+func hash() -> int:
+	return _Value.hash_value([x, angle])
+
+## `is_default` returns whether all fields are set to their default values.
+func is_default() -> bool:
+	if x != 0.0:
+		return false
+	if angle != 0.0:
+		return false
+	return true
+
+## `clone` returns a deep copy of this message.
+func clone() -> RefCounted:
+	var _copy: RefCounted = get_script().new()
+	_copy.copy_from(self)
+	return _copy
+
+## `copy_from` replaces this message's fields with deep copies of `other`'s.
+func copy_from(other: Object) -> void:
+	assert(is_instance_of(other, get_script()), "Invalid argument; mismatched message type")
+	x = other.x
+	angle = other.angle
+
+## `merge_from` merges `other` into this message. Set scalar fields overwrite this
+## message's values, repeated fields are appended (and truncated to any maximum
+## length), map entries are inserted, and nested messages are merged recursively.
+func merge_from(other: Object) -> void:
+	assert(is_instance_of(other, get_script()), "Invalid argument; mismatched message type")
+	if other.x != 0.0:
+		x = other.x
+	if other.angle != 0.0:
+		angle = other.angle
+
+## `to_dict` converts this message into a JSON-compatible `Dictionary` keyed by
+## field name.
+func to_dict() -> Dictionary:
+	var _data := {}
+	_data["x"] = _Value.to_json_value(x)
+	_data["angle"] = _Value.to_json_value(angle)
+	return _data
+
+## `from_dict` replaces this message's fields with those in `data`, which has the
+## format produced by `to_dict`. Missing fields are reset to their defaults and
+## unknown keys are ignored.
+func from_dict(data: Dictionary) -> Error:
+	x = 0.0
+	angle = 0.0
+	var _out := [null]
+	if data.has("x"):
+		var _err := _Value.from_json_value(data["x"], TYPE_FLOAT, _out)
+		if _err != OK:
+			return _err
+		x = _out[0]
+	if data.has("angle"):
+		var _err := _Value.from_json_value(data["angle"], TYPE_FLOAT, _out)
+		if _err != OK:
+			return _err
+		angle = _out[0]
+	return OK
+
+## `to_json` converts this message into a JSON string.
+func to_json() -> String:
+	return JSON.stringify(to_dict(), "", false, true)
+
+## `from_json` replaces this message's contents with those in the JSON string `text`.
+func from_json(text: String) -> Error:
+	var _data: Variant = JSON.parse_string(text)
+	if not _data is Dictionary:
+		return ERR_PARSE_ERROR
+	return from_dict(_data)
+
+# -- PRIVATE METHODS ----------------------------------------------------------------- #
+
+## `_encode` serializes fields to the writer.
+func _encode(_writer: _Writer) -> void:
+	_writer.write_quantized(x, -1024.0, 1024.0, 18)
+	_writer.write_quantized(angle, 0.0, 360.0, 12, false)
+
+## `_decode` deserializes fields from the reader.
+func _decode(_reader: _Reader) -> Error:
+	if _reader.enter_message() != OK:
+		return _reader.get_error()
+	x = _reader.read_quantized(-1024.0, 1024.0, 18)
+	if _reader.get_error() != OK:
+		return _reader.get_error()
+	angle = _reader.read_quantized(0.0, 360.0, 12)
+	if _reader.get_error() != OK:
+		return _reader.get_error()
+	_reader.exit_message()
+	return _reader.get_error()
+
+## `_encode_delta` serializes fields which differ from `baseline` to the writer.
+func _encode_delta(baseline: Object, _writer: _Writer) -> void:
+	var _changed: Array[bool] = [x != baseline.x, angle != baseline.angle]
+	for _c in _changed:
+		_writer.write_bool(_c)
+	if _changed[0]:
+		_writer.write_quantized(x, -1024.0, 1024.0, 18)
+	if _changed[1]:
+		_writer.write_quantized(angle, 0.0, 360.0, 12, false)
+
+## `_decode_delta` deserializes fields which differ from `baseline` from the reader.
+func _decode_delta(baseline: Object, _reader: _Reader) -> Error:
+	if _reader.enter_message() != OK:
+		return _reader.get_error()
+	var _changed: Array[bool] = []
+	for _i in range(2):
+		_changed.append(_reader.read_bool())
+	if _reader.get_error() != OK:
+		return _reader.get_error()
+	if _changed[0]:
+		x = _reader.read_quantized(-1024.0, 1024.0, 18)
+		if _reader.get_error() != OK:
+			return _reader.get_error()
+	else:
+		x = baseline.x
+	if _changed[1]:
+		angle = _reader.read_quantized(0.0, 360.0, 12)
+		if _reader.get_error() != OK:
+			return _reader.get_error()
+	else:
+		angle = baseline.angle
+	_reader.exit_message()
+	return _reader.get_error()
+
+# -- DEBUGGING ----------------------------------------------------------------------- #
+
+func _to_string() -> String:
+	return "Snapshot(x=" + _Value.to_debug_string(x) + ", angle=" + _Value.to_debug_string(angle) + ")"
+
+"#;
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_codec_generation_for_length_directives() {
+        // Given: A schema with fields declaring fixed and bounded lengths.
+        let pkg = PackageName::try_from(vec!["test"]).unwrap();
+        let schema = Schema {
+            packages: vec![Package {
+                name: pkg.clone(),
+                messages: vec![Message {
+                    descriptor: DescriptorBuilder::default()
+                        .package(pkg)
+                        .path(vec!["Inventory".to_string()])
+                        .build()
+                        .unwrap(),
+                    doc: None,
+                    fields: vec![
+                        Field {
+                            name: "checksum".to_string(),
+                            index: 0,
+                            encoding: Encoding {
+                                wire: WireFormat::LengthPrefixed { prefix_bits: 32 },
+                                native: NativeType::Bytes,
+                                transforms: vec![],
+                                padding_bits: None,
+                            },
+                            doc: Some("@gdscript length(fixed = 4)".to_string()),
+                        },
+                        Field {
+                            name: "owner".to_string(),
+                            index: 1,
+                            encoding: Encoding {
+                                wire: WireFormat::LengthPrefixed { prefix_bits: 32 },
+                                native: NativeType::String,
+                                transforms: vec![],
+                                padding_bits: None,
+                            },
+                            doc: Some("@gdscript length(max = 9)".to_string()),
+                        },
+                        Field {
+                            name: "slots".to_string(),
+                            index: 2,
+                            encoding: Encoding {
+                                wire: WireFormat::LengthPrefixed { prefix_bits: 32 },
+                                native: NativeType::Array {
+                                    element: Box::new(Encoding {
+                                        wire: WireFormat::Bits { count: 32 },
+                                        native: NativeType::Int {
+                                            bits: 32,
+                                            signed: true,
+                                        },
+                                        transforms: vec![],
+                                        padding_bits: None,
+                                    }),
+                                },
+                                transforms: vec![],
+                                padding_bits: None,
+                            },
+                            doc: Some("@gdscript length(fixed = 3)".to_string()),
+                        },
+                    ],
+                    messages: vec![],
+                    enums: vec![],
+                }],
+                enums: vec![],
+            }],
+        };
+
+        // When: Generating GDScript code.
+        let output = GDScript::default().generate(&schema).unwrap();
+
+        // Then: The Inventory message file matches expected content exactly.
+        let actual = output.files.get(Path::new("test/inventory.gd")).unwrap();
+
+        let expected = r#"## DO NOT EDIT: Generated by 'baproto-gdscript'
+
+extends RefCounted
+
+# -- DEPENDENCIES -------------------------------------------------------------------- #
+
+const _Framing := preload("res://addons/baproto/runtime/framing.gd")
+const _Reader := preload("res://addons/baproto/runtime/reader.gd")
+const _Value := preload("res://addons/baproto/runtime/value.gd")
+const _Writer := preload("res://addons/baproto/runtime/writer.gd")
+
+# -- TYPES --------------------------------------------------------------------------- #
+
+
+# -- INITIALIZATION ------------------------------------------------------------------ #
+
+var checksum: PackedByteArray = _Value.sized_array(TYPE_PACKED_BYTE_ARRAY, 4)
+var owner: String = ""
+var slots: PackedInt32Array = _Value.sized_array(TYPE_PACKED_INT32_ARRAY, 3)
+
+# -- PUBLIC METHODS ------------------------------------------------------------------ #
+
+## `serialize` writes this message to a `PackedByteArray`.
+func serialize(out: PackedByteArray) -> Error:
+	var _writer := _Writer.new()
+	_encode(_writer)
+	out.append_array(_writer.to_bytes())
+	return _writer.get_error()
+
+## `deserialize` reads this message from a `PackedByteArray`.
+func deserialize(data: PackedByteArray) -> Error:
+	var _reader := _Reader.new(data)
+	_decode(_reader)
+	return _reader.get_error()
+
+## `write_to` writes this message to `writer`, starting at its current bit position.
+## Returns the writer's error state.
+func write_to(writer: _Writer) -> Error:
+	_encode(writer)
+	return writer.get_error()
+
+## `read_from` reads this message from `reader`, starting at its current bit position.
+## Afterwards, `reader.get_position()` is the bit position following the message.
+func read_from(reader: _Reader) -> Error:
+	_decode(reader)
+	return reader.get_error()
+
+## `serialize_delimited` writes this message to a `PackedByteArray`, prefixed with its
+## length so that it can be followed by other data.
+func serialize_delimited(out: PackedByteArray) -> Error:
+	var _writer := _Writer.new()
+	_encode(_writer)
+	_Framing.write_frame(_writer.to_bytes(), out)
+	return _writer.get_error()
+
+## `deserialize_delimited` reads this message from the length-prefixed data in `data`
+## starting at `offset`. Returns the number of bytes consumed, `_Framing.INCOMPLETE`
+## if `data` ends before the message's data does, or `_Framing.INVALID` on error.
+func deserialize_delimited(data: PackedByteArray, offset: int = 0) -> int:
+	var _frame := [null]
+	var _size := _Framing.read_frame(data, offset, _frame)
+	if _size <= 0:
+		return _size
+	if deserialize(_frame[0]) != OK:
+		return _Framing.INVALID
+	return _size
+
+## `serialize_delta` writes the fields of this message which differ from `baseline`
+## to a `PackedByteArray`. The data can only be read by `deserialize_delta` using an
+## equal baseline.
+func serialize_delta(baseline: Object, out: PackedByteArray) -> Error:
+	if baseline == null:
+		baseline = get_script().new()
+	assert(is_instance_of(baseline, get_script()), "Invalid argument; mismatched message type")
+	var _writer := _Writer.new()
+	_encode_delta(baseline, _writer)
+	out.append_array(_writer.to_bytes())
+	return _writer.get_error()
+
+## `deserialize_delta` reads this message from a `PackedByteArray` written by
+## `serialize_delta`, copying unchanged fields from `baseline`.
+func deserialize_delta(baseline: Object, data: PackedByteArray) -> Error:
+	if baseline == null:
+		baseline = get_script().new()
+	assert(is_instance_of(baseline, get_script()), "Invalid argument; mismatched message type")
+	var _reader := _Reader.new(data)
+	_decode_delta(baseline, _reader)
+	return _reader.get_error()
+
+## `equals` returns whether `other` is a message of this type with equal fields.
+func equals(other: Object) -> bool:
+	if not is_instance_of(other, get_script()):
+		return false
+	if checksum != other.checksum:
+		return false
+	if owner != other.owner:
+		return false
+	if slots != other.slots:
+		return false
+	return true
+
+## `hash` returns a hash of this message's fields consistent with `equals`.
+func hash() -> int:
+	return _Value.hash_value([checksum, owner, slots])
+
+## `is_default` returns whether all fields are set to their default values.
+func is_default() -> bool:
+	if checksum != _Value.sized_array(TYPE_PACKED_BYTE_ARRAY, 4):
+		return false
+	if owner != "":
+		return false
+	if slots != _Value.sized_array(TYPE_PACKED_INT32_ARRAY, 3):
+		return false
+	return true
+
+## `clone` returns a deep copy of this message.
+func clone() -> RefCounted:
+	var _copy: RefCounted = get_script().new()
+	_copy.copy_from(self)
+	return _copy
+
+## `copy_from` replaces this message's fields with deep copies of `other`'s.
+func copy_from(other: Object) -> void:
+	assert(is_instance_of(other, get_script()), "Invalid argument; mismatched message type")
+	checksum = _Value.copy(other.checksum)
+	owner = other.owner
+	slots = _Value.copy(other.slots)
+
+## `merge_from` merges `other` into this message. Set scalar fields overwrite this
+## message's values, repeated fields are appended (and truncated to any maximum
+## length), map entries are inserted, and nested messages are merged recursively.
+func merge_from(other: Object) -> void:
+	assert(is_instance_of(other, get_script()), "Invalid argument; mismatched message type")
+	if other.checksum != _Value.sized_array(TYPE_PACKED_BYTE_ARRAY, 4):
+		checksum = _Value.copy(other.checksum)
+	if other.owner != "":
+		owner = other.owner
+	if other.slots != _Value.sized_array(TYPE_PACKED_INT32_ARRAY, 3):
+		slots = _Value.copy(other.slots)
+
+## `to_dict` converts this message into a JSON-compatible `Dictionary` keyed by
+## field name.
+func to_dict() -> Dictionary:
+	var _data := {}
+	_data["checksum"] = _Value.to_json_value(checksum)
+	_data["owner"] = _Value.to_json_value(owner)
+	_data["slots"] = _Value.to_json_value(slots)
+	return _data
+
+## `from_dict` replaces this message's fields with those in `data`, which has the
+## format produced by `to_dict`. Missing fields are reset to their defaults and
+## unknown keys are ignored.
+func from_dict(data: Dictionary) -> Error:
+	checksum = _Value.sized_array(TYPE_PACKED_BYTE_ARRAY, 4)
+	owner = ""
+	slots = _Value.sized_array(TYPE_PACKED_INT32_ARRAY, 3)
+	var _out := [null]
+	if data.has("checksum"):
+		var _err := _Value.from_json_value(data["checksum"], TYPE_PACKED_BYTE_ARRAY, _out)
+		if _err != OK:
+			return _err
+		checksum = _out[0]
+	if data.has("owner"):
+		var _err := _Value.from_json_value(data["owner"], TYPE_STRING, _out)
+		if _err != OK:
+			return _err
+		owner = _out[0]
+	if data.has("slots"):
+		var _err := _Value.from_json_value(data["slots"], TYPE_PACKED_INT32_ARRAY, _out)
+		if _err != OK:
+			return _err
+		slots = _out[0]
+	return OK
+
+## `to_json` converts this message into a JSON string.
+func to_json() -> String:
+	return JSON.stringify(to_dict(), "", false, true)
+
+## `from_json` replaces this message's contents with those in the JSON string `text`.
+func from_json(text: String) -> Error:
+	var _data: Variant = JSON.parse_string(text)
+	if not _data is Dictionary:
+		return ERR_PARSE_ERROR
+	return from_dict(_data)
+
+# -- PRIVATE METHODS ----------------------------------------------------------------- #
+
+## `_encode` serializes fields to the writer.
+func _encode(_writer: _Writer) -> void:
+	if checksum.size() != 4:
+		_writer.set_error(ERR_PARAMETER_RANGE_ERROR)
+		return
+	_writer.write_bytes(checksum)
+	_writer.write_string(owner, 4, 9)
+	if slots.size() != 3:
+		_writer.set_error(ERR_PARAMETER_RANGE_ERROR)
+		return
+	_writer.write_int32_array(slots, 4)
+
+## `_decode` deserializes fields from the reader.
+func _decode(_reader: _Reader) -> Error:
+	if _reader.enter_message() != OK:
+		return _reader.get_error()
+	checksum = _reader.read_bytes(4)
+	if _reader.get_error() != OK:
+		return _reader.get_error()
+	owner = _reader.read_string(4, 9)
+	if _reader.get_error() != OK:
+		return _reader.get_error()
+	slots = _reader.read_int32_array(3, 4, true)
+	if _reader.get_error() != OK:
+		return _reader.get_error()
+	_reader.exit_message()
+	return _reader.get_error()
+
+## `_encode_delta` serializes fields which differ from `baseline` to the writer.
+func _encode_delta(baseline: Object, _writer: _Writer) -> void:
+	var _changed: Array[bool] = [checksum != baseline.checksum, owner != baseline.owner, slots != baseline.slots]
+	for _c in _changed:
+		_writer.write_bool(_c)
+	if _changed[0]:
+		if checksum.size() != 4:
+			_writer.set_error(ERR_PARAMETER_RANGE_ERROR)
+			return
+		_writer.write_bytes(checksum)
+	if _changed[1]:
+		_writer.write_string(owner, 4, 9)
+	if _changed[2]:
+		if slots.size() != 3:
+			_writer.set_error(ERR_PARAMETER_RANGE_ERROR)
+			return
+		_writer.write_int32_array(slots, 4)
+
+## `_decode_delta` deserializes fields which differ from `baseline` from the reader.
+func _decode_delta(baseline: Object, _reader: _Reader) -> Error:
+	if _reader.enter_message() != OK:
+		return _reader.get_error()
+	var _changed: Array[bool] = []
+	for _i in range(3):
+		_changed.append(_reader.read_bool())
+	if _reader.get_error() != OK:
+		return _reader.get_error()
+	if _changed[0]:
+		checksum = _reader.read_bytes(4)
+		if _reader.get_error() != OK:
+			return _reader.get_error()
+	else:
+		checksum = _Value.copy(baseline.checksum)
+	if _changed[1]:
+		owner = _reader.read_string(4, 9)
+		if _reader.get_error() != OK:
+			return _reader.get_error()
+	else:
+		owner = baseline.owner
+	if _changed[2]:
+		slots = _reader.read_int32_array(3, 4, true)
+		if _reader.get_error() != OK:
+			return _reader.get_error()
+	else:
+		slots = _Value.copy(baseline.slots)
+	_reader.exit_message()
+	return _reader.get_error()
+
+# -- DEBUGGING ----------------------------------------------------------------------- #
+
+func _to_string() -> String:
+	return "Inventory(checksum=" + _Value.to_debug_string(checksum) + ", owner=" + _Value.to_debug_string(owner) + ", slots=" + _Value.to_debug_string(slots) + ")"
+
+"#;
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_codec_generation_for_narrow_length_prefixes() {
+        // Given: A schema with fields declaring 8- and 16-bit length prefixes.
+        let pkg = PackageName::try_from(vec!["test"]).unwrap();
+        let prefixed = |prefix_bits: u8, native: NativeType| Encoding {
+            wire: WireFormat::LengthPrefixed { prefix_bits },
+            native,
+            transforms: vec![],
+            padding_bits: None,
+        };
+        let schema = Schema {
+            packages: vec![Package {
+                name: pkg.clone(),
+                messages: vec![Message {
+                    descriptor: DescriptorBuilder::default()
+                        .package(pkg)
+                        .path(vec!["Chat".to_string()])
+                        .build()
+                        .unwrap(),
+                    doc: None,
+                    fields: vec![
+                        Field {
+                            name: "sender".to_string(),
+                            index: 0,
+                            encoding: prefixed(8, NativeType::String),
+                            doc: None,
+                        },
+                        Field {
+                            name: "attachment".to_string(),
+                            index: 1,
+                            encoding: prefixed(16, NativeType::Bytes),
+                            doc: None,
+                        },
+                        Field {
+                            name: "mentions".to_string(),
+                            index: 2,
+                            encoding: prefixed(
+                                8,
+                                NativeType::Array {
+                                    element: Box::new(prefixed(16, NativeType::String)),
+                                },
+                            ),
+                            doc: None,
+                        },
+                    ],
+                    messages: vec![],
+                    enums: vec![],
+                }],
+                enums: vec![],
+            }],
+        };
+
+        // When: Generating GDScript code.
+        let output = GDScript::default().generate(&schema).unwrap();
+
+        // Then: The Chat message file matches expected content exactly.
+        let actual = output.files.get(Path::new("test/chat.gd")).unwrap();
+
+        let expected = r#"## DO NOT EDIT: Generated by 'baproto-gdscript'
+
+extends RefCounted
+
+# -- DEPENDENCIES -------------------------------------------------------------------- #
+
+const _Framing := preload("res://addons/baproto/runtime/framing.gd")
+const _Reader := preload("res://addons/baproto/runtime/reader.gd")
+const _Value := preload("res://addons/baproto/runtime/value.gd")
+const _Writer := preload("res://addons/baproto/runtime/writer.gd")
+
+# -- TYPES --------------------------------------------------------------------------- #
+
+
+# -- INITIALIZATION ------------------------------------------------------------------ #
+
+var sender: String = ""
+var attachment: PackedByteArray = PackedByteArray()
+var mentions: PackedStringArray = PackedStringArray()
+
+# -- PUBLIC METHODS ------------------------------------------------------------------ #
+
+## `serialize` writes this message to a `PackedByteArray`.
+func serialize(out: PackedByteArray) -> Error:
+	var _writer := _Writer.new()
+	_encode(_writer)
+	out.append_array(_writer.to_bytes())
+	return _writer.get_error()
+
+## `deserialize` reads this message from a `PackedByteArray`.
+func deserialize(data: PackedByteArray) -> Error:
+	var _reader := _Reader.new(data)
+	_decode(_reader)
+	return _reader.get_error()
+
+## `write_to` writes this message to `writer`, starting at its current bit position.
+## Returns the writer's error state.
+func write_to(writer: _Writer) -> Error:
+	_encode(writer)
+	return writer.get_error()
+
+## `read_from` reads this message from `reader`, starting at its current bit position.
+## Afterwards, `reader.get_position()` is the bit position following the message.
+func read_from(reader: _Reader) -> Error:
+	_decode(reader)
+	return reader.get_error()
+
+## `serialize_delimited` writes this message to a `PackedByteArray`, prefixed with its
+## length so that it can be followed by other data.
+func serialize_delimited(out: PackedByteArray) -> Error:
+	var _writer := _Writer.new()
+	_encode(_writer)
+	_Framing.write_frame(_writer.to_bytes(), out)
+	return _writer.get_error()
+
+## `deserialize_delimited` reads this message from the length-prefixed data in `data`
+## starting at `offset`. Returns the number of bytes consumed, `_Framing.INCOMPLETE`
+## if `data` ends before the message's data does, or `_Framing.INVALID` on error.
+func deserialize_delimited(data: PackedByteArray, offset: int = 0) -> int:
+	var _frame := [null]
+	var _size := _Framing.read_frame(data, offset, _frame)
+	if _size <= 0:
+		return _size
+	if deserialize(_frame[0]) != OK:
+		return _Framing.INVALID
+	return _size
+
+## `serialize_delta` writes the fields of this message which differ from `baseline`
+## to a `PackedByteArray`. The data can only be read by `deserialize_delta` using an
+## equal baseline.
+func serialize_delta(baseline: Object, out: PackedByteArray) -> Error:
+	if baseline == null:
+		baseline = get_script().new()
+	assert(is_instance_of(baseline, get_script()), "Invalid argument; mismatched message type")
+	var _writer := _Writer.new()
+	_encode_delta(baseline, _writer)
+	out.append_array(_writer.to_bytes())
+	return _writer.get_error()
+
+## `deserialize_delta` reads this message from a `PackedByteArray` written by
+## `serialize_delta`, copying unchanged fields from `baseline`.
+func deserialize_delta(baseline: Object, data: PackedByteArray) -> Error:
+	if baseline == null:
+		baseline = get_script().new()
+	assert(is_instance_of(baseline, get_script()), "Invalid argument; mismatched message type")
+	var _reader := _Reader.new(data)
+	_decode_delta(baseline, _reader)
+	return _reader.get_error()
+
+## `equals` returns whether `other` is a message of this type with equal fields.
+func equals(other: Object) -> bool:
+	if not is_instance_of(other, get_script()):
+		return false
+	if sender != other.sender:
+		return false
+	if attachment != other.attachment:
+		return false
+	if mentions != other.mentions:
+		return false
+	return true
+
+## `hash` returns a hash of this message's fields consistent with `equals`.
+func hash() -> int:
+	return _Value.hash_value([sender, attachment, mentions])
+
+## `is_default` returns whether all fields are set to their default values.
+func is_default() -> bool:
+	if sender != "":
+		return false
+	if not attachment.is_empty():
+		return false
+	if not mentions.is_empty():
+		return false
+	return true
+
+## `clone` returns a deep copy of this message.
+func clone() -> RefCounted:
+	var _copy: RefCounted = get_script().new()
+	_copy.copy_from(self)
+	return _copy
+
+## `copy_from` replaces this message's fields with deep copies of `other`'s.
+func copy_from(other: Object) -> void:
+	assert(is_instance_of(other, get_script()), "Invalid argument; mismatched message type")
+	sender = other.sender
+	attachment = _Value.copy(other.attachment)
+	mentions = _Value.copy(other.mentions)
+
+## `merge_from` merges `other` into this message. Set scalar fields overwrite this
+## message's values, repeated fields are appended (and truncated to any maximum
+## length), map entries are inserted, and nested messages are merged recursively.
+func merge_from(other: Object) -> void:
+	assert(is_instance_of(other, get_script()), "Invalid argument; mismatched message type")
+	if other.sender != "":
+		sender = other.sender
+	if not other.attachment.is_empty():
+		attachment = _Value.copy(other.attachment)
+	mentions.append_array(_Value.copy(other.mentions))
+
+## `to_dict` converts this message into a JSON-compatible `Dictionary` keyed by
+## field name.
+func to_dict() -> Dictionary:
+	var _data := {}
+	_data["sender"] = _Value.to_json_value(sender)
+	_data["attachment"] = _Value.to_json_value(attachment)
+	_data["mentions"] = _Value.to_json_value(mentions)
+	return _data
+
+## `from_dict` replaces this message's fields with those in `data`, which has the
+## format produced by `to_dict`. Missing fields are reset to their defaults and
+## unknown keys are ignored.
+func from_dict(data: Dictionary) -> Error:
+	sender = ""
+	attachment = PackedByteArray()
+	mentions = PackedStringArray()
+	var _out := [null]
+	if data.has("sender"):
+		var _err := _Value.from_json_value(data["sender"], TYPE_STRING, _out)
+		if _err != OK:
+			return _err
+		sender = _out[0]
+	if data.has("attachment"):
+		var _err := _Value.from_json_value(data["attachment"], TYPE_PACKED_BYTE_ARRAY, _out)
+		if _err != OK:
+			return _err
+		attachment = _out[0]
+	if data.has("mentions"):
+		var _err := _Value.from_json_value(data["mentions"], TYPE_PACKED_STRING_ARRAY, _out)
+		if _err != OK:
+			return _err
+		mentions = _out[0]
+	return OK
+
+## `to_json` converts this message into a JSON string.
+func to_json() -> String:
+	return JSON.stringify(to_dict(), "", false, true)
+
+## `from_json` replaces this message's contents with those in the JSON string `text`.
+func from_json(text: String) -> Error:
+	var _data: Variant = JSON.parse_string(text)
+	if not _data is Dictionary:
+		return ERR_PARSE_ERROR
+	return from_dict(_data)
+
+# -- PRIVATE METHODS ----------------------------------------------------------------- #
+
+## `_encode` serializes fields to the writer.
+func _encode(_writer: _Writer) -> void:
+	_writer.write_string(sender, 8)
+	_writer.write_size(attachment.size(), 16)
+	_writer.write_bytes(attachment)
+	_writer.write_size(mentions.size(), 8)
+	for _item in mentions:
+		_writer.write_string(_item, 16)
+
+## `_decode` deserializes fields from the reader.
+func _decode(_reader: _Reader) -> Error:
+	if _reader.enter_message() != OK:
+		return _reader.get_error()
+	sender = _reader.read_string(8)
+	if _reader.get_error() != OK:
+		return _reader.get_error()
+	attachment = _reader.read_bytes(_reader.read_size(16))
+	if _reader.get_error() != OK:
+		return _reader.get_error()
+	mentions = PackedStringArray()
+	for _i in range(_reader.read_length(8)):
+		var _temp: String
+		_temp = _reader.read_string(16)
+		if _reader.get_error() != OK:
+			return _reader.get_error()
+		mentions.append(_temp)
+	_reader.exit_message()
+	return _reader.get_error()
+
+## `_encode_delta` serializes fields which differ from `baseline` to the writer.
+func _encode_delta(baseline: Object, _writer: _Writer) -> void:
+	var _changed: Array[bool] = [sender != baseline.sender, attachment != baseline.attachment, mentions != baseline.mentions]
+	for _c in _changed:
+		_writer.write_bool(_c)
+	if _changed[0]:
+		_writer.write_string(sender, 8)
+	if _changed[1]:
+		_writer.write_size(attachment.size(), 16)
+		_writer.write_bytes(attachment)
+	if _changed[2]:
+		_writer.write_size(mentions.size(), 8)
+		for _item in mentions:
+			_writer.write_string(_item, 16)
+
+## `_decode_delta` deserializes fields which differ from `baseline` from the reader.
+func _decode_delta(baseline: Object, _reader: _Reader) -> Error:
+	if _reader.enter_message() != OK:
+		return _reader.get_error()
+	var _changed: Array[bool] = []
+	for _i in range(3):
+		_changed.append(_reader.read_bool())
+	if _reader.get_error() != OK:
+		return _reader.get_error()
+	if _changed[0]:
+		sender = _reader.read_string(8)
+		if _reader.get_error() != OK:
+			return _reader.get_error()
+	else:
+		sender = baseline.sender
+	if _changed[1]:
+		attachment = _reader.read_bytes(_reader.read_size(16))
+		if _reader.get_error() != OK:
+			return _reader.get_error()
+	else:
+		attachment = _Value.copy(baseline.attachment)
+	if _changed[2]:
+		mentions = PackedStringArray()
+		for _i in range(_reader.read_length(8)):
+			var _temp: String
+			_temp = _reader.read_string(16)
+			if _reader.get_error() != OK:
+				return _reader.get_error()
+			mentions.append(_temp)
+	else:
+		mentions = _Value.copy(baseline.mentions)
+	_reader.exit_message()
+	return _reader.get_error()
+
+# -- DEBUGGING ----------------------------------------------------------------------- #
+
+func _to_string() -> String:
+	return "Chat(sender=" + _Value.to_debug_string(sender) + ", attachment=" + _Value.to_debug_string(attachment) + ", mentions=" + _Value.to_debug_string(mentions) + ")"
+
+"#;
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_codec_generation_for_enum_discriminant_encoding() {
+        // Given: A schema with an enum using a 3-bit discriminant.
+        let pkg = PackageName::try_from(vec!["test"]).unwrap();
+        let schema = Schema {
+            packages: vec![Package {
+                name: pkg.clone(),
+                messages: vec![],
+                enums: vec![Enum {
+                    descriptor: DescriptorBuilder::default()
+                        .package(pkg)
+                        .path(vec!["Action".to_string()])
+                        .build()
+                        .unwrap(),
+                    discriminant: Encoding {
+                        wire: WireFormat::Bits { count: 3 },
+                        native: NativeType::Int {
+                            bits: 8,
+                            signed: false,
+                        },
+                        transforms: vec![],
+                        padding_bits: None,
+                    },
+                    doc: None,
+                    variants: vec![
+                        Variant::Unit {
+                            name: "IDLE".to_string(),
+                            index: 0,
+                            doc: None,
+                        },
+                        Variant::Unit {
+                            name: "JUMP".to_string(),
+                            index: 1,
+                            doc: None,
+                        },
+                    ],
+                }],
+            }],
+        };
+
+        // When: Generating GDScript code.
+        let output = GDScript::default().generate(&schema).unwrap();
+
+        // Then: The Action enum file matches expected content exactly.
+        let actual = output.files.get(Path::new("test/action.gd")).unwrap();
+
+        let expected = r#"## DO NOT EDIT: Generated by 'baproto-gdscript'
+
+extends RefCounted
+
+# -- DEPENDENCIES -------------------------------------------------------------------- #
+
+const _Framing := preload("res://addons/baproto/runtime/framing.gd")
+const _Reader := preload("res://addons/baproto/runtime/reader.gd")
+const _Value := preload("res://addons/baproto/runtime/value.gd")
+const _Writer := preload("res://addons/baproto/runtime/writer.gd")
+
+# -- DISCRIMINANTS ------------------------------------------------------------------- #
+
+enum {
+	NONE = -1,
+	IDLE = 0,
+	JUMP = 1,
+}
+
+# -- INITIALIZATION ------------------------------------------------------------------ #
+
+var _discriminant: int = NONE
+var _value: Variant = null
+
+# -- PUBLIC METHODS ------------------------------------------------------------------ #
+
+## `which` returns the current discriminant.
+func which() -> int:
+	return _discriminant
+
+## `is_none` checks if the enum is unset.
+func is_none() -> bool:
+	return _discriminant == NONE
+
+## `clear` sets the enum to NONE.
+func clear() -> void:
+	_discriminant = NONE
+	_value = null
+
+func has_idle() -> bool:
+	return _discriminant == IDLE
+
+func set_idle() -> void:
+	_discriminant = IDLE
+	_value = null
+
+func clear_idle() -> void:
+	if _discriminant == IDLE:
+		_discriminant = NONE
+		_value = null
+
+func has_jump() -> bool:
+	return _discriminant == JUMP
+
+func set_jump() -> void:
+	_discriminant = JUMP
+	_value = null
+
+func clear_jump() -> void:
+	if _discriminant == JUMP:
+		_discriminant = NONE
+		_value = null
+
+## `serialize` writes this enum to a `PackedByteArray`.
+func serialize(out: PackedByteArray) -> Error:
+	var _writer := _Writer.new()
+	self._encode(_writer)
+	out.append_array(_writer.to_bytes())
+	return _writer.get_error()
+
+## `deserialize` reads this enum from a `PackedByteArray`.
+func deserialize(data: PackedByteArray) -> Error:
+	var _reader := _Reader.new(data)
+	self._decode(_reader)
+	return _reader.get_error()
+
+## `write_to` writes this enum to `writer`, starting at its current bit position.
+## Returns the writer's error state.
+func write_to(writer: _Writer) -> Error:
+	_encode(writer)
+	return writer.get_error()
+
+## `read_from` reads this enum from `reader`, starting at its current bit position.
+## Afterwards, `reader.get_position()` is the bit position following the enum.
+func read_from(reader: _Reader) -> Error:
+	_decode(reader)
+	return reader.get_error()
+
+## `serialize_delimited` writes this enum to a `PackedByteArray`, prefixed with its
+## length so that it can be followed by other data.
+func serialize_delimited(out: PackedByteArray) -> Error:
+	var _writer := _Writer.new()
+	_encode(_writer)
+	_Framing.write_frame(_writer.to_bytes(), out)
+	return _writer.get_error()
+
+## `deserialize_delimited` reads this enum from the length-prefixed data in `data`
+## starting at `offset`. Returns the number of bytes consumed, `_Framing.INCOMPLETE`
+## if `data` ends before the enum's data does, or `_Framing.INVALID` on error.
+func deserialize_delimited(data: PackedByteArray, offset: int = 0) -> int:
+	var _frame := [null]
+	var _size := _Framing.read_frame(data, offset, _frame)
+	if _size <= 0:
+		return _size
+	if deserialize(_frame[0]) != OK:
+		return _Framing.INVALID
+	return _size
+
+## `equals` returns whether `other` is an enum of this type with an equal value.
+func equals(other: Object) -> bool:
+	if not is_instance_of(other, get_script()):
+		return false
+	if _discriminant != other._discriminant:
+		return false
+	return _Value.equals(_value, other._value)
+
+## `hash` returns a hash of this enum's value consistent with `equals`.
+func hash() -> int:
+	return _Value.hash_value([_discriminant, _value])
+
+## `is_default` returns whether the enum is unset (i.e. NONE).
+func is_default() -> bool:
+	return is_none()
+
+## `clone` returns a deep copy of this enum.
+func clone() -> RefCounted:
+	var _copy: RefCounted = get_script().new()
+	_copy.copy_from(self)
+	return _copy
+
+## `copy_from` replaces this enum's value with a deep copy of `other`'s.
+func copy_from(other: Object) -> void:
+	assert(is_instance_of(other, get_script()), "Invalid argument; mismatched enum type")
+	_discriminant = other._discriminant
+	_value = _Value.copy(other._value)
+
+## `merge_from` merges `other` into this enum. If both hold a message of the same
+## variant, the messages are merged; otherwise `other`'s value (if set) replaces
+## this enum's value.
+func merge_from(other: Object) -> void:
+	assert(is_instance_of(other, get_script()), "Invalid argument; mismatched enum type")
+	if other.is_none():
+		return
+	if _discriminant == other._discriminant and _value is Object:
+		_value.merge_from(other._value)
+		return
+	copy_from(other)
+
+## `to_dict` converts this enum into a JSON-compatible `Dictionary` with a single
+## entry mapping the variant name to its value (or an empty one if NONE).
+func to_dict() -> Dictionary:
+	match _discriminant:
+		IDLE:
+			return {"IDLE": null}
+		JUMP:
+			return {"JUMP": null}
+
+	return {}
+
+## `from_dict` replaces this enum's value with the one in `data`, which has the
+## format produced by `to_dict`.
+func from_dict(data: Dictionary) -> Error:
+	clear()
+	if data.is_empty():
+		return OK
+	if data.size() != 1:
+		return ERR_INVALID_DATA
+	match data.keys()[0]:
+		"IDLE":
+			_discriminant = IDLE
+		"JUMP":
+			_discriminant = JUMP
+		_:
+			return ERR_INVALID_DATA
+
+	return OK
+
+## `to_json` converts this enum into a JSON string.
+func to_json() -> String:
+	return JSON.stringify(to_dict(), "", false, true)
+
+## `from_json` replaces this enum's contents with those in the JSON string `text`.
+func from_json(text: String) -> Error:
+	var _data: Variant = JSON.parse_string(text)
+	if not _data is Dictionary:
+		return ERR_PARSE_ERROR
+	return from_dict(_data)
+
+# -- PRIVATE METHODS ----------------------------------------------------------------- #
+
+## `_encode` serializes the enum to the writer.
+func _encode(_writer: _Writer) -> void:
+	if _discriminant == NONE:
+		_writer.set_error(ERR_INVALID_DATA)
+		return
+	_writer.write_bits(_discriminant, 3)
+	match _discriminant:
+		IDLE:
+			pass
+		JUMP:
+			pass
+
+
+## `_decode` deserializes the enum from the reader.
+func _decode(_reader: _Reader) -> Error:
+	if _reader.enter_message() != OK:
+		return _reader.get_error()
+	_discriminant = _reader.read_bits(3)
+	if _reader.get_error() != OK:
+		return _reader.get_error()
+	match _discriminant:
+		IDLE:
+			_value = null
+		JUMP:
+			_value = null
+		_:
+			_reader.set_error(ERR_INVALID_DATA)
+			return _reader.get_error()
+
+	_reader.exit_message()
+	return _reader.get_error()
+
+# -- ENGINE METHODS (OVERRIDES) ------------------------------------------------------ #
+
+func _init() -> void:
+	_discriminant = NONE
+	_value = null
+
+# -- DEBUGGING ----------------------------------------------------------------------- #
+
+func _to_string() -> String:
+	match _discriminant:
+		NONE:
+			return "<NONE>"
+		IDLE:
+			return "IDLE"
+		JUMP:
+			return "JUMP"
+
+	return ""
+
+"#;
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_codec_generation_for_builtin_godot_types() {
+        // Given: Well-known Godot types declared in the 'godot' package.
+        let godot = PackageName::try_from(vec!["godot"]).unwrap();
+        let descriptor = |name: &str| {
+            DescriptorBuilder::default()
+                .package(godot.clone())
+                .path(vec![name.to_string()])
+                .build()
+                .unwrap()
+        };
+        let component = |name: &str, index: u32, native: NativeType| Field {
+            name: name.to_string(),
+            index,
+            encoding: Encoding {
+                wire: WireFormat::Bits { count: 32 },
+                native,
+                transforms: vec![],
+                padding_bits: None,
+            },
+            doc: None,
+        };
+        let f32 = || NativeType::Float { bits: 32 };
+        let vector2 = || NativeType::Message {
+            descriptor: descriptor("Vector2"),
+        };
+        let builtin = |name: &str, fields: Vec<Field>| Message {
+            descriptor: descriptor(name),
+            doc: None,
+            fields,
+            messages: vec![],
+            enums: vec![],
+        };
+
+        // Given: A schema with a message using those types.
+        let pkg = PackageName::try_from(vec!["test"]).unwrap();
+        let schema = Schema {
+            packages: vec![
+                Package {
+                    name: godot.clone(),
+                    messages: vec![
+                        builtin(
+                            "Vector2",
+                            vec![component("x", 0, f32()), component("y", 1, f32())],
+                        ),
+                        builtin(
+                            "Vector3",
+                            vec![
+                                component("x", 0, f32()),
+                                component("y", 1, f32()),
+                                component("z", 2, f32()),
+                            ],
+                        ),
+                        builtin(
+                            "Transform2D",
+                            vec![
+                                component("x", 0, vector2()),
+                                component("y", 1, vector2()),
+                                component("origin", 2, vector2()),
+                            ],
+                        ),
+                    ],
+                    enums: vec![],
+                },
+                Package {
+                    name: pkg.clone(),
+                    messages: vec![Message {
+                        descriptor: DescriptorBuilder::default()
+                            .package(pkg)
+                            .path(vec!["Entity".to_string()])
+                            .build()
+                            .unwrap(),
+                        doc: None,
+                        fields: vec![
+                            component(
+                                "position",
+                                0,
+                                NativeType::Message {
+                                    descriptor: descriptor("Vector3"),
+                                },
+                            ),
+                            component(
+                                "transform",
+                                1,
+                                NativeType::Message {
+                                    descriptor: descriptor("Transform2D"),
+                                },
+                            ),
+                            Field {
+                                name: "path".to_string(),
+                                index: 2,
+                                encoding: Encoding {
+                                    wire: WireFormat::LengthPrefixed { prefix_bits: 64 },
+                                    native: NativeType::Array {
+                                        element: Box::new(Encoding {
+                                            wire: WireFormat::Bits { count: 32 },
+                                            native: vector2(),
+                                            transforms: vec![],
+                                            padding_bits: None,
+                                        }),
+                                    },
+                                    transforms: vec![],
+                                    padding_bits: None,
+                                },
+                                doc: None,
+                            },
+                        ],
+                        messages: vec![],
+                        enums: vec![],
+                    }],
+                    enums: vec![],
+                },
+            ],
+        };
+
+        // When: Generating GDScript code.
+        let output = GDScript::default().generate(&schema).unwrap();
+
+        // Then: No scripts are generated for the built-in types.
+        assert!(!output.files.contains_key(Path::new("godot/vector3.gd")));
+
+        // Then: The Entity message file matches expected content exactly.
+        let actual = output.files.get(Path::new("test/entity.gd")).unwrap();
+
+        let expected = r#"## DO NOT EDIT: Generated by 'baproto-gdscript'
+
+extends RefCounted
+
+# -- DEPENDENCIES -------------------------------------------------------------------- #
+
+const _Framing := preload("res://addons/baproto/runtime/framing.gd")
+const _Reader := preload("res://addons/baproto/runtime/reader.gd")
+const _Value := preload("res://addons/baproto/runtime/value.gd")
+const _Writer := preload("res://addons/baproto/runtime/writer.gd")
+
+# -- TYPES --------------------------------------------------------------------------- #
+
+
+# -- INITIALIZATION ------------------------------------------------------------------ #
+
+var position: Vector3 = Vector3()
+var transform: Transform2D = Transform2D()
+var path: PackedVector2Array = PackedVector2Array()
+
+# -- PUBLIC METHODS ------------------------------------------------------------------ #
+
+## `serialize` writes this message to a `PackedByteArray`.
+func serialize(out: PackedByteArray) -> Error:
+	var _writer := _Writer.new()
+	_encode(_writer)
+	out.append_array(_writer.to_bytes())
+	return _writer.get_error()
+
+## `deserialize` reads this message from a `PackedByteArray`.
+func deserialize(data: PackedByteArray) -> Error:
+	var _reader := _Reader.new(data)
+	_decode(_reader)
+	return _reader.get_error()
+
+## `write_to` writes this message to `writer`, starting at its current bit position.
+## Returns the writer's error state.
+func write_to(writer: _Writer) -> Error:
+	_encode(writer)
+	return writer.get_error()
+
+## `read_from` reads this message from `reader`, starting at its current bit position.
+## Afterwards, `reader.get_position()` is the bit position following the message.
+func read_from(reader: _Reader) -> Error:
+	_decode(reader)
+	return reader.get_error()
+
+## `serialize_delimited` writes this message to a `PackedByteArray`, prefixed with its
+## length so that it can be followed by other data.
+func serialize_delimited(out: PackedByteArray) -> Error:
+	var _writer := _Writer.new()
+	_encode(_writer)
+	_Framing.write_frame(_writer.to_bytes(), out)
+	return _writer.get_error()
+
+## `deserialize_delimited` reads this message from the length-prefixed data in `data`
+## starting at `offset`. Returns the number of bytes consumed, `_Framing.INCOMPLETE`
+## if `data` ends before the message's data does, or `_Framing.INVALID` on error.
+func deserialize_delimited(data: PackedByteArray, offset: int = 0) -> int:
+	var _frame := [null]
+	var _size := _Framing.read_frame(data, offset, _frame)
+	if _size <= 0:
+		return _size
+	if deserialize(_frame[0]) != OK:
+		return _Framing.INVALID
+	return _size
+
+## `serialize_delta` writes the fields of this message which differ from `baseline`
+## to a `PackedByteArray`. The data can only be read by `deserialize_delta` using an
+## equal baseline.
+func serialize_delta(baseline: Object, out: PackedByteArray) -> Error:
+	if baseline == null:
+		baseline = get_script().new()
+	assert(is_instance_of(baseline, get_script()), "Invalid argument; mismatched message type")
+	var _writer := _Writer.new()
+	_encode_delta(baseline, _writer)
+	out.append_array(_writer.to_bytes())
+	return _writer.get_error()
+
+## `deserialize_delta` reads this message from a `PackedByteArray` written by
+## `serialize_delta`, copying unchanged fields from `baseline`.
+func deserialize_delta(baseline: Object, data: PackedByteArray) -> Error:
+	if baseline == null:
+		baseline = get_script().new()
+	assert(is_instance_of(baseline, get_script()), "Invalid argument; mismatched message type")
+	var _reader := _Reader.new(data)
+	_decode_delta(baseline, _reader)
+	return _reader.get_error()
+
+## `equals` returns whether `other` is a message of this type with equal fields.
+func equals(other: Object) -> bool:
+	if not is_instance_of(other, get_script()):
+		return false
+	if position != other.position:
+		return false
+	if transform != other.transform:
+		return false
+	if path != other.path:
+		return false
+	return true
+
+## `hash` returns a hash of this message's fields consistent with `equals`.
+func hash() -> int:
+	return _Value.hash_value([position, transform, path])
+
+## `is_default` returns whether all fields are set to their default values.
+func is_default() -> bool:
+	if position != Vector3():
+		return false
+	if transform != Transform2D():
+		return false
+	if not path.is_empty():
+		return false
+	return true
+
+## `clone` returns a deep copy of this message.
+func clone() -> RefCounted:
+	var _copy: RefCounted = get_script().new()
+	_copy.copy_from(self)
+	return _copy
+
+## `copy_from` replaces this message's fields with deep copies of `other`'s.
+func copy_from(other: Object) -> void:
+	assert(is_instance_of(other, get_script()), "Invalid argument; mismatched message type")
+	position = other.position
+	transform = other.transform
+	path = _Value.copy(other.path)
+
+## `merge_from` merges `other` into this message. Set scalar fields overwrite this
+## message's values, repeated fields are appended (and truncated to any maximum
+## length), map entries are inserted, and nested messages are merged recursively.
+func merge_from(other: Object) -> void:
+	assert(is_instance_of(other, get_script()), "Invalid argument; mismatched message type")
+	if other.position != Vector3():
+		position = other.position
+	if other.transform != Transform2D():
+		transform = other.transform
+	path.append_array(_Value.copy(other.path))
+
+## `to_dict` converts this message into a JSON-compatible `Dictionary` keyed by
+## field name.
+func to_dict() -> Dictionary:
+	var _data := {}
+	_data["position"] = _Value.to_json_value(position)
+	_data["transform"] = _Value.to_json_value(transform)
+	_data["path"] = _Value.to_json_value(path)
+	return _data
+
+## `from_dict` replaces this message's fields with those in `data`, which has the
+## format produced by `to_dict`. Missing fields are reset to their defaults and
+## unknown keys are ignored.
+func from_dict(data: Dictionary) -> Error:
+	position = Vector3()
+	transform = Transform2D()
+	path = PackedVector2Array()
+	var _out := [null]
+	if data.has("position"):
+		var _err := _Value.from_json_value(data["position"], TYPE_VECTOR3, _out)
+		if _err != OK:
+			return _err
+		position = _out[0]
+	if data.has("transform"):
+		var _err := _Value.from_json_value(data["transform"], TYPE_TRANSFORM2D, _out)
+		if _err != OK:
+			return _err
+		transform = _out[0]
+	if data.has("path"):
+		var _err := _Value.from_json_value(data["path"], TYPE_PACKED_VECTOR2_ARRAY, _out)
+		if _err != OK:
+			return _err
+		path = _out[0]
+	return OK
+
+## `to_json` converts this message into a JSON string.
+func to_json() -> String:
+	return JSON.stringify(to_dict(), "", false, true)
+
+## `from_json` replaces this message's contents with those in the JSON string `text`.
+func from_json(text: String) -> Error:
+	var _data: Variant = JSON.parse_string(text)
+	if not _data is Dictionary:
+		return ERR_PARSE_ERROR
+	return from_dict(_data)
+
+# -- PRIVATE METHODS ----------------------------------------------------------------- #
+
+## `_encode` serializes fields to the writer.
+func _encode(_writer: _Writer) -> void:
+	_writer.write_vector3(position)
+	_writer.write_transform2d(transform)
+	_writer.write_size(path.size(), 64)
+	for _item in path:
+		_writer.write_vector2(_item)
+
+## `_decode` deserializes fields from the reader.
+func _decode(_reader: _Reader) -> Error:
+	if _reader.enter_message() != OK:
+		return _reader.get_error()
+	position = _reader.read_vector3()
+	if _reader.get_error() != OK:
+		return _reader.get_error()
+	transform = _reader.read_transform2d()
+	if _reader.get_error() != OK:
+		return _reader.get_error()
+	path = PackedVector2Array()
+	for _i in range(_reader.read_length(64)):
+		var _temp: Vector2
+		_temp = _reader.read_vector2()
+		if _reader.get_error() != OK:
+			return _reader.get_error()
+		path.append(_temp)
+	_reader.exit_message()
+	return _reader.get_error()
+
+## `_encode_delta` serializes fields which differ from `baseline` to the writer.
+func _encode_delta(baseline: Object, _writer: _Writer) -> void:
+	var _changed: Array[bool] = [position != baseline.position, transform != baseline.transform, path != baseline.path]
+	for _c in _changed:
+		_writer.write_bool(_c)
+	if _changed[0]:
+		_writer.write_vector3(position)
+	if _changed[1]:
+		_writer.write_transform2d(transform)
+	if _changed[2]:
+		_writer.write_size(path.size(), 64)
+		for _item in path:
+			_writer.write_vector2(_item)
+
+## `_decode_delta` deserializes fields which differ from `baseline` from the reader.
+func _decode_delta(baseline: Object, _reader: _Reader) -> Error:
+	if _reader.enter_message() != OK:
+		return _reader.get_error()
+	var _changed: Array[bool] = []
+	for _i in range(3):
+		_changed.append(_reader.read_bool())
+	if _reader.get_error() != OK:
+		return _reader.get_error()
+	if _changed[0]:
+		position = _reader.read_vector3()
+		if _reader.get_error() != OK:
+			return _reader.get_error()
+	else:
+		position = baseline.position
+	if _changed[1]:
+		transform = _reader.read_transform2d()
+		if _reader.get_error() != OK:
+			return _reader.get_error()
+	else:
+		transform = baseline.transform
+	if _changed[2]:
+		path = PackedVector2Array()
+		for _i in range(_reader.read_length(64)):
+			var _temp: Vector2
+			_temp = _reader.read_vector2()
+			if _reader.get_error() != OK:
+				return _reader.get_error()
+			path.append(_temp)
+	else:
+		path = _Value.copy(baseline.path)
+	_reader.exit_message()
+	return _reader.get_error()
+
+# -- DEBUGGING ----------------------------------------------------------------------- #
+
+func _to_string() -> String:
+	return "Entity(position=" + _Value.to_debug_string(position) + ", transform=" + _Value.to_debug_string(transform) + ", path=" + _Value.to_debug_string(path) + ")"
+
+"#;
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_codec_generation_for_complex_schema() {
+        // Given: A schema with a message containing multiple field types.
+        let pkg = PackageName::try_from(vec!["test"]).unwrap();
+
+        let item_descriptor = DescriptorBuilder::default()
+            .package(pkg.clone())
+            .path(vec!["Item".to_string()])
+            .build()
+            .unwrap();
+
+        let schema = Schema {
+            packages: vec![Package {
+                name: pkg.clone(),
+                messages: vec![
+                    Message {
+                        descriptor: item_descriptor.clone(),
+                        doc: None,
+                        fields: vec![Field {
+                            name: "id".to_string(),
+                            index: 0,
+                            encoding: Encoding {
+                                wire: WireFormat::Bits { count: 32 },
+                                native: NativeType::Int {
+                                    bits: 32,
+                                    signed: false,
+                                },
+                                transforms: vec![],
+                                padding_bits: None,
+                            },
+                            doc: None,
+                        }],
+                        messages: vec![],
+                        enums: vec![],
+                    },
+                    Message {
+                        descriptor: DescriptorBuilder::default()
+                            .package(pkg)
+                            .path(vec!["Complex".to_string()])
+                            .build()
+                            .unwrap(),
+                        doc: None,
+                        fields: vec![
+                            Field {
+                                name: "enabled".to_string(),
+                                index: 0,
+                                encoding: Encoding {
+                                    wire: WireFormat::Bits { count: 1 },
+                                    native: NativeType::Bool,
+                                    transforms: vec![],
+                                    padding_bits: None,
+                                },
+                                doc: None,
+                            },
+                            Field {
+                                name: "data".to_string(),
+                                index: 1,
+                                encoding: Encoding {
+                                    wire: WireFormat::LengthPrefixed { prefix_bits: 64 },
+                                    native: NativeType::Bytes,
+                                    transforms: vec![],
+                                    padding_bits: None,
+                                },
+                                doc: None,
+                            },
+                            Field {
+                                name: "values".to_string(),
+                                index: 2,
+                                encoding: Encoding {
+                                    wire: WireFormat::LengthPrefixed { prefix_bits: 64 },
+                                    native: NativeType::Array {
+                                        element: Box::new(Encoding {
+                                            wire: WireFormat::Bits { count: 32 },
+                                            native: NativeType::Int {
+                                                bits: 32,
+                                                signed: true,
+                                            },
+                                            transforms: vec![],
+                                            padding_bits: None,
+                                        }),
+                                    },
+                                    transforms: vec![],
+                                    padding_bits: None,
+                                },
+                                doc: None,
+                            },
+                            Field {
+                                name: "items".to_string(),
+                                index: 3,
+                                encoding: Encoding {
+                                    wire: WireFormat::LengthPrefixed { prefix_bits: 64 },
+                                    native: NativeType::Array {
+                                        element: Box::new(Encoding {
+                                            wire: WireFormat::LengthPrefixed { prefix_bits: 64 },
+                                            native: NativeType::Message {
+                                                descriptor: item_descriptor.clone(),
+                                            },
+                                            transforms: vec![],
+                                            padding_bits: None,
+                                        }),
+                                    },
+                                    transforms: vec![],
+                                    padding_bits: None,
+                                },
+                                doc: None,
+                            },
+                            Field {
+                                name: "metadata".to_string(),
+                                index: 4,
+                                encoding: Encoding {
+                                    wire: WireFormat::LengthPrefixed { prefix_bits: 64 },
+                                    native: NativeType::Map {
+                                        key: Box::new(Encoding {
+                                            wire: WireFormat::LengthPrefixed { prefix_bits: 64 },
+                                            native: NativeType::String,
+                                            transforms: vec![],
+                                            padding_bits: None,
+                                        }),
+                                        value: Box::new(Encoding {
+                                            wire: WireFormat::Bits { count: 32 },
+                                            native: NativeType::Int {
+                                                bits: 32,
+                                                signed: false,
+                                            },
+                                            transforms: vec![],
+                                            padding_bits: None,
+                                        }),
+                                    },
+                                    transforms: vec![],
+                                    padding_bits: None,
+                                },
+                                doc: None,
+                            },
+                            Field {
+                                name: "nested".to_string(),
+                                index: 5,
+                                encoding: Encoding {
+                                    wire: WireFormat::LengthPrefixed { prefix_bits: 64 },
+                                    native: NativeType::Message {
+                                        descriptor: item_descriptor,
+                                    },
+                                    transforms: vec![],
+                                    padding_bits: None,
+                                },
+                                doc: None,
+                            },
+                        ],
+                        messages: vec![],
+                        enums: vec![],
+                    },
+                ],
+                enums: vec![],
+            }],
+        };
+
+        // When: Generating GDScript code.
+        let output = GDScript::default().generate(&schema).unwrap();
+
+        // Then: The Complex message file matches expected content exactly.
+        let actual = output.files.get(Path::new("test/complex.gd")).unwrap();
+
+        let expected = r#"## DO NOT EDIT: Generated by 'baproto-gdscript'
+
+extends RefCounted
+
+# -- DEPENDENCIES -------------------------------------------------------------------- #
+
+const _Framing := preload("res://addons/baproto/runtime/framing.gd")
+const _Reader := preload("res://addons/baproto/runtime/reader.gd")
+const _Value := preload("res://addons/baproto/runtime/value.gd")
+const _Writer := preload("res://addons/baproto/runtime/writer.gd")
+const Item := preload("./item.gd")
+
+# -- TYPES --------------------------------------------------------------------------- #
+
+
+# -- INITIALIZATION ------------------------------------------------------------------ #
+
+var enabled: bool = false
+var data: PackedByteArray = PackedByteArray()
+var values: PackedInt32Array = PackedInt32Array()
+var items: Array[Item] = []
+var metadata: Dictionary[String, int] = {}
+var nested: Item = null
+
+# -- PUBLIC METHODS ------------------------------------------------------------------ #
+
+## `serialize` writes this message to a `PackedByteArray`.
+func serialize(out: PackedByteArray) -> Error:
+	var _writer := _Writer.new()
+	_encode(_writer)
+	out.append_array(_writer.to_bytes())
+	return _writer.get_error()
+
+## `deserialize` reads this message from a `PackedByteArray`.
+func deserialize(data: PackedByteArray) -> Error:
+	var _reader := _Reader.new(data)
+	_decode(_reader)
+	return _reader.get_error()
+
+## `write_to` writes this message to `writer`, starting at its current bit position.
+## Returns the writer's error state.
+func write_to(writer: _Writer) -> Error:
+	_encode(writer)
+	return writer.get_error()
+
+## `read_from` reads this message from `reader`, starting at its current bit position.
+## Afterwards, `reader.get_position()` is the bit position following the message.
+func read_from(reader: _Reader) -> Error:
+	_decode(reader)
+	return reader.get_error()
+
+## `serialize_delimited` writes this message to a `PackedByteArray`, prefixed with its
+## length so that it can be followed by other data.
+func serialize_delimited(out: PackedByteArray) -> Error:
+	var _writer := _Writer.new()
+	_encode(_writer)
+	_Framing.write_frame(_writer.to_bytes(), out)
+	return _writer.get_error()
+
+## `deserialize_delimited` reads this message from the length-prefixed data in `data`
+## starting at `offset`. Returns the number of bytes consumed, `_Framing.INCOMPLETE`
+## if `data` ends before the message's data does, or `_Framing.INVALID` on error.
+func deserialize_delimited(data: PackedByteArray, offset: int = 0) -> int:
+	var _frame := [null]
+	var _size := _Framing.read_frame(data, offset, _frame)
+	if _size <= 0:
+		return _size
+	if deserialize(_frame[0]) != OK:
+		return _Framing.INVALID
+	return _size
+
+## `serialize_delta` writes the fields of this message which differ from `baseline`
+## to a `PackedByteArray`. The data can only be read by `deserialize_delta` using an
+## equal baseline.
+func serialize_delta(baseline: Object, out: PackedByteArray) -> Error:
+	if baseline == null:
+		baseline = get_script().new()
+	assert(is_instance_of(baseline, get_script()), "Invalid argument; mismatched message type")
+	var _writer := _Writer.new()
+	_encode_delta(baseline, _writer)
+	out.append_array(_writer.to_bytes())
+	return _writer.get_error()
+
+## `deserialize_delta` reads this message from a `PackedByteArray` written by
+## `serialize_delta`, copying unchanged fields from `baseline`.
+func deserialize_delta(baseline: Object, data: PackedByteArray) -> Error:
+	if baseline == null:
+		baseline = get_script().new()
+	assert(is_instance_of(baseline, get_script()), "Invalid argument; mismatched message type")
+	var _reader := _Reader.new(data)
+	_decode_delta(baseline, _reader)
+	return _reader.get_error()
+
+## `equals` returns whether `other` is a message of this type with equal fields.
+func equals(other: Object) -> bool:
+	if not is_instance_of(other, get_script()):
+		return false
+	if enabled != other.enabled:
+		return false
+	if data != other.data:
+		return false
+	if values != other.values:
+		return false
+	if not _Value.equals(items, other.items):
+		return false
+	if not _Value.equals(metadata, other.metadata):
+		return false
+	if not _Value.equals(nested, other.nested):
+		return false
+	return true
+
+## `hash` returns a hash of this message's fields consistent with `equals`.
+func hash() -> int:
+	return _Value.hash_value([enabled, data, values, items, metadata, nested])
+
+## `is_default` returns whether all fields are set to their default values.
+func is_default() -> bool:
+	if enabled != false:
+		return false
+	if not data.is_empty():
+		return false
+	if not values.is_empty():
+		return false
+	if not items.is_empty():
+		return false
+	if not metadata.is_empty():
+		return false
+	if nested != null:
+		return false
+	return true
+
+## `clone` returns a deep copy of this message.
+func clone() -> RefCounted:
+	var _copy: RefCounted = get_script().new()
+	_copy.copy_from(self)
+	return _copy
+
+## `copy_from` replaces this message's fields with deep copies of `other`'s.
+func copy_from(other: Object) -> void:
+	assert(is_instance_of(other, get_script()), "Invalid argument; mismatched message type")
+	enabled = other.enabled
+	data = _Value.copy(other.data)
+	values = _Value.copy(other.values)
+	items = _Value.copy(other.items)
+	metadata = _Value.copy(other.metadata)
+	nested = _Value.copy(other.nested)
+
+## `merge_from` merges `other` into this message. Set scalar fields overwrite this
+## message's values, repeated fields are appended (and truncated to any maximum
+## length), map entries are inserted, and nested messages are merged recursively.
+func merge_from(other: Object) -> void:
+	assert(is_instance_of(other, get_script()), "Invalid argument; mismatched message type")
+	if other.enabled != false:
+		enabled = other.enabled
+	if not other.data.is_empty():
+		data = _Value.copy(other.data)
+	values.append_array(_Value.copy(other.values))
+	items.append_array(_Value.copy(other.items))
+	metadata.merge(_Value.copy(other.metadata), true)
+	if other.nested != null:
+		if nested == null:
+			nested = other.nested.clone()
+		else:
+			nested.merge_from(other.nested)
+
+## `to_dict` converts this message into a JSON-compatible `Dictionary` keyed by
+## field name.
+func to_dict() -> Dictionary:
+	var _data := {}
+	_data["enabled"] = _Value.to_json_value(enabled)
+	_data["data"] = _Value.to_json_value(data)
+	_data["values"] = _Value.to_json_value(values)
+	_data["items"] = _Value.to_json_value(items)
+	_data["metadata"] = _Value.to_json_value(metadata)
+	_data["nested"] = _Value.to_json_value(nested)
+	return _data
+
+## `from_dict` replaces this message's fields with those in `data`, which has the
+## format produced by `to_dict`. Missing fields are reset to their defaults and
+## unknown keys are ignored.
+func from_dict(data: Dictionary) -> Error:
+	enabled = false
+	data = PackedByteArray()
+	values = PackedInt32Array()
+	items = []
+	metadata = {}
+	nested = null
+	var _out := [null]
+	if data.has("enabled"):
+		var _err := _Value.from_json_value(data["enabled"], TYPE_BOOL, _out)
+		if _err != OK:
+			return _err
+		enabled = _out[0]
+	if data.has("data"):
+		var _err := _Value.from_json_value(data["data"], TYPE_PACKED_BYTE_ARRAY, _out)
+		if _err != OK:
+			return _err
+		data = _out[0]
+	if data.has("values"):
+		var _err := _Value.from_json_value(data["values"], TYPE_PACKED_INT32_ARRAY, _out)
+		if _err != OK:
+			return _err
+		values = _out[0]
+	if data.has("items"):
+		var _err := _Value.from_json_value(data["items"], [TYPE_ARRAY, Item], _out)
+		if _err != OK:
+			return _err
+		items.assign(_out[0])
+	if data.has("metadata"):
+		var _err := _Value.from_json_value(data["metadata"], [TYPE_DICTIONARY, TYPE_STRING, TYPE_INT], _out)
+		if _err != OK:
+			return _err
+		metadata.merge(_out[0])
+	if data.has("nested"):
+		var _err := _Value.from_json_value(data["nested"], Item, _out)
+		if _err != OK:
+			return _err
+		nested = _out[0]
+	return OK
+
+## `to_json` converts this message into a JSON string.
+func to_json() -> String:
+	return JSON.stringify(to_dict(), "", false, true)
+
+## `from_json` replaces this message's contents with those in the JSON string `text`.
+func from_json(text: String) -> Error:
+	var _data: Variant = JSON.parse_string(text)
+	if not _data is Dictionary:
+		return ERR_PARSE_ERROR
+	return from_dict(_data)
+
+# -- PRIVATE METHODS ----------------------------------------------------------------- #
+
+## `_encode` serializes fields to the writer.
+func _encode(_writer: _Writer) -> void:
+	_writer.write_bool(enabled)
+	_writer.write_size(data.size(), 64)
+	_writer.write_bytes(data)
+	_writer.write_size(values.size(), 64)
+	_writer.write_int32_array(values, 4)
+	_writer.write_size(items.size(), 64)
+	for _item in items:
+		if _item == null:
+			_writer.set_error(ERR_INVALID_DATA)
+			return
+		_item._encode(_writer)
+	_writer.write_size(metadata.size(), 64)
+	for _key in metadata:
+		_writer.write_string(_key, 64)
+		_writer.write_u32(metadata[_key])
+	if nested == null:
+		_writer.set_error(ERR_INVALID_DATA)
+		return
+	nested._encode(_writer)
+
+## `_decode` deserializes fields from the reader.
+func _decode(_reader: _Reader) -> Error:
+	if _reader.enter_message() != OK:
+		return _reader.get_error()
+	enabled = _reader.read_bool()
+	if _reader.get_error() != OK:
+		return _reader.get_error()
+	data = _reader.read_bytes(_reader.read_size(64))
+	if _reader.get_error() != OK:
+		return _reader.get_error()
+	values = _reader.read_int32_array(_reader.read_length(64), 4, true)
+	if _reader.get_error() != OK:
+		return _reader.get_error()
+	items = []
+	for _i in range(_reader.read_length(64)):
+		var _item := Item.new()
+		_item._decode(_reader)
+		if _reader.get_error() != OK:
+			return _reader.get_error()
+		items.append(_item)
+	metadata = {}
+	for _i in range(_reader.read_length(64)):
+		var _key := _reader.read_string(64)
+		if _reader.get_error() != OK:
+			return _reader.get_error()
+		metadata[_key] = _reader.read_u32()
+		if _reader.get_error() != OK:
+			return _reader.get_error()
+	nested = Item.new()
+	nested._decode(_reader)
+	if _reader.get_error() != OK:
+		return _reader.get_error()
+	_reader.exit_message()
+	return _reader.get_error()
+
+## `_encode_delta` serializes fields which differ from `baseline` to the writer.
+func _encode_delta(baseline: Object, _writer: _Writer) -> void:
+	var _changed: Array[bool] = [enabled != baseline.enabled, data != baseline.data, values != baseline.values, not _Value.equals(items, baseline.items), not _Value.equals(metadata, baseline.metadata), not _Value.equals(nested, baseline.nested)]
+	for _c in _changed:
+		_writer.write_bool(_c)
+	if _changed[0]:
+		_writer.write_bool(enabled)
+	if _changed[1]:
+		_writer.write_size(data.size(), 64)
+		_writer.write_bytes(data)
+	if _changed[2]:
+		_writer.write_size(values.size(), 64)
+		_writer.write_int32_array(values, 4)
+	if _changed[3]:
+		_writer.write_size(items.size(), 64)
+		for _item in items:
+			if _item == null:
+				_writer.set_error(ERR_INVALID_DATA)
+				return
+			_item._encode(_writer)
+	if _changed[4]:
+		_writer.write_size(metadata.size(), 64)
+		for _key in metadata:
+			_writer.write_string(_key, 64)
+			_writer.write_u32(metadata[_key])
+	if _changed[5]:
+		if nested != null and baseline.nested != null:
+			_writer.write_bool(true)
+			nested._encode_delta(baseline.nested, _writer)
+		else:
+			_writer.write_bool(false)
+			if nested == null:
+				_writer.set_error(ERR_INVALID_DATA)
+				return
+			nested._encode(_writer)
+
+## `_decode_delta` deserializes fields which differ from `baseline` from the reader.
+func _decode_delta(baseline: Object, _reader: _Reader) -> Error:
+	if _reader.enter_message() != OK:
+		return _reader.get_error()
+	var _changed: Array[bool] = []
+	for _i in range(6):
+		_changed.append(_reader.read_bool())
+	if _reader.get_error() != OK:
+		return _reader.get_error()
+	if _changed[0]:
+		enabled = _reader.read_bool()
+		if _reader.get_error() != OK:
+			return _reader.get_error()
+	else:
+		enabled = baseline.enabled
+	if _changed[1]:
+		data = _reader.read_bytes(_reader.read_size(64))
+		if _reader.get_error() != OK:
+			return _reader.get_error()
+	else:
+		data = _Value.copy(baseline.data)
+	if _changed[2]:
+		values = _reader.read_int32_array(_reader.read_length(64), 4, true)
+		if _reader.get_error() != OK:
+			return _reader.get_error()
+	else:
+		values = _Value.copy(baseline.values)
+	if _changed[3]:
+		items = []
+		for _i in range(_reader.read_length(64)):
+			var _item := Item.new()
+			_item._decode(_reader)
+			if _reader.get_error() != OK:
+				return _reader.get_error()
+			items.append(_item)
+	else:
+		items = _Value.copy(baseline.items)
+	if _changed[4]:
+		metadata = {}
+		for _i in range(_reader.read_length(64)):
+			var _key := _reader.read_string(64)
+			if _reader.get_error() != OK:
+				return _reader.get_error()
+			metadata[_key] = _reader.read_u32()
+			if _reader.get_error() != OK:
+				return _reader.get_error()
+	else:
+		metadata = _Value.copy(baseline.metadata)
+	if _changed[5]:
+		if _reader.read_bool():
+			if baseline.nested == null:
+				_reader.set_error(ERR_INVALID_DATA)
+				return _reader.get_error()
+			nested = Item.new()
+			nested._decode_delta(baseline.nested, _reader)
+			if _reader.get_error() != OK:
+				return _reader.get_error()
+		else:
+			nested = Item.new()
+			nested._decode(_reader)
+			if _reader.get_error() != OK:
+				return _reader.get_error()
+	else:
+		nested = _Value.copy(baseline.nested)
+	_reader.exit_message()
+	return _reader.get_error()
+
+# -- DEBUGGING ----------------------------------------------------------------------- #
+
+func _to_string() -> String:
+	return "Complex(enabled=" + _Value.to_debug_string(enabled) + ", data=" + _Value.to_debug_string(data) + ", values=" + _Value.to_debug_string(values) + ", items=" + _Value.to_debug_string(items) + ", metadata=" + _Value.to_debug_string(metadata) + ", nested=" + _Value.to_debug_string(nested) + ")"
+
+"#;
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_codec_generation_for_tagged_message() {
+        // Given: A schema with a message containing sparsely-indexed fields.
+        let pkg = PackageName::try_from(vec!["test"]).unwrap();
+        let schema = Schema {
+            packages: vec![Package {
+                name: pkg.clone(),
+                messages: vec![Message {
+                    descriptor: DescriptorBuilder::default()
+                        .package(pkg)
+                        .path(vec!["Player".to_string()])
+                        .build()
+                        .unwrap(),
+                    doc: None,
+                    fields: vec![
+                        Field {
+                            name: "level".to_string(),
+                            index: 0,
+                            encoding: Encoding {
+                                wire: WireFormat::Bits { count: 32 },
+                                native: NativeType::Int {
+                                    bits: 32,
+                                    signed: true,
+                                },
+                                transforms: vec![],
+                                padding_bits: None,
+                            },
+                            doc: None,
+                        },
+                        Field {
+                            name: "name".to_string(),
+                            index: 2,
+                            encoding: Encoding {
+                                wire: WireFormat::LengthPrefixed { prefix_bits: 64 },
+                                native: NativeType::String,
+                                transforms: vec![],
+                                padding_bits: None,
+                            },
+                            doc: None,
+                        },
+                        Field {
+                            name: "scores".to_string(),
+                            index: 3,
+                            encoding: Encoding {
+                                wire: WireFormat::LengthPrefixed { prefix_bits: 64 },
+                                native: NativeType::Array {
+                                    element: Box::new(Encoding {
+                                        wire: WireFormat::Bits { count: 32 },
+                                        native: NativeType::Float { bits: 32 },
+                                        transforms: vec![],
+                                        padding_bits: None,
+                                    }),
+                                },
+                                transforms: vec![],
+                                padding_bits: None,
+                            },
+                            doc: None,
+                        },
+                    ],
+                    messages: vec![],
+                    enums: vec![],
+                }],
+                enums: vec![],
+            }],
+        };
+
+        // Given: A generator configured to use the tagged wire format.
+        let generator = GDScript::new(Options {
+            tagged: true,
+            ..Default::default()
+        });
+
+        // When: Generating GDScript code.
+        let output = generator.generate(&schema).unwrap();
+
+        // Then: The Player message file matches expected content exactly.
+        let actual = output.files.get(Path::new("test/player.gd")).unwrap();
+
+        let expected = r#"## DO NOT EDIT: Generated by 'baproto-gdscript'
+
+extends RefCounted
+
+# -- DEPENDENCIES -------------------------------------------------------------------- #
+
+const _Framing := preload("res://addons/baproto/runtime/framing.gd")
+const _Reader := preload("res://addons/baproto/runtime/reader.gd")
+const _Value := preload("res://addons/baproto/runtime/value.gd")
+const _Writer := preload("res://addons/baproto/runtime/writer.gd")
+
+# -- TYPES --------------------------------------------------------------------------- #
+
+
+# -- INITIALIZATION ------------------------------------------------------------------ #
+
+var level: int = 0
+var name: String = ""
+var scores: PackedFloat32Array = PackedFloat32Array()
+## `_presence` tracks which fields were present in decoded data.
+var _presence: int = 0
+
+# -- PUBLIC METHODS ------------------------------------------------------------------ #
+
+## `serialize` writes this message to a `PackedByteArray`.
+func serialize(out: PackedByteArray) -> Error:
+	var _writer := _Writer.new()
+	_encode(_writer)
+	out.append_array(_writer.to_bytes())
+	return _writer.get_error()
+
+## `deserialize` reads this message from a `PackedByteArray`.
+func deserialize(data: PackedByteArray) -> Error:
+	var _reader := _Reader.new(data)
+	_decode(_reader)
+	return _reader.get_error()
+
+## `write_to` writes this message to `writer`, starting at its current bit position.
+## Returns the writer's error state.
+func write_to(writer: _Writer) -> Error:
+	_encode(writer)
+	return writer.get_error()
+
+## `read_from` reads this message from `reader`, starting at its current bit position.
+## Afterwards, `reader.get_position()` is the bit position following the message.
+func read_from(reader: _Reader) -> Error:
+	_decode(reader)
+	return reader.get_error()
+
+## `serialize_delimited` writes this message to a `PackedByteArray`, prefixed with its
+## length so that it can be followed by other data.
+func serialize_delimited(out: PackedByteArray) -> Error:
+	var _writer := _Writer.new()
+	_encode(_writer)
+	_Framing.write_frame(_writer.to_bytes(), out)
+	return _writer.get_error()
+
+## `deserialize_delimited` reads this message from the length-prefixed data in `data`
+## starting at `offset`. Returns the number of bytes consumed, `_Framing.INCOMPLETE`
+## if `data` ends before the message's data does, or `_Framing.INVALID` on error.
+func deserialize_delimited(data: PackedByteArray, offset: int = 0) -> int:
+	var _frame := [null]
+	var _size := _Framing.read_frame(data, offset, _frame)
+	if _size <= 0:
+		return _size
+	if deserialize(_frame[0]) != OK:
+		return _Framing.INVALID
+	return _size
+
+## `serialize_delta` writes the fields of this message which differ from `baseline`
+## to a `PackedByteArray`. The data can only be read by `deserialize_delta` using an
+## equal baseline.
+func serialize_delta(baseline: Object, out: PackedByteArray) -> Error:
+	if baseline == null:
+		baseline = get_script().new()
+	assert(is_instance_of(baseline, get_script()), "Invalid argument; mismatched message type")
+	var _writer := _Writer.new()
+	_encode_delta(baseline, _writer)
+	out.append_array(_writer.to_bytes())
+	return _writer.get_error()
+
+## `deserialize_delta` reads this message from a `PackedByteArray` written by
+## `serialize_delta`, copying unchanged fields from `baseline`.
+func deserialize_delta(baseline: Object, data: PackedByteArray) -> Error:
+	if baseline == null:
+		baseline = get_script().new()
+	assert(is_instance_of(baseline, get_script()), "Invalid argument; mismatched message type")
+	var _reader := _Reader.new(data)
+	_decode_delta(baseline, _reader)
+	return _reader.get_error()
+
+## `equals` returns whether `other` is a message of this type with equal fields.
+func equals(other: Object) -> bool:
+	if not is_instance_of(other, get_script()):
+		return false
+	if level != other.level:
+		return false
+	if name != other.name:
+		return false
+	if scores != other.scores:
+		return false
+	return true
+
+## `hash` returns a hash of this message's fields consistent with `equals`.
+func hash() -> int:
+	return _Value.hash_value([level, name, scores])
+
+## `is_default` returns whether all fields are set to their default values.
+func is_default() -> bool:
+	if level != 0:
+		return false
+	if name != "":
+		return false
+	if not scores.is_empty():
+		return false
+	return true
+
+## `clone` returns a deep copy of this message.
+func clone() -> RefCounted:
+	var _copy: RefCounted = get_script().new()
+	_copy.copy_from(self)
+	return _copy
+
+## `copy_from` replaces this message's fields with deep copies of `other`'s.
+func copy_from(other: Object) -> void:
+	assert(is_instance_of(other, get_script()), "Invalid argument; mismatched message type")
+	level = other.level
+	name = other.name
+	scores = _Value.copy(other.scores)
+	_presence = other._presence
+
+## `merge_from` merges `other` into this message. Set scalar fields overwrite this
+## message's values, repeated fields are appended (and truncated to any maximum
+## length), map entries are inserted, and nested messages are merged recursively.
+func merge_from(other: Object) -> void:
+	assert(is_instance_of(other, get_script()), "Invalid argument; mismatched message type")
+	if other.has_level():
+		level = other.level
+	if other.has_name():
+		name = other.name
+	scores.append_array(_Value.copy(other.scores))
+	_presence = _presence | other._presence
+
+## `to_dict` converts this message into a JSON-compatible `Dictionary` keyed by
+## field name.
+func to_dict() -> Dictionary:
+	var _data := {}
+	_data["level"] = _Value.to_json_value(level)
+	_data["name"] = _Value.to_json_value(name)
+	_data["scores"] = _Value.to_json_value(scores)
+	return _data
+
+## `from_dict` replaces this message's fields with those in `data`, which has the
+## format produced by `to_dict`. Missing fields are reset to their defaults and
+## unknown keys are ignored.
+func from_dict(data: Dictionary) -> Error:
+	level = 0
+	name = ""
+	scores = PackedFloat32Array()
+	_presence = 0
+	var _out := [null]
+	if data.has("level"):
+		var _err := _Value.from_json_value(data["level"], TYPE_INT, _out)
+		if _err != OK:
+			return _err
+		level = _out[0]
+		_presence = _presence | 1
+	if data.has("name"):
+		var _err := _Value.from_json_value(data["name"], TYPE_STRING, _out)
+		if _err != OK:
+			return _err
+		name = _out[0]
+		_presence = _presence | 2
+	if data.has("scores"):
+		var _err := _Value.from_json_value(data["scores"], TYPE_PACKED_FLOAT32_ARRAY, _out)
+		if _err != OK:
+			return _err
+		scores = _out[0]
+		_presence = _presence | 4
+	return OK
+
+## `to_json` converts this message into a JSON string.
+func to_json() -> String:
+	return JSON.stringify(to_dict(), "", false, true)
+
+## `from_json` replaces this message's contents with those in the JSON string `text`.
+func from_json(text: String) -> Error:
+	var _data: Variant = JSON.parse_string(text)
+	if not _data is Dictionary:
+		return ERR_PARSE_ERROR
+	return from_dict(_data)
+
+## `has_level` returns whether `level` was decoded or differs from its default.
+func has_level() -> bool:
+	return _presence & 1 != 0 or level != 0
+
+## `clear_level` resets `level` to its default and marks it as not decoded.
+func clear_level() -> void:
+	level = 0
+	_presence = _presence & ~1
+
+## `has_name` returns whether `name` was decoded or differs from its default.
+func has_name() -> bool:
+	return _presence & 2 != 0 or name != ""
+
+## `clear_name` resets `name` to its default and marks it as not decoded.
+func clear_name() -> void:
+	name = ""
+	_presence = _presence & ~2
+
+## `has_scores` returns whether `scores` was decoded or differs from its default.
+func has_scores() -> bool:
+	return _presence & 4 != 0 or scores.size() != 0
+
+## `clear_scores` resets `scores` to its default and marks it as not decoded.
+func clear_scores() -> void:
+	scores = PackedFloat32Array()
+	_presence = _presence & ~4
+
+# -- PRIVATE METHODS ----------------------------------------------------------------- #
+
+## `_encode` serializes fields to the writer.
+func _encode(_writer: _Writer) -> void:
+	if has_level():
+		var _start := _writer.begin_field(0)
+		_writer.write_i32(level)
+		_writer.end_field(_start)
+	if has_name():
+		var _start := _writer.begin_field(2)
+		_writer.write_string(name, 64)
+		_writer.end_field(_start)
+	if has_scores():
+		var _start := _writer.begin_field(3)
+		_writer.write_size(scores.size(), 64)
+		_writer.write_float32_array(scores)
+		_writer.end_field(_start)
+	_writer.end_fields()
+
+## `_decode` deserializes fields from the reader.
+func _decode(_reader: _Reader) -> Error:
+	if _reader.enter_message() != OK:
+		return _reader.get_error()
+	level = 0
+	name = ""
+	scores = PackedFloat32Array()
+	_presence = 0
+	var _index := _reader.read_field_index()
+	while _index >= 0:
+		var _end := _reader.read_field_end()
+		if _reader.get_error() != OK:
+			return _reader.get_error()
+		match _index:
+			0:
+				level = _reader.read_i32()
+				if _reader.get_error() != OK:
+					return _reader.get_error()
+				_presence = _presence | 1
+			2:
+				name = _reader.read_string(64)
+				if _reader.get_error() != OK:
+					return _reader.get_error()
+				_presence = _presence | 2
+			3:
+				scores = _reader.read_float32_array(_reader.read_length(64))
+				if _reader.get_error() != OK:
+					return _reader.get_error()
+				_presence = _presence | 4
+
+		_reader.skip_field(_end)
+		_index = _reader.read_field_index()
+	_reader.exit_message()
+	return _reader.get_error()
+
+## `_encode_delta` serializes fields which differ from `baseline` to the writer.
+func _encode_delta(baseline: Object, _writer: _Writer) -> void:
+	var _changed: Array[bool] = [level != baseline.level, name != baseline.name, scores != baseline.scores]
+	for _c in _changed:
+		_writer.write_bool(_c)
+	if _changed[0]:
+		_writer.write_i32(level)
+	if _changed[1]:
+		_writer.write_string(name, 64)
+	if _changed[2]:
+		_writer.write_size(scores.size(), 64)
+		_writer.write_float32_array(scores)
+
+## `_decode_delta` deserializes fields which differ from `baseline` from the reader.
+func _decode_delta(baseline: Object, _reader: _Reader) -> Error:
+	if _reader.enter_message() != OK:
+		return _reader.get_error()
+	_presence = baseline._presence
+	var _changed: Array[bool] = []
+	for _i in range(3):
+		_changed.append(_reader.read_bool())
+	if _reader.get_error() != OK:
+		return _reader.get_error()
+	if _changed[0]:
+		level = _reader.read_i32()
+		if _reader.get_error() != OK:
+			return _reader.get_error()
+		_presence = _presence | 1
+	else:
+		level = baseline.level
+	if _changed[1]:
+		name = _reader.read_string(64)
+		if _reader.get_error() != OK:
+			return _reader.get_error()
+		_presence = _presence | 2
+	else:
+		name = baseline.name
+	if _changed[2]:
+		scores = _reader.read_float32_array(_reader.read_length(64))
+		if _reader.get_error() != OK:
+			return _reader.get_error()
+		_presence = _presence | 4
+	else:
+		scores = _Value.copy(baseline.scores)
+	_reader.exit_message()
+	return _reader.get_error()
+
+# -- DEBUGGING ----------------------------------------------------------------------- #
+
+func _to_string() -> String:
+	return "Player(level=" + _Value.to_debug_string(level) + ", name=" + _Value.to_debug_string(name) + ", scores=" + _Value.to_debug_string(scores) + ")"
+
+"#;
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_codec_generation_for_recursive_messages() {
+        // Given: A schema with a message containing an array of its own type, as well
+        // as two messages which reference each other.
+        let pkg = PackageName::try_from(vec!["test"]).unwrap();
+
+        let descriptor = |name: &str| {
+            DescriptorBuilder::default()
+                .package(pkg.clone())
+                .path(vec![name.to_string()])
+                .build()
+                .unwrap()
+        };
+
+        let tree_node_descriptor = descriptor("TreeNode");
+        let ping_descriptor = descriptor("Ping");
+        let pong_descriptor = descriptor("Pong");
+
+        let message_encoding = |descriptor: &Descriptor| Encoding {
+            wire: WireFormat::LengthPrefixed { prefix_bits: 64 },
+            native: NativeType::Message {
+                descriptor: descriptor.clone(),
+            },
+            transforms: vec![],
+            padding_bits: None,
+        };
+
+        let message = |descriptor: &Descriptor, fields: Vec<Field>| Message {
+            descriptor: descriptor.clone(),
+            doc: None,
+            fields,
+            messages: vec![],
+            enums: vec![],
+        };
+
+        let schema = Schema {
+            packages: vec![Package {
+                name: pkg.clone(),
+                messages: vec![
+                    message(
+                        &tree_node_descriptor,
+                        vec![Field {
+                            name: "children".to_string(),
+                            index: 0,
+                            encoding: Encoding {
+                                wire: WireFormat::LengthPrefixed { prefix_bits: 64 },
+                                native: NativeType::Array {
+                                    element: Box::new(message_encoding(&tree_node_descriptor)),
+                                },
+                                transforms: vec![],
+                                padding_bits: None,
+                            },
+                            doc: None,
+                        }],
+                    ),
+                    message(
+                        &ping_descriptor,
+                        vec![Field {
+                            name: "pong".to_string(),
+                            index: 0,
+                            encoding: message_encoding(&pong_descriptor),
+                            doc: None,
+                        }],
+                    ),
+                    message(
+                        &pong_descriptor,
+                        vec![Field {
+                            name: "ping".to_string(),
+                            index: 0,
+                            encoding: message_encoding(&ping_descriptor),
+                            doc: None,
+                        }],
+                    ),
+                ],
+                enums: vec![],
+            }],
+        };
+
+        // When: Generating GDScript code.
+        let output = GDScript::new(Options::default()).generate(&schema).unwrap();
+
+        // Then: The mutually-referential messages load each other lazily.
+        for (file, field, dependency) in [("ping", "pong", "Pong"), ("pong", "ping", "Ping")] {
+            let path = format!("test/{}.gd", file);
+            let content = output.files.get(Path::new(&path)).unwrap();
+
+            assert!(content.contains(&format!("static var {}: GDScript = null\n", dependency)));
+            assert!(!content.contains(&format!("const {} := preload", dependency)));
+            assert!(content.contains(&format!("var {}: RefCounted = null\n", field)));
+        }
+
+        // Then: The TreeNode message file matches expected content exactly.
+        let actual = output.files.get(Path::new("test/treenode.gd")).unwrap();
+
+        let expected = r#"## DO NOT EDIT: Generated by 'baproto-gdscript'
+
+extends RefCounted
+
+# -- DEPENDENCIES -------------------------------------------------------------------- #
+
+const _Framing := preload("res://addons/baproto/runtime/framing.gd")
+const _Reader := preload("res://addons/baproto/runtime/reader.gd")
+const _Value := preload("res://addons/baproto/runtime/value.gd")
+const _Writer := preload("res://addons/baproto/runtime/writer.gd")
+static var TreeNode: GDScript = null
+
+# -- TYPES --------------------------------------------------------------------------- #
+
+
+# -- INITIALIZATION ------------------------------------------------------------------ #
+
+var children: Array[RefCounted] = []
+
+# -- PUBLIC METHODS ------------------------------------------------------------------ #
+
+## `serialize` writes this message to a `PackedByteArray`.
+func serialize(out: PackedByteArray) -> Error:
+	var _writer := _Writer.new()
+	_encode(_writer)
+	out.append_array(_writer.to_bytes())
+	return _writer.get_error()
+
+## `deserialize` reads this message from a `PackedByteArray`.
+func deserialize(data: PackedByteArray) -> Error:
+	var _reader := _Reader.new(data)
+	_decode(_reader)
+	return _reader.get_error()
+
+## `write_to` writes this message to `writer`, starting at its current bit position.
+## Returns the writer's error state.
+func write_to(writer: _Writer) -> Error:
+	_encode(writer)
+	return writer.get_error()
+
+## `read_from` reads this message from `reader`, starting at its current bit position.
+## Afterwards, `reader.get_position()` is the bit position following the message.
+func read_from(reader: _Reader) -> Error:
+	_decode(reader)
+	return reader.get_error()
+
+## `serialize_delimited` writes this message to a `PackedByteArray`, prefixed with its
+## length so that it can be followed by other data.
+func serialize_delimited(out: PackedByteArray) -> Error:
+	var _writer := _Writer.new()
+	_encode(_writer)
+	_Framing.write_frame(_writer.to_bytes(), out)
+	return _writer.get_error()
+
+## `deserialize_delimited` reads this message from the length-prefixed data in `data`
+## starting at `offset`. Returns the number of bytes consumed, `_Framing.INCOMPLETE`
+## if `data` ends before the message's data does, or `_Framing.INVALID` on error.
+func deserialize_delimited(data: PackedByteArray, offset: int = 0) -> int:
+	var _frame := [null]
+	var _size := _Framing.read_frame(data, offset, _frame)
+	if _size <= 0:
+		return _size
+	if deserialize(_frame[0]) != OK:
+		return _Framing.INVALID
+	return _size
+
+## `serialize_delta` writes the fields of this message which differ from `baseline`
+## to a `PackedByteArray`. The data can only be read by `deserialize_delta` using an
+## equal baseline.
+func serialize_delta(baseline: Object, out: PackedByteArray) -> Error:
+	if baseline == null:
+		baseline = get_script().new()
+	assert(is_instance_of(baseline, get_script()), "Invalid argument; mismatched message type")
+	var _writer := _Writer.new()
+	_encode_delta(baseline, _writer)
+	out.append_array(_writer.to_bytes())
+	return _writer.get_error()
+
+## `deserialize_delta` reads this message from a `PackedByteArray` written by
+## `serialize_delta`, copying unchanged fields from `baseline`.
+func deserialize_delta(baseline: Object, data: PackedByteArray) -> Error:
+	if baseline == null:
+		baseline = get_script().new()
+	assert(is_instance_of(baseline, get_script()), "Invalid argument; mismatched message type")
+	var _reader := _Reader.new(data)
+	_decode_delta(baseline, _reader)
+	return _reader.get_error()
+
+## `equals` returns whether `other` is a message of this type with equal fields.
+func equals(other: Object) -> bool:
+	if not is_instance_of(other, get_script()):
+		return false
+	if not _Value.equals(children, other.children):
+		return false
+	return true
+
+## `hash` returns a hash of this message's fields consistent with `equals`.
+func hash() -> int:
+	return _Value.hash_value([children])
+
+## `is_default` returns whether all fields are set to their default values.
+func is_default() -> bool:
+	if not children.is_empty():
+		return false
+	return true
+
+## `clone` returns a deep copy of this message.
+func clone() -> RefCounted:
+	var _copy: RefCounted = get_script().new()
+	_copy.copy_from(self)
+	return _copy
+
+## `copy_from` replaces this message's fields with deep copies of `other`'s.
+func copy_from(other: Object) -> void:
+	assert(is_instance_of(other, get_script()), "Invalid argument; mismatched message type")
+	children = _Value.copy(other.children)
+
+## `merge_from` merges `other` into this message. Set scalar fields overwrite this
+## message's values, repeated fields are appended (and truncated to any maximum
+## length), map entries are inserted, and nested messages are merged recursively.
+func merge_from(other: Object) -> void:
+	assert(is_instance_of(other, get_script()), "Invalid argument; mismatched message type")
+	children.append_array(_Value.copy(other.children))
+
+## `to_dict` converts this message into a JSON-compatible `Dictionary` keyed by
+## field name.
+func to_dict() -> Dictionary:
+	var _data := {}
+	_data["children"] = _Value.to_json_value(children)
+	return _data
+
+## `from_dict` replaces this message's fields with those in `data`, which has the
+## format produced by `to_dict`. Missing fields are reset to their defaults and
+## unknown keys are ignored.
+func from_dict(data: Dictionary) -> Error:
+	children = []
+	var _out := [null]
+	if data.has("children"):
+		var _err := _Value.from_json_value(data["children"], [TYPE_ARRAY, TreeNode], _out)
+		if _err != OK:
+			return _err
+		children.assign(_out[0])
+	return OK
+
+## `to_json` converts this message into a JSON string.
+func to_json() -> String:
+	return JSON.stringify(to_dict(), "", false, true)
+
+## `from_json` replaces this message's contents with those in the JSON string `text`.
+func from_json(text: String) -> Error:
+	var _data: Variant = JSON.parse_string(text)
+	if not _data is Dictionary:
+		return ERR_PARSE_ERROR
+	return from_dict(_data)
+
+# -- PRIVATE METHODS ----------------------------------------------------------------- #
+
+## `_encode` serializes fields to the writer.
+func _encode(_writer: _Writer) -> void:
+	_writer.write_size(children.size(), 64)
+	for _item in children:
+		if _item == null:
+			_writer.set_error(ERR_INVALID_DATA)
+			return
+		_item._encode(_writer)
+
+## `_decode` deserializes fields from the reader.
+func _decode(_reader: _Reader) -> Error:
+	if _reader.enter_message() != OK:
+		return _reader.get_error()
+	children = []
+	for _i in range(_reader.read_length(64)):
+		var _item: RefCounted = TreeNode.new()
+		_item._decode(_reader)
+		if _reader.get_error() != OK:
+			return _reader.get_error()
+		children.append(_item)
+	_reader.exit_message()
+	return _reader.get_error()
+
+## `_encode_delta` serializes fields which differ from `baseline` to the writer.
+func _encode_delta(baseline: Object, _writer: _Writer) -> void:
+	var _changed: Array[bool] = [not _Value.equals(children, baseline.children)]
+	for _c in _changed:
+		_writer.write_bool(_c)
+	if _changed[0]:
+		_writer.write_size(children.size(), 64)
+		for _item in children:
+			if _item == null:
+				_writer.set_error(ERR_INVALID_DATA)
+				return
+			_item._encode(_writer)
+
+## `_decode_delta` deserializes fields which differ from `baseline` from the reader.
+func _decode_delta(baseline: Object, _reader: _Reader) -> Error:
+	if _reader.enter_message() != OK:
+		return _reader.get_error()
+	var _changed: Array[bool] = []
+	for _i in range(1):
+		_changed.append(_reader.read_bool())
+	if _reader.get_error() != OK:
+		return _reader.get_error()
+	if _changed[0]:
+		children = []
+		for _i in range(_reader.read_length(64)):
+			var _item: RefCounted = TreeNode.new()
+			_item._decode(_reader)
+			if _reader.get_error() != OK:
+				return _reader.get_error()
+			children.append(_item)
+	else:
+		children = _Value.copy(baseline.children)
+	_reader.exit_message()
+	return _reader.get_error()
+
+# -- ENGINE METHODS (OVERRIDES) ------------------------------------------------------ #
+
+func _init() -> void:
+	if TreeNode == null:
+		TreeNode = load(get_script().resource_path.get_base_dir().path_join("treenode.gd"))
+
+# -- DEBUGGING ----------------------------------------------------------------------- #
+
+func _to_string() -> String:
+	return "TreeNode(children=" + _Value.to_debug_string(children) + ")"
+
 "#;
 
         assert_eq!(actual, expected);
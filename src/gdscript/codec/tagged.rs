@@ -0,0 +1,314 @@
+use std::collections::HashSet;
+
+use baproto::Field;
+
+use crate::gdscript::ast::{
+    Assignment, Block, Expr, FnCall, IfBuilder, Item, Literal, Match, MatchArm, Operator,
+    WhileBuilder,
+};
+use crate::gdscript::directive::FieldDirectives;
+use crate::gdscript::types::{escape_keyword, field_default_value};
+
+use super::decode::gen_reader_error_check;
+use super::{gen_decode_field_stmts, gen_encode_field_stmts};
+
+/* -------------------------------------------------------------------------- */
+/*                           Const: MAX_TAGGED_FIELDS                         */
+/* -------------------------------------------------------------------------- */
+
+/// `MAX_TAGGED_FIELDS` is the maximum number of fields in a tagged message, limited by
+/// the number of bits in the (signed) integer used to track field presence.
+const MAX_TAGGED_FIELDS: usize = 63;
+
+/* -------------------------------------------------------------------------- */
+/*                          Fn: check_tagged_fields                           */
+/* -------------------------------------------------------------------------- */
+
+/// `check_tagged_fields` validates that a message's fields can be encoded using the
+/// tagged wire format, which requires unique field indices.
+pub fn check_tagged_fields(fields: &[Field]) -> anyhow::Result<()> {
+    if fields.len() > MAX_TAGGED_FIELDS {
+        anyhow::bail!(
+            "Too many fields for tagged message: {} (max={})",
+            fields.len(),
+            MAX_TAGGED_FIELDS
+        );
+    }
+
+    let mut seen = HashSet::new();
+    for field in fields {
+        if !seen.insert(field.index) {
+            anyhow::bail!(
+                "Duplicate field index in tagged message: {} ({})",
+                field.index,
+                field.name
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/* -------------------------------------------------------------------------- */
+/*                             Fn: presence_mask                              */
+/* -------------------------------------------------------------------------- */
+
+/// `presence_mask` returns the bit within `_presence` which tracks whether the field at
+/// `position` (in declaration order) was present in decoded data.
+pub fn presence_mask(position: usize) -> i64 {
+    1 << position
+}
+
+/* -------------------------------------------------------------------------- */
+/*                         Fn: gen_tagged_encode_stmts                        */
+/* -------------------------------------------------------------------------- */
+
+/// `gen_tagged_encode_stmts` generates encoding statements for a message's fields using
+/// the tagged wire format. Only fields reported by `has_xxx()` are written, each
+/// preceded by its index and size so that readers can skip unknown fields.
+///
+/// # Generated GDScript
+/// ```gdscript
+/// if has_score():
+///     var _start := _writer.begin_field(1)
+///     _writer.write_i32(score)
+///     _writer.end_field(_start)
+/// _writer.end_fields()
+/// ```
+pub fn gen_tagged_encode_stmts(
+    fields: &[Field],
+    lazy: &HashSet<String>,
+) -> anyhow::Result<Vec<Item>> {
+    check_tagged_fields(fields)?;
+
+    let mut stmts = Vec::new();
+
+    for field in fields {
+        let field_name = escape_keyword(&field.name);
+        let directives = FieldDirectives::parse(field.doc.as_deref())?;
+
+        let begin_field = FnCall::method_args(
+            Expr::ident("_writer"),
+            "begin_field",
+            vec![Expr::Literal((field.index as i64).into())],
+        );
+
+        let mut body = vec![Assignment::var("_start", begin_field).into()];
+        body.extend(gen_encode_field_stmts(
+            &field_name,
+            &field.encoding,
+            &directives,
+            lazy,
+        )?);
+        body.push(
+            FnCall::method_args(
+                Expr::ident("_writer"),
+                "end_field",
+                vec![Expr::ident("_start")],
+            )
+            .into(),
+        );
+
+        stmts.push(
+            IfBuilder::default()
+                .condition(FnCall::function(format!("has_{}", field.name)))
+                .then_body(Block::from(body))
+                .build()
+                .unwrap()
+                .into(),
+        );
+    }
+
+    stmts.push(FnCall::method(Expr::ident("_writer"), "end_fields").into());
+
+    Ok(stmts)
+}
+
+/* -------------------------------------------------------------------------- */
+/*                         Fn: gen_tagged_decode_stmts                        */
+/* -------------------------------------------------------------------------- */
+
+/// `gen_tagged_decode_stmts` generates decoding statements for a message's fields using
+/// the tagged wire format. Fields are first reset to their defaults (constructing the
+/// `instantiated` fields), so that fields missing from the data keep their default
+/// values. Fields with unknown indices are skipped.
+///
+/// # Generated GDScript
+/// ```gdscript
+/// score = 0
+/// _presence = 0
+/// var _index := _reader.read_field_index()
+/// while _index >= 0:
+///     var _end := _reader.read_field_end()
+///     if _reader.get_error() != OK:
+///         return _reader.get_error()
+///     match _index:
+///         1:
+///             score = _reader.read_i32()
+///             if _reader.get_error() != OK:
+///                 return _reader.get_error()
+///             _presence = _presence | 1
+///     _reader.skip_field(_end)
+///     _index = _reader.read_field_index()
+/// ```
+pub fn gen_tagged_decode_stmts(
+    fields: &[Field],
+    instantiated: &HashSet<String>,
+    lazy: &HashSet<String>,
+) -> anyhow::Result<Vec<Item>> {
+    check_tagged_fields(fields)?;
+
+    let mut stmts = Vec::new();
+
+    // Reset fields to their defaults.
+    for field in fields {
+        let field_name = escape_keyword(&field.name);
+        let directives = FieldDirectives::parse(field.doc.as_deref())?;
+        let default_value = field_default_value(field, directives.length.as_ref(), instantiated);
+        stmts.push(Assignment::reassign(field_name, default_value).into());
+    }
+
+    if !fields.is_empty() {
+        stmts.push(Assignment::reassign("_presence", Expr::Literal(Literal::Int(0))).into());
+    }
+
+    // Read fields until the end marker.
+    let read_index = FnCall::method(Expr::ident("_reader"), "read_field_index");
+    stmts.push(Assignment::var("_index", read_index.clone()).into());
+
+    let mut body = vec![
+        Assignment::var(
+            "_end",
+            FnCall::method(Expr::ident("_reader"), "read_field_end"),
+        )
+        .into(),
+        gen_reader_error_check(),
+    ];
+
+    if !fields.is_empty() {
+        let mut arms = Vec::new();
+
+        for (position, field) in fields.iter().enumerate() {
+            let field_name = escape_keyword(&field.name);
+            let directives = FieldDirectives::parse(field.doc.as_deref())?;
+
+            let mut arm_body =
+                gen_decode_field_stmts(&field_name, &field.encoding, &directives, lazy)?;
+            arm_body.push(
+                Assignment::reassign(
+                    "_presence",
+                    Expr::binary_op(
+                        Expr::ident("_presence"),
+                        Operator::BitOr,
+                        Expr::Literal(Literal::Int(presence_mask(position))),
+                    ),
+                )
+                .into(),
+            );
+
+            arms.push(MatchArm {
+                pattern: Expr::Literal((field.index as i64).into()),
+                body: Block::from(arm_body),
+            });
+        }
+
+        body.push(
+            Match {
+                scrutinee: Expr::ident("_index"),
+                arms,
+            }
+            .into(),
+        );
+    }
+
+    // Skip any unread (or unknown) field data.
+    body.push(
+        FnCall::method_args(
+            Expr::ident("_reader"),
+            "skip_field",
+            vec![Expr::ident("_end")],
+        )
+        .into(),
+    );
+    body.push(Assignment::reassign("_index", read_index).into());
+
+    stmts.push(
+        WhileBuilder::default()
+            .condition(Expr::binary_op(
+                Expr::ident("_index"),
+                Operator::Ge,
+                Expr::Literal(Literal::Int(0)),
+            ))
+            .body(body)
+            .build()
+            .unwrap()
+            .into(),
+    );
+
+    Ok(stmts)
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                 Mod: Tests                                 */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+    use baproto::{Encoding, NativeType, WireFormat};
+
+    use super::*;
+
+    fn field(name: &str, index: u32) -> Field {
+        Field {
+            name: name.to_string(),
+            index,
+            encoding: Encoding {
+                wire: WireFormat::Bits { count: 1 },
+                native: NativeType::Bool,
+                transforms: vec![],
+                padding_bits: None,
+            },
+            doc: None,
+        }
+    }
+
+    /* ----------------------- Tests: check_tagged_fields ------------------- */
+
+    #[test]
+    fn test_check_tagged_fields_unique_indices() {
+        // Given: Fields with unique, non-contiguous indices.
+        let fields = vec![field("a", 0), field("b", 5)];
+
+        // When: Validating the fields.
+        let result = check_tagged_fields(&fields);
+
+        // Then: The fields are accepted.
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_check_tagged_fields_duplicate_index() {
+        // Given: Fields sharing an index.
+        let fields = vec![field("a", 1), field("b", 1)];
+
+        // When: Validating the fields.
+        let result = check_tagged_fields(&fields);
+
+        // Then: An error is returned.
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_check_tagged_fields_too_many() {
+        // Given: More fields than presence bits.
+        let fields = (0..=MAX_TAGGED_FIELDS as u32)
+            .map(|i| field(&format!("f{}", i), i))
+            .collect::<Vec<_>>();
+
+        // When: Validating the fields.
+        let result = check_tagged_fields(&fields);
+
+        // Then: An error is returned.
+        assert!(result.is_err());
+    }
+}
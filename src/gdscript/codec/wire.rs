@@ -1,6 +1,9 @@
 use baproto::{Encoding, NativeType, Transform, WireFormat};
 
-use crate::gdscript::ast::Expr;
+use crate::gdscript::ast::{Expr, Literal};
+use crate::gdscript::builtin::BuiltinType;
+use crate::gdscript::directive::{Length, Quantize};
+use crate::gdscript::types::packed_array_name;
 
 /* -------------------------------------------------------------------------- */
 /*                             Struct: CodecMethod                            */
@@ -21,6 +24,14 @@ pub struct CodecMethod {
 
 /// `get_write_method` returns the writer method name and extra arguments for an encoding.
 pub fn get_write_method(encoding: &Encoding) -> anyhow::Result<CodecMethod> {
+    // Built-in Godot types use the runtime's compact codec for the type.
+    if let Some(builtin) = BuiltinType::from_native(&encoding.native) {
+        return Ok(CodecMethod {
+            method: format!("write_{}", builtin.method_suffix()),
+            extra_args: vec![],
+        });
+    }
+
     let has_zigzag = encoding
         .transforms
         .iter()
@@ -121,7 +132,31 @@ pub fn get_write_method(encoding: &Encoding) -> anyhow::Result<CodecMethod> {
             extra_args: vec![],
         }),
 
-        // Integers with varint encoding
+        // Integers with arbitrary-width encoding
+        (NativeType::Int { signed: true, .. }, WireFormat::Bits { count })
+            if (1..=64).contains(count) =>
+        {
+            Ok(CodecMethod {
+                method: "write_bits_signed".to_string(),
+                extra_args: vec![Expr::Literal((*count as i64).into())],
+            })
+        }
+        (NativeType::Int { signed: false, .. }, WireFormat::Bits { count })
+            if (1..=64).contains(count) =>
+        {
+            Ok(CodecMethod {
+                method: "write_bits".to_string(),
+                extra_args: vec![Expr::Literal((*count as i64).into())],
+            })
+        }
+
+        // Integers with varint encoding (ZigZag is implied by the signed varint)
+        (NativeType::Int { .. }, WireFormat::LengthPrefixed { .. }) if has_zigzag => {
+            Ok(CodecMethod {
+                method: "write_varint_signed".to_string(),
+                extra_args: vec![],
+            })
+        }
         (NativeType::Int { signed: true, .. }, WireFormat::LengthPrefixed { .. }) => {
             Ok(CodecMethod {
                 method: "write_varint_signed".to_string(),
@@ -148,7 +183,7 @@ pub fn get_write_method(encoding: &Encoding) -> anyhow::Result<CodecMethod> {
         // String
         (NativeType::String, WireFormat::LengthPrefixed { .. }) => Ok(CodecMethod {
             method: "write_string".to_string(),
-            extra_args: vec![],
+            extra_args: vec![Expr::Literal(get_length_prefix_bits(encoding)?.into())],
         }),
 
         // Unsupported combinations
@@ -166,6 +201,14 @@ pub fn get_write_method(encoding: &Encoding) -> anyhow::Result<CodecMethod> {
 
 /// `get_read_method` returns the reader method name and extra arguments for an encoding.
 pub fn get_read_method(encoding: &Encoding) -> anyhow::Result<CodecMethod> {
+    // Built-in Godot types use the runtime's compact codec for the type.
+    if let Some(builtin) = BuiltinType::from_native(&encoding.native) {
+        return Ok(CodecMethod {
+            method: format!("read_{}", builtin.method_suffix()),
+            extra_args: vec![],
+        });
+    }
+
     let has_zigzag = encoding
         .transforms
         .iter()
@@ -266,7 +309,31 @@ pub fn get_read_method(encoding: &Encoding) -> anyhow::Result<CodecMethod> {
             extra_args: vec![],
         }),
 
-        // Integers with varint encoding
+        // Integers with arbitrary-width encoding
+        (NativeType::Int { signed: true, .. }, WireFormat::Bits { count })
+            if (1..=64).contains(count) =>
+        {
+            Ok(CodecMethod {
+                method: "read_bits_signed".to_string(),
+                extra_args: vec![Expr::Literal((*count as i64).into())],
+            })
+        }
+        (NativeType::Int { signed: false, .. }, WireFormat::Bits { count })
+            if (1..=64).contains(count) =>
+        {
+            Ok(CodecMethod {
+                method: "read_bits".to_string(),
+                extra_args: vec![Expr::Literal((*count as i64).into())],
+            })
+        }
+
+        // Integers with varint encoding (ZigZag is implied by the signed varint)
+        (NativeType::Int { .. }, WireFormat::LengthPrefixed { .. }) if has_zigzag => {
+            Ok(CodecMethod {
+                method: "read_varint_signed".to_string(),
+                extra_args: vec![],
+            })
+        }
         (NativeType::Int { signed: true, .. }, WireFormat::LengthPrefixed { .. }) => {
             Ok(CodecMethod {
                 method: "read_varint_signed".to_string(),
@@ -293,7 +360,7 @@ pub fn get_read_method(encoding: &Encoding) -> anyhow::Result<CodecMethod> {
         // String
         (NativeType::String, WireFormat::LengthPrefixed { .. }) => Ok(CodecMethod {
             method: "read_string".to_string(),
-            extra_args: vec![],
+            extra_args: vec![Expr::Literal(get_length_prefix_bits(encoding)?.into())],
         }),
 
         // Unsupported combinations
@@ -305,6 +372,309 @@ pub fn get_read_method(encoding: &Encoding) -> anyhow::Result<CodecMethod> {
     }
 }
 
+/* -------------------------------------------------------------------------- */
+/*                         Fn: get_bulk_write_method                          */
+/* -------------------------------------------------------------------------- */
+
+/// `get_bulk_write_method` returns the writer method name and extra arguments for
+/// writing all elements of a packed array at once, if the element encoding supports it.
+/// Only fixed-width, byte-sized elements without transforms or padding qualify.
+pub fn get_bulk_write_method(element: &Encoding) -> Option<CodecMethod> {
+    match get_bulk_element(element)? {
+        BulkElement::Int {
+            storage,
+            byte_count,
+            ..
+        } => Some(CodecMethod {
+            method: format!("write_int{}_array", storage),
+            extra_args: vec![Expr::Literal(byte_count.into())],
+        }),
+        BulkElement::Float { bits } => Some(CodecMethod {
+            method: format!("write_float{}_array", bits),
+            extra_args: vec![],
+        }),
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+/*                          Fn: get_bulk_read_method                          */
+/* -------------------------------------------------------------------------- */
+
+/// `get_bulk_read_method` returns the reader method name and extra arguments (following
+/// the element count) for reading all elements of a packed array at once, if the
+/// element encoding supports it. See [`get_bulk_write_method`].
+pub fn get_bulk_read_method(element: &Encoding) -> Option<CodecMethod> {
+    match get_bulk_element(element)? {
+        BulkElement::Int {
+            storage,
+            byte_count,
+            signed,
+        } => Some(CodecMethod {
+            method: format!("read_int{}_array", storage),
+            extra_args: vec![
+                Expr::Literal(byte_count.into()),
+                Expr::Literal(Literal::Bool(signed)),
+            ],
+        }),
+        BulkElement::Float { bits } => Some(CodecMethod {
+            method: format!("read_float{}_array", bits),
+            extra_args: vec![],
+        }),
+    }
+}
+
+/* ------------------------- Enum: BulkElement ------------------------------ */
+
+/// `BulkElement` describes a packed array element which the runtime can encode in bulk.
+enum BulkElement {
+    /// `Int` is an integer stored in a `PackedInt32Array` or `PackedInt64Array`.
+    Int {
+        storage: u8,
+        byte_count: i64,
+        signed: bool,
+    },
+    /// `Float` is a float stored in a `PackedFloat32Array` or `PackedFloat64Array`.
+    Float { bits: u8 },
+}
+
+/* ------------------------- Fn: get_bulk_element -------------------------- */
+
+fn get_bulk_element(element: &Encoding) -> Option<BulkElement> {
+    if !element.transforms.is_empty() || element.padding_bits.unwrap_or(0) > 0 {
+        return None;
+    }
+
+    match (&element.native, &element.wire) {
+        (NativeType::Int { signed, .. }, WireFormat::Bits { count })
+            if matches!(count, 8 | 16 | 32 | 64) =>
+        {
+            let storage = match packed_array_name(&element.native)? {
+                "PackedInt32Array" => 32,
+                _ => 64,
+            };
+
+            Some(BulkElement::Int {
+                storage,
+                byte_count: (*count / 8) as i64,
+                signed: *signed,
+            })
+        }
+        (NativeType::Float { bits: 32 }, WireFormat::Bits { count: 32 }) => {
+            Some(BulkElement::Float { bits: 32 })
+        }
+        (NativeType::Float { bits: 64 }, WireFormat::Bits { count: 64 }) => {
+            Some(BulkElement::Float { bits: 64 })
+        }
+        _ => None,
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+/*                       Fn: get_quantized_write_method                       */
+/* -------------------------------------------------------------------------- */
+
+/// `get_quantized_write_method` returns the writer method name and extra arguments for
+/// a float encoded as a quantized integer.
+pub fn get_quantized_write_method(
+    encoding: &Encoding,
+    quantize: &Quantize,
+) -> anyhow::Result<CodecMethod> {
+    let mut extra_args = gen_quantize_args(encoding, quantize)?;
+    if !quantize.clamp {
+        extra_args.push(Expr::Literal(Literal::Bool(false)));
+    }
+
+    Ok(CodecMethod {
+        method: "write_quantized".to_string(),
+        extra_args,
+    })
+}
+
+/* -------------------------------------------------------------------------- */
+/*                       Fn: get_quantized_read_method                        */
+/* -------------------------------------------------------------------------- */
+
+/// `get_quantized_read_method` returns the reader method name and extra arguments for
+/// a float encoded as a quantized integer.
+pub fn get_quantized_read_method(
+    encoding: &Encoding,
+    quantize: &Quantize,
+) -> anyhow::Result<CodecMethod> {
+    Ok(CodecMethod {
+        method: "read_quantized".to_string(),
+        extra_args: gen_quantize_args(encoding, quantize)?,
+    })
+}
+
+/* ------------------------- Fn: gen_quantize_args -------------------------- */
+
+fn gen_quantize_args(encoding: &Encoding, quantize: &Quantize) -> anyhow::Result<Vec<Expr>> {
+    if !matches!(encoding.native, NativeType::Float { .. }) {
+        anyhow::bail!(
+            "Quantization requires a float type: native={:?}",
+            encoding.native
+        );
+    }
+
+    Ok(vec![
+        Expr::Literal(Literal::Float(quantize.min)),
+        Expr::Literal(Literal::Float(quantize.max)),
+        Expr::Literal((quantize.bits as i64).into()),
+    ])
+}
+
+/* -------------------------------------------------------------------------- */
+/*                          Fn: validate_transforms                           */
+/* -------------------------------------------------------------------------- */
+
+/// `validate_transforms` returns an error if any of an encoding's transforms (or those
+/// of its array elements, map keys, or map values) do not apply to the encoding's
+/// native type. Field encodings are validated once, before any codec calls are chosen
+/// for them, so the `get_*_method` functions assume valid transforms.
+pub fn validate_transforms(encoding: &Encoding) -> anyhow::Result<()> {
+    for transform in &encoding.transforms {
+        match transform {
+            Transform::ZigZag => {
+                if !matches!(encoding.native, NativeType::Int { .. }) {
+                    anyhow::bail!(
+                        "Transform {:?} requires an integer type: native={:?}",
+                        transform,
+                        encoding.native
+                    );
+                }
+            }
+        }
+    }
+
+    match &encoding.native {
+        NativeType::Array { element } => validate_transforms(element),
+        NativeType::Map { key, value } => {
+            validate_transforms(key)?;
+            validate_transforms(value)
+        }
+        _ => Ok(()),
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+/*                         Fn: get_length_prefix_bits                         */
+/* -------------------------------------------------------------------------- */
+
+/// `get_length_prefix_bits` returns the width, in bits, of the size prefix written
+/// before a string, bytes, array, or map value. Returns an error if the encoding isn't
+/// length-prefixed or the width isn't supported by the runtime (1-64 bits).
+pub fn get_length_prefix_bits(encoding: &Encoding) -> anyhow::Result<i64> {
+    match &encoding.wire {
+        WireFormat::LengthPrefixed { prefix_bits } if (1..=64).contains(prefix_bits) => {
+            Ok(*prefix_bits as i64)
+        }
+        _ => anyhow::bail!(
+            "Unsupported length prefix: native={:?}, wire={:?}",
+            encoding.native,
+            encoding.wire
+        ),
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+/*                           Fn: validate_optional                            */
+/* -------------------------------------------------------------------------- */
+
+/// `validate_optional` returns an error if an encoding can't be used for an optional
+/// field. Only generated messages and enums may be optional, since they're the only
+/// types whose values can be `null`.
+pub fn validate_optional(encoding: &Encoding) -> anyhow::Result<()> {
+    match &encoding.native {
+        native if BuiltinType::from_native(native).is_some() => anyhow::bail!(
+            "Optional requires a message or enum type: native={:?}",
+            encoding.native
+        ),
+        NativeType::Message { .. } | NativeType::Enum { .. } => Ok(()),
+        _ => anyhow::bail!(
+            "Optional requires a message or enum type: native={:?}",
+            encoding.native
+        ),
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                 Enum: Size                                 */
+/* -------------------------------------------------------------------------- */
+
+/// `Size` describes how the size of a string, bytes, array, or map value is encoded.
+#[derive(Debug, PartialEq)]
+pub enum Size {
+    /// `Prefixed` is a size written before the value as a `bits`-bit unsigned integer,
+    /// optionally validated against an upper bound.
+    Prefixed { bits: i64, max: Option<i64> },
+    /// `Fixed` is a size implied by the schema, which isn't written at all.
+    Fixed(i64),
+}
+
+/* ------------------------------ Impl: Size -------------------------------- */
+
+impl Size {
+    /// `prefix_args` returns the arguments passed to the runtime's size methods (e.g.
+    /// `write_size` and `read_length`) to describe the length prefix. A fixed size has
+    /// no length prefix, so no arguments are returned.
+    pub fn prefix_args(&self) -> Vec<Expr> {
+        match self {
+            Size::Prefixed { bits, max } => std::iter::once(*bits)
+                .chain(*max)
+                .map(|v| Expr::Literal(v.into()))
+                .collect(),
+            Size::Fixed(_) => vec![],
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+/*                               Fn: get_size                                 */
+/* -------------------------------------------------------------------------- */
+
+/// `get_size` returns how the size of a string, bytes, array, or map value is encoded,
+/// applying the field's `length` directive, if any. Fixed lengths are only supported
+/// for bytes and arrays, while bounded lengths are also supported for strings.
+///
+/// NOTE: Fixed-length arrays are pre-sized with default elements, which would be `null`
+/// for messages and enums, so those element types are rejected.
+pub fn get_size(encoding: &Encoding, length: Option<&Length>) -> anyhow::Result<Size> {
+    let Some(length) = length else {
+        return Ok(Size::Prefixed {
+            bits: get_length_prefix_bits(encoding)?,
+            max: None,
+        });
+    };
+
+    match (&encoding.native, length) {
+        (NativeType::String | NativeType::Bytes | NativeType::Array { .. }, Length::Max(max)) => {
+            Ok(Size::Prefixed {
+                bits: length.prefix_bits().unwrap_or_default() as i64,
+                max: Some(*max as i64),
+            })
+        }
+        (NativeType::Array { element }, Length::Fixed(_))
+            if matches!(
+                element.native,
+                NativeType::Message { .. } | NativeType::Enum { .. }
+            ) =>
+        {
+            anyhow::bail!(
+                "Unsupported fixed-length array element: {:?}",
+                element.native
+            )
+        }
+        (NativeType::Bytes | NativeType::Array { .. }, Length::Fixed(size)) => {
+            Ok(Size::Fixed(*size as i64))
+        }
+        _ => anyhow::bail!(
+            "Unsupported length directive: native={:?}, length={:?}",
+            encoding.native,
+            length
+        ),
+    }
+}
+
 /* -------------------------------------------------------------------------- */
 /*                                 Mod: Tests                                 */
 /* -------------------------------------------------------------------------- */
@@ -398,9 +768,9 @@ mod tests {
 
     #[test]
     fn test_get_write_method_string() {
-        // Given: A string encoding.
+        // Given: A string encoding with an 8-bit length prefix.
         let encoding = Encoding {
-            wire: WireFormat::LengthPrefixed { prefix_bits: 64 },
+            wire: WireFormat::LengthPrefixed { prefix_bits: 8 },
             native: NativeType::String,
             transforms: vec![],
             padding_bits: None,
@@ -409,11 +779,231 @@ mod tests {
         // When: Getting the write method.
         let result = get_write_method(&encoding).unwrap();
 
-        // Then: The method is write_string.
+        // Then: The method is write_string with the prefix width.
         assert_eq!(result.method, "write_string");
+        assert_eq!(result.extra_args, vec![Expr::Literal(8.into())]);
+    }
+
+    #[test]
+    fn test_get_write_method_bits_unsigned() {
+        // Given: An unsigned encoding with a non-standard bit width.
+        let encoding = Encoding {
+            wire: WireFormat::Bits { count: 12 },
+            native: NativeType::Int {
+                bits: 16,
+                signed: false,
+            },
+            transforms: vec![],
+            padding_bits: None,
+        };
+
+        // When: Getting the write method.
+        let result = get_write_method(&encoding).unwrap();
+
+        // Then: The method is write_bits with the bit count.
+        assert_eq!(result.method, "write_bits");
+        assert_eq!(result.extra_args, vec![Expr::Literal(12.into())]);
+    }
+
+    #[test]
+    fn test_get_write_method_bits_signed() {
+        // Given: A signed encoding with a non-standard bit width.
+        let encoding = Encoding {
+            wire: WireFormat::Bits { count: 5 },
+            native: NativeType::Int {
+                bits: 8,
+                signed: true,
+            },
+            transforms: vec![],
+            padding_bits: None,
+        };
+
+        // When: Getting the write method.
+        let result = get_write_method(&encoding).unwrap();
+
+        // Then: The method is write_bits_signed with the bit count.
+        assert_eq!(result.method, "write_bits_signed");
+        assert_eq!(result.extra_args, vec![Expr::Literal(5.into())]);
+    }
+
+    #[test]
+    fn test_get_write_method_bits_out_of_range() {
+        // Given: An integer encoding wider than 64 bits.
+        let encoding = Encoding {
+            wire: WireFormat::Bits { count: 65 },
+            native: NativeType::Int {
+                bits: 64,
+                signed: false,
+            },
+            transforms: vec![],
+            padding_bits: None,
+        };
+
+        // When: Getting the write method.
+        let result = get_write_method(&encoding);
+
+        // Then: An error is returned.
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_get_write_method_zigzag_varint() {
+        // Given: A zigzag encoding of an unsigned varint.
+        let encoding = Encoding {
+            wire: WireFormat::LengthPrefixed { prefix_bits: 64 },
+            native: NativeType::Int {
+                bits: 64,
+                signed: false,
+            },
+            transforms: vec![Transform::ZigZag],
+            padding_bits: None,
+        };
+
+        // When: Getting the write method.
+        let result = get_write_method(&encoding).unwrap();
+
+        // Then: The method is write_varint_signed, which applies ZigZag.
+        assert_eq!(result.method, "write_varint_signed");
         assert_eq!(result.extra_args.len(), 0);
     }
 
+    /* -------------------- Tests: get_bulk_write_method --------------------- */
+
+    #[test]
+    fn test_get_bulk_write_method_narrow_unsigned() {
+        // Given: A u8 element encoding.
+        let encoding = Encoding {
+            wire: WireFormat::Bits { count: 8 },
+            native: NativeType::Int {
+                bits: 8,
+                signed: false,
+            },
+            transforms: vec![],
+            padding_bits: None,
+        };
+
+        // When: Getting the bulk write and read methods.
+        let write = get_bulk_write_method(&encoding).unwrap();
+        let read = get_bulk_read_method(&encoding).unwrap();
+
+        // Then: The elements are stored as 32-bit ints and encoded as single bytes.
+        assert_eq!(write.method, "write_int32_array");
+        assert_eq!(write.extra_args, vec![Expr::Literal(1.into())]);
+        assert_eq!(read.method, "read_int32_array");
+        assert_eq!(
+            read.extra_args,
+            vec![Expr::Literal(1.into()), Expr::Literal(Literal::Bool(false))]
+        );
+    }
+
+    #[test]
+    fn test_get_bulk_write_method_padded_element() {
+        // Given: An f64 element encoding with padding.
+        let encoding = Encoding {
+            wire: WireFormat::Bits { count: 64 },
+            native: NativeType::Float { bits: 64 },
+            transforms: vec![],
+            padding_bits: Some(3),
+        };
+
+        // When: Getting the bulk write method.
+        let result = get_bulk_write_method(&encoding);
+
+        // Then: Padded elements can't be written in bulk.
+        assert_eq!(result, None);
+    }
+
+    /* ------------------- Tests: get_quantized_write_method ------------------ */
+
+    #[test]
+    fn test_get_quantized_write_method() {
+        // Given: A float encoding with a quantization directive.
+        let encoding = Encoding {
+            wire: WireFormat::Bits { count: 32 },
+            native: NativeType::Float { bits: 32 },
+            transforms: vec![],
+            padding_bits: None,
+        };
+        let quantize = Quantize {
+            min: -1.0,
+            max: 1.0,
+            bits: 10,
+            clamp: false,
+        };
+
+        // When: Getting the write method.
+        let result = get_quantized_write_method(&encoding, &quantize).unwrap();
+
+        // Then: The method is write_quantized with the range, bits and clamp mode.
+        assert_eq!(result.method, "write_quantized");
+        assert_eq!(
+            result.extra_args,
+            vec![
+                Expr::Literal(Literal::Float(-1.0)),
+                Expr::Literal(Literal::Float(1.0)),
+                Expr::Literal(Literal::Int(10)),
+                Expr::Literal(Literal::Bool(false)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_get_quantized_read_method_full_precision() {
+        // Given: A quantization range which isn't representable with 32-bit floats.
+        let encoding = Encoding {
+            wire: WireFormat::Bits { count: 64 },
+            native: NativeType::Float { bits: 64 },
+            transforms: vec![],
+            padding_bits: None,
+        };
+        let quantize = Quantize {
+            min: 0.1,
+            max: 16777217.25,
+            bits: 32,
+            clamp: true,
+        };
+
+        // When: Getting the read method.
+        let result = get_quantized_read_method(&encoding, &quantize).unwrap();
+
+        // Then: The range is passed through without losing precision.
+        assert_eq!(result.method, "read_quantized");
+        assert_eq!(
+            result.extra_args,
+            vec![
+                Expr::Literal(Literal::Float(0.1)),
+                Expr::Literal(Literal::Float(16777217.25)),
+                Expr::Literal(32.into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_get_quantized_write_method_non_float() {
+        // Given: An integer encoding with a quantization directive.
+        let encoding = Encoding {
+            wire: WireFormat::Bits { count: 32 },
+            native: NativeType::Int {
+                bits: 32,
+                signed: true,
+            },
+            transforms: vec![],
+            padding_bits: None,
+        };
+        let quantize = Quantize {
+            min: -1.0,
+            max: 1.0,
+            bits: 10,
+            clamp: true,
+        };
+
+        // When: Getting the write method.
+        let result = get_quantized_write_method(&encoding, &quantize);
+
+        // Then: An error is returned.
+        assert!(result.is_err());
+    }
+
     /* ----------------------- Tests: get_read_method ----------------------- */
 
     #[test]
@@ -472,4 +1062,253 @@ mod tests {
         assert_eq!(result.method, "read_f64");
         assert_eq!(result.extra_args.len(), 0);
     }
+
+    #[test]
+    fn test_get_read_method_bits_unsigned() {
+        // Given: An unsigned encoding with a non-standard bit width.
+        let encoding = Encoding {
+            wire: WireFormat::Bits { count: 24 },
+            native: NativeType::Int {
+                bits: 32,
+                signed: false,
+            },
+            transforms: vec![],
+            padding_bits: None,
+        };
+
+        // When: Getting the read method.
+        let result = get_read_method(&encoding).unwrap();
+
+        // Then: The method is read_bits with the bit count.
+        assert_eq!(result.method, "read_bits");
+        assert_eq!(result.extra_args, vec![Expr::Literal(24.into())]);
+    }
+
+    #[test]
+    fn test_get_read_method_bits_signed() {
+        // Given: A signed encoding with a non-standard bit width.
+        let encoding = Encoding {
+            wire: WireFormat::Bits { count: 48 },
+            native: NativeType::Int {
+                bits: 64,
+                signed: true,
+            },
+            transforms: vec![],
+            padding_bits: None,
+        };
+
+        // When: Getting the read method.
+        let result = get_read_method(&encoding).unwrap();
+
+        // Then: The method is read_bits_signed with the bit count.
+        assert_eq!(result.method, "read_bits_signed");
+        assert_eq!(result.extra_args, vec![Expr::Literal(48.into())]);
+    }
+
+    /* --------------------- Tests: validate_transforms --------------------- */
+
+    #[test]
+    fn test_validate_transforms_zigzag_non_integer() {
+        // Given: A zigzag encoding of a float.
+        let encoding = Encoding {
+            wire: WireFormat::Bits { count: 32 },
+            native: NativeType::Float { bits: 32 },
+            transforms: vec![Transform::ZigZag],
+            padding_bits: None,
+        };
+
+        // When: Validating the encoding's transforms.
+        let result = validate_transforms(&encoding);
+
+        // Then: An error is returned.
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_validate_transforms_zigzag_array_element() {
+        // Given: An array encoding whose float elements are zigzag encoded.
+        let encoding = Encoding {
+            wire: WireFormat::LengthPrefixed { prefix_bits: 32 },
+            native: NativeType::Array {
+                element: Box::new(Encoding {
+                    wire: WireFormat::Bits { count: 32 },
+                    native: NativeType::Float { bits: 32 },
+                    transforms: vec![Transform::ZigZag],
+                    padding_bits: None,
+                }),
+            },
+            transforms: vec![],
+            padding_bits: None,
+        };
+
+        // When: Validating the encoding's transforms.
+        let result = validate_transforms(&encoding);
+
+        // Then: An error is returned for the element.
+        assert!(result.is_err());
+    }
+
+    /* -------------------- Tests: get_length_prefix_bits ------------------- */
+
+    #[test]
+    fn test_get_length_prefix_bits_supported() {
+        // Given: A bytes encoding with a 16-bit length prefix.
+        let encoding = Encoding {
+            wire: WireFormat::LengthPrefixed { prefix_bits: 16 },
+            native: NativeType::Bytes,
+            transforms: vec![],
+            padding_bits: None,
+        };
+
+        // When: Getting the prefix width.
+        let result = get_length_prefix_bits(&encoding);
+
+        // Then: The declared width is returned.
+        assert_eq!(result.unwrap(), 16);
+    }
+
+    #[test]
+    fn test_get_length_prefix_bits_unsupported() {
+        // Given: Encodings without a supported length prefix.
+        for wire in [
+            WireFormat::LengthPrefixed { prefix_bits: 0 },
+            WireFormat::LengthPrefixed { prefix_bits: 65 },
+            WireFormat::Bits { count: 8 },
+        ] {
+            let encoding = Encoding {
+                wire,
+                native: NativeType::Bytes,
+                transforms: vec![],
+                padding_bits: None,
+            };
+
+            // When: Getting the prefix width.
+            let result = get_length_prefix_bits(&encoding);
+
+            // Then: An error is returned.
+            assert!(result.is_err());
+        }
+    }
+
+    /* ------------------------- Tests: get_size ---------------------------- */
+
+    #[test]
+    fn test_get_size_without_directive() {
+        // Given: A string encoding with an 8-bit length prefix.
+        let encoding = Encoding {
+            wire: WireFormat::LengthPrefixed { prefix_bits: 8 },
+            native: NativeType::String,
+            transforms: vec![],
+            padding_bits: None,
+        };
+
+        // When: Getting the size encoding without a length directive.
+        let result = get_size(&encoding, None).unwrap();
+
+        // Then: The declared prefix is used without a bound.
+        assert_eq!(result, Size::Prefixed { bits: 8, max: None });
+        assert_eq!(result.prefix_args(), vec![Expr::Literal(8.into())]);
+    }
+
+    #[test]
+    fn test_get_size_bounded() {
+        // Given: An array encoding with a 32-bit length prefix.
+        let encoding = Encoding {
+            wire: WireFormat::LengthPrefixed { prefix_bits: 32 },
+            native: NativeType::Array {
+                element: Box::new(Encoding {
+                    wire: WireFormat::Bits { count: 8 },
+                    native: NativeType::Int {
+                        bits: 8,
+                        signed: false,
+                    },
+                    transforms: vec![],
+                    padding_bits: None,
+                }),
+            },
+            transforms: vec![],
+            padding_bits: None,
+        };
+
+        // When: Getting the size encoding for a bounded length.
+        let result = get_size(&encoding, Some(&Length::Max(9))).unwrap();
+
+        // Then: The prefix width is derived from the bound.
+        assert_eq!(
+            result,
+            Size::Prefixed {
+                bits: 4,
+                max: Some(9)
+            }
+        );
+        assert_eq!(
+            result.prefix_args(),
+            vec![Expr::Literal(4.into()), Expr::Literal(9.into())]
+        );
+    }
+
+    #[test]
+    fn test_get_size_fixed() {
+        // Given: A bytes encoding.
+        let encoding = Encoding {
+            wire: WireFormat::LengthPrefixed { prefix_bits: 32 },
+            native: NativeType::Bytes,
+            transforms: vec![],
+            padding_bits: None,
+        };
+
+        // When: Getting the size encoding for a fixed length.
+        let result = get_size(&encoding, Some(&Length::Fixed(32))).unwrap();
+
+        // Then: No length prefix is used.
+        assert_eq!(result, Size::Fixed(32));
+        assert!(result.prefix_args().is_empty());
+    }
+
+    #[test]
+    fn test_get_size_unsupported() {
+        // Given: A string encoding.
+        let encoding = Encoding {
+            wire: WireFormat::LengthPrefixed { prefix_bits: 32 },
+            native: NativeType::String,
+            transforms: vec![],
+            padding_bits: None,
+        };
+
+        // When: Getting the size encoding for a fixed length.
+        let result = get_size(&encoding, Some(&Length::Fixed(4)));
+
+        // Then: An error is returned.
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_get_size_fixed_message_array() {
+        // Given: An array encoding with message elements.
+        let encoding = Encoding {
+            wire: WireFormat::LengthPrefixed { prefix_bits: 32 },
+            native: NativeType::Array {
+                element: Box::new(Encoding {
+                    wire: WireFormat::LengthPrefixed { prefix_bits: 64 },
+                    native: NativeType::Message {
+                        descriptor: baproto::DescriptorBuilder::default()
+                            .package(baproto::PackageName::try_from(vec!["test"]).unwrap())
+                            .path(vec!["Item".to_string()])
+                            .build()
+                            .unwrap(),
+                    },
+                    transforms: vec![],
+                    padding_bits: None,
+                }),
+            },
+            transforms: vec![],
+            padding_bits: None,
+        };
+
+        // When: Getting the size encoding for a fixed length.
+        let result = get_size(&encoding, Some(&Length::Fixed(4)));
+
+        // Then: An error is returned, since the elements can't be pre-sized.
+        assert!(result.is_err());
+    }
 }
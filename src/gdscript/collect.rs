@@ -1,4 +1,10 @@
-use baproto::{Enum, Message, Package};
+use std::collections::{HashMap, HashSet};
+
+use baproto::{Descriptor, Enum, Message, NativeType, Package, Variant};
+
+use crate::gdscript::builtin::BuiltinType;
+use crate::gdscript::directive::FieldDirectives;
+use crate::gdscript::types::global_class_name;
 
 /* -------------------------------------------------------------------------- */
 /*                               Enum: TypeKind                               */
@@ -30,6 +36,18 @@ pub struct TypeEntry {
     pub nested: Vec<String>,
 }
 
+/* ----------------------------- Impl: TypeEntry ---------------------------- */
+
+impl TypeEntry {
+    /// `descriptor` returns the descriptor of the entry's type.
+    pub fn descriptor(&self) -> &Descriptor {
+        match &self.kind {
+            TypeKind::Message(msg) => &msg.descriptor,
+            TypeKind::Enum(enm) => &enm.descriptor,
+        }
+    }
+}
+
 /* -------------------------------------------------------------------------- */
 /*                          Fn: collect_package_types                         */
 /* -------------------------------------------------------------------------- */
@@ -52,12 +70,284 @@ pub fn collect_package_types(pkg: &Package) -> Vec<TypeEntry> {
 
     // Collect top-level messages (recursively collects nested types).
     for msg in &pkg.messages {
+        // Built-in Godot types map to native GDScript types; no script is generated.
+        if BuiltinType::from_descriptor(&msg.descriptor).is_some() {
+            continue;
+        }
+
         collect_message(&mut entries, msg, &[]);
     }
 
     entries
 }
 
+/* -------------------------------------------------------------------------- */
+/*                           Fn: check_class_names                            */
+/* -------------------------------------------------------------------------- */
+
+/// `check_class_names` validates that the global class names of all types in the
+/// provided packages (see [`global_class_name`]) are unique and don't conflict with
+/// any of the `reserved` names.
+pub fn check_class_names(packages: &[Package], reserved: &[&str]) -> anyhow::Result<()> {
+    let mut seen: HashMap<String, String> = reserved
+        .iter()
+        .map(|name| (name.to_string(), name.to_string()))
+        .collect();
+
+    for pkg in packages {
+        for entry in collect_package_types(pkg) {
+            let descriptor = entry.descriptor();
+            let name = qualified_name(descriptor);
+
+            if let Some(other) = seen.insert(global_class_name(descriptor), name.clone()) {
+                anyhow::bail!(
+                    "Conflicting class name for types: {} and {} ({})",
+                    other,
+                    name,
+                    global_class_name(descriptor)
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/* -------------------------------------------------------------------------- */
+/*                       Fn: collect_instantiated_fields                      */
+/* -------------------------------------------------------------------------- */
+
+/// `collect_instantiated_fields` returns, for each type in the provided packages (keyed
+/// by its [`qualified_name`]), the names of the members which are default-constructed
+/// when the type is instantiated. For messages, these are the required message and enum
+/// fields; for enums, this is the first variant if it holds a message or enum.
+///
+/// A member is excluded if its type can reach back to the containing type, as
+/// constructing it would otherwise recurse forever. Such members are left `null`.
+pub fn collect_instantiated_fields(
+    packages: &[Package],
+) -> anyhow::Result<HashMap<String, HashSet<String>>> {
+    // Collect the edges from each type to the types its members construct.
+    let mut edges: HashMap<String, Vec<(String, String)>> = HashMap::new();
+
+    for pkg in packages {
+        for entry in collect_package_types(pkg) {
+            let members = edges.entry(qualified_name(entry.descriptor())).or_default();
+
+            match &entry.kind {
+                TypeKind::Message(msg) => {
+                    for field in &msg.fields {
+                        if FieldDirectives::parse(field.doc.as_deref())?.optional {
+                            continue;
+                        }
+
+                        if let Some(target) = instantiable_type(&field.encoding.native) {
+                            members.push((field.name.clone(), target));
+                        }
+                    }
+                }
+                TypeKind::Enum(enm) => {
+                    let Some(Variant::Field { name, field, .. }) = enm.variants.first() else {
+                        continue;
+                    };
+
+                    if FieldDirectives::parse(field.doc.as_deref())?.optional {
+                        continue;
+                    }
+
+                    if let Some(target) = instantiable_type(&field.encoding.native) {
+                        members.push((name.clone(), target));
+                    }
+                }
+            }
+        }
+    }
+
+    let graph = edges
+        .iter()
+        .map(|(source, members)| {
+            let targets = members.iter().map(|(_, target)| target.clone()).collect();
+            (source.clone(), targets)
+        })
+        .collect();
+
+    let mut instantiated = HashMap::new();
+
+    for (source, members) in &edges {
+        let names = members
+            .iter()
+            .filter(|(_, target)| !is_reachable(&graph, target, source))
+            .map(|(name, _)| name.clone())
+            .collect::<HashSet<_>>();
+
+        instantiated.insert(source.clone(), names);
+    }
+
+    Ok(instantiated)
+}
+
+/* -------------------------------------------------------------------------- */
+/*                        Fn: collect_lazy_dependencies                       */
+/* -------------------------------------------------------------------------- */
+
+/// `collect_lazy_dependencies` returns, for each type in the provided packages (keyed by
+/// its [`qualified_name`]), the qualified names of the types it references which must be
+/// loaded lazily. Godot can't load scripts which preload each other, so a reference is
+/// lazy if the referenced type's script (transitively) preloads the referencing one.
+///
+/// NOTE: Nested types are always preloaded by their parent, which can't form a cycle on
+/// its own; a cycle through a nested type is broken at the reference which closes it.
+pub fn collect_lazy_dependencies(packages: &[Package]) -> HashMap<String, HashSet<String>> {
+    let mut graph: HashMap<String, Vec<String>> = HashMap::new();
+    let mut references: HashMap<String, Vec<String>> = HashMap::new();
+
+    for pkg in packages {
+        for entry in collect_package_types(pkg) {
+            let descriptor = entry.descriptor();
+            let source = qualified_name(descriptor);
+
+            let mut targets = Vec::new();
+            match &entry.kind {
+                TypeKind::Message(msg) => {
+                    for field in &msg.fields {
+                        collect_referenced_types(&field.encoding.native, descriptor, &mut targets);
+                    }
+                }
+                TypeKind::Enum(enm) => {
+                    for variant in &enm.variants {
+                        if let Variant::Field { field, .. } = variant {
+                            collect_referenced_types(
+                                &field.encoding.native,
+                                descriptor,
+                                &mut targets,
+                            );
+                        }
+                    }
+                }
+            }
+
+            // Parents preload their nested types.
+            let nested = match &entry.kind {
+                TypeKind::Message(msg) => msg
+                    .enums
+                    .iter()
+                    .map(|enm| &enm.descriptor)
+                    .chain(msg.messages.iter().map(|msg| &msg.descriptor))
+                    .filter(|d| BuiltinType::from_descriptor(d).is_none())
+                    .map(qualified_name)
+                    .collect(),
+                TypeKind::Enum(_) => Vec::new(),
+            };
+
+            graph
+                .entry(source.clone())
+                .or_default()
+                .extend(targets.iter().cloned().chain(nested));
+            references.insert(source, targets);
+        }
+    }
+
+    let mut lazy = HashMap::new();
+
+    for (source, targets) in &references {
+        let names = targets
+            .iter()
+            .filter(|target| is_reachable(&graph, target, source))
+            .cloned()
+            .collect::<HashSet<_>>();
+
+        lazy.insert(source.clone(), names);
+    }
+
+    lazy
+}
+
+/* ----------------------- Fn: collect_referenced_types ---------------------- */
+
+/// `collect_referenced_types` collects the qualified names of the generated types which
+/// `native` references (and which `owner` preloads), recursing into collections.
+fn collect_referenced_types(native: &NativeType, owner: &Descriptor, out: &mut Vec<String>) {
+    match native {
+        NativeType::Array { element } => collect_referenced_types(&element.native, owner, out),
+        NativeType::Map { key, value } => {
+            collect_referenced_types(&key.native, owner, out);
+            collect_referenced_types(&value.native, owner, out);
+        }
+        NativeType::Message { descriptor } | NativeType::Enum { descriptor } => {
+            // NOTE: Types nested within the owner are referenced via its own preloads.
+            let is_nested = descriptor
+                .path
+                .join("_")
+                .starts_with(&format!("{}_", owner.path.join("_")));
+
+            if is_nested {
+                return;
+            }
+
+            if let Some(target) = instantiable_type(native) {
+                out.push(target);
+            }
+        }
+        _ => {}
+    }
+}
+
+/* -------------------------- Fn: instantiable_type ------------------------- */
+
+/// `instantiable_type` returns the qualified name of a generated message or enum type,
+/// or `None` for all other types (including built-in Godot types).
+fn instantiable_type(native: &NativeType) -> Option<String> {
+    match native {
+        NativeType::Message { descriptor } | NativeType::Enum { descriptor }
+            if BuiltinType::from_native(native).is_none() =>
+        {
+            Some(qualified_name(descriptor))
+        }
+        _ => None,
+    }
+}
+
+/* --------------------------- Fn: is_reachable ----------------------------- */
+
+/// `is_reachable` returns whether `to` can be reached from `from` by following the
+/// edges of `graph`.
+fn is_reachable(graph: &HashMap<String, Vec<String>>, from: &str, to: &str) -> bool {
+    let mut visited = HashSet::new();
+    let mut pending = vec![from];
+
+    while let Some(current) = pending.pop() {
+        if current == to {
+            return true;
+        }
+
+        if !visited.insert(current) {
+            continue;
+        }
+
+        if let Some(targets) = graph.get(current) {
+            pending.extend(targets.iter().map(String::as_str));
+        }
+    }
+
+    false
+}
+
+/* -------------------------------------------------------------------------- */
+/*                             Fn: qualified_name                             */
+/* -------------------------------------------------------------------------- */
+
+/// `qualified_name` returns the fully-qualified, dot-separated name of a type (e.g.
+/// `game.Player.Stats`).
+pub fn qualified_name(descriptor: &Descriptor) -> String {
+    descriptor
+        .package
+        .iter()
+        .chain(descriptor.path.iter())
+        .cloned()
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
 /* --------------------------- Fn: collect_message -------------------------- */
 
 /// `collect_message` recursively collects a message and its nested types.
@@ -227,6 +517,189 @@ pub(crate) mod tests {
         assert!(entries.iter().any(|e| e.file_stem == "Outer_Middle_Inner"));
     }
 
+    /* ---------------------- Tests: check_class_names --------------------- */
+
+    #[test]
+    fn test_check_class_names_unique() {
+        // Given: A package with uniquely-named types.
+        let pkg = create_test_package(
+            vec![create_test_message("Player", vec![], vec![])],
+            vec![create_test_enum("State")],
+        );
+
+        // When: Validating the class names.
+        let result = check_class_names(&[pkg], &["BAProto"]);
+
+        // Then: The class names are accepted.
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_check_class_names_collision() {
+        // Given: Two types whose class names are both "TestPlayerStats".
+        let mut nested = create_test_message("Player", vec![], vec![]);
+        nested.descriptor.path.push("Stats".to_string());
+        let outer = create_test_message("Player", vec![nested], vec![]);
+        let flat = create_test_message("PlayerStats", vec![], vec![]);
+        let pkg = create_test_package(vec![outer, flat], vec![]);
+
+        // When: Validating the class names.
+        let result = check_class_names(&[pkg], &[]);
+
+        // Then: An error is returned.
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_check_class_names_reserved() {
+        // Given: A type whose class name matches a reserved name.
+        let pkg = create_test_package(vec![create_test_message("Player", vec![], vec![])], vec![]);
+
+        // When: Validating the class names.
+        let result = check_class_names(&[pkg], &["TestPlayer"]);
+
+        // Then: An error is returned.
+        assert!(result.is_err());
+    }
+
+    /* ------------------- Tests: collect_instantiated_fields ------------------ */
+
+    #[test]
+    fn test_collect_instantiated_fields_nested_message() {
+        // Given: A message with a required and an optional message field.
+        let stats = create_test_message("Stats", vec![], vec![]);
+        let mut player = create_test_message("Player", vec![], vec![]);
+        player.fields = vec![
+            create_test_field("stats", &stats, None),
+            create_test_field("backup", &stats, Some("@gdscript optional")),
+        ];
+        let pkg = create_test_package(vec![player, stats], vec![]);
+
+        // When: Collecting the instantiated fields.
+        let result = collect_instantiated_fields(&[pkg]).unwrap();
+
+        // Then: Only the required field is instantiated.
+        assert_eq!(result["test.Player"], HashSet::from(["stats".to_string()]));
+        assert!(result["test.Stats"].is_empty());
+    }
+
+    #[test]
+    fn test_collect_instantiated_fields_excludes_cycles() {
+        // Given: A self-referential message and two mutually-referential messages.
+        let mut node = create_test_message("Node", vec![], vec![]);
+        node.fields = vec![create_test_field("next", &node.clone(), None)];
+        let mut a = create_test_message("A", vec![], vec![]);
+        let mut b = create_test_message("B", vec![], vec![]);
+        a.fields = vec![create_test_field("b", &b, None)];
+        b.fields = vec![create_test_field("a", &a, None)];
+
+        // Given: A message which references the cycle without being part of it.
+        let mut root = create_test_message("Root", vec![], vec![]);
+        root.fields = vec![create_test_field("a", &a, None)];
+
+        let pkg = create_test_package(vec![node, a, b, root], vec![]);
+
+        // When: Collecting the instantiated fields.
+        let result = collect_instantiated_fields(&[pkg]).unwrap();
+
+        // Then: Fields which close a cycle are not instantiated.
+        assert!(result["test.Node"].is_empty());
+        assert!(result["test.A"].is_empty());
+        assert!(result["test.B"].is_empty());
+
+        // Then: Fields leading into a cycle are still instantiated.
+        assert_eq!(result["test.Root"], HashSet::from(["a".to_string()]));
+    }
+
+    #[test]
+    fn test_collect_instantiated_fields_enum_first_variant() {
+        // Given: An enum whose first variant holds a message.
+        let stats = create_test_message("Stats", vec![], vec![]);
+        let mut status = create_test_enum("Status");
+        status.variants = vec![
+            Variant::Field {
+                name: "Stats".to_string(),
+                index: 0,
+                field: create_test_field("Stats", &stats, None),
+                doc: None,
+            },
+            Variant::Unit {
+                name: "Idle".to_string(),
+                index: 1,
+                doc: None,
+            },
+        ];
+        let pkg = create_test_package(vec![stats], vec![status]);
+
+        // When: Collecting the instantiated fields.
+        let result = collect_instantiated_fields(&[pkg]).unwrap();
+
+        // Then: The first variant's payload is instantiated.
+        assert_eq!(result["test.Status"], HashSet::from(["Stats".to_string()]));
+    }
+
+    /* -------------------- Tests: collect_lazy_dependencies ------------------- */
+
+    #[test]
+    fn test_collect_lazy_dependencies_cycles() {
+        // Given: A message which references itself through an array.
+        let mut node = create_test_message("Node", vec![], vec![]);
+        let mut children = create_test_field("children", &node.clone(), None);
+        children.encoding.native = NativeType::Array {
+            element: Box::new(children.encoding.clone()),
+        };
+        node.fields = vec![children];
+
+        // Given: Two mutually-referential messages.
+        let mut a = create_test_message("A", vec![], vec![]);
+        let mut b = create_test_message("B", vec![], vec![]);
+        a.fields = vec![create_test_field("b", &b, None)];
+        b.fields = vec![create_test_field("a", &a, None)];
+
+        // Given: A message which references the cycle without being part of it.
+        let mut root = create_test_message("Root", vec![], vec![]);
+        root.fields = vec![create_test_field("a", &a, None)];
+
+        let pkg = create_test_package(vec![node, a, b, root], vec![]);
+
+        // When: Collecting the lazy dependencies.
+        let result = collect_lazy_dependencies(&[pkg]);
+
+        // Then: References which close a cycle are lazy.
+        assert_eq!(
+            result["test.Node"],
+            HashSet::from(["test.Node".to_string()])
+        );
+        assert_eq!(result["test.A"], HashSet::from(["test.B".to_string()]));
+        assert_eq!(result["test.B"], HashSet::from(["test.A".to_string()]));
+
+        // Then: References leading into a cycle are still preloaded.
+        assert!(result["test.Root"].is_empty());
+    }
+
+    #[test]
+    fn test_collect_lazy_dependencies_nested_references_parent() {
+        // Given: A message with a nested message which references its parent.
+        let mut parent = create_test_message("Parent", vec![], vec![]);
+        let mut child = create_test_message("Child", vec![], vec![]);
+        child.descriptor.path = vec!["Parent".to_string(), "Child".to_string()];
+        child.fields = vec![create_test_field("parent", &parent, None)];
+        parent.fields = vec![create_test_field("child", &child, None)];
+        parent.messages = vec![child];
+
+        let pkg = create_test_package(vec![parent], vec![]);
+
+        // When: Collecting the lazy dependencies.
+        let result = collect_lazy_dependencies(&[pkg]);
+
+        // Then: The parent preloads its nested message, which loads the parent lazily.
+        assert!(result["test.Parent"].is_empty());
+        assert_eq!(
+            result["test.Parent.Child"],
+            HashSet::from(["test.Parent".to_string()])
+        );
+    }
+
     /* ----------------------- Fn: create_test_package ---------------------- */
 
     pub(crate) fn create_test_package(messages: Vec<Message>, enums: Vec<Enum>) -> Package {
@@ -257,6 +730,24 @@ pub(crate) mod tests {
         }
     }
 
+    /* ------------------------ Fn: create_test_field ----------------------- */
+
+    fn create_test_field(name: &str, msg: &Message, doc: Option<&str>) -> baproto::Field {
+        baproto::Field {
+            name: name.to_string(),
+            index: 0,
+            encoding: baproto::Encoding {
+                wire: baproto::WireFormat::LengthPrefixed { prefix_bits: 64 },
+                native: NativeType::Message {
+                    descriptor: msg.descriptor.clone(),
+                },
+                transforms: vec![],
+                padding_bits: None,
+            },
+            doc: doc.map(str::to_string),
+        }
+    }
+
     /* ------------------------ Fn: create_test_enum ------------------------ */
 
     pub(crate) fn create_test_enum(name: &str) -> Enum {
@@ -0,0 +1,504 @@
+/* -------------------------------------------------------------------------- */
+/*                              Const: PREFIX                                 */
+/* -------------------------------------------------------------------------- */
+
+/// `PREFIX` marks a doc comment line as a GDScript generator directive.
+const PREFIX: &str = "@gdscript";
+
+/* -------------------------------------------------------------------------- */
+/*                          Struct: FieldDirectives                           */
+/* -------------------------------------------------------------------------- */
+
+/// `FieldDirectives` contains GDScript-specific options for a field which cannot be
+/// expressed in the schema's encoding. Directives are declared on their own lines in a
+/// field's doc comment, for example:
+///
+/// ```text
+/// /// The player's horizontal position.
+/// /// @gdscript quantize(min = -1024, max = 1024, precision = 0.01)
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FieldDirectives {
+    /// `quantize` encodes a float field as an N-bit integer within a fixed range.
+    pub quantize: Option<Quantize>,
+    /// `length` fixes or bounds the length of a string, bytes, or array field.
+    pub length: Option<Length>,
+    /// `optional` allows a message or enum field to be `null`, which is encoded as a
+    /// single presence bit.
+    pub optional: bool,
+}
+
+/* ------------------------- Impl: FieldDirectives -------------------------- */
+
+impl FieldDirectives {
+    /// `parse` extracts the directives from a field's doc comment. Unknown directives
+    /// and malformed arguments are errors, since ignoring them would silently change
+    /// the generated wire format.
+    pub fn parse(doc: Option<&str>) -> anyhow::Result<FieldDirectives> {
+        let mut directives = FieldDirectives::default();
+
+        for line in doc.into_iter().flat_map(str::lines) {
+            let Some(directive) = parse_line(line) else {
+                continue;
+            };
+
+            let (name, args) = directive?;
+
+            match name {
+                "quantize" => {
+                    if directives.quantize.is_some() {
+                        anyhow::bail!("Duplicate directive: {}", name);
+                    }
+
+                    directives.quantize = Some(Quantize::from_args(&args)?);
+                }
+                "length" => {
+                    if directives.length.is_some() {
+                        anyhow::bail!("Duplicate directive: {}", name);
+                    }
+
+                    directives.length = Some(Length::from_args(&args)?);
+                }
+                "optional" => {
+                    if directives.optional {
+                        anyhow::bail!("Duplicate directive: {}", name);
+                    }
+
+                    if !args.is_empty() {
+                        anyhow::bail!("Unexpected arguments for 'optional'");
+                    }
+
+                    directives.optional = true;
+                }
+                _ => anyhow::bail!("Unknown directive: {} {}", PREFIX, name),
+            }
+        }
+
+        let names = [
+            ("quantize", directives.quantize.is_some()),
+            ("length", directives.length.is_some()),
+            ("optional", directives.optional),
+        ]
+        .into_iter()
+        .filter_map(|(name, set)| set.then_some(name))
+        .collect::<Vec<_>>();
+
+        if names.len() > 1 {
+            anyhow::bail!("Conflicting directives: {}", names.join(", "));
+        }
+
+        Ok(directives)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+/*                              Struct: Quantize                              */
+/* -------------------------------------------------------------------------- */
+
+/// `Quantize` describes a float encoded as an unsigned integer spanning `[min, max]`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Quantize {
+    /// `min` is the smallest representable value.
+    pub min: f64,
+    /// `max` is the largest representable value.
+    pub max: f64,
+    /// `bits` is the number of bits used to encode the value.
+    pub bits: u8,
+    /// `clamp` controls whether out-of-range values are clamped (`true`) or rejected
+    /// with an error (`false`) when encoding.
+    pub clamp: bool,
+}
+
+/* ---------------------------- Impl: Quantize ------------------------------ */
+
+impl Quantize {
+    /// `from_args` creates a [`Quantize`] from directive arguments. Exactly one of
+    /// `precision` or `bits` must be given; with `precision`, the bit count is the
+    /// smallest which represents the range in steps no larger than `precision`.
+    fn from_args(args: &[(&str, &str)]) -> anyhow::Result<Quantize> {
+        let mut min = None;
+        let mut max = None;
+        let mut precision = None;
+        let mut bits = None;
+        let mut clamp = true;
+
+        for (key, value) in args {
+            match *key {
+                "min" => min = Some(parse_float(key, value)?),
+                "max" => max = Some(parse_float(key, value)?),
+                "precision" => precision = Some(parse_float(key, value)?),
+                "bits" => {
+                    bits = Some(
+                        value
+                            .parse::<u8>()
+                            .map_err(|_| anyhow::anyhow!("Invalid value for 'bits': {}", value))?,
+                    )
+                }
+                "clamp" => {
+                    clamp = value
+                        .parse::<bool>()
+                        .map_err(|_| anyhow::anyhow!("Invalid value for 'clamp': {}", value))?
+                }
+                _ => anyhow::bail!("Unknown argument for 'quantize': {}", key),
+            }
+        }
+
+        let min = min.ok_or_else(|| anyhow::anyhow!("Missing argument for 'quantize': min"))?;
+        let max = max.ok_or_else(|| anyhow::anyhow!("Missing argument for 'quantize': max"))?;
+
+        if max <= min {
+            anyhow::bail!("Invalid range for 'quantize': min={}, max={}", min, max);
+        }
+
+        let bits = match (precision, bits) {
+            (Some(precision), None) => {
+                if precision <= 0.0 {
+                    anyhow::bail!("Invalid value for 'precision': {}", precision);
+                }
+
+                let steps = ((max - min) / precision).ceil();
+                if steps >= (1u64 << 63) as f64 {
+                    anyhow::bail!("Precision too fine for 'quantize': {}", precision);
+                }
+
+                (u64::BITS - (steps as u64).leading_zeros()).max(1) as u8
+            }
+            (None, Some(bits)) => bits,
+            _ => anyhow::bail!("Expected exactly one of 'precision' or 'bits' for 'quantize'"),
+        };
+
+        if !(1..=63).contains(&bits) {
+            anyhow::bail!("Invalid bit count for 'quantize': {}", bits);
+        }
+
+        Ok(Quantize {
+            min,
+            max,
+            bits,
+            clamp,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+/*                               Enum: Length                                 */
+/* -------------------------------------------------------------------------- */
+
+/// `Length` describes the length of a string, bytes, or array field, in bytes for
+/// strings and bytes and in elements for arrays.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Length {
+    /// `Fixed` is a length which is always exactly the given value. No length prefix is
+    /// encoded, since the length is implied by the schema.
+    Fixed(u32),
+    /// `Max` is a length no larger than the given value. The length prefix uses the
+    /// fewest bits which can represent the bound.
+    Max(u32),
+}
+
+/* ----------------------------- Impl: Length ------------------------------- */
+
+impl Length {
+    /// `from_args` creates a [`Length`] from directive arguments. Exactly one of `fixed`
+    /// or `max` must be given.
+    fn from_args(args: &[(&str, &str)]) -> anyhow::Result<Length> {
+        let mut fixed = None;
+        let mut max = None;
+
+        for (key, value) in args {
+            let parsed = value
+                .parse::<u32>()
+                .ok()
+                .filter(|v| *v > 0)
+                .ok_or_else(|| anyhow::anyhow!("Invalid value for '{}': {}", key, value))?;
+
+            match *key {
+                "fixed" => fixed = Some(parsed),
+                "max" => max = Some(parsed),
+                _ => anyhow::bail!("Unknown argument for 'length': {}", key),
+            }
+        }
+
+        match (fixed, max) {
+            (Some(fixed), None) => Ok(Length::Fixed(fixed)),
+            (None, Some(max)) => Ok(Length::Max(max)),
+            _ => anyhow::bail!("Expected exactly one of 'fixed' or 'max' for 'length'"),
+        }
+    }
+
+    /// `prefix_bits` returns the width of the length prefix, or `None` if the length is
+    /// fixed and no prefix is encoded.
+    pub fn prefix_bits(&self) -> Option<u8> {
+        match self {
+            Length::Fixed(_) => None,
+            Length::Max(max) => Some((u32::BITS - max.leading_zeros()) as u8),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+/*                           Fn: strip_directives                             */
+/* -------------------------------------------------------------------------- */
+
+/// `strip_directives` removes directive lines from a doc comment, returning `None` if
+/// nothing remains.
+pub fn strip_directives(doc: Option<&str>) -> Option<String> {
+    let lines = doc?
+        .lines()
+        .filter(|line| parse_line(line).is_none())
+        .collect::<Vec<_>>();
+
+    if lines.iter().all(|line| line.trim().is_empty()) {
+        return None;
+    }
+
+    Some(lines.join("\n"))
+}
+
+/* ----------------------------- Fn: parse_line ----------------------------- */
+
+/// `parse_line` parses a directive line of the form `@gdscript name(key = value, ...)`.
+/// Returns `None` if the line is not a directive.
+#[allow(clippy::type_complexity)]
+fn parse_line(line: &str) -> Option<anyhow::Result<(&str, Vec<(&str, &str)>)>> {
+    let rest = line.trim().strip_prefix(PREFIX)?;
+    if !rest.is_empty() && !rest.starts_with(char::is_whitespace) {
+        return None;
+    }
+
+    let rest = rest.trim();
+
+    let Some((name, args)) = rest.split_once('(') else {
+        return Some(Ok((rest, vec![])));
+    };
+
+    let Some(args) = args.trim_end().strip_suffix(')') else {
+        return Some(Err(anyhow::anyhow!("Malformed directive: {}", line.trim())));
+    };
+
+    let args = args
+        .split(',')
+        .filter(|arg| !arg.trim().is_empty())
+        .map(|arg| {
+            arg.split_once('=')
+                .map(|(k, v)| (k.trim(), v.trim()))
+                .ok_or_else(|| anyhow::anyhow!("Malformed directive argument: {}", arg.trim()))
+        })
+        .collect::<anyhow::Result<Vec<_>>>();
+
+    Some(args.map(|args| (name.trim(), args)))
+}
+
+/* ---------------------------- Fn: parse_float ----------------------------- */
+
+fn parse_float(key: &str, value: &str) -> anyhow::Result<f64> {
+    value
+        .parse::<f64>()
+        .ok()
+        .filter(|v| v.is_finite())
+        .ok_or_else(|| anyhow::anyhow!("Invalid value for '{}': {}", key, value))
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                 Mod: Tests                                 */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /* ---------------------- Tests: FieldDirectives::parse --------------------- */
+
+    #[test]
+    fn test_parse_no_doc() {
+        // Given: A field without a doc comment.
+        // When: Parsing directives.
+        let directives = FieldDirectives::parse(None).unwrap();
+
+        // Then: No directives are set.
+        assert_eq!(directives, FieldDirectives::default());
+    }
+
+    #[test]
+    fn test_parse_quantize_with_precision() {
+        // Given: A doc comment with a quantize directive using a precision.
+        let doc = "Position.\n@gdscript quantize(min = -1024, max = 1024, precision = 0.01)";
+
+        // When: Parsing directives.
+        let directives = FieldDirectives::parse(Some(doc)).unwrap();
+
+        // Then: The bit count covers the range at the requested precision.
+        assert_eq!(
+            directives.quantize,
+            Some(Quantize {
+                min: -1024.0,
+                max: 1024.0,
+                bits: 18,
+                clamp: true,
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_quantize_with_bits() {
+        // Given: A doc comment with a quantize directive using a bit count.
+        let doc = "@gdscript quantize(min=0, max=360, bits=12, clamp=false)";
+
+        // When: Parsing directives.
+        let directives = FieldDirectives::parse(Some(doc)).unwrap();
+
+        // Then: The explicit bit count and clamping mode are used.
+        assert_eq!(
+            directives.quantize,
+            Some(Quantize {
+                min: 0.0,
+                max: 360.0,
+                bits: 12,
+                clamp: false,
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_quantize_invalid_range() {
+        // Given: A quantize directive with an empty range.
+        let doc = "@gdscript quantize(min = 1, max = 1, bits = 8)";
+
+        // When: Parsing directives.
+        let result = FieldDirectives::parse(Some(doc));
+
+        // Then: An error is returned.
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_parse_quantize_missing_precision() {
+        // Given: A quantize directive without a precision or bit count.
+        let doc = "@gdscript quantize(min = 0, max = 1)";
+
+        // When: Parsing directives.
+        let result = FieldDirectives::parse(Some(doc));
+
+        // Then: An error is returned.
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_parse_unknown_directive() {
+        // Given: A doc comment with an unknown directive.
+        let doc = "@gdscript frobnicate";
+
+        // When: Parsing directives.
+        let result = FieldDirectives::parse(Some(doc));
+
+        // Then: An error is returned.
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_parse_length_max() {
+        // Given: A doc comment with a bounded length directive.
+        let doc = "@gdscript length(max = 9)";
+
+        // When: Parsing directives.
+        let directives = FieldDirectives::parse(Some(doc)).unwrap();
+
+        // Then: The prefix is wide enough to represent the bound.
+        assert_eq!(directives.length, Some(Length::Max(9)));
+        assert_eq!(directives.length.unwrap().prefix_bits(), Some(4));
+    }
+
+    #[test]
+    fn test_parse_length_fixed() {
+        // Given: A doc comment with a fixed length directive.
+        let doc = "@gdscript length(fixed = 32)";
+
+        // When: Parsing directives.
+        let directives = FieldDirectives::parse(Some(doc)).unwrap();
+
+        // Then: No length prefix is used.
+        assert_eq!(directives.length, Some(Length::Fixed(32)));
+        assert_eq!(directives.length.unwrap().prefix_bits(), None);
+    }
+
+    #[test]
+    fn test_parse_length_invalid() {
+        // Given: Length directives with invalid arguments.
+        let docs = [
+            "@gdscript length()",
+            "@gdscript length(fixed = 0)",
+            "@gdscript length(max = -1)",
+            "@gdscript length(fixed = 4, max = 8)",
+        ];
+
+        for doc in docs {
+            // When: Parsing directives.
+            let result = FieldDirectives::parse(Some(doc));
+
+            // Then: An error is returned.
+            assert!(result.is_err(), "expected error: {}", doc);
+        }
+    }
+
+    #[test]
+    fn test_parse_optional() {
+        // Given: A doc comment with an optional directive.
+        let doc = "The player's target.\n@gdscript optional";
+
+        // When: Parsing directives.
+        let directives = FieldDirectives::parse(Some(doc)).unwrap();
+
+        // Then: The field is optional.
+        assert!(directives.optional);
+    }
+
+    #[test]
+    fn test_parse_conflicting_directives() {
+        // Given: A doc comment with multiple directives which can't be combined.
+        let doc = "@gdscript optional\n@gdscript length(max = 4)";
+
+        // When: Parsing directives.
+        let result = FieldDirectives::parse(Some(doc));
+
+        // Then: An error is returned.
+        assert!(result.is_err());
+    }
+
+    /* ------------------------ Tests: strip_directives ------------------------- */
+
+    #[test]
+    fn test_strip_directives_keeps_doc_text() {
+        // Given: A doc comment with text and a directive.
+        let doc = "The player's position.\n@gdscript quantize(min = 0, max = 1, bits = 8)";
+
+        // When: Stripping directives.
+        let stripped = strip_directives(Some(doc));
+
+        // Then: Only the doc text remains.
+        assert_eq!(stripped.as_deref(), Some("The player's position."));
+    }
+
+    #[test]
+    fn test_strip_directives_only_directives() {
+        // Given: A doc comment containing only a directive.
+        let doc = "@gdscript quantize(min = 0, max = 1, bits = 8)";
+
+        // When: Stripping directives.
+        let stripped = strip_directives(Some(doc));
+
+        // Then: Nothing remains.
+        assert_eq!(stripped, None);
+    }
+
+    #[test]
+    fn test_strip_directives_ignores_similar_words() {
+        // Given: A doc comment with a word that merely starts with the prefix.
+        let doc = "@gdscripted is not a directive.";
+
+        // When: Stripping directives.
+        let stripped = strip_directives(Some(doc));
+
+        // Then: The line is kept.
+        assert_eq!(stripped.as_deref(), Some(doc));
+    }
+}
@@ -1,10 +1,17 @@
-use baproto::{CodeWriter, Enum, StringWriter, Variant};
+use std::collections::HashSet;
+
+use baproto::{CodeWriter, Encoding, Enum, NativeType, StringWriter, Variant};
 
 use crate::gdscript::ast::*;
 use crate::gdscript::codec::{gen_enum_decode_stmts, gen_enum_encode_stmts};
 use crate::gdscript::collect::TypeEntry;
+use crate::gdscript::message::{
+    gen_delimited_methods, gen_json_string_methods, gen_stream_methods,
+};
+use crate::gdscript::options::Options;
 use crate::gdscript::types::{
-    collect_variant_dependencies, default_value, escape_keyword, gen_dependencies_section,
+    Dependency, collect_variant_dependencies, default_value, escape_keyword,
+    gen_dependencies_section, gen_lazy_load_stmts, global_class_name, json_spec, packed_array_name,
     type_name,
 };
 
@@ -14,19 +21,29 @@ use crate::gdscript::types::{
 
 /// `generate_enum` generates the GDScript code for an enum type.
 ///
-/// Enums are represented as discriminated unions with serialization support.
+/// Enums are represented as discriminated unions with serialization support. If
+/// `options.instantiate_messages` is set, new enums are set to their first variant,
+/// constructing its value if it's one of the `instantiated` variants. The `lazy` types
+/// are loaded on first use (see [`collect_lazy_dependencies`]).
+///
+/// [`collect_lazy_dependencies`]: crate::gdscript::collect::collect_lazy_dependencies
 pub fn generate_enum(
     cw: &mut CodeWriter,
     enm: &Enum,
     entry: &TypeEntry,
     pkg: &[String],
+    options: &Options,
+    instantiated: &HashSet<String>,
+    lazy: &HashSet<String>,
 ) -> anyhow::Result<String> {
     let mut w = StringWriter::default();
 
+    let deps = collect_variant_dependencies(&enm.variants, pkg, &entry.file_stem, lazy);
+
     let mut sections = Vec::new();
 
     // Dependencies
-    sections.push(gen_dependencies(&enm.variants, pkg, &entry.file_stem));
+    sections.push(gen_dependencies_section(&deps));
 
     // Discriminants (GDScript enum)
     if !enm.variants.is_empty() {
@@ -39,8 +56,13 @@ pub fn generate_enum(
     // Public methods
     let mut public_methods = Vec::new();
     public_methods.extend(gen_discriminant_methods());
-    public_methods.extend(gen_accessor_methods(&enm.variants));
+    public_methods.extend(gen_accessor_methods(&enm.variants, options, lazy));
     public_methods.extend(gen_serialization_methods());
+    public_methods.extend(gen_stream_methods("enum"));
+    public_methods.extend(gen_delimited_methods("enum"));
+    public_methods.extend(gen_value_methods());
+    public_methods.extend(gen_copy_methods());
+    public_methods.extend(gen_json_methods(&enm.variants, options, lazy));
 
     sections.push(
         SectionBuilder::default()
@@ -56,7 +78,7 @@ pub fn generate_enum(
     );
 
     // Private methods
-    let private_methods = gen_private_methods(&enm.variants)?;
+    let private_methods = gen_private_methods(&enm.discriminant, &enm.variants, options, lazy)?;
     sections.push(
         SectionBuilder::default()
             .header("PRIVATE METHODS")
@@ -71,7 +93,12 @@ pub fn generate_enum(
     );
 
     // Engine methods
-    sections.push(gen_engine_methods());
+    sections.push(gen_engine_methods(
+        &enm.variants,
+        options,
+        instantiated,
+        &deps,
+    ));
 
     // Debugging
     let to_string_method = gen_to_string_method(&enm.variants);
@@ -85,6 +112,11 @@ pub fn generate_enum(
 
     let script = ScriptBuilder::default()
         .header(Comment::do_not_edit())
+        .class_name(
+            options
+                .class_names
+                .then(|| global_class_name(&enm.descriptor)),
+        )
         .comment(enm.doc.as_ref().map(Comment::from))
         .extends("RefCounted".to_string())
         .sections(sections)
@@ -191,7 +223,11 @@ fn gen_discriminant_methods() -> Vec<FnDef> {
 
 /* ----------------------- Fn: gen_accessor_methods ------------------------ */
 
-fn gen_accessor_methods(variants: &[Variant]) -> Vec<FnDef> {
+fn gen_accessor_methods(
+    variants: &[Variant],
+    options: &Options,
+    lazy: &HashSet<String>,
+) -> Vec<FnDef> {
     let mut methods = Vec::new();
 
     for variant in variants {
@@ -251,8 +287,8 @@ fn gen_accessor_methods(variants: &[Variant]) -> Vec<FnDef> {
             Variant::Field { name, field, .. } => {
                 let snake_name = name.to_lowercase();
                 let variant_const = escape_keyword(name);
-                let type_str = type_name(&field.encoding.native);
-                let default_val = default_value(&field.encoding.native);
+                let type_str = type_name(&field.encoding.native, options, lazy);
+                let default_val = default_value(&field.encoding.native, None);
 
                 // has_xxx() -> bool
                 let has_func = FnDefBuilder::default()
@@ -330,13 +366,6 @@ fn gen_accessor_methods(variants: &[Variant]) -> Vec<FnDef> {
     methods
 }
 
-/* -------------------------- Fn: gen_dependencies -------------------------- */
-
-fn gen_dependencies(variants: &[Variant], pkg: &[String], name: &str) -> Section {
-    let deps = collect_variant_dependencies(variants, pkg, name);
-    gen_dependencies_section(deps)
-}
-
 /* -------------------- Fn: gen_serialization_methods ---------------------- */
 
 fn gen_serialization_methods() -> Vec<FnDef> {
@@ -387,13 +416,417 @@ fn gen_serialization_methods() -> Vec<FnDef> {
     methods
 }
 
+/* ------------------------- Fn: gen_value_methods ------------------------- */
+
+fn gen_value_methods() -> Vec<FnDef> {
+    let mut methods = Vec::new();
+
+    let return_false = || Block::from(vec![Item::Return(Some(Literal::Bool(false).into()))]);
+
+    // equals(other: Object) -> bool
+    let equals_func = FnDefBuilder::default()
+        .name("equals")
+        .comment("`equals` returns whether `other` is an enum of this type with an equal value.")
+        .params(vec![Assignment::param("other", "Object")])
+        .type_hint(TypeHint::Explicit("bool".to_string()))
+        .body(vec![
+            IfBuilder::default()
+                .condition(Expr::not(FnCall::function_args(
+                    "is_instance_of",
+                    vec![Expr::ident("other"), FnCall::function("get_script")],
+                )))
+                .then_body(return_false())
+                .build()
+                .unwrap()
+                .into(),
+            IfBuilder::default()
+                .condition(Expr::binary_op(
+                    Expr::ident("_discriminant"),
+                    Operator::NotEq,
+                    Expr::field(Expr::ident("other"), "_discriminant"),
+                ))
+                .then_body(return_false())
+                .build()
+                .unwrap()
+                .into(),
+            Item::Return(Some(FnCall::method_args(
+                Expr::ident("_Value"),
+                "equals",
+                vec![
+                    Expr::ident("_value"),
+                    Expr::field(Expr::ident("other"), "_value"),
+                ],
+            ))),
+        ])
+        .build()
+        .unwrap();
+    methods.push(equals_func);
+
+    // hash() -> int
+    let hash_func = FnDefBuilder::default()
+        .name("hash")
+        .comment("`hash` returns a hash of this enum's value consistent with `equals`.")
+        .type_hint(TypeHint::Explicit("int".to_string()))
+        .body(vec![Item::Return(Some(FnCall::method_args(
+            Expr::ident("_Value"),
+            "hash_value",
+            vec![Expr::Literal(Literal::Array(vec![
+                Expr::ident("_discriminant"),
+                Expr::ident("_value"),
+            ]))],
+        )))])
+        .build()
+        .unwrap();
+    methods.push(hash_func);
+
+    // is_default() -> bool
+    let is_default_func = FnDefBuilder::default()
+        .name("is_default")
+        .comment("`is_default` returns whether the enum is unset (i.e. NONE).")
+        .type_hint(TypeHint::Explicit("bool".to_string()))
+        .body(vec![Item::Return(Some(FnCall::function("is_none")))])
+        .build()
+        .unwrap();
+    methods.push(is_default_func);
+
+    methods
+}
+
+/* ------------------------- Fn: gen_copy_methods -------------------------- */
+
+fn gen_copy_methods() -> Vec<FnDef> {
+    let mut methods = Vec::new();
+
+    let check_type: Item = FnCall::function_args(
+        "assert",
+        vec![
+            FnCall::function_args(
+                "is_instance_of",
+                vec![Expr::ident("other"), FnCall::function("get_script")],
+            ),
+            Literal::from("Invalid argument; mismatched enum type").into(),
+        ],
+    )
+    .into();
+
+    // clone() -> RefCounted
+    let clone_func = FnDefBuilder::default()
+        .name("clone")
+        .comment("`clone` returns a deep copy of this enum.")
+        .type_hint(TypeHint::Explicit("RefCounted".to_string()))
+        .body(vec![
+            AssignmentBuilder::default()
+                .declaration(DeclarationKind::Var)
+                .variable("_copy")
+                .type_hint(TypeHint::Explicit("RefCounted".to_string()))
+                .value(ValueKind::Expr(FnCall::method(
+                    FnCall::function("get_script"),
+                    "new",
+                )))
+                .build()
+                .unwrap()
+                .into(),
+            FnCall::method_args(Expr::ident("_copy"), "copy_from", vec![Expr::ident("self")])
+                .into(),
+            Item::Return(Some(Expr::ident("_copy"))),
+        ])
+        .build()
+        .unwrap();
+    methods.push(clone_func);
+
+    // copy_from(other: Object) -> void
+    let copy_from_func = FnDefBuilder::default()
+        .name("copy_from")
+        .comment("`copy_from` replaces this enum's value with a deep copy of `other`'s.")
+        .params(vec![Assignment::param("other", "Object")])
+        .body(vec![
+            check_type.clone(),
+            Assignment::reassign(
+                "_discriminant",
+                Expr::field(Expr::ident("other"), "_discriminant"),
+            )
+            .into(),
+            Assignment::reassign(
+                "_value",
+                FnCall::method_args(
+                    Expr::ident("_Value"),
+                    "copy",
+                    vec![Expr::field(Expr::ident("other"), "_value")],
+                ),
+            )
+            .into(),
+        ])
+        .build()
+        .unwrap();
+    methods.push(copy_from_func);
+
+    // merge_from(other: Object) -> void
+    let merge_from_func = FnDefBuilder::default()
+        .name("merge_from")
+        .comment(
+            "`merge_from` merges `other` into this enum. If both hold a message of the same\n\
+             variant, the messages are merged; otherwise `other`'s value (if set) replaces\n\
+             this enum's value.",
+        )
+        .params(vec![Assignment::param("other", "Object")])
+        .body(vec![
+            check_type,
+            IfBuilder::default()
+                .condition(FnCall::method(Expr::ident("other"), "is_none"))
+                .then_body(Block::from(vec![Item::Return(None)]))
+                .build()
+                .unwrap()
+                .into(),
+            IfBuilder::default()
+                .condition(Expr::binary_op(
+                    Expr::binary_op(
+                        Expr::ident("_discriminant"),
+                        Operator::Eq,
+                        Expr::field(Expr::ident("other"), "_discriminant"),
+                    ),
+                    Operator::And,
+                    Expr::binary_op(Expr::ident("_value"), Operator::Is, Expr::ident("Object")),
+                ))
+                .then_body(Block::from(vec![
+                    FnCall::method_args(
+                        Expr::ident("_value"),
+                        "merge_from",
+                        vec![Expr::field(Expr::ident("other"), "_value")],
+                    )
+                    .into(),
+                    Item::Return(None),
+                ]))
+                .build()
+                .unwrap()
+                .into(),
+            FnCall::function_args("copy_from", vec![Expr::ident("other")]).into(),
+        ])
+        .build()
+        .unwrap();
+    methods.push(merge_from_func);
+
+    methods
+}
+
+/* ------------------------- Fn: gen_json_methods -------------------------- */
+
+fn gen_json_methods(variants: &[Variant], options: &Options, lazy: &HashSet<String>) -> Vec<FnDef> {
+    let mut methods = Vec::new();
+
+    // to_dict() -> Dictionary
+    let mut to_dict_arms = Vec::new();
+
+    for variant in variants {
+        let (name, value) = match variant {
+            Variant::Unit { name, .. } => (name, Expr::null()),
+            Variant::Field { name, .. } => (
+                name,
+                FnCall::method_args(
+                    Expr::ident("_Value"),
+                    "to_json_value",
+                    vec![Expr::ident("_value")],
+                ),
+            ),
+        };
+
+        to_dict_arms.push(MatchArm {
+            pattern: Expr::ident(escape_keyword(name)),
+            body: Block::from(vec![Item::Return(Some(Expr::Literal(Literal::Dict(
+                vec![(Literal::from(name.as_str()).into(), value)],
+            ))))]),
+        });
+    }
+
+    let mut to_dict_body = Vec::new();
+
+    if !to_dict_arms.is_empty() {
+        to_dict_body.push(
+            Match {
+                scrutinee: Expr::ident("_discriminant"),
+                arms: to_dict_arms,
+            }
+            .into(),
+        );
+    }
+
+    let to_dict_func = FnDefBuilder::default()
+        .name("to_dict")
+        .comment(
+            "`to_dict` converts this enum into a JSON-compatible `Dictionary` with a single\n\
+             entry mapping the variant name to its value (or an empty one if NONE).",
+        )
+        .type_hint(TypeHint::Explicit("Dictionary".to_string()))
+        .body(to_dict_body)
+        .return_value(Expr::empty_dict())
+        .build()
+        .unwrap();
+    methods.push(to_dict_func);
+
+    // from_dict(data: Dictionary) -> Error
+    let return_invalid = || Block::from(vec![Item::Return(Some(Expr::ident("ERR_INVALID_DATA")))]);
+
+    let mut from_dict_body = vec![
+        FnCall::function("clear").into(),
+        IfBuilder::default()
+            .condition(FnCall::method(Expr::ident("data"), "is_empty"))
+            .then_body(Block::from(vec![Item::Return(Some(Expr::ident("OK")))]))
+            .build()
+            .unwrap()
+            .into(),
+        IfBuilder::default()
+            .condition(Expr::binary_op(
+                FnCall::method(Expr::ident("data"), "size"),
+                Operator::NotEq,
+                Expr::Literal(Literal::Int(1)),
+            ))
+            .then_body(return_invalid())
+            .build()
+            .unwrap()
+            .into(),
+    ];
+
+    if variants.iter().any(|v| matches!(v, Variant::Field { .. })) {
+        from_dict_body.push(
+            Assignment::var("_out", Expr::Literal(Literal::Array(vec![Expr::null()]))).into(),
+        );
+    }
+
+    let mut from_dict_arms = Vec::new();
+
+    for variant in variants {
+        let (name, body) = match variant {
+            Variant::Unit { name, .. } => (name, vec![]),
+            Variant::Field { name, field, .. } => {
+                let key: Expr = Literal::from(name.as_str()).into();
+                (
+                    name,
+                    gen_variant_from_json_stmts(&field.encoding.native, key, options, lazy),
+                )
+            }
+        };
+
+        let mut body = body;
+        body.push(Assignment::reassign("_discriminant", Expr::ident(escape_keyword(name))).into());
+
+        from_dict_arms.push(MatchArm {
+            pattern: Literal::from(name.as_str()).into(),
+            body: Block::from(body),
+        });
+    }
+
+    from_dict_arms.push(MatchArm {
+        pattern: Expr::ident("_"),
+        body: return_invalid(),
+    });
+
+    from_dict_body.push(
+        Match {
+            scrutinee: Expr::index(
+                FnCall::method(Expr::ident("data"), "keys"),
+                Expr::Literal(Literal::Int(0)),
+            ),
+            arms: from_dict_arms,
+        }
+        .into(),
+    );
+
+    let from_dict_func = FnDefBuilder::default()
+        .name("from_dict")
+        .comment(
+            "`from_dict` replaces this enum's value with the one in `data`, which has the\n\
+             format produced by `to_dict`.",
+        )
+        .params(vec![Assignment::param("data", "Dictionary")])
+        .type_hint(TypeHint::Explicit("Error".to_string()))
+        .body(from_dict_body)
+        .return_value(Expr::ident("OK"))
+        .build()
+        .unwrap();
+    methods.push(from_dict_func);
+
+    methods.extend(gen_json_string_methods("enum"));
+
+    methods
+}
+
+/* ------------------- Fn: gen_variant_from_json_stmts --------------------- */
+
+/// `gen_variant_from_json_stmts` generates statements which convert a field variant's
+/// JSON value (at `data[key]`) into `_value`. Like `gen_variant_decode_stmts`, arrays
+/// and maps are converted into a typed local variable before being stored.
+fn gen_variant_from_json_stmts(
+    native: &NativeType,
+    key: Expr,
+    options: &Options,
+    lazy: &HashSet<String>,
+) -> Vec<Item> {
+    let out = Expr::index(Expr::ident("_out"), Expr::Literal(Literal::Int(0)));
+
+    let mut stmts = vec![
+        Assignment::var(
+            "_err",
+            FnCall::method_args(
+                Expr::ident("_Value"),
+                "from_json_value",
+                vec![
+                    Expr::index(Expr::ident("data"), key),
+                    json_spec(native),
+                    Expr::ident("_out"),
+                ],
+            ),
+        )
+        .into(),
+        IfBuilder::default()
+            .condition(Expr::binary_op(
+                Expr::ident("_err"),
+                Operator::NotEq,
+                Expr::ident("OK"),
+            ))
+            .then_body(Block::from(vec![Item::Return(Some(Expr::ident("_err")))]))
+            .build()
+            .unwrap()
+            .into(),
+    ];
+
+    let method = match native {
+        NativeType::Array { element } if packed_array_name(&element.native).is_none() => {
+            Some("assign")
+        }
+        NativeType::Map { .. } => Some("merge"),
+        _ => None,
+    };
+
+    match method {
+        Some(method) => {
+            let declare_payload = AssignmentBuilder::default()
+                .declaration(DeclarationKind::Var)
+                .variable("_payload")
+                .type_hint(TypeHint::Explicit(type_name(native, options, lazy)))
+                .build()
+                .unwrap();
+
+            stmts.push(Item::Assignment(declare_payload));
+            stmts.push(FnCall::method_args(Expr::ident("_payload"), method, vec![out]).into());
+            stmts.push(Assignment::reassign("_value", Expr::ident("_payload")).into());
+        }
+        None => stmts.push(Assignment::reassign("_value", out).into()),
+    }
+
+    stmts
+}
+
 /* ----------------------- Fn: gen_private_methods ------------------------- */
 
-fn gen_private_methods(variants: &[Variant]) -> anyhow::Result<Vec<FnDef>> {
+fn gen_private_methods(
+    discriminant: &Encoding,
+    variants: &[Variant],
+    options: &Options,
+    lazy: &HashSet<String>,
+) -> anyhow::Result<Vec<FnDef>> {
     let mut methods = Vec::new();
 
     // _encode(_writer: _Writer) -> void
-    let encode_body = gen_enum_encode_stmts(variants)?;
+    let encode_body = gen_enum_encode_stmts(discriminant, variants, lazy)?;
     let encode_func = FnDefBuilder::default()
         .name("_encode")
         .comment("`_encode` serializes the enum to the writer.")
@@ -404,7 +837,7 @@ fn gen_private_methods(variants: &[Variant]) -> anyhow::Result<Vec<FnDef>> {
     methods.push(encode_func);
 
     // _decode(_reader: _Reader) -> Error
-    let decode_body = gen_enum_decode_stmts(variants)?;
+    let decode_body = gen_enum_decode_stmts(discriminant, variants, options, lazy)?;
     let decode_func = FnDefBuilder::default()
         .name("_decode")
         .comment("`_decode` deserializes the enum from the reader.")
@@ -449,7 +882,11 @@ fn gen_to_string_method(variants: &[Variant]) -> FnDef {
                         Expr::from(format!("\"{}(\"", name)),
                         Operator::Add,
                         Expr::binary_op(
-                            FnCall::function_args("str", vec![Expr::ident("_value")]),
+                            FnCall::method_args(
+                                Expr::ident("_Value"),
+                                "to_debug_string",
+                                vec![Expr::ident("_value")],
+                            ),
                             Operator::Add,
                             Expr::from("\")\""),
                         ),
@@ -475,14 +912,44 @@ fn gen_to_string_method(variants: &[Variant]) -> FnDef {
 
 /* ------------------------ Fn: gen_engine_methods ------------------------ */
 
-fn gen_engine_methods() -> Section {
+fn gen_engine_methods(
+    variants: &[Variant],
+    options: &Options,
+    instantiated: &HashSet<String>,
+    deps: &[Dependency],
+) -> Section {
+    let (discriminant, value) = match variants.first() {
+        Some(Variant::Unit { name, .. }) if options.instantiate_messages => {
+            (Expr::ident(escape_keyword(name)), Expr::null())
+        }
+        Some(Variant::Field { name, field, .. }) if options.instantiate_messages => {
+            let value = match &field.encoding.native {
+                NativeType::Message { descriptor } | NativeType::Enum { descriptor }
+                    if instantiated.contains(name) =>
+                {
+                    FnCall::method(Expr::ident(descriptor.path.join("_")), "new")
+                }
+                native => default_value(native, None),
+            };
+
+            (Expr::ident(escape_keyword(name)), value)
+        }
+        _ => (Expr::ident("NONE"), Expr::null()),
+    };
+
     let init_func = FnDefBuilder::default()
         .name("_init")
         .type_hint(TypeHint::Explicit("void".to_string()))
-        .body(vec![
-            Assignment::reassign("_discriminant", Expr::ident("NONE")).into(),
-            Assignment::reassign("_value", Expr::null()).into(),
-        ])
+        .body(
+            [
+                gen_lazy_load_stmts(deps),
+                vec![
+                    Assignment::reassign("_discriminant", discriminant).into(),
+                    Assignment::reassign("_value", value).into(),
+                ],
+            ]
+            .concat(),
+        )
         .build()
         .unwrap();
 
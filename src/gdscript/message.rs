@@ -1,40 +1,76 @@
-use baproto::Field;
+use std::collections::HashSet;
+
 use baproto::{CodeWriter, Message, StringWriter};
+use baproto::{Field, NativeType};
 
 use crate::gdscript::ast::*;
+use crate::gdscript::builtin::BuiltinType;
 use crate::gdscript::codec;
 use crate::gdscript::collect::TypeEntry;
+use crate::gdscript::directive::{FieldDirectives, Length, strip_directives};
+use crate::gdscript::options::Options;
 use crate::gdscript::types::{
-    collect_field_dependencies, default_value, escape_keyword, gen_dependencies_section, type_name,
+    Dependency, collect_field_dependencies, default_value, escape_keyword, field_default_value,
+    gen_dependencies_section, gen_lazy_load_stmts, global_class_name, is_scalar, json_spec,
+    packed_array_name, requires_deep_equals, type_name,
 };
 
 /* -------------------------------------------------------------------------- */
 /*                            Fn: generate_message                            */
 /* -------------------------------------------------------------------------- */
 
-/// `generate_message` generates the GDScript code for a message type.
+/// `generate_message` generates the GDScript code for a message type. The
+/// `instantiated` fields are default-constructed when the message is instantiated (see
+/// [`collect_instantiated_fields`]), and the `lazy` types are loaded on first use (see
+/// [`collect_lazy_dependencies`]).
+///
+/// [`collect_instantiated_fields`]: crate::gdscript::collect::collect_instantiated_fields
+/// [`collect_lazy_dependencies`]: crate::gdscript::collect::collect_lazy_dependencies
 pub fn generate_message(
     cw: &mut CodeWriter,
     msg: &Message,
     entry: &TypeEntry,
     pkg: &[String],
+    options: &Options,
+    instantiated: &HashSet<String>,
+    lazy: &HashSet<String>,
 ) -> anyhow::Result<String> {
     let mut w = StringWriter::default();
 
+    let deps = collect_field_dependencies(&msg.fields, pkg, &entry.file_stem, lazy);
+
+    check_field_names(msg, options, &deps)?;
+
     let mut sections = Vec::new();
 
-    sections.push(gen_dependencies(&msg.fields, pkg, &entry.file_stem));
+    sections.push(gen_dependencies_section(&deps));
     sections.push(gen_types(entry));
 
     if !msg.fields.is_empty() {
-        sections.push(gen_fields(&msg.fields));
+        sections.push(gen_fields(&msg.fields, options, lazy)?);
+    }
+
+    sections.push(gen_public_methods(&msg.fields, options, instantiated)?);
+    sections.push(gen_private_methods(
+        &msg.fields,
+        options,
+        instantiated,
+        lazy,
+    )?);
+
+    if !instantiated.is_empty() || deps.iter().any(Dependency::is_lazy) {
+        sections.push(gen_engine_methods(&msg.fields, instantiated, &deps)?);
     }
 
-    sections.push(gen_public_methods());
-    sections.push(gen_private_methods(&msg.fields)?);
+    sections.push(gen_debugging(&msg.descriptor.path.join("."), &msg.fields));
 
     let script = ScriptBuilder::default()
         .header(Comment::do_not_edit())
+        .class_name(
+            options
+                .class_names
+                .then(|| global_class_name(&msg.descriptor)),
+        )
         .comment(msg.doc.as_ref().map(Comment::from))
         .extends("RefCounted".to_string())
         .sections(sections)
@@ -46,25 +82,92 @@ pub fn generate_message(
     Ok(w.into_content())
 }
 
-/* -------------------------- Fn: gen_dependencies -------------------------- */
+/* -------------------------- Fn: check_field_names ------------------------- */
+
+/// `GENERATED_MEMBERS` contains the names of the members generated for every message,
+/// including the runtime dependencies preloaded by [`gen_dependencies_section`].
+const GENERATED_MEMBERS: &[&str] = &[
+    "_Framing",
+    "_Reader",
+    "_Value",
+    "_Writer",
+    "_decode",
+    "_decode_delta",
+    "_encode",
+    "_encode_delta",
+    "_init",
+    "_presence",
+    "_to_string",
+    "clone",
+    "copy_from",
+    "deserialize",
+    "deserialize_delimited",
+    "deserialize_delta",
+    "equals",
+    "from_dict",
+    "from_json",
+    "hash",
+    "is_default",
+    "merge_from",
+    "read_from",
+    "serialize",
+    "serialize_delimited",
+    "serialize_delta",
+    "to_dict",
+    "to_json",
+    "write_to",
+];
+
+/// `check_field_names` returns an error if the (escaped) name of one of the message's
+/// fields clashes with a generated member, such as a method, a presence method of
+/// another field, or a dependency's constant. GDScript doesn't allow a variable to
+/// share its name with another member, so the generated script wouldn't parse.
+fn check_field_names(msg: &Message, options: &Options, deps: &[Dependency]) -> anyhow::Result<()> {
+    let mut members: HashSet<String> = GENERATED_MEMBERS
+        .iter()
+        .map(|name| name.to_string())
+        .chain(deps.iter().map(|dep| dep.const_name().to_string()))
+        .collect();
+
+    for field in &msg.fields {
+        if options.tagged || FieldDirectives::parse(field.doc.as_deref())?.optional {
+            members.insert(format!("has_{}", field.name));
+            members.insert(format!("clear_{}", field.name));
+        }
+    }
+
+    for field in &msg.fields {
+        let field_name = escape_keyword(&field.name);
 
-fn gen_dependencies(fields: &[Field], pkg: &[String], name: &str) -> Section {
-    let deps = collect_field_dependencies(fields, pkg, name);
-    gen_dependencies_section(deps)
+        if members.contains(&field_name) {
+            anyhow::bail!(
+                "Field name conflicts with a generated member: message={}, field={}",
+                msg.descriptor.path.join("."),
+                field.name
+            );
+        }
+    }
+
+    Ok(())
 }
 
 /* ----------------------------- Fn: gen_fields ----------------------------- */
 
-fn gen_fields(fields: &[Field]) -> Section {
+fn gen_fields(
+    fields: &[Field],
+    options: &Options,
+    lazy: &HashSet<String>,
+) -> anyhow::Result<Section> {
     let mut items = Vec::new();
 
     for field in fields {
-        let type_str = type_name(&field.encoding.native);
-        let default_value = default_value(&field.encoding.native);
+        let type_str = type_name(&field.encoding.native, options, lazy);
+        let length = field_length(field)?;
+        let default_value = default_value(&field.encoding.native, length.as_ref());
 
         items.push(
             AssignmentBuilder::default()
-                .comment(field.doc.as_ref().map(Comment::from))
+                .comment(strip_directives(field.doc.as_deref()).map(Comment::from))
                 .declaration(DeclarationKind::Var)
                 .variable(escape_keyword(&field.name))
                 .type_hint(TypeHint::Explicit(type_str))
@@ -75,16 +178,43 @@ fn gen_fields(fields: &[Field]) -> Section {
         );
     }
 
-    SectionBuilder::default()
+    if options.tagged {
+        items.push(
+            AssignmentBuilder::default()
+                .comment(Some(Comment::from(
+                    "`_presence` tracks which fields were present in decoded data.",
+                )))
+                .declaration(DeclarationKind::Var)
+                .variable("_presence")
+                .type_hint(TypeHint::Explicit("int".to_owned()))
+                .value(ValueKind::Expr(Literal::Int(0).into()))
+                .build()
+                .unwrap()
+                .into(),
+        );
+    }
+
+    Ok(SectionBuilder::default()
         .header("INITIALIZATION")
         .body(items)
         .build()
-        .unwrap()
+        .unwrap())
+}
+
+/* ---------------------------- Fn: field_length ---------------------------- */
+
+/// `field_length` returns the `length` directive declared on a field, if any.
+fn field_length(field: &Field) -> anyhow::Result<Option<Length>> {
+    Ok(FieldDirectives::parse(field.doc.as_deref())?.length)
 }
 
 /* ------------------------- Fn: gen_public_methods ------------------------- */
 
-fn gen_public_methods() -> Section {
+fn gen_public_methods(
+    fields: &[Field],
+    options: &Options,
+    instantiated: &HashSet<String>,
+) -> anyhow::Result<Section> {
     let serialize = FnDefBuilder::default()
         .comment("`serialize` writes this message to a `PackedByteArray`.")
         .name("serialize")
@@ -121,30 +251,1042 @@ fn gen_public_methods() -> Section {
         .build()
         .unwrap();
 
-    SectionBuilder::default()
+    let mut items = vec![Item::FnDef(serialize), Item::FnDef(deserialize)];
+    items.extend(gen_stream_methods("message").into_iter().map(Item::FnDef));
+    items.extend(
+        gen_delimited_methods("message")
+            .into_iter()
+            .map(Item::FnDef),
+    );
+    items.extend(gen_delta_methods());
+    items.extend(gen_value_methods(fields, instantiated)?);
+    items.extend(gen_copy_methods(fields, options)?);
+    items.extend(gen_json_methods(fields, options, instantiated)?);
+
+    if options.tagged {
+        items.extend(gen_presence_methods(fields, instantiated)?);
+    } else {
+        items.extend(gen_optional_methods(fields)?);
+    }
+
+    Ok(SectionBuilder::default()
         .header("PUBLIC METHODS")
-        .body(vec![Item::FnDef(serialize), Item::FnDef(deserialize)])
+        .body(items)
+        .build()
+        .unwrap())
+}
+
+/* ------------------------- Fn: gen_stream_methods ------------------------- */
+
+/// `gen_stream_methods` generates `write_to` and `read_from` methods, which encode the
+/// type directly to (or from) an existing bit stream. Unlike `serialize`, values are
+/// not aligned to a byte boundary, so several values can share one stream.
+pub(crate) fn gen_stream_methods(kind: &str) -> Vec<FnDef> {
+    // write_to(writer: _Writer) -> Error
+    let write_to = FnDefBuilder::default()
+        .comment(format!(
+            "`write_to` writes this {} to `writer`, starting at its current bit position.\n\
+             Returns the writer's error state.",
+            kind
+        ))
+        .name("write_to")
+        .params(vec![Assignment::param("writer", "_Writer")])
+        .type_hint(TypeHint::Explicit("Error".to_string()))
+        .body(vec![
+            FnCall::function_args("_encode", vec!["writer"]).into(),
+        ])
+        .return_value(FnCall::method("writer", "get_error"))
+        .build()
+        .unwrap();
+
+    // read_from(reader: _Reader) -> Error
+    let read_from = FnDefBuilder::default()
+        .comment(format!(
+            "`read_from` reads this {} from `reader`, starting at its current bit position.\n\
+             Afterwards, `reader.get_position()` is the bit position following the {}.",
+            kind, kind
+        ))
+        .name("read_from")
+        .params(vec![Assignment::param("reader", "_Reader")])
+        .type_hint(TypeHint::Explicit("Error".to_string()))
+        .body(vec![
+            FnCall::function_args("_decode", vec!["reader"]).into(),
+        ])
+        .return_value(FnCall::method("reader", "get_error"))
+        .build()
+        .unwrap();
+
+    vec![write_to, read_from]
+}
+
+/* ----------------------- Fn: gen_delimited_methods ------------------------ */
+
+/// `gen_delimited_methods` generates `serialize_delimited` and `deserialize_delimited`
+/// methods, which frame the type's serialized data with its length so that multiple
+/// values can be concatenated in one buffer.
+pub(crate) fn gen_delimited_methods(kind: &str) -> Vec<FnDef> {
+    // serialize_delimited(out: PackedByteArray) -> Error
+    let serialize_delimited = FnDefBuilder::default()
+        .comment(format!(
+            "`serialize_delimited` writes this {} to a `PackedByteArray`, prefixed with its\n\
+             length so that it can be followed by other data.",
+            kind
+        ))
+        .name("serialize_delimited")
+        .params(vec![Assignment::param("out", "PackedByteArray")])
+        .type_hint(TypeHint::Explicit("Error".to_string()))
+        .body(vec![
+            Assignment::var("_writer", FnCall::method("_Writer", "new")).into(),
+            FnCall::function_args("_encode", vec!["_writer"]).into(),
+            FnCall::method_args(
+                "_Framing",
+                "write_frame",
+                vec![FnCall::method("_writer", "to_bytes"), Expr::ident("out")],
+            )
+            .into(),
+        ])
+        .return_value(FnCall::method("_writer", "get_error"))
+        .build()
+        .unwrap();
+
+    // deserialize_delimited(data: PackedByteArray, offset: int = 0) -> int
+    let deserialize_delimited = FnDefBuilder::default()
+        .comment(format!(
+            "`deserialize_delimited` reads this {} from the length-prefixed data in `data`\n\
+             starting at `offset`. Returns the number of bytes consumed, `_Framing.INCOMPLETE`\n\
+             if `data` ends before the {}'s data does, or `_Framing.INVALID` on error.",
+            kind, kind
+        ))
+        .name("deserialize_delimited")
+        .params(vec![
+            Assignment::param("data", "PackedByteArray"),
+            Assignment::param_with_default("offset", "int", Literal::Int(0)),
+        ])
+        .type_hint(TypeHint::Explicit("int".to_string()))
+        .body(vec![
+            Assignment::var("_frame", Literal::Array(vec![Expr::null()])).into(),
+            Assignment::var(
+                "_size",
+                FnCall::method_args("_Framing", "read_frame", vec!["data", "offset", "_frame"]),
+            )
+            .into(),
+            IfBuilder::default()
+                .condition(Expr::binary_op(
+                    Expr::ident("_size"),
+                    Operator::Le,
+                    Expr::Literal(Literal::Int(0)),
+                ))
+                .then_body(Block::from(vec![Item::Return(Some(Expr::ident("_size")))]))
+                .build()
+                .unwrap()
+                .into(),
+            IfBuilder::default()
+                .condition(Expr::binary_op(
+                    FnCall::function_args(
+                        "deserialize",
+                        vec![Expr::index(
+                            Expr::ident("_frame"),
+                            Expr::Literal(Literal::Int(0)),
+                        )],
+                    ),
+                    Operator::NotEq,
+                    Expr::ident("OK"),
+                ))
+                .then_body(Block::from(vec![Item::Return(Some(Expr::field(
+                    Expr::ident("_Framing"),
+                    "INVALID",
+                )))]))
+                .build()
+                .unwrap()
+                .into(),
+        ])
+        .return_value(Expr::ident("_size"))
+        .build()
+        .unwrap();
+
+    vec![serialize_delimited, deserialize_delimited]
+}
+
+/* ------------------------- Fn: gen_delta_methods ------------------------- */
+
+fn gen_delta_methods() -> Vec<Item> {
+    // NOTE: A missing baseline is treated as a message with all default values.
+    let default_baseline = || -> Item {
+        IfBuilder::default()
+            .condition(Expr::binary_op(
+                Expr::ident("baseline"),
+                Operator::Eq,
+                Expr::null(),
+            ))
+            .then_body(Block::from(vec![
+                Assignment::reassign(
+                    "baseline",
+                    FnCall::method(FnCall::function("get_script"), "new"),
+                )
+                .into(),
+            ]))
+            .build()
+            .unwrap()
+            .into()
+    };
+
+    let check_type = || -> Item {
+        FnCall::function_args(
+            "assert",
+            vec![
+                FnCall::function_args(
+                    "is_instance_of",
+                    vec![Expr::ident("baseline"), FnCall::function("get_script")],
+                ),
+                Literal::from("Invalid argument; mismatched message type").into(),
+            ],
+        )
+        .into()
+    };
+
+    let serialize_delta = FnDefBuilder::default()
+        .comment(
+            "`serialize_delta` writes the fields of this message which differ from `baseline`\n\
+             to a `PackedByteArray`. The data can only be read by `deserialize_delta` using an\n\
+             equal baseline.",
+        )
+        .name("serialize_delta")
+        .params(vec![
+            Assignment::param("baseline", "Object"),
+            Assignment::param("out", "PackedByteArray"),
+        ])
+        .type_hint(TypeHint::Explicit("Error".to_string()))
+        .body(vec![
+            default_baseline(),
+            check_type(),
+            Assignment::var("_writer", FnCall::method("_Writer", "new")).into(),
+            FnCall::function_args("_encode_delta", vec!["baseline", "_writer"]).into(),
+            FnCall::method_args(
+                "out",
+                "append_array",
+                vec![FnCall::method("_writer", "to_bytes")],
+            )
+            .into(),
+        ])
+        .return_value(FnCall::method("_writer", "get_error"))
+        .build()
+        .unwrap();
+
+    let deserialize_delta = FnDefBuilder::default()
+        .comment(
+            "`deserialize_delta` reads this message from a `PackedByteArray` written by\n\
+             `serialize_delta`, copying unchanged fields from `baseline`.",
+        )
+        .name("deserialize_delta")
+        .params(vec![
+            Assignment::param("baseline", "Object"),
+            Assignment::param("data", "PackedByteArray"),
+        ])
+        .type_hint(TypeHint::Explicit("Error".to_string()))
+        .body(vec![
+            default_baseline(),
+            check_type(),
+            Assignment::var(
+                "_reader",
+                FnCall::method_args("_Reader", "new", vec!["data"]),
+            )
+            .into(),
+            FnCall::function_args("_decode_delta", vec!["baseline", "_reader"]).into(),
+        ])
+        .return_value(FnCall::method("_reader", "get_error"))
+        .build()
+        .unwrap();
+
+    vec![Item::FnDef(serialize_delta), Item::FnDef(deserialize_delta)]
+}
+
+/* ------------------------- Fn: gen_value_methods ------------------------- */
+
+fn gen_value_methods(
+    fields: &[Field],
+    instantiated: &HashSet<String>,
+) -> anyhow::Result<Vec<Item>> {
+    let return_false = || Block::from(vec![Item::Return(Some(Literal::Bool(false).into()))]);
+
+    // equals(other: Object) -> bool
+    let mut equals_body = vec![
+        IfBuilder::default()
+            .condition(Expr::not(FnCall::function_args(
+                "is_instance_of",
+                vec![Expr::ident("other"), FnCall::function("get_script")],
+            )))
+            .then_body(return_false())
+            .build()
+            .unwrap()
+            .into(),
+    ];
+
+    for field in fields {
+        let field_name = escape_keyword(&field.name);
+        let other = Expr::field(Expr::ident("other"), &field_name);
+
+        let condition = if requires_deep_equals(&field.encoding.native) {
+            Expr::not(FnCall::method_args(
+                Expr::ident("_Value"),
+                "equals",
+                vec![Expr::ident(&field_name), other],
+            ))
+        } else {
+            Expr::binary_op(Expr::ident(&field_name), Operator::NotEq, other)
+        };
+
+        equals_body.push(
+            IfBuilder::default()
+                .condition(condition)
+                .then_body(return_false())
+                .build()
+                .unwrap()
+                .into(),
+        );
+    }
+
+    equals_body.push(Item::Return(Some(Literal::Bool(true).into())));
+
+    let equals = FnDefBuilder::default()
+        .comment("`equals` returns whether `other` is a message of this type with equal fields.")
+        .name("equals")
+        .params(vec![Assignment::param("other", "Object")])
+        .type_hint(TypeHint::Explicit("bool".to_owned()))
+        .body(equals_body)
+        .build()
+        .unwrap();
+
+    // hash() -> int
+    let hash = FnDefBuilder::default()
+        .comment("`hash` returns a hash of this message's fields consistent with `equals`.")
+        .name("hash")
+        .type_hint(TypeHint::Explicit("int".to_owned()))
+        .body(vec![Item::Return(Some(FnCall::method_args(
+            Expr::ident("_Value"),
+            "hash_value",
+            vec![Expr::Literal(Literal::Array(
+                fields
+                    .iter()
+                    .map(|f| Expr::ident(escape_keyword(&f.name)))
+                    .collect(),
+            ))],
+        )))])
+        .build()
+        .unwrap();
+
+    // is_default() -> bool
+    let mut is_default_body = Vec::new();
+
+    for field in fields {
+        let field_name = escape_keyword(&field.name);
+        let length = field_length(field)?;
+
+        let condition = match &field.encoding.native {
+            _ if instantiated.contains(&field.name) => Expr::not(FnCall::method_args(
+                Expr::ident("_Value"),
+                "equals",
+                vec![
+                    Expr::ident(&field_name),
+                    field_default_value(field, length.as_ref(), instantiated),
+                ],
+            )),
+            NativeType::Array { .. } | NativeType::Bytes | NativeType::Map { .. }
+                if !matches!(length, Some(Length::Fixed(_))) =>
+            {
+                Expr::not(FnCall::method(Expr::ident(&field_name), "is_empty"))
+            }
+            native => Expr::binary_op(
+                Expr::ident(&field_name),
+                Operator::NotEq,
+                default_value(native, length.as_ref()),
+            ),
+        };
+
+        is_default_body.push(
+            IfBuilder::default()
+                .condition(condition)
+                .then_body(return_false())
+                .build()
+                .unwrap()
+                .into(),
+        );
+    }
+
+    is_default_body.push(Item::Return(Some(Literal::Bool(true).into())));
+
+    let is_default = FnDefBuilder::default()
+        .comment("`is_default` returns whether all fields are set to their default values.")
+        .name("is_default")
+        .type_hint(TypeHint::Explicit("bool".to_owned()))
+        .body(is_default_body)
+        .build()
+        .unwrap();
+
+    Ok(vec![
+        Item::FnDef(equals),
+        Item::FnDef(hash),
+        Item::FnDef(is_default),
+    ])
+}
+
+/* ------------------------- Fn: gen_copy_methods -------------------------- */
+
+fn gen_copy_methods(fields: &[Field], options: &Options) -> anyhow::Result<Vec<Item>> {
+    let other_field = |name: &str| Expr::field(Expr::ident("other"), name);
+
+    let check_type: Item = FnCall::function_args(
+        "assert",
+        vec![
+            FnCall::function_args(
+                "is_instance_of",
+                vec![Expr::ident("other"), FnCall::function("get_script")],
+            ),
+            Literal::from("Invalid argument; mismatched message type").into(),
+        ],
+    )
+    .into();
+
+    // clone() -> RefCounted
+    let clone = FnDefBuilder::default()
+        .comment("`clone` returns a deep copy of this message.")
+        .name("clone")
+        .type_hint(TypeHint::Explicit("RefCounted".to_owned()))
+        .body(vec![
+            AssignmentBuilder::default()
+                .declaration(DeclarationKind::Var)
+                .variable("_copy")
+                .type_hint(TypeHint::Explicit("RefCounted".to_owned()))
+                .value(ValueKind::Expr(FnCall::method(
+                    FnCall::function("get_script"),
+                    "new",
+                )))
+                .build()
+                .unwrap()
+                .into(),
+            FnCall::method_args(Expr::ident("_copy"), "copy_from", vec![Expr::ident("self")])
+                .into(),
+            Item::Return(Some(Expr::ident("_copy"))),
+        ])
+        .build()
+        .unwrap();
+
+    // copy_from(other: Object) -> void
+    let mut copy_from_body = vec![check_type.clone()];
+
+    for field in fields {
+        let field_name = escape_keyword(&field.name);
+
+        let value = if is_scalar(&field.encoding.native) {
+            other_field(&field_name)
+        } else {
+            FnCall::method_args(
+                Expr::ident("_Value"),
+                "copy",
+                vec![other_field(&field_name)],
+            )
+        };
+
+        copy_from_body.push(Assignment::reassign(&field_name, value).into());
+    }
+
+    if options.tagged {
+        copy_from_body.push(Assignment::reassign("_presence", other_field("_presence")).into());
+    }
+
+    let copy_from = FnDefBuilder::default()
+        .comment("`copy_from` replaces this message's fields with deep copies of `other`'s.")
+        .name("copy_from")
+        .params(vec![Assignment::param("other", "Object")])
+        .body(copy_from_body)
+        .build()
+        .unwrap();
+
+    // merge_from(other: Object) -> void
+    let mut merge_from_body = vec![check_type];
+
+    for field in fields {
+        let field_name = escape_keyword(&field.name);
+        let other = other_field(&field_name);
+        let length = field_length(field)?;
+
+        let copy = FnCall::method_args(Expr::ident("_Value"), "copy", vec![other.clone()]);
+
+        let stmt: Item = match &field.encoding.native {
+            native if BuiltinType::from_native(native).is_some() => gen_merge_scalar(
+                field,
+                options,
+                length.as_ref(),
+                Assignment::reassign(&field_name, other).into(),
+            ),
+            // NOTE: Appending would change the length of a fixed-length array.
+            NativeType::Array { .. } if matches!(length, Some(Length::Fixed(_))) => {
+                gen_merge_scalar(
+                    field,
+                    options,
+                    length.as_ref(),
+                    Assignment::reassign(&field_name, copy).into(),
+                )
+            }
+            NativeType::Array { .. } => {
+                FnCall::method_args(Expr::ident(&field_name), "append_array", vec![copy]).into()
+            }
+            NativeType::Map { .. } => FnCall::method_args(
+                Expr::ident(&field_name),
+                "merge",
+                vec![copy, Literal::Bool(true).into()],
+            )
+            .into(),
+            NativeType::Message { .. } | NativeType::Enum { .. } => IfBuilder::default()
+                .condition(Expr::binary_op(
+                    other.clone(),
+                    Operator::NotEq,
+                    Expr::null(),
+                ))
+                .then_body(Block::from(vec![
+                    IfBuilder::default()
+                        .condition(Expr::binary_op(
+                            Expr::ident(&field_name),
+                            Operator::Eq,
+                            Expr::null(),
+                        ))
+                        .then_body(Block::from(vec![
+                            Assignment::reassign(
+                                &field_name,
+                                FnCall::method(other.clone(), "clone"),
+                            )
+                            .into(),
+                        ]))
+                        .else_body(Block::from(vec![
+                            FnCall::method_args(
+                                Expr::ident(&field_name),
+                                "merge_from",
+                                vec![other],
+                            )
+                            .into(),
+                        ]))
+                        .build()
+                        .unwrap()
+                        .into(),
+                ]))
+                .build()
+                .unwrap()
+                .into(),
+            NativeType::Bytes => gen_merge_scalar(
+                field,
+                options,
+                length.as_ref(),
+                Assignment::reassign(&field_name, copy).into(),
+            ),
+            _ => gen_merge_scalar(
+                field,
+                options,
+                length.as_ref(),
+                Assignment::reassign(&field_name, other).into(),
+            ),
+        };
+
+        merge_from_body.push(stmt);
+
+        // NOTE: Appending may grow a bounded array past its maximum length.
+        if let (NativeType::Array { .. }, Some(Length::Max(max))) =
+            (&field.encoding.native, &length)
+        {
+            let max = Expr::Literal(Literal::Int(i64::from(*max)));
+
+            merge_from_body.push(
+                IfBuilder::default()
+                    .condition(Expr::binary_op(
+                        FnCall::method(Expr::ident(&field_name), "size"),
+                        Operator::Gt,
+                        max.clone(),
+                    ))
+                    .then_body(Block::from(vec![
+                        FnCall::method_args(Expr::ident(&field_name), "resize", vec![max]).into(),
+                    ]))
+                    .build()
+                    .unwrap()
+                    .into(),
+            );
+        }
+    }
+
+    if options.tagged {
+        merge_from_body.push(
+            Assignment::reassign(
+                "_presence",
+                Expr::binary_op(
+                    Expr::ident("_presence"),
+                    Operator::BitOr,
+                    other_field("_presence"),
+                ),
+            )
+            .into(),
+        );
+    }
+
+    let merge_from = FnDefBuilder::default()
+        .comment(
+            "`merge_from` merges `other` into this message. Set scalar fields overwrite this\n\
+             message's values, repeated fields are appended (and truncated to any maximum\n\
+             length), map entries are inserted, and nested messages are merged recursively.",
+        )
+        .name("merge_from")
+        .params(vec![Assignment::param("other", "Object")])
+        .body(merge_from_body)
+        .build()
+        .unwrap();
+
+    Ok(vec![
+        Item::FnDef(clone),
+        Item::FnDef(copy_from),
+        Item::FnDef(merge_from),
+    ])
+}
+
+/* ------------------------- Fn: gen_json_methods -------------------------- */
+
+fn gen_json_methods(
+    fields: &[Field],
+    options: &Options,
+    instantiated: &HashSet<String>,
+) -> anyhow::Result<Vec<Item>> {
+    // to_dict() -> Dictionary
+    let mut to_dict_body = vec![Assignment::var("_data", Expr::empty_dict()).into()];
+
+    for field in fields {
+        let field_name = escape_keyword(&field.name);
+
+        to_dict_body.push(
+            Assignment::reassign(
+                Expr::index(Expr::ident("_data"), Literal::from(field.name.as_str())),
+                FnCall::method_args(
+                    Expr::ident("_Value"),
+                    "to_json_value",
+                    vec![Expr::ident(&field_name)],
+                ),
+            )
+            .into(),
+        );
+    }
+
+    to_dict_body.push(Item::Return(Some(Expr::ident("_data"))));
+
+    let to_dict = FnDefBuilder::default()
+        .comment(
+            "`to_dict` converts this message into a JSON-compatible `Dictionary` keyed by\n\
+             field name.",
+        )
+        .name("to_dict")
+        .type_hint(TypeHint::Explicit("Dictionary".to_owned()))
+        .body(to_dict_body)
+        .build()
+        .unwrap();
+
+    // from_dict(data: Dictionary) -> Error
+    let mut from_dict_body = Vec::new();
+
+    for field in fields {
+        let field_name = escape_keyword(&field.name);
+        let default_value = field_default_value(field, field_length(field)?.as_ref(), instantiated);
+        from_dict_body.push(Assignment::reassign(&field_name, default_value).into());
+    }
+
+    if options.tagged && !fields.is_empty() {
+        from_dict_body
+            .push(Assignment::reassign("_presence", Expr::Literal(Literal::Int(0))).into());
+    }
+
+    if !fields.is_empty() {
+        from_dict_body.push(
+            Assignment::var("_out", Expr::Literal(Literal::Array(vec![Expr::null()]))).into(),
+        );
+    }
+
+    for (position, field) in fields.iter().enumerate() {
+        let field_name = escape_keyword(&field.name);
+        let key: Expr = Literal::from(field.name.as_str()).into();
+        let out = Expr::index(Expr::ident("_out"), Expr::Literal(Literal::Int(0)));
+
+        let mut body = vec![
+            Assignment::var(
+                "_err",
+                FnCall::method_args(
+                    Expr::ident("_Value"),
+                    "from_json_value",
+                    vec![
+                        Expr::index(Expr::ident("data"), key.clone()),
+                        json_spec(&field.encoding.native),
+                        Expr::ident("_out"),
+                    ],
+                ),
+            )
+            .into(),
+            IfBuilder::default()
+                .condition(Expr::binary_op(
+                    Expr::ident("_err"),
+                    Operator::NotEq,
+                    Expr::ident("OK"),
+                ))
+                .then_body(Block::from(vec![Item::Return(Some(Expr::ident("_err")))]))
+                .build()
+                .unwrap()
+                .into(),
+        ];
+
+        body.push(match &field.encoding.native {
+            NativeType::Array { element } if packed_array_name(&element.native).is_none() => {
+                FnCall::method_args(Expr::ident(&field_name), "assign", vec![out]).into()
+            }
+            NativeType::Map { .. } => {
+                FnCall::method_args(Expr::ident(&field_name), "merge", vec![out]).into()
+            }
+            _ => Assignment::reassign(&field_name, out).into(),
+        });
+
+        if options.tagged {
+            body.push(
+                Assignment::reassign(
+                    "_presence",
+                    Expr::binary_op(
+                        Expr::ident("_presence"),
+                        Operator::BitOr,
+                        Expr::Literal(Literal::Int(codec::presence_mask(position))),
+                    ),
+                )
+                .into(),
+            );
+        }
+
+        from_dict_body.push(
+            IfBuilder::default()
+                .condition(FnCall::method_args(Expr::ident("data"), "has", vec![key]))
+                .then_body(Block::from(body))
+                .build()
+                .unwrap()
+                .into(),
+        );
+    }
+
+    let from_dict = FnDefBuilder::default()
+        .comment(
+            "`from_dict` replaces this message's fields with those in `data`, which has the\n\
+             format produced by `to_dict`. Missing fields are reset to their defaults and\n\
+             unknown keys are ignored.",
+        )
+        .name("from_dict")
+        .params(vec![Assignment::param("data", "Dictionary")])
+        .type_hint(TypeHint::Explicit("Error".to_owned()))
+        .body(from_dict_body)
+        .return_value(Expr::ident("OK"))
+        .build()
+        .unwrap();
+
+    let mut items = vec![Item::FnDef(to_dict), Item::FnDef(from_dict)];
+    items.extend(
+        gen_json_string_methods("message")
+            .into_iter()
+            .map(Item::FnDef),
+    );
+    Ok(items)
+}
+
+/* ---------------------- Fn: gen_json_string_methods ----------------------- */
+
+/// `gen_json_string_methods` generates `to_json` and `from_json` methods, which convert
+/// to and from a JSON string using the type's `to_dict` and `from_dict` methods.
+pub(crate) fn gen_json_string_methods(kind: &str) -> Vec<FnDef> {
+    // to_json() -> String
+    let to_json = FnDefBuilder::default()
+        .comment(format!(
+            "`to_json` converts this {} into a JSON string.",
+            kind
+        ))
+        .name("to_json")
+        .type_hint(TypeHint::Explicit("String".to_owned()))
+        .body(vec![Item::Return(Some(FnCall::method_args(
+            Expr::ident("JSON"),
+            "stringify",
+            vec![
+                FnCall::function("to_dict"),
+                Literal::from("").into(),
+                Literal::Bool(false).into(),
+                Literal::Bool(true).into(),
+            ],
+        )))])
+        .build()
+        .unwrap();
+
+    // from_json(text: String) -> Error
+    let from_json = FnDefBuilder::default()
+        .comment(format!(
+            "`from_json` replaces this {}'s contents with those in the JSON string `text`.",
+            kind
+        ))
+        .name("from_json")
+        .params(vec![Assignment::param("text", "String")])
+        .type_hint(TypeHint::Explicit("Error".to_owned()))
+        .body(vec![
+            AssignmentBuilder::default()
+                .declaration(DeclarationKind::Var)
+                .variable("_data")
+                .type_hint(TypeHint::Explicit("Variant".to_owned()))
+                .value(ValueKind::Expr(FnCall::method_args(
+                    Expr::ident("JSON"),
+                    "parse_string",
+                    vec![Expr::ident("text")],
+                )))
+                .build()
+                .unwrap()
+                .into(),
+            IfBuilder::default()
+                .condition(Expr::not(Expr::binary_op(
+                    Expr::ident("_data"),
+                    Operator::Is,
+                    Expr::ident("Dictionary"),
+                )))
+                .then_body(Block::from(vec![Item::Return(Some(Expr::ident(
+                    "ERR_PARSE_ERROR",
+                )))]))
+                .build()
+                .unwrap()
+                .into(),
+        ])
+        .return_value(FnCall::function_args(
+            "from_dict",
+            vec![Expr::ident("_data")],
+        ))
+        .build()
+        .unwrap();
+
+    vec![to_json, from_json]
+}
+
+/* -------------------------- Fn: gen_merge_scalar -------------------------- */
+
+/// `gen_merge_scalar` wraps `assign` in a check that `other` has a value set for the
+/// (singular) field. Tagged messages track presence explicitly; otherwise a value is
+/// considered set if it differs from the default.
+fn gen_merge_scalar(
+    field: &Field,
+    options: &Options,
+    length: Option<&Length>,
+    assign: Item,
+) -> Item {
+    let field_name = escape_keyword(&field.name);
+    let other = Expr::field(Expr::ident("other"), &field_name);
+
+    let condition = if options.tagged {
+        FnCall::method(Expr::ident("other"), format!("has_{}", field.name))
+    } else if matches!(field.encoding.native, NativeType::Bytes)
+        && !matches!(length, Some(Length::Fixed(_)))
+    {
+        Expr::not(FnCall::method(other, "is_empty"))
+    } else {
+        Expr::binary_op(
+            other,
+            Operator::NotEq,
+            default_value(&field.encoding.native, length),
+        )
+    };
+
+    IfBuilder::default()
+        .condition(condition)
+        .then_body(Block::from(vec![assign]))
         .build()
         .unwrap()
+        .into()
+}
+
+/* ------------------------ Fn: gen_presence_methods ------------------------ */
+
+fn gen_presence_methods(
+    fields: &[Field],
+    instantiated: &HashSet<String>,
+) -> anyhow::Result<Vec<Item>> {
+    let mut items = Vec::new();
+
+    for (position, field) in fields.iter().enumerate() {
+        let field_name = escape_keyword(&field.name);
+        let mask = codec::presence_mask(position);
+        let length = field_length(field)?;
+
+        let is_set = match &field.encoding.native {
+            _ if instantiated.contains(&field.name) => Expr::not(FnCall::method_args(
+                Expr::ident("_Value"),
+                "equals",
+                vec![
+                    Expr::ident(&field_name),
+                    field_default_value(field, length.as_ref(), instantiated),
+                ],
+            )),
+            NativeType::Array { .. } | NativeType::Bytes | NativeType::Map { .. }
+                if !matches!(length, Some(Length::Fixed(_))) =>
+            {
+                Expr::binary_op(
+                    FnCall::method(Expr::ident(&field_name), "size"),
+                    Operator::NotEq,
+                    Expr::Literal(Literal::Int(0)),
+                )
+            }
+            native => Expr::binary_op(
+                Expr::ident(&field_name),
+                Operator::NotEq,
+                default_value(native, length.as_ref()),
+            ),
+        };
+
+        let has = FnDefBuilder::default()
+            .comment(format!(
+                "`has_{}` returns whether `{}` was decoded or differs from its default.",
+                field.name, field_name,
+            ))
+            .name(format!("has_{}", field.name))
+            .type_hint(TypeHint::Explicit("bool".to_owned()))
+            .body(vec![Item::Return(Some(Expr::binary_op(
+                Expr::binary_op(
+                    Expr::binary_op(
+                        Expr::ident("_presence"),
+                        Operator::BitAnd,
+                        Expr::Literal(Literal::Int(mask)),
+                    ),
+                    Operator::NotEq,
+                    Expr::Literal(Literal::Int(0)),
+                ),
+                Operator::Or,
+                is_set,
+            )))])
+            .build()
+            .unwrap();
+
+        let clear = FnDefBuilder::default()
+            .comment(format!(
+                "`clear_{}` resets `{}` to its default and marks it as not decoded.",
+                field.name, field_name,
+            ))
+            .name(format!("clear_{}", field.name))
+            .body(vec![
+                Assignment::reassign(
+                    &field_name,
+                    field_default_value(field, length.as_ref(), instantiated),
+                )
+                .into(),
+                Assignment::reassign(
+                    "_presence",
+                    Expr::binary_op(
+                        Expr::ident("_presence"),
+                        Operator::BitAnd,
+                        Expr::bit_not(Expr::Literal(Literal::Int(mask))),
+                    ),
+                )
+                .into(),
+            ])
+            .build()
+            .unwrap();
+
+        items.push(Item::FnDef(has));
+        items.push(Item::FnDef(clear));
+    }
+
+    Ok(items)
+}
+
+/* ------------------------ Fn: gen_optional_methods ------------------------ */
+
+/// `gen_optional_methods` generates `has_xxx` and `clear_xxx` methods for optional
+/// fields. Tagged messages don't need them, since every field has presence methods.
+fn gen_optional_methods(fields: &[Field]) -> anyhow::Result<Vec<Item>> {
+    let mut items = Vec::new();
+
+    for field in fields {
+        if !FieldDirectives::parse(field.doc.as_deref())?.optional {
+            continue;
+        }
+
+        let field_name = escape_keyword(&field.name);
+
+        let has = FnDefBuilder::default()
+            .comment(format!(
+                "`has_{}` returns whether the optional `{}` is set.",
+                field.name, field_name,
+            ))
+            .name(format!("has_{}", field.name))
+            .type_hint(TypeHint::Explicit("bool".to_owned()))
+            .body(vec![Item::Return(Some(Expr::binary_op(
+                Expr::ident(&field_name),
+                Operator::NotEq,
+                Expr::null(),
+            )))])
+            .build()
+            .unwrap();
+
+        let clear = FnDefBuilder::default()
+            .comment(format!(
+                "`clear_{}` unsets the optional `{}`.",
+                field.name, field_name,
+            ))
+            .name(format!("clear_{}", field.name))
+            .body(vec![Assignment::reassign(&field_name, Expr::null()).into()])
+            .build()
+            .unwrap();
+
+        items.push(Item::FnDef(has));
+        items.push(Item::FnDef(clear));
+    }
+
+    Ok(items)
 }
 
 /* ------------------------- Fn: gen_private_methods ------------------------ */
 
-fn gen_private_methods(fields: &[Field]) -> anyhow::Result<Section> {
+fn gen_private_methods(
+    fields: &[Field],
+    options: &Options,
+    instantiated: &HashSet<String>,
+    lazy: &HashSet<String>,
+) -> anyhow::Result<Section> {
+    let (encode_stmts, decode_stmts) = if options.tagged {
+        (
+            codec::gen_tagged_encode_stmts(fields, lazy)?,
+            codec::gen_tagged_decode_stmts(fields, instantiated, lazy)?,
+        )
+    } else {
+        (
+            gen_encode_stmts(fields, lazy)?,
+            gen_decode_stmts(fields, lazy)?,
+        )
+    };
+
+    // Track nesting depth so that readers can reject deeply-nested data.
+    let enter_message = IfBuilder::default()
+        .condition(Expr::binary_op(
+            FnCall::method(Expr::ident("_reader"), "enter_message"),
+            Operator::NotEq,
+            Expr::ident("OK"),
+        ))
+        .then_body(Block::from(vec![Item::Return(Some(FnCall::method(
+            Expr::ident("_reader"),
+            "get_error",
+        )))]))
+        .build()
+        .unwrap();
+
+    let exit_message: Item = FnCall::method(Expr::ident("_reader"), "exit_message").into();
+
+    let decode_stmts = std::iter::once(enter_message.clone().into())
+        .chain(decode_stmts)
+        .chain(std::iter::once(exit_message.clone()))
+        .collect::<Vec<Item>>();
+
+    let decode_delta_stmts = std::iter::once(enter_message.into())
+        .chain(codec::gen_delta_decode_stmts(fields, options, lazy)?)
+        .chain(std::iter::once(exit_message))
+        .collect::<Vec<Item>>();
+
     let encode = FnDefBuilder::default()
         .comment("`_encode` serializes fields to the writer.")
         .name("_encode")
         .params(vec![Assignment::param("_writer", "_Writer")])
-        .body(
-            fields
-                .iter()
-                .try_fold(Vec::new(), |mut out, f| -> anyhow::Result<Vec<Item>> {
-                    let field_name = escape_keyword(&f.name);
-                    let stmts = codec::gen_encode_stmts(&field_name, &f.encoding)?;
-                    out.extend(stmts);
-                    Ok(out)
-                })?,
-        )
+        .body(encode_stmts)
         .build()
         .unwrap();
 
@@ -153,27 +1295,174 @@ fn gen_private_methods(fields: &[Field]) -> anyhow::Result<Section> {
         .name("_decode")
         .params(vec![Assignment::param("_reader", "_Reader")])
         .type_hint(TypeHint::Explicit("Error".to_string()))
-        .body(
-            fields
-                .iter()
-                .try_fold(Vec::new(), |mut out, f| -> anyhow::Result<Vec<Item>> {
-                    let field_name = escape_keyword(&f.name);
-                    let stmts = codec::gen_decode_stmts(&field_name, &f.encoding)?;
-                    out.extend(stmts);
-                    Ok(out)
-                })?,
+        .body(decode_stmts)
+        .return_value(FnCall::method("_reader", "get_error"))
+        .build()
+        .unwrap();
+
+    let encode_delta = FnDefBuilder::default()
+        .comment("`_encode_delta` serializes fields which differ from `baseline` to the writer.")
+        .name("_encode_delta")
+        .params(vec![
+            Assignment::param("baseline", "Object"),
+            Assignment::param("_writer", "_Writer"),
+        ])
+        .body(codec::gen_delta_encode_stmts(fields, lazy)?)
+        .build()
+        .unwrap();
+
+    let decode_delta = FnDefBuilder::default()
+        .comment(
+            "`_decode_delta` deserializes fields which differ from `baseline` from the reader.",
         )
+        .name("_decode_delta")
+        .params(vec![
+            Assignment::param("baseline", "Object"),
+            Assignment::param("_reader", "_Reader"),
+        ])
+        .type_hint(TypeHint::Explicit("Error".to_string()))
+        .body(decode_delta_stmts)
         .return_value(FnCall::method("_reader", "get_error"))
         .build()
         .unwrap();
 
     Ok(SectionBuilder::default()
         .header("PRIVATE METHODS")
-        .body(vec![Item::FnDef(encode), Item::FnDef(decode)])
+        .body(vec![
+            Item::FnDef(encode),
+            Item::FnDef(decode),
+            Item::FnDef(encode_delta),
+            Item::FnDef(decode_delta),
+        ])
         .build()
         .unwrap())
 }
 
+/* ------------------------- Fn: gen_engine_methods ------------------------- */
+
+/// `gen_engine_methods` generates an `_init` method which loads any lazy dependencies
+/// in `deps` and then default-constructs the `instantiated` fields.
+fn gen_engine_methods(
+    fields: &[Field],
+    instantiated: &HashSet<String>,
+    deps: &[Dependency],
+) -> anyhow::Result<Section> {
+    let mut init_body = gen_lazy_load_stmts(deps);
+
+    for field in fields {
+        if !instantiated.contains(&field.name) {
+            continue;
+        }
+
+        init_body.push(
+            Assignment::reassign(
+                escape_keyword(&field.name),
+                field_default_value(field, field_length(field)?.as_ref(), instantiated),
+            )
+            .into(),
+        );
+    }
+
+    let init = FnDefBuilder::default()
+        .name("_init")
+        .type_hint(TypeHint::Explicit("void".to_string()))
+        .body(init_body)
+        .build()
+        .unwrap();
+
+    Ok(SectionBuilder::default()
+        .header("ENGINE METHODS (OVERRIDES)")
+        .body(vec![Item::FnDef(init)])
+        .build()
+        .unwrap())
+}
+
+/* -------------------------- Fn: gen_encode_stmts -------------------------- */
+
+fn gen_encode_stmts(fields: &[Field], lazy: &HashSet<String>) -> anyhow::Result<Vec<Item>> {
+    fields
+        .iter()
+        .try_fold(Vec::new(), |mut out, f| -> anyhow::Result<Vec<Item>> {
+            let field_name = escape_keyword(&f.name);
+            let directives = FieldDirectives::parse(f.doc.as_deref())?;
+            out.extend(codec::gen_encode_field_stmts(
+                &field_name,
+                &f.encoding,
+                &directives,
+                lazy,
+            )?);
+            Ok(out)
+        })
+}
+
+/* -------------------------- Fn: gen_decode_stmts -------------------------- */
+
+fn gen_decode_stmts(fields: &[Field], lazy: &HashSet<String>) -> anyhow::Result<Vec<Item>> {
+    fields
+        .iter()
+        .try_fold(Vec::new(), |mut out, f| -> anyhow::Result<Vec<Item>> {
+            let field_name = escape_keyword(&f.name);
+            let directives = FieldDirectives::parse(f.doc.as_deref())?;
+            out.extend(codec::gen_decode_field_stmts(
+                &field_name,
+                &f.encoding,
+                &directives,
+                lazy,
+            )?);
+            Ok(out)
+        })
+}
+
+/* ---------------------------- Fn: gen_debugging --------------------------- */
+
+fn gen_debugging(name: &str, fields: &[Field]) -> Section {
+    // Produces `"Name(a=" + _Value.to_debug_string(a) + ", b=" + ... + ")"`.
+    let mut text: Option<Expr> = None;
+    let mut label = format!("{}(", name);
+
+    for field in fields {
+        label.push_str(&format!("{}=", field.name));
+
+        let prefix: Expr = Literal::String(std::mem::take(&mut label)).into();
+        let value = FnCall::method_args(
+            Expr::ident("_Value"),
+            "to_debug_string",
+            vec![Expr::ident(escape_keyword(&field.name))],
+        );
+
+        text = Some(Expr::binary_op(
+            match text {
+                Some(text) => Expr::binary_op(text, Operator::Add, prefix),
+                None => prefix,
+            },
+            Operator::Add,
+            value,
+        ));
+
+        label.push_str(", ");
+    }
+
+    // Close the parentheses, dropping any trailing separator.
+    let suffix: Expr = Literal::String(format!("{})", label.trim_end_matches(", "))).into();
+    let text = match text {
+        Some(text) => Expr::binary_op(text, Operator::Add, suffix),
+        None => suffix,
+    };
+
+    let to_string = FnDefBuilder::default()
+        .name("_to_string")
+        .type_hint(TypeHint::Explicit("String".to_string()))
+        .body(vec![Item::Return(Some(text))])
+        .build()
+        .unwrap();
+
+    SectionBuilder::default()
+        .header("DEBUGGING")
+        .body(vec![Item::FnDef(to_string)])
+        .build()
+        .unwrap()
+}
+
 /* ------------------------------ Fn: gen_types ----------------------------- */
 
 fn gen_types(entry: &TypeEntry) -> Section {
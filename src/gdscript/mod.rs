@@ -1,6 +1,10 @@
 use baproto::{CodeWriter, CodeWriterBuilder, Generator, GeneratorError, GeneratorOutput, Schema};
 
-use crate::gdscript::collect::{TypeKind, collect_package_types};
+use crate::gdscript::builtin::BuiltinType;
+use crate::gdscript::collect::{
+    TypeKind, check_class_names, collect_instantiated_fields, collect_lazy_dependencies,
+    collect_package_types, qualified_name,
+};
 use crate::gdscript::types::pkg_to_path;
 
 /* -------------------------------- Mod: Collect ------------------------------ */
@@ -11,10 +15,23 @@ pub mod collect;
 
 mod ast;
 
+/* ------------------------------- Mod: Builtin ------------------------------- */
+
+mod builtin;
+
 /* -------------------------------- Mod: Codec -------------------------------- */
 
 mod codec;
 
+/* ------------------------------ Mod: Directive ------------------------------ */
+
+mod directive;
+
+/* ------------------------------- Mod: Options ------------------------------- */
+
+mod options;
+pub use options::Options;
+
 /* -------------------------------- Mod: Types -------------------------------- */
 
 mod types;
@@ -31,6 +48,13 @@ mod enumeration;
 
 mod namespace;
 
+/* -------------------------------------------------------------------------- */
+/*                           Const: ROOT_CLASS_NAME                           */
+/* -------------------------------------------------------------------------- */
+
+/// `ROOT_CLASS_NAME` is the global `class_name` of the root namespace.
+const ROOT_CLASS_NAME: &str = "BAProto";
+
 /* -------------------------------------------------------------------------- */
 /*                              Struct: GDScript                              */
 /* -------------------------------------------------------------------------- */
@@ -40,12 +64,20 @@ mod namespace;
 ///
 /// It generates one file per type (message or enum), organized into package
 /// subdirectories with namespace `mod.gd` files.
-#[derive(Clone, Debug)]
-pub struct GDScript;
+#[derive(Clone, Debug, Default)]
+pub struct GDScript {
+    /// `options` configures the generated code.
+    pub options: Options,
+}
 
 /* ----------------------------- Impl: Default -------------------------------- */
 
 impl GDScript {
+    /// `new` creates a new [`GDScript`] generator with the provided options.
+    pub fn new(options: Options) -> Self {
+        Self { options }
+    }
+
     /// `writer` creates a new [`CodeWriter`] suited for GDScript files.
     fn writer() -> CodeWriter {
         CodeWriterBuilder::default()
@@ -69,8 +101,34 @@ impl Generator for GDScript {
 
         let mut output = GeneratorOutput::default();
 
+        // Global class names must be unique across all packages.
+        if self.options.class_names {
+            check_class_names(&schema.packages, &[ROOT_CLASS_NAME])
+                .map_err(|e| GeneratorError::Generation(e.to_string()))?;
+        }
+
+        // Required message fields are constructed unless doing so would recurse.
+        let instantiated = if self.options.instantiate_messages {
+            collect_instantiated_fields(&schema.packages)
+                .map_err(|e| GeneratorError::Generation(e.to_string()))?
+        } else {
+            Default::default()
+        };
+
+        // Dependencies which would create a preload cycle are loaded lazily instead.
+        let lazy = collect_lazy_dependencies(&schema.packages);
+
         // Step 1: Generate type files for each package.
         for pkg in &schema.packages {
+            // Verify that well-known Godot types match their native codec's layout.
+            for msg in &pkg.messages {
+                if let Some(builtin) = BuiltinType::from_descriptor(&msg.descriptor) {
+                    builtin
+                        .validate(msg)
+                        .map_err(|e| GeneratorError::Generation(e.to_string()))?;
+                }
+            }
+
             let entries = collect_package_types(pkg);
             if entries.is_empty() {
                 continue;
@@ -82,13 +140,29 @@ impl Generator for GDScript {
                 let path = format!("{}/{}.gd", pkg_path, entry.file_stem.to_lowercase());
                 let mut cw = GDScript::writer();
 
+                let name = qualified_name(entry.descriptor());
+                let members = instantiated.get(&name).cloned().unwrap_or_default();
+                let lazy_deps = lazy.get(&name).cloned().unwrap_or_default();
+
                 let content = match &entry.kind {
-                    TypeKind::Message(msg) => {
-                        message::generate_message(&mut cw, msg, entry, &pkg.name)
-                    }
-                    TypeKind::Enum(enm) => {
-                        enumeration::generate_enum(&mut cw, enm, entry, &pkg.name)
-                    }
+                    TypeKind::Message(msg) => message::generate_message(
+                        &mut cw,
+                        msg,
+                        entry,
+                        &pkg.name,
+                        &self.options,
+                        &members,
+                        &lazy_deps,
+                    ),
+                    TypeKind::Enum(enm) => enumeration::generate_enum(
+                        &mut cw,
+                        enm,
+                        entry,
+                        &pkg.name,
+                        &self.options,
+                        &members,
+                        &lazy_deps,
+                    ),
                 }
                 .map_err(|e| GeneratorError::Generation(e.to_string()))?;
 
@@ -135,9 +209,15 @@ impl Generator for GDScript {
 
             // Generate mod.gd with both types and subpackages.
             let mut cw = GDScript::writer();
-            let content =
-                namespace::generate_namespace(&mut cw, &pkg_name, None, &entries, &subpackages)
-                    .map_err(|e| GeneratorError::Generation(e.to_string()))?;
+            let content = namespace::generate_namespace(
+                &mut cw,
+                &pkg_name,
+                None,
+                &entries,
+                &subpackages,
+                &[],
+            )
+            .map_err(|e| GeneratorError::Generation(e.to_string()))?;
 
             output.add(format!("{}/mod.gd", pkg_path), content);
         }
@@ -151,10 +231,20 @@ impl Generator for GDScript {
                 .collect();
             root_subpackages.sort();
 
+            // Register every message under a stable type id.
+            let registry = namespace::collect_registry(&schema.packages)
+                .map_err(|e| GeneratorError::Generation(e.to_string()))?;
+
             let mut cw = GDScript::writer();
-            let content =
-                namespace::generate_namespace(&mut cw, "", Some("BAProto"), &[], &root_subpackages)
-                    .map_err(|e| GeneratorError::Generation(e.to_string()))?;
+            let content = namespace::generate_namespace(
+                &mut cw,
+                "",
+                Some(ROOT_CLASS_NAME),
+                &[],
+                &root_subpackages,
+                &registry,
+            )
+            .map_err(|e| GeneratorError::Generation(e.to_string()))?;
 
             output.add("mod.gd".to_string(), content);
         }
@@ -182,7 +272,7 @@ pub(crate) mod tests {
         let schema = Schema { packages: vec![] };
 
         // When: Generating code.
-        let output = GDScript.generate(&schema).unwrap();
+        let output = GDScript::default().generate(&schema).unwrap();
 
         // Then: No files should be generated.
         assert!(output.files.is_empty());
@@ -200,7 +290,7 @@ pub(crate) mod tests {
         };
 
         // When: Generating code.
-        let output = GDScript.generate(&schema).unwrap();
+        let output = GDScript::default().generate(&schema).unwrap();
 
         // Then: Should generate namespace files (test/mod.gd + root mod.gd).
         assert_eq!(output.files.len(), 2);
@@ -231,7 +321,7 @@ pub(crate) mod tests {
         };
 
         // When: Generating code.
-        let output = GDScript.generate(&schema).unwrap();
+        let output = GDScript::default().generate(&schema).unwrap();
 
         // Then: Three files should be generated (message + game/mod.gd + root mod.gd).
         assert_eq!(output.files.len(), 3);
@@ -240,6 +330,47 @@ pub(crate) mod tests {
         assert!(output.files.contains_key(Path::new("mod.gd")));
     }
 
+    #[test]
+    fn test_generate_class_names() {
+        // Given: A schema with a single message.
+        let pkg = PackageName::try_from(vec!["game"]).unwrap();
+        let schema = Schema {
+            packages: vec![Package {
+                name: pkg.clone(),
+                messages: vec![Message {
+                    descriptor: DescriptorBuilder::default()
+                        .package(pkg)
+                        .path(vec!["Player".to_string()])
+                        .build()
+                        .unwrap(),
+                    doc: None,
+                    fields: vec![],
+                    messages: vec![],
+                    enums: vec![],
+                }],
+                enums: vec![],
+            }],
+        };
+
+        // Given: A generator which registers global class names.
+        let generator = GDScript::new(Options {
+            class_names: true,
+            ..Default::default()
+        });
+
+        // When: Generating code.
+        let output = generator.generate(&schema).unwrap();
+
+        // Then: The message registers a package-prefixed class name.
+        let content = output.files.get(Path::new("game/player.gd")).unwrap();
+        assert!(content.contains("class_name GamePlayer\n"));
+
+        // Then: Without the option, no class name is registered.
+        let output = GDScript::default().generate(&schema).unwrap();
+        let content = output.files.get(Path::new("game/player.gd")).unwrap();
+        assert!(!content.contains("class_name"));
+    }
+
     #[test]
     fn test_generate_message_with_fields() {
         // Given: A schema with a message containing fields.
@@ -289,7 +420,7 @@ pub(crate) mod tests {
         };
 
         // When: Generating code.
-        let output = GDScript.generate(&schema).unwrap();
+        let output = GDScript::default().generate(&schema).unwrap();
 
         // Then: The message file should contain the fields.
         let content = output.files.get(Path::new("game/player.gd")).unwrap();
@@ -298,6 +429,125 @@ pub(crate) mod tests {
         assert!(content.contains("## A player entity."));
     }
 
+    #[test]
+    fn test_generate_message_field_name_conflict() {
+        // Given: A schema whose messages declare fields named like generated members.
+        let schema = |names: &[&str]| {
+            let pkg = PackageName::try_from(vec!["game"]).unwrap();
+            Schema {
+                packages: vec![Package {
+                    name: pkg.clone(),
+                    messages: vec![Message {
+                        descriptor: DescriptorBuilder::default()
+                            .package(pkg)
+                            .path(vec!["Player".to_string()])
+                            .build()
+                            .unwrap(),
+                        doc: None,
+                        fields: names
+                            .iter()
+                            .enumerate()
+                            .map(|(index, name)| Field {
+                                name: name.to_string(),
+                                index: index as u32,
+                                encoding: Encoding {
+                                    wire: WireFormat::Bits { count: 1 },
+                                    native: NativeType::Bool,
+                                    transforms: vec![],
+                                    padding_bits: None,
+                                },
+                                doc: None,
+                            })
+                            .collect(),
+                        messages: vec![],
+                        enums: vec![],
+                    }],
+                    enums: vec![],
+                }],
+            }
+        };
+
+        // When: Generating code for fields which clash with generated methods.
+        for name in ["hash", "clone", "to_dict", "_presence"] {
+            let result = GDScript::default().generate(&schema(&[name]));
+
+            // Then: An error names the conflicting field.
+            let err = result.unwrap_err().to_string();
+            assert!(err.contains(&format!("field={}", name)), "{}", err);
+        }
+
+        // When: Generating a tagged message with a field named like a presence method.
+        let tagged = GDScript::new(Options {
+            tagged: true,
+            ..Default::default()
+        });
+        let result = tagged.generate(&schema(&["alive", "has_alive"]));
+
+        // Then: An error is returned.
+        assert!(result.is_err());
+
+        // Then: Without presence methods, the same fields don't conflict.
+        assert!(
+            GDScript::default()
+                .generate(&schema(&["alive", "has_alive"]))
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_generate_message_merge_truncates_bounded_array() {
+        // Given: A schema with a message containing a length-bounded array field.
+        let pkg = PackageName::try_from(vec!["game"]).unwrap();
+        let schema = Schema {
+            packages: vec![Package {
+                name: pkg.clone(),
+                messages: vec![Message {
+                    descriptor: DescriptorBuilder::default()
+                        .package(pkg)
+                        .path(vec!["Player".to_string()])
+                        .build()
+                        .unwrap(),
+                    doc: None,
+                    fields: vec![Field {
+                        name: "tags".to_string(),
+                        index: 0,
+                        encoding: Encoding {
+                            wire: WireFormat::LengthPrefixed { prefix_bits: 32 },
+                            native: NativeType::Array {
+                                element: Box::new(Encoding {
+                                    wire: WireFormat::Bits { count: 8 },
+                                    native: NativeType::Int {
+                                        bits: 8,
+                                        signed: false,
+                                    },
+                                    transforms: vec![],
+                                    padding_bits: None,
+                                }),
+                            },
+                            transforms: vec![],
+                            padding_bits: None,
+                        },
+                        doc: Some("@gdscript length(max = 4)".to_string()),
+                    }],
+                    messages: vec![],
+                    enums: vec![],
+                }],
+                enums: vec![],
+            }],
+        };
+
+        // When: Generating GDScript code.
+        let output = GDScript::default().generate(&schema).unwrap();
+
+        // Then: `merge_from` truncates the appended array to its maximum length.
+        let content = output.files.get(Path::new("game/player.gd")).unwrap();
+        assert!(content.contains(
+            "\ttags.append_array(_Value.copy(other.tags))\n\
+             \tif tags.size() > 4:\n\
+             \t\ttags.resize(4)\n"
+        ));
+    }
+
     #[test]
     fn test_generate_single_enum() {
         // Given: A schema with a single enum.
@@ -339,7 +589,7 @@ pub(crate) mod tests {
         };
 
         // When: Generating code.
-        let output = GDScript.generate(&schema).unwrap();
+        let output = GDScript::default().generate(&schema).unwrap();
 
         // Then: Three files should be generated (enum + game/mod.gd + root mod.gd).
         assert_eq!(output.files.len(), 3);
@@ -399,7 +649,7 @@ pub(crate) mod tests {
         };
 
         // When: Generating code.
-        let output = GDScript.generate(&schema).unwrap();
+        let output = GDScript::default().generate(&schema).unwrap();
 
         // Then: Four files should be generated (2 types + game/mod.gd + root mod.gd).
         assert_eq!(output.files.len(), 4);
@@ -466,7 +716,7 @@ pub(crate) mod tests {
         };
 
         // When: Generating code.
-        let output = GDScript.generate(&schema).unwrap();
+        let output = GDScript::default().generate(&schema).unwrap();
 
         // Then: Four files should be generated (2 types + game/mod.gd + root mod.gd).
         assert_eq!(output.files.len(), 4);
@@ -526,7 +776,7 @@ pub(crate) mod tests {
         };
 
         // When: Generating code.
-        let output = GDScript.generate(&schema).unwrap();
+        let output = GDScript::default().generate(&schema).unwrap();
 
         // Then: Six files should be generated.
         // (2 messages + 2 package mod.gd + 1 intermediate game/mod.gd + 1 root mod.gd).
@@ -550,5 +800,13 @@ pub(crate) mod tests {
         // The root mod.gd should reference the game package.
         let root_mod = output.files.get(Path::new("mod.gd")).unwrap();
         assert!(root_mod.contains("const game := preload(\"./game/mod.gd\")"));
+
+        // The root mod.gd should register both messages by their stable type ids.
+        assert!(root_mod.contains(&format!(
+            "const TYPES := {{{}: game.enemy.Enemy, {}: game.player.Player}}",
+            namespace::type_id("game.enemy.Enemy"),
+            namespace::type_id("game.player.Player"),
+        )));
+        assert!(root_mod.contains("static func read_any(reader: _Reader, out: Array) -> Error:"));
     }
 }
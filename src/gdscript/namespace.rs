@@ -1,7 +1,87 @@
-use baproto::{CodeWriter, StringWriter};
+use std::collections::HashMap;
+
+use baproto::{CodeWriter, Package, StringWriter};
 
 use crate::gdscript::ast::*;
-use crate::gdscript::collect::TypeEntry;
+use crate::gdscript::collect::{TypeEntry, TypeKind, collect_package_types};
+
+/* -------------------------------------------------------------------------- */
+/*                            Struct: RegistryEntry                           */
+/* -------------------------------------------------------------------------- */
+
+/// `RegistryEntry` is a message type included in the root namespace's registry.
+#[derive(Clone, Debug)]
+pub struct RegistryEntry {
+    /// `type_id` is the message's stable type id (see [`type_id`]).
+    pub type_id: u32,
+    /// `name` is the message's fully-qualified name (e.g. "game.Player.Stats").
+    pub name: String,
+    /// `script` is the message's script, relative to the root namespace (e.g.
+    /// "game.Player_Stats").
+    pub script: String,
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                Fn: type_id                                 */
+/* -------------------------------------------------------------------------- */
+
+/// `type_id` derives a message's stable type id from its fully-qualified name, using
+/// the 32-bit FNV-1a hash so that ids don't change as types are added or removed.
+pub fn type_id(name: &str) -> u32 {
+    name.bytes().fold(0x811c9dc5, |hash, b| {
+        (hash ^ b as u32).wrapping_mul(0x01000193)
+    })
+}
+
+/* -------------------------------------------------------------------------- */
+/*                           Fn: collect_registry                             */
+/* -------------------------------------------------------------------------- */
+
+/// `collect_registry` collects a [`RegistryEntry`] for each generated message in the
+/// provided packages, sorted by name. Returns an error if two messages' type ids
+/// collide.
+pub fn collect_registry(packages: &[Package]) -> anyhow::Result<Vec<RegistryEntry>> {
+    let mut registry = Vec::new();
+
+    for pkg in packages {
+        let segments: Vec<String> = pkg.name.iter().map(|s| s.to_string()).collect();
+
+        for entry in collect_package_types(pkg) {
+            let TypeKind::Message(msg) = &entry.kind else {
+                continue;
+            };
+
+            let name = segments
+                .iter()
+                .chain(msg.descriptor.path.iter())
+                .cloned()
+                .collect::<Vec<_>>()
+                .join(".");
+
+            registry.push(RegistryEntry {
+                type_id: type_id(&name),
+                name,
+                script: format!("{}.{}", segments.join("."), entry.file_stem),
+            });
+        }
+    }
+
+    registry.sort_by(|a, b| a.name.cmp(&b.name));
+
+    let mut seen: HashMap<u32, &str> = HashMap::new();
+    for entry in &registry {
+        if let Some(other) = seen.insert(entry.type_id, &entry.name) {
+            anyhow::bail!(
+                "Type id collision between messages: {} and {} ({})",
+                other,
+                entry.name,
+                entry.type_id
+            );
+        }
+    }
+
+    Ok(registry)
+}
 
 /* -------------------------------------------------------------------------- */
 /*                           Fn: generate_namespace                           */
@@ -11,13 +91,15 @@ use crate::gdscript::collect::TypeEntry;
 ///
 /// The namespace file provides preloads for all types in the package and
 /// subpackages, allowing users to import the entire package with a single
-/// preload.
+/// preload. If `registry` is non-empty, the namespace also maps each message's type
+/// id to its script and provides methods for encoding messages of any type.
 pub fn generate_namespace(
     cw: &mut CodeWriter,
     pkg_name: &str,
     class_name: Option<&str>,
     entries: &[TypeEntry],
     subpackages: &[String],
+    registry: &[RegistryEntry],
 ) -> anyhow::Result<String> {
     let mut w = StringWriter::default();
 
@@ -29,14 +111,19 @@ pub fn generate_namespace(
 
     let mut sections = Vec::new();
 
-    if !subpackages.is_empty() {
-        sections.push(gen_dependencies(subpackages));
+    if !subpackages.is_empty() || !registry.is_empty() {
+        sections.push(gen_dependencies(subpackages, !registry.is_empty()));
     }
 
     if !entries.is_empty() {
         sections.push(gen_types(entries));
     }
 
+    if !registry.is_empty() {
+        sections.push(gen_registry(registry));
+        sections.push(gen_registry_methods());
+    }
+
     sections.push(gen_engine_overrides(name));
 
     let script = ScriptBuilder::default()
@@ -55,9 +142,17 @@ pub fn generate_namespace(
 
 /* -------------------------- Fn: gen_dependencies -------------------------- */
 
-fn gen_dependencies(deps: &[String]) -> Section {
+fn gen_dependencies(deps: &[String], runtime: bool) -> Section {
     let mut items = Vec::new();
 
+    // Runtime dependencies.
+    if runtime {
+        let path_runtime = "res://addons/baproto/runtime";
+        items.push(Assignment::preload("_Any", format!("{}/any.gd", path_runtime)).into());
+        items.push(Assignment::preload("_Reader", format!("{}/reader.gd", path_runtime)).into());
+        items.push(Assignment::preload("_Writer", format!("{}/writer.gd", path_runtime)).into());
+    }
+
     for dep in deps {
         let assignment = Assignment::preload(dep.clone(), format!("./{}/mod.gd", dep));
         items.push(assignment.into());
@@ -70,6 +165,110 @@ fn gen_dependencies(deps: &[String]) -> Section {
         .unwrap()
 }
 
+/* ---------------------------- Fn: gen_registry ---------------------------- */
+
+fn gen_registry(registry: &[RegistryEntry]) -> Section {
+    let types = AssignmentBuilder::default()
+        .comment(Some(Comment::from(
+            "`TYPES` maps the stable type id of each message to its script.",
+        )))
+        .declaration(DeclarationKind::Const)
+        .variable("TYPES")
+        .value(ValueKind::Expr(Expr::Literal(Literal::Dict(
+            registry
+                .iter()
+                .map(|entry| {
+                    (
+                        Expr::Literal(Literal::Int(entry.type_id as i64)),
+                        Expr::ident(&entry.script),
+                    )
+                })
+                .collect(),
+        ))))
+        .build()
+        .unwrap();
+
+    SectionBuilder::default()
+        .header("REGISTRY")
+        .body(vec![types.into()])
+        .build()
+        .unwrap()
+}
+
+/* ------------------------ Fn: gen_registry_methods ------------------------ */
+
+fn gen_registry_methods() -> Section {
+    // get_type_id(message: Object) -> int
+    let get_type_id = FnDefBuilder::default()
+        .comment(
+            "`get_type_id` returns the stable type id of `message`, or `-1` if its type isn't\n\
+             registered.",
+        )
+        .is_static(true)
+        .name("get_type_id")
+        .params(vec![Assignment::param("message", "Object")])
+        .type_hint(TypeHint::Explicit("int".to_string()))
+        .body(vec![Item::Return(Some(FnCall::method_args(
+            "_Any",
+            "get_type_id",
+            vec!["TYPES", "message"],
+        )))])
+        .build()
+        .unwrap();
+
+    // write_any(writer: _Writer, message: Object) -> Error
+    let write_any = FnDefBuilder::default()
+        .comment(
+            "`write_any` writes `message`, which may be of any registered type, to `writer`\n\
+             along with its type id.",
+        )
+        .is_static(true)
+        .name("write_any")
+        .params(vec![
+            Assignment::param("writer", "_Writer"),
+            Assignment::param("message", "Object"),
+        ])
+        .type_hint(TypeHint::Explicit("Error".to_string()))
+        .body(vec![Item::Return(Some(FnCall::method_args(
+            "_Any",
+            "write",
+            vec!["writer", "TYPES", "message"],
+        )))])
+        .build()
+        .unwrap();
+
+    // read_any(reader: _Reader, out: Array) -> Error
+    let read_any = FnDefBuilder::default()
+        .comment(
+            "`read_any` reads a message written by `write_any` from `reader` and stores it in\n\
+             `out[0]`. Returns `ERR_UNAVAILABLE` if the message's type isn't registered.",
+        )
+        .is_static(true)
+        .name("read_any")
+        .params(vec![
+            Assignment::param("reader", "_Reader"),
+            Assignment::param("out", "Array"),
+        ])
+        .type_hint(TypeHint::Explicit("Error".to_string()))
+        .body(vec![Item::Return(Some(FnCall::method_args(
+            "_Any",
+            "read",
+            vec!["reader", "TYPES", "out"],
+        )))])
+        .build()
+        .unwrap();
+
+    SectionBuilder::default()
+        .header("PUBLIC METHODS")
+        .body(vec![
+            Item::FnDef(get_type_id),
+            Item::FnDef(write_any),
+            Item::FnDef(read_any),
+        ])
+        .build()
+        .unwrap()
+}
+
 /* ------------------------ Fn: gen_engine_overrides ------------------------ */
 
 fn gen_engine_overrides(name: &str) -> Section {
@@ -113,3 +312,69 @@ fn gen_types(entries: &[TypeEntry]) -> Section {
         .build()
         .unwrap()
 }
+
+/* -------------------------------------------------------------------------- */
+/*                                 Mod: Tests                                 */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+    use baproto::{DescriptorBuilder, Message, PackageName};
+
+    use super::*;
+
+    fn message(pkg: &PackageName, path: &[&str]) -> Message {
+        Message {
+            descriptor: DescriptorBuilder::default()
+                .package(pkg.clone())
+                .path(path.iter().map(|s| s.to_string()).collect::<Vec<_>>())
+                .build()
+                .unwrap(),
+            doc: None,
+            fields: vec![],
+            messages: vec![],
+            enums: vec![],
+        }
+    }
+
+    /* ----------------------------- Tests: type_id ------------------------- */
+
+    #[test]
+    fn test_type_id_matches_fnv1a() {
+        // Given: Names with known 32-bit FNV-1a hashes.
+        let cases = [("", 0x811c9dc5), ("a", 0xe40c292c), ("foobar", 0xbf9cf968)];
+
+        // When: Deriving each name's type id.
+        for (name, expected) in cases {
+            let result = type_id(name);
+
+            // Then: The type id matches the hash.
+            assert_eq!(result, expected, "{}", name);
+        }
+    }
+
+    /* ------------------------ Tests: collect_registry --------------------- */
+
+    #[test]
+    fn test_collect_registry_includes_nested_messages() {
+        // Given: A package with a nested message.
+        let pkg = PackageName::try_from(vec!["game"]).unwrap();
+        let mut player = message(&pkg, &["Player"]);
+        player.messages.push(message(&pkg, &["Player", "Stats"]));
+
+        let packages = vec![Package {
+            name: pkg,
+            messages: vec![player],
+            enums: vec![],
+        }];
+
+        // When: Collecting the registry.
+        let registry = collect_registry(&packages).unwrap();
+
+        // Then: Both messages are registered by their fully-qualified names.
+        let names = registry.iter().map(|e| e.name.as_str()).collect::<Vec<_>>();
+        assert_eq!(names, vec!["game.Player", "game.Player.Stats"]);
+        assert_eq!(registry[1].script, "game.Player_Stats");
+        assert_eq!(registry[1].type_id, type_id("game.Player.Stats"));
+    }
+}
@@ -0,0 +1,37 @@
+/* -------------------------------------------------------------------------- */
+/*                               Struct: Options                              */
+/* -------------------------------------------------------------------------- */
+
+/// `Options` configures how GDScript bindings are generated.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Options {
+    /// `typed_dictionaries` controls whether map fields are declared as typed
+    /// `Dictionary[K, V]` values. Typed dictionaries require Godot 4.4 or later; when
+    /// disabled, map fields are declared as an untyped `Dictionary`.
+    pub typed_dictionaries: bool,
+    /// `tagged` controls whether messages use the tagged wire format. Tagged messages
+    /// prefix each field with its index and size, so that fields can be added, removed,
+    /// or omitted without breaking compatibility with other schema versions.
+    pub tagged: bool,
+    /// `class_names` controls whether each generated type registers a global
+    /// `class_name`, prefixed with its package (e.g. `GamePlayer` for `game.Player`), so
+    /// that it can be used in exported variables, typed signals, and the inspector.
+    pub class_names: bool,
+    /// `instantiate_messages` controls whether required message and enum fields are
+    /// default-constructed when their message is instantiated, rather than left `null`.
+    /// Fields which would recursively construct their own message are left `null`.
+    pub instantiate_messages: bool,
+}
+
+/* ----------------------------- Impl: Default ------------------------------ */
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            typed_dictionaries: true,
+            tagged: false,
+            class_names: false,
+            instantiate_messages: false,
+        }
+    }
+}
@@ -1,24 +1,31 @@
-use baproto::NativeType;
+use baproto::{Descriptor, Field, NativeType};
 use std::collections::HashSet;
 
 use super::ast::*;
+use super::builtin::BuiltinType;
+use super::collect::qualified_name;
+use super::directive::Length;
+use super::options::Options;
 
 /* -------------------------------------------------------------------------- */
 /*                             Struct: Dependency                             */
 /* -------------------------------------------------------------------------- */
 
-/// `Dependency` represents a type dependency requiring a preload statement.
+/// `Dependency` represents a type dependency requiring a preload statement, or a lazy
+/// load if preloading it would create a cycle.
 pub struct Dependency {
     const_name: String,
     preload_path: String,
+    lazy: bool,
 }
 
 impl Dependency {
     /// `new` creates a new dependency.
-    fn new(const_name: String, preload_path: String) -> Self {
+    fn new(const_name: String, preload_path: String, lazy: bool) -> Self {
         Self {
             const_name,
             preload_path,
+            lazy,
         }
     }
 
@@ -31,24 +38,66 @@ impl Dependency {
     pub fn preload_path(&self) -> &str {
         &self.preload_path
     }
+
+    /// `is_lazy` returns whether the dependency is loaded on first use instead of being
+    /// preloaded.
+    pub fn is_lazy(&self) -> bool {
+        self.lazy
+    }
 }
 
 /* -------------------------------------------------------------------------- */
 /*                                Fn: type_name                               */
 /* -------------------------------------------------------------------------- */
 
-/// `type_name` returns the GDScript type name for a native type.
-pub fn type_name(native: &NativeType) -> String {
+/// `type_name` returns the GDScript type name for a native type. Maps are declared as
+/// typed dictionaries unless disabled by `options`. Types which are loaded lazily (see
+/// [`collect_lazy_dependencies`]) can't be used as type hints, so they're named by
+/// their base type, `RefCounted`.
+///
+/// [`collect_lazy_dependencies`]: crate::gdscript::collect::collect_lazy_dependencies
+pub fn type_name(native: &NativeType, options: &Options, lazy: &HashSet<String>) -> String {
+    match native {
+        NativeType::Array { element } => match packed_array_name(&element.native) {
+            Some(packed) => packed.to_string(),
+            None => format!("Array[{}]", element_type_name(&element.native, lazy)),
+        },
+        NativeType::Map { key, value } if options.typed_dictionaries => format!(
+            "Dictionary[{}, {}]",
+            element_type_name(&key.native, lazy),
+            element_type_name(&value.native, lazy)
+        ),
+        _ => element_type_name(native, lazy),
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+/*                            Fn: element_type_name                           */
+/* -------------------------------------------------------------------------- */
+
+/// `element_type_name` returns the GDScript type name for a native type contained in
+/// an array or map. GDScript does not support nested typed collections, so arrays and
+/// maps are named by their untyped container type.
+pub fn element_type_name(native: &NativeType, lazy: &HashSet<String>) -> String {
+    if let Some(builtin) = BuiltinType::from_native(native) {
+        return builtin.name().to_string();
+    }
+
     match native {
         NativeType::Bool => "bool".to_string(),
         NativeType::Int { .. } => "int".to_string(),
         NativeType::Float { .. } => "float".to_string(),
         NativeType::String => "String".to_string(),
         NativeType::Bytes => "PackedByteArray".to_string(),
-        NativeType::Array { element } => {
-            format!("Array[{}]", type_name(&element.native))
-        }
+        NativeType::Array { element } => packed_array_name(&element.native)
+            .unwrap_or("Array")
+            .to_string(),
         NativeType::Map { .. } => "Dictionary".to_string(),
+        NativeType::Message { descriptor } | NativeType::Enum { descriptor }
+            if lazy.contains(&qualified_name(descriptor)) =>
+        {
+            "RefCounted".to_string()
+        }
         NativeType::Message { descriptor } | NativeType::Enum { descriptor } => {
             descriptor.path.join("_")
         }
@@ -59,10 +108,26 @@ pub fn type_name(native: &NativeType) -> String {
 /*                              Fn: default_value                             */
 /* -------------------------------------------------------------------------- */
 
-/// `default_value` returns the GDScript default value for a native type.
-pub fn default_value(native: &NativeType) -> Expr {
+/// `default_value` returns the GDScript default value for a native type. Arrays and
+/// byte arrays with a fixed `length` are pre-sized with default elements (see
+/// `_Value.sized_array`).
+pub fn default_value(native: &NativeType, length: Option<&Length>) -> Expr {
+    if let (Some(Length::Fixed(size)), NativeType::Array { .. } | NativeType::Bytes) =
+        (length, native)
+    {
+        return FnCall::method_args(
+            Expr::ident("_Value"),
+            "sized_array",
+            vec![json_spec(native), Expr::Literal((*size as i64).into())],
+        );
+    }
+
+    if let Some(builtin) = BuiltinType::from_native(native) {
+        return FnCall::function(builtin.name());
+    }
+
     match native {
-        NativeType::Array { .. } => Expr::empty_array(),
+        NativeType::Array { element } => array_default_value(&element.native),
         NativeType::Bool => Literal::Bool(false).into(),
         NativeType::Bytes => FnCall::function("PackedByteArray"),
         NativeType::Enum { .. } => Expr::null(),
@@ -74,6 +139,160 @@ pub fn default_value(native: &NativeType) -> Expr {
     }
 }
 
+/* ------------------------- Fn: field_default_value ------------------------ */
+
+/// `field_default_value` returns the GDScript default value for a field. If the field
+/// is one of the `instantiated` fields (see [`collect_instantiated_fields`]), this is a
+/// new instance of its type.
+///
+/// [`collect_instantiated_fields`]: crate::gdscript::collect::collect_instantiated_fields
+pub fn field_default_value(
+    field: &Field,
+    length: Option<&Length>,
+    instantiated: &HashSet<String>,
+) -> Expr {
+    match &field.encoding.native {
+        NativeType::Message { descriptor } | NativeType::Enum { descriptor }
+            if instantiated.contains(&field.name) =>
+        {
+            FnCall::method(Expr::ident(descriptor.path.join("_")), "new")
+        }
+        native => default_value(native, length),
+    }
+}
+
+/* ------------------------- Fn: array_default_value ------------------------ */
+
+/// `array_default_value` returns the GDScript default value for an array with the
+/// provided element type.
+pub fn array_default_value(element: &NativeType) -> Expr {
+    match packed_array_name(element) {
+        Some(packed) => FnCall::function(packed),
+        None => Expr::empty_array(),
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+/*                            Fn: packed_array_name                           */
+/* -------------------------------------------------------------------------- */
+
+/// `packed_array_name` returns the name of the packed array type used for arrays with
+/// the provided element type, if one exists. Packed arrays store their elements
+/// contiguously, which makes them far more compact than `Array[T]` and allows the
+/// runtime to encode and decode them in bulk.
+pub fn packed_array_name(element: &NativeType) -> Option<&'static str> {
+    if let Some(builtin) = BuiltinType::from_native(element) {
+        return match builtin {
+            BuiltinType::Vector2 => Some("PackedVector2Array"),
+            BuiltinType::Vector3 => Some("PackedVector3Array"),
+            BuiltinType::Color => Some("PackedColorArray"),
+            _ => None,
+        };
+    }
+
+    match element {
+        // NOTE: Unsigned 32-bit values don't fit in a signed 32-bit element.
+        NativeType::Int { bits, signed } if *bits < 32 || (*bits == 32 && *signed) => {
+            Some("PackedInt32Array")
+        }
+        NativeType::Int { .. } => Some("PackedInt64Array"),
+        NativeType::Float { bits: 32 } => Some("PackedFloat32Array"),
+        NativeType::Float { .. } => Some("PackedFloat64Array"),
+        NativeType::String => Some("PackedStringArray"),
+        _ => None,
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                Fn: is_scalar                               */
+/* -------------------------------------------------------------------------- */
+
+/// `is_scalar` returns whether values of the provided type are immutable values, which
+/// can be copied by assignment.
+pub fn is_scalar(native: &NativeType) -> bool {
+    BuiltinType::from_native(native).is_some()
+        || matches!(
+            native,
+            NativeType::Bool
+                | NativeType::Float { .. }
+                | NativeType::Int { .. }
+                | NativeType::String
+        )
+}
+
+/* -------------------------------------------------------------------------- */
+/*                          Fn: requires_deep_equals                          */
+/* -------------------------------------------------------------------------- */
+
+/// `requires_deep_equals` returns whether values of the provided type must be compared
+/// using `_Value.equals` rather than `==`, which compares objects by reference.
+pub fn requires_deep_equals(native: &NativeType) -> bool {
+    if BuiltinType::from_native(native).is_some() {
+        return false;
+    }
+
+    match native {
+        NativeType::Enum { .. } | NativeType::Map { .. } | NativeType::Message { .. } => true,
+        NativeType::Array { element } => requires_deep_equals(&element.native),
+        _ => false,
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+/*                               Fn: json_spec                                */
+/* -------------------------------------------------------------------------- */
+
+/// `json_spec` returns the runtime type specification used by `_Value.from_json_value`
+/// to convert a JSON value into the provided native type. Scalars are described by
+/// their `Variant.Type`, messages and enums by their script, and (non-packed) arrays
+/// and maps by a nested array of specifications.
+pub fn json_spec(native: &NativeType) -> Expr {
+    if let Some(builtin) = BuiltinType::from_native(native) {
+        return Expr::ident(format!("TYPE_{}", builtin.name().to_uppercase()));
+    }
+
+    match native {
+        NativeType::Bool => Expr::ident("TYPE_BOOL"),
+        NativeType::Int { .. } => Expr::ident("TYPE_INT"),
+        NativeType::Float { .. } => Expr::ident("TYPE_FLOAT"),
+        NativeType::String => Expr::ident("TYPE_STRING"),
+        NativeType::Bytes => Expr::ident("TYPE_PACKED_BYTE_ARRAY"),
+        NativeType::Array { element } => match packed_array_name(&element.native) {
+            Some(packed) => Expr::ident(variant_type_name(packed)),
+            None => {
+                Literal::Array(vec![Expr::ident("TYPE_ARRAY"), json_spec(&element.native)]).into()
+            }
+        },
+        NativeType::Map { key, value } => Literal::Array(vec![
+            Expr::ident("TYPE_DICTIONARY"),
+            json_spec(&key.native),
+            json_spec(&value.native),
+        ])
+        .into(),
+        NativeType::Message { descriptor } | NativeType::Enum { descriptor } => {
+            Expr::ident(descriptor.path.join("_"))
+        }
+    }
+}
+
+/* ------------------------- Fn: variant_type_name ------------------------- */
+
+/// `variant_type_name` converts a GDScript type name (e.g. `PackedInt32Array`) into the
+/// name of its `Variant.Type` constant (e.g. `TYPE_PACKED_INT32_ARRAY`).
+fn variant_type_name(name: &str) -> String {
+    let mut out = String::from("TYPE");
+
+    for c in name.chars() {
+        if c.is_ascii_uppercase() {
+            out.push('_');
+        }
+
+        out.push(c.to_ascii_uppercase());
+    }
+
+    out
+}
+
 /* -------------------------------------------------------------------------- */
 /*                               Fn: pkg_to_path                              */
 /* -------------------------------------------------------------------------- */
@@ -83,16 +302,41 @@ pub fn pkg_to_path(pkg: &[String]) -> String {
     pkg.join("/")
 }
 
+/* -------------------------------------------------------------------------- */
+/*                            Fn: global_class_name                           */
+/* -------------------------------------------------------------------------- */
+
+/// `global_class_name` returns the global `class_name` of the type described by
+/// `descriptor`. Each package segment and type name is converted to `PascalCase` and
+/// concatenated, so that `game.Player.Stats` becomes `GamePlayerStats`.
+pub fn global_class_name(descriptor: &Descriptor) -> String {
+    descriptor
+        .package
+        .iter()
+        .chain(descriptor.path.iter())
+        .flat_map(|segment| segment.split('_'))
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect::<String>(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
 /* -------------------------------------------------------------------------- */
 /*                       Fn: collect_field_dependencies                       */
 /* -------------------------------------------------------------------------- */
 
 /// `collect_field_dependencies` collects all external type dependencies from
-/// the fields of a message (message and enum types that need preloads).
+/// the fields of a message (message and enum types that need preloads). Dependencies
+/// on `lazy` types are loaded on first use instead.
 pub fn collect_field_dependencies(
     fields: &[baproto::Field],
     current_pkg: &[String],
     current_file_stem: &str,
+    lazy: &HashSet<String>,
 ) -> Vec<Dependency> {
     let mut seen = HashSet::new();
     let mut deps = Vec::new();
@@ -102,6 +346,7 @@ pub fn collect_field_dependencies(
             &field.encoding.native,
             current_pkg,
             current_file_stem,
+            lazy,
             &mut seen,
             &mut deps,
         );
@@ -115,11 +360,13 @@ pub fn collect_field_dependencies(
 /* -------------------------------------------------------------------------- */
 
 /// `collect_variant_dependencies` collects all external type dependencies from
-/// the variants of an enum (message and enum types that need preloads).
+/// the variants of an enum (message and enum types that need preloads). Dependencies
+/// on `lazy` types are loaded on first use instead.
 pub fn collect_variant_dependencies(
     variants: &[baproto::Variant],
     current_pkg: &[String],
     current_file_stem: &str,
+    lazy: &HashSet<String>,
 ) -> Vec<Dependency> {
     let mut seen = HashSet::new();
     let mut deps = Vec::new();
@@ -131,6 +378,7 @@ pub fn collect_variant_dependencies(
                 &field.encoding.native,
                 current_pkg,
                 current_file_stem,
+                lazy,
                 &mut seen,
                 &mut deps,
             ),
@@ -145,20 +393,36 @@ pub fn collect_variant_dependencies(
 /* -------------------------------------------------------------------------- */
 
 /// `gen_dependencies_section` generates a DEPENDENCIES section with runtime
-/// dependencies and the provided type dependencies.
-pub fn gen_dependencies_section(deps: Vec<Dependency>) -> Section {
+/// dependencies and the provided type dependencies. Lazy dependencies are declared as
+/// static variables, which are set by the statements from [`gen_lazy_load_stmts`].
+pub fn gen_dependencies_section(deps: &[Dependency]) -> Section {
     let mut items = Vec::new();
 
     // Runtime dependencies.
     let path_runtime = "res://addons/baproto/runtime";
+    items.push(Assignment::preload("_Framing", format!("{}/framing.gd", path_runtime)).into());
     items.push(Assignment::preload("_Reader", format!("{}/reader.gd", path_runtime)).into());
+    items.push(Assignment::preload("_Value", format!("{}/value.gd", path_runtime)).into());
     items.push(Assignment::preload("_Writer", format!("{}/writer.gd", path_runtime)).into());
 
     // Type dependencies.
-    for dep in &deps {
+    for dep in deps.iter().filter(|dep| !dep.is_lazy()) {
         items.push(Assignment::preload(dep.const_name(), dep.preload_path()).into());
     }
 
+    for dep in deps.iter().filter(|dep| dep.is_lazy()) {
+        items.push(
+            AssignmentBuilder::default()
+                .declaration(DeclarationKind::StaticVar)
+                .variable(dep.const_name())
+                .type_hint(TypeHint::Explicit("GDScript".to_owned()))
+                .value(Expr::null())
+                .build()
+                .unwrap()
+                .into(),
+        );
+    }
+
     SectionBuilder::default()
         .header("DEPENDENCIES")
         .body(items)
@@ -166,6 +430,52 @@ pub fn gen_dependencies_section(deps: Vec<Dependency>) -> Section {
         .unwrap()
 }
 
+/* -------------------------------------------------------------------------- */
+/*                           Fn: gen_lazy_load_stmts                          */
+/* -------------------------------------------------------------------------- */
+
+/// `gen_lazy_load_stmts` generates statements which load each lazy dependency, relative
+/// to the current script, if it hasn't been loaded yet.
+///
+/// # Generated GDScript
+/// ```gdscript
+/// if Player == null:
+///     Player = load(get_script().resource_path.get_base_dir().path_join("player.gd"))
+/// ```
+pub fn gen_lazy_load_stmts(deps: &[Dependency]) -> Vec<Item> {
+    deps.iter()
+        .filter(|dep| dep.is_lazy())
+        .map(|dep| {
+            let base_dir = FnCall::method(
+                Expr::field(FnCall::function("get_script"), "resource_path"),
+                "get_base_dir",
+            );
+            let path = FnCall::method_args(
+                base_dir,
+                "path_join",
+                vec![Literal::from(dep.preload_path().trim_start_matches("./"))],
+            );
+
+            IfBuilder::default()
+                .condition(Expr::binary_op(
+                    Expr::ident(dep.const_name()),
+                    Operator::Eq,
+                    Expr::null(),
+                ))
+                .then_body(Block::from(vec![
+                    Assignment::reassign(
+                        dep.const_name(),
+                        FnCall::function_args("load", vec![path]),
+                    )
+                    .into(),
+                ]))
+                .build()
+                .unwrap()
+                .into()
+        })
+        .collect()
+}
+
 /* -------------------------------------------------------------------------- */
 /*                       Fn: collect_native_dependencies                      */
 /* -------------------------------------------------------------------------- */
@@ -176,9 +486,15 @@ fn collect_native_dependencies(
     native: &NativeType,
     current_pkg: &[String],
     current_file_stem: &str,
+    lazy: &HashSet<String>,
     seen: &mut HashSet<String>,
     deps: &mut Vec<Dependency>,
 ) {
+    // Built-in Godot types are native to GDScript and need no preload.
+    if BuiltinType::from_native(native).is_some() {
+        return;
+    }
+
     match native {
         NativeType::Message { descriptor } | NativeType::Enum { descriptor } => {
             let file_stem = descriptor.path.join("_");
@@ -192,20 +508,36 @@ fn collect_native_dependencies(
             }
 
             let path = resolve_preload_path(&descriptor.package, &descriptor.path, current_pkg);
-            deps.push(Dependency::new(file_stem, path));
+            let is_lazy = lazy.contains(&qualified_name(descriptor));
+            deps.push(Dependency::new(file_stem, path, is_lazy));
         }
         NativeType::Array { element } => {
             collect_native_dependencies(
                 &element.native,
                 current_pkg,
                 current_file_stem,
+                lazy,
                 seen,
                 deps,
             );
         }
         NativeType::Map { key, value } => {
-            collect_native_dependencies(&key.native, current_pkg, current_file_stem, seen, deps);
-            collect_native_dependencies(&value.native, current_pkg, current_file_stem, seen, deps);
+            collect_native_dependencies(
+                &key.native,
+                current_pkg,
+                current_file_stem,
+                lazy,
+                seen,
+                deps,
+            );
+            collect_native_dependencies(
+                &value.native,
+                current_pkg,
+                current_file_stem,
+                lazy,
+                seen,
+                deps,
+            );
         }
         _ => {}
     }
@@ -308,7 +640,7 @@ mod tests {
         let native = NativeType::Bool;
 
         // When: Getting the type name.
-        let result = type_name(&native);
+        let result = type_name(&native, &Options::default(), &HashSet::new());
 
         // Then: It should be "bool".
         assert_eq!(result, "bool");
@@ -323,7 +655,7 @@ mod tests {
         };
 
         // When: Getting the type name.
-        let result = type_name(&native);
+        let result = type_name(&native, &Options::default(), &HashSet::new());
 
         // Then: It should be "int".
         assert_eq!(result, "int");
@@ -335,7 +667,7 @@ mod tests {
         let native = NativeType::Float { bits: 32 };
 
         // When: Getting the type name.
-        let result = type_name(&native);
+        let result = type_name(&native, &Options::default(), &HashSet::new());
 
         // Then: It should be "float".
         assert_eq!(result, "float");
@@ -347,7 +679,7 @@ mod tests {
         let native = NativeType::String;
 
         // When: Getting the type name.
-        let result = type_name(&native);
+        let result = type_name(&native, &Options::default(), &HashSet::new());
 
         // Then: It should be "String".
         assert_eq!(result, "String");
@@ -359,7 +691,7 @@ mod tests {
         let native = NativeType::Bytes;
 
         // When: Getting the type name.
-        let result = type_name(&native);
+        let result = type_name(&native, &Options::default(), &HashSet::new());
 
         // Then: It should be "PackedByteArray".
         assert_eq!(result, "PackedByteArray");
@@ -381,10 +713,51 @@ mod tests {
         };
 
         // When: Getting the type name.
-        let result = type_name(&native);
+        let result = type_name(&native, &Options::default(), &HashSet::new());
 
-        // Then: It should be "Array[int]".
-        assert_eq!(result, "Array[int]");
+        // Then: It should be "PackedInt32Array".
+        assert_eq!(result, "PackedInt32Array");
+    }
+
+    #[test]
+    fn test_type_name_array_unsigned_32() {
+        // Given: An array of u32s.
+        let native = NativeType::Array {
+            element: Box::new(Encoding {
+                wire: WireFormat::Bits { count: 32 },
+                native: NativeType::Int {
+                    bits: 32,
+                    signed: false,
+                },
+                transforms: vec![],
+                padding_bits: None,
+            }),
+        };
+
+        // When: Getting the type name.
+        let result = type_name(&native, &Options::default(), &HashSet::new());
+
+        // Then: It should be "PackedInt64Array", since u32 values overflow an i32.
+        assert_eq!(result, "PackedInt64Array");
+    }
+
+    #[test]
+    fn test_type_name_array_without_packed_type() {
+        // Given: An array of bools.
+        let native = NativeType::Array {
+            element: Box::new(Encoding {
+                wire: WireFormat::Bits { count: 1 },
+                native: NativeType::Bool,
+                transforms: vec![],
+                padding_bits: None,
+            }),
+        };
+
+        // When: Getting the type name.
+        let result = type_name(&native, &Options::default(), &HashSet::new());
+
+        // Then: It should be "Array[bool]".
+        assert_eq!(result, "Array[bool]");
     }
 
     #[test]
@@ -409,12 +782,78 @@ mod tests {
         };
 
         // When: Getting the type name.
-        let result = type_name(&native);
+        let result = type_name(&native, &Options::default(), &HashSet::new());
+
+        // Then: It should be "Dictionary[String, int]".
+        assert_eq!(result, "Dictionary[String, int]");
+    }
+
+    #[test]
+    fn test_type_name_map_untyped() {
+        // Given: A map type.
+        let native = NativeType::Map {
+            key: Box::new(Encoding {
+                wire: WireFormat::LengthPrefixed { prefix_bits: 16 },
+                native: NativeType::String,
+                transforms: vec![],
+                padding_bits: None,
+            }),
+            value: Box::new(Encoding {
+                wire: WireFormat::Bits { count: 8 },
+                native: NativeType::Bool,
+                transforms: vec![],
+                padding_bits: None,
+            }),
+        };
+
+        // Given: Options which disable typed dictionaries.
+        let options = Options {
+            typed_dictionaries: false,
+            ..Default::default()
+        };
+
+        // When: Getting the type name.
+        let result = type_name(&native, &options, &HashSet::new());
 
         // Then: It should be "Dictionary".
         assert_eq!(result, "Dictionary");
     }
 
+    #[test]
+    fn test_type_name_map_with_array_values() {
+        // Given: A map with array values.
+        let native = NativeType::Map {
+            key: Box::new(Encoding {
+                wire: WireFormat::Bits { count: 32 },
+                native: NativeType::Int {
+                    bits: 32,
+                    signed: false,
+                },
+                transforms: vec![],
+                padding_bits: None,
+            }),
+            value: Box::new(Encoding {
+                wire: WireFormat::LengthPrefixed { prefix_bits: 64 },
+                native: NativeType::Array {
+                    element: Box::new(Encoding {
+                        wire: WireFormat::LengthPrefixed { prefix_bits: 16 },
+                        native: NativeType::String,
+                        transforms: vec![],
+                        padding_bits: None,
+                    }),
+                },
+                transforms: vec![],
+                padding_bits: None,
+            }),
+        };
+
+        // When: Getting the type name.
+        let result = type_name(&native, &Options::default(), &HashSet::new());
+
+        // Then: The nested array uses its packed array type.
+        assert_eq!(result, "Dictionary[int, PackedStringArray]");
+    }
+
     #[test]
     fn test_type_name_enum() {
         // Given: An enum reference type (we can't construct Descriptor directly,
@@ -425,12 +864,43 @@ mod tests {
         };
 
         // When: Getting the type name for an int (which is how enums are represented).
-        let result = type_name(&native);
+        let result = type_name(&native, &Options::default(), &HashSet::new());
 
         // Then: It should be "int".
         assert_eq!(result, "int");
     }
 
+    #[test]
+    fn test_type_name_lazy_message() {
+        // Given: An array of message references.
+        let native = NativeType::Array {
+            element: Box::new(Encoding {
+                wire: WireFormat::LengthPrefixed { prefix_bits: 64 },
+                native: NativeType::Message {
+                    descriptor: baproto::DescriptorBuilder::default()
+                        .package(baproto::PackageName::try_from(vec!["test"]).unwrap())
+                        .path(vec!["Tree".to_string(), "Node".to_string()])
+                        .build()
+                        .unwrap(),
+                },
+                transforms: vec![],
+                padding_bits: None,
+            }),
+        };
+
+        // When: Getting the type name with and without the message being lazy.
+        let lazy = type_name(
+            &native,
+            &Options::default(),
+            &HashSet::from(["test.Tree.Node".into()]),
+        );
+        let preloaded = type_name(&native, &Options::default(), &HashSet::new());
+
+        // Then: Only the lazy message is named by its base type.
+        assert_eq!(lazy, "Array[RefCounted]");
+        assert_eq!(preloaded, "Array[Tree_Node]");
+    }
+
     /* ------------------------ Tests: default_value ------------------------ */
 
     #[test]
@@ -439,7 +909,7 @@ mod tests {
         let native = NativeType::Bool;
 
         // When: Getting the default value.
-        let result = default_value(&native);
+        let result = default_value(&native, None);
 
         // Then: It should be "false".
         assert_eq!(result, Expr::Literal(Literal::Bool(false)));
@@ -454,7 +924,7 @@ mod tests {
         };
 
         // When: Getting the default value.
-        let result = default_value(&native);
+        let result = default_value(&native, None);
 
         // Then: It should be "0".
         assert_eq!(result, Expr::Literal(Literal::Int(0)));
@@ -466,12 +936,164 @@ mod tests {
         let native = NativeType::String;
 
         // When: Getting the default value.
-        let result = default_value(&native);
+        let result = default_value(&native, None);
 
         // Then: It should be empty string literal.
         assert_eq!(result, Expr::Literal(Literal::String(String::new())));
     }
 
+    #[test]
+    fn test_default_value_fixed_array() {
+        // Given: An array of signed 32-bit integers.
+        let native = NativeType::Array {
+            element: Box::new(Encoding {
+                wire: WireFormat::Bits { count: 32 },
+                native: NativeType::Int {
+                    bits: 32,
+                    signed: true,
+                },
+                transforms: vec![],
+                padding_bits: None,
+            }),
+        };
+
+        // When: Getting the default value for a fixed length.
+        let result = default_value(&native, Some(&Length::Fixed(2)));
+
+        // Then: It should be a packed array sized by the runtime.
+        assert_eq!(
+            result,
+            FnCall::method_args(
+                Expr::ident("_Value"),
+                "sized_array",
+                vec![
+                    Expr::ident("TYPE_PACKED_INT32_ARRAY"),
+                    Expr::Literal(2.into())
+                ],
+            )
+        );
+    }
+
+    /* --------------------- Tests: field_default_value --------------------- */
+
+    #[test]
+    fn test_field_default_value_instantiated() {
+        // Given: A message field.
+        let field = Field {
+            name: "stats".to_string(),
+            index: 0,
+            encoding: Encoding {
+                wire: WireFormat::LengthPrefixed { prefix_bits: 64 },
+                native: NativeType::Message {
+                    descriptor: baproto::DescriptorBuilder::default()
+                        .package(baproto::PackageName::try_from(vec!["test"]).unwrap())
+                        .path(vec!["Player".to_string(), "Stats".to_string()])
+                        .build()
+                        .unwrap(),
+                },
+                transforms: vec![],
+                padding_bits: None,
+            },
+            doc: None,
+        };
+
+        // When: Getting the default value with and without instantiation.
+        let instantiated = field_default_value(&field, None, &HashSet::from(["stats".into()]));
+        let uninstantiated = field_default_value(&field, None, &HashSet::new());
+
+        // Then: Only the instantiated field constructs a new message.
+        assert_eq!(
+            instantiated,
+            FnCall::method(Expr::ident("Player_Stats"), "new")
+        );
+        assert_eq!(uninstantiated, Expr::null());
+    }
+
+    /* -------------------------- Tests: json_spec -------------------------- */
+
+    #[test]
+    fn test_json_spec_packed_array() {
+        // Given: An array of signed 32-bit integers.
+        let native = NativeType::Array {
+            element: Box::new(Encoding {
+                wire: WireFormat::Bits { count: 32 },
+                native: NativeType::Int {
+                    bits: 32,
+                    signed: true,
+                },
+                transforms: vec![],
+                padding_bits: None,
+            }),
+        };
+
+        // When: Getting the JSON type specification.
+        let result = json_spec(&native);
+
+        // Then: It should be the packed array's variant type.
+        assert_eq!(result, Expr::ident("TYPE_PACKED_INT32_ARRAY"));
+    }
+
+    #[test]
+    fn test_json_spec_nested_collection() {
+        // Given: A map from strings to arrays of bytes.
+        let bytes = Encoding {
+            wire: WireFormat::LengthPrefixed { prefix_bits: 32 },
+            native: NativeType::Bytes,
+            transforms: vec![],
+            padding_bits: None,
+        };
+        let native = NativeType::Map {
+            key: Box::new(Encoding {
+                wire: WireFormat::LengthPrefixed { prefix_bits: 32 },
+                native: NativeType::String,
+                transforms: vec![],
+                padding_bits: None,
+            }),
+            value: Box::new(Encoding {
+                wire: WireFormat::LengthPrefixed { prefix_bits: 32 },
+                native: NativeType::Array {
+                    element: Box::new(bytes),
+                },
+                transforms: vec![],
+                padding_bits: None,
+            }),
+        };
+
+        // When: Getting the JSON type specification.
+        let result = json_spec(&native);
+
+        // Then: It should describe the nested collection types.
+        assert_eq!(
+            result,
+            Expr::Literal(Literal::Array(vec![
+                Expr::ident("TYPE_DICTIONARY"),
+                Expr::ident("TYPE_STRING"),
+                Expr::Literal(Literal::Array(vec![
+                    Expr::ident("TYPE_ARRAY"),
+                    Expr::ident("TYPE_PACKED_BYTE_ARRAY"),
+                ])),
+            ]))
+        );
+    }
+
+    /* ---------------------- Tests: global_class_name --------------------- */
+
+    #[test]
+    fn test_global_class_name_prefixes_package() {
+        // Given: A nested type within a multi-segment package.
+        let descriptor = baproto::DescriptorBuilder::default()
+            .package(baproto::PackageName::try_from(vec!["my_game", "net"]).unwrap())
+            .path(vec!["Player".to_string(), "Stats".to_string()])
+            .build()
+            .unwrap();
+
+        // When: Getting the global class name.
+        let result = global_class_name(&descriptor);
+
+        // Then: Each segment is converted to PascalCase and concatenated.
+        assert_eq!(result, "MyGameNetPlayerStats");
+    }
+
     /* --------------------- Tests: resolve_preload_path -------------------- */
 
     #[test]